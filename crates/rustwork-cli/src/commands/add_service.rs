@@ -1,12 +1,18 @@
 use anyhow::Result;
 use std::path::Path;
-use tokio::fs;
 
-use super::new::create_service_in_project;
+use super::new::{create_service_in_project, Starter, StarterOptions};
+use super::ports::PortRegistry;
+use super::service::refs::add_member;
+use super::utils::detect_rustwork_services;
 use crate::mcp::common::workspace_root::WorkspaceRoot;
-use crate::templates::create_micro_env;
+use crate::templates::{create_micro_env, output};
 
-pub async fn execute(service_name: &str, project_path: Option<&str>) -> Result<()> {
+pub async fn execute(
+    service_name: &str,
+    project_path: Option<&str>,
+    starter: StarterOptions,
+) -> Result<()> {
     // Detect workspace root
     let current_dir = std::env::current_dir()?;
     let workspace_root = if let Some(path) = project_path {
@@ -68,18 +74,32 @@ pub async fn execute(service_name: &str, project_path: Option<&str>) -> Result<(
         );
     }
 
+    // Charger le starter avant de créer quoi que ce soit
+    let starter = Starter::load(&starter)?;
+
     println!("🔧 Adding service '{}' to the workspace...", service_name);
     println!("   Workspace: {}", workspace_root.path().display());
+    if let Some(starter) = &starter {
+        println!("   Starter: {}", starter.label());
+    }
 
     // Use micro-services template environment
     let env = create_micro_env();
 
-    // Count existing services to determine port
-    let existing_services = count_existing_services(&services_dir).await?;
-    let service_port = 3001 + existing_services as u16;
+    // Unique port from the workspace registry (existing services are registered first)
+    let existing = detect_rustwork_services(workspace_root.path())?;
+    let mut registry = PortRegistry::load(workspace_root.path(), &existing)?;
+    let ports = registry.allocate(&service_name)?;
+    println!("   Port: http {}", ports.http);
 
     // Create the service
-    create_service_in_project(&service_path, &service_name, service_port, &env).await?;
+    create_service_in_project(&service_path, &service_name, ports, &env).await?;
+    if let Some(starter) = &starter {
+        starter
+            .apply(&service_path, &service_name, ports, &env)
+            .await?;
+    }
+    registry.save(workspace_root.path())?;
 
     // Update Backend/Cargo.toml workspace
     let backend_cargo_toml = workspace_root.path().join("Backend/Cargo.toml");
@@ -113,7 +133,7 @@ async fn update_readme(readme_path: &Path, service_name: &str) -> Result<()> {
         return Ok(());
     }
 
-    let content = fs::read_to_string(&readme_path).await?;
+    let content = output::read_to_string(readme_path)?;
 
     // Chercher la section Services et ajouter le nouveau service
     if content.contains("## Services") {
@@ -167,47 +187,18 @@ async fn update_readme(readme_path: &Path, service_name: &str) -> Result<()> {
         }
 
         let new_content = new_lines.join("\n") + "\n";
-        fs::write(&readme_path, new_content).await?;
-        println!("   Updated README.md");
+        output::update(readme_path, &new_content)?;
     }
 
     Ok(())
 }
 
-/// Count existing services in the services directory (excluding 'shared')
-async fn count_existing_services(services_dir: &Path) -> Result<usize> {
-    let mut count = 0;
-    let mut entries = fs::read_dir(services_dir).await?;
-
-    while let Some(entry) = entries.next_entry().await? {
-        let path = entry.path();
-        if path.is_dir() {
-            if let Some(name) = path.file_name().and_then(|n| n.to_str()) {
-                // Skip 'shared' and hidden directories
-                if name != "shared" && !name.starts_with('.') {
-                    count += 1;
-                }
-            }
-        }
-    }
-
-    Ok(count)
-}
-
 /// Update Backend/Cargo.toml to include the new service in workspace members
 async fn update_workspace_cargo_toml(cargo_toml_path: &Path, service_name: &str) -> Result<()> {
-    let content = fs::read_to_string(&cargo_toml_path).await?;
-
-    // Find the members array and add the new service
-    let new_member = format!("    \"services/{}\",", service_name);
+    let content = output::read_to_string(cargo_toml_path)?;
 
-    // Find where to insert (before the closing bracket of members)
-    if let Some(members_end) = content.find("]\n") {
-        let (before, after) = content.split_at(members_end);
-        let new_content = format!("{}\n{}{}", before, new_member, after);
-        fs::write(&cargo_toml_path, new_content).await?;
-        println!("   Updated Backend/Cargo.toml");
-    }
+    let member = format!("services/{}", service_name);
+    output::update(cargo_toml_path, &add_member(&content, &member))?;
 
     Ok(())
 }
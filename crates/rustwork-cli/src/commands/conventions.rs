@@ -1,7 +1,8 @@
 use anyhow::{Context, Result};
-use std::fs;
 use std::path::PathBuf;
 
+use crate::templates::output;
+
 /// Commande `rustwork conventions init`
 /// Génère un fichier `.rustwork/conventions.json` avec une structure de base
 pub fn conventions_init(workspace_root: Option<PathBuf>) -> Result<()> {
@@ -19,16 +20,12 @@ pub fn conventions_init(workspace_root: Option<PathBuf>) -> Result<()> {
         return Ok(());
     }
 
-    // Créer le dossier .rustwork s'il n'existe pas
-    if !rustwork_dir.exists() {
-        fs::create_dir_all(&rustwork_dir).context("Failed to create .rustwork directory")?;
-    }
-
     // Charger le template de conventions projet
     let template_content = include_str!("../../data/conventions/template_project_conventions.json");
 
     // Écrire le fichier
-    fs::write(&conventions_path, template_content).context("Failed to write conventions.json")?;
+    output::create(&conventions_path, template_content)
+        .context("Failed to write conventions.json")?;
 
     println!("✅ Fichier .rustwork/conventions.json créé avec succès !");
     println!();
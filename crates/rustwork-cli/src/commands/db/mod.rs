@@ -0,0 +1,5 @@
+/// Commandes base de données (db)
+mod seed;
+pub(crate) mod seeders;
+
+pub use seed::{execute as seed, SeedOptions};
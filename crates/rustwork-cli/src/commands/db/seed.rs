@@ -0,0 +1,113 @@
+use anyhow::{Context, Result};
+use std::path::{Path, PathBuf};
+use tokio::process::Command;
+
+use super::seeders::write_seeders_mod;
+use crate::commands::utils::detect_rustwork_services;
+use crate::mcp::common::workspace_root::WorkspaceRoot;
+
+/// Options de `rustwork db seed`
+#[derive(Debug, Default)]
+pub struct SeedOptions {
+    pub class: Option<String>,
+    pub service: Option<String>,
+    pub profile: Option<String>,
+    pub project: Option<String>,
+}
+
+/// Point d'entrée de la commande `rustwork db seed`
+///
+/// Régénère `src/seeders/mod.rs` de chaque service puis lance le binaire
+/// du service en mode seed (`cargo run -- seed`), qui résout l'ordre des
+/// dépendances et filtre les seeders selon le profil.
+pub async fn execute(options: SeedOptions) -> Result<()> {
+    let current_dir = std::env::current_dir()?;
+    let workspace_root = if let Some(ref path) = options.project {
+        WorkspaceRoot::detect_with_explicit(&current_dir, Some(&PathBuf::from(path)))?
+    } else {
+        WorkspaceRoot::detect(&current_dir)?
+    };
+    let root = workspace_root.path();
+
+    let mut services = detect_rustwork_services(root)?;
+    if let Some(ref name) = options.service {
+        services.retain(|s| &s.name == name);
+        if services.is_empty() {
+            anyhow::bail!("Service '{}' not found in workspace", name);
+        }
+    }
+    services.retain(|s| s.path.join("src/seeders").is_dir());
+
+    if services.is_empty() {
+        anyhow::bail!(
+            "No seeders found. Create one with: rustwork make seeder <Name> (from a service directory)"
+        );
+    }
+
+    println!("🌱 Seeding databases...");
+
+    let mut seeded = 0;
+    for service in &services {
+        let seeders = write_seeders_mod(&service.path.join("src/seeders")).await?;
+
+        if let Some(ref class) = options.class {
+            if !seeders.contains(class) {
+                continue;
+            }
+        }
+        if seeders.is_empty() {
+            println!("  {}: no seeders", service.name);
+            continue;
+        }
+        if !has_seed_hook(&service.path).await {
+            println!(
+                "⚠️  {}: src/main.rs does not handle seed mode, skipped (see docs/SEEDING.md)",
+                service.name
+            );
+            continue;
+        }
+
+        println!("\n📦 {} ({})", service.name, seeders.join(", "));
+        run_seed(&service.path, &options)
+            .await
+            .with_context(|| format!("Seeding failed for service '{}'", service.name))?;
+        seeded += 1;
+    }
+
+    if seeded == 0 {
+        if let Some(ref class) = options.class {
+            anyhow::bail!("Seeder '{}' not found in any service", class);
+        }
+        println!("\nNo service was seeded.");
+        return Ok(());
+    }
+
+    println!("\n✅ {} service(s) seeded", seeded);
+    Ok(())
+}
+
+async fn has_seed_hook(service_path: &Path) -> bool {
+    tokio::fs::read_to_string(service_path.join("src/main.rs"))
+        .await
+        .is_ok_and(|content| content.contains("SeedCommand"))
+}
+
+async fn run_seed(service_path: &Path, options: &SeedOptions) -> Result<()> {
+    let mut command = Command::new("cargo");
+    command
+        .args(["run", "--quiet", "--", "seed"])
+        .current_dir(service_path);
+    if let Some(ref class) = options.class {
+        command.args(["--class", class]);
+    }
+    if let Some(ref profile) = options.profile {
+        command.env("APP_ENV", profile);
+    }
+
+    let status = command.status().await.context("Failed to run cargo")?;
+    if !status.success() {
+        anyhow::bail!("cargo run exited with {}", status);
+    }
+
+    Ok(())
+}
@@ -0,0 +1,210 @@
+use anyhow::{Context, Result};
+use std::path::Path;
+use tokio::fs;
+
+use crate::templates::output;
+
+/// Fichier d'un dossier de registre (`src/seeders/`, `src/jobs/`) et les types qu'il déclare
+#[derive(Debug, Clone, PartialEq)]
+pub(crate) struct ImplModule {
+    pub module: String,
+    pub types: Vec<String>,
+}
+
+/// Découvre les `impl Seeder for X` dans `src/seeders/*.rs` (hors mod.rs)
+pub(crate) async fn discover_seeders(seeders_dir: &Path) -> Result<Vec<ImplModule>> {
+    discover_impls(seeders_dir, "Seeder").await
+}
+
+/// Découvre les `impl <trait_name> for X` dans `dir/*.rs` (hors mod.rs)
+pub(crate) async fn discover_impls(dir: &Path, trait_name: &str) -> Result<Vec<ImplModule>> {
+    let mut modules = Vec::new();
+
+    let mut entries = fs::read_dir(dir)
+        .await
+        .with_context(|| format!("Failed to read {}", dir.display()))?;
+    while let Some(entry) = entries.next_entry().await? {
+        let path = entry.path();
+        let Some(module) = path.file_stem().and_then(|s| s.to_str()) else {
+            continue;
+        };
+        if module == "mod" || path.extension().and_then(|e| e.to_str()) != Some("rs") {
+            continue;
+        }
+
+        let content = fs::read_to_string(&path).await?;
+        let file = syn::parse_file(&content)
+            .with_context(|| format!("Failed to parse {}", path.display()))?;
+        let types = impl_types(&file, trait_name);
+        if !types.is_empty() {
+            modules.push(ImplModule {
+                module: module.to_string(),
+                types,
+            });
+        }
+    }
+
+    // Ordre stable : les dépendances fixent l'ordre d'exécution, pas les fichiers
+    modules.sort_by(|a, b| a.module.cmp(&b.module));
+    Ok(modules)
+}
+
+fn impl_types(file: &syn::File, trait_name: &str) -> Vec<String> {
+    file.items
+        .iter()
+        .filter_map(|item| match item {
+            syn::Item::Impl(item_impl) => Some(item_impl),
+            _ => None,
+        })
+        .filter(|item_impl| {
+            item_impl
+                .trait_
+                .as_ref()
+                .and_then(|(_, path, _)| path.segments.last())
+                .is_some_and(|segment| segment.ident == trait_name)
+        })
+        .filter_map(|item_impl| match item_impl.self_ty.as_ref() {
+            syn::Type::Path(type_path) => type_path
+                .path
+                .segments
+                .last()
+                .map(|segment| segment.ident.to_string()),
+            _ => None,
+        })
+        .collect()
+}
+
+/// Contenu généré de `src/seeders/mod.rs`
+pub(crate) fn render_seeders_mod(modules: &[ImplModule]) -> String {
+    let mut content = String::from(
+        "//! Seeders of this service\n\
+         //!\n\
+         //! Generated by `rustwork make seeder` and `rustwork db seed`.\n\
+         //! Do not edit by hand: every `impl Seeder for X` in this directory is registered\n\
+         //! (seeders must implement `Default`).\n\
+         \n\
+         use rustwork::SeederRegistry;\n",
+    );
+
+    if !modules.is_empty() {
+        content.push('\n');
+    }
+    for module in modules {
+        content.push_str(&format!("pub mod {};\n", module.module));
+    }
+
+    content.push_str("\npub fn registry() -> SeederRegistry {\n    SeederRegistry::new()");
+    for module in modules {
+        for ty in &module.types {
+            content.push_str(&format!(
+                "\n        .register({}::{}::default())",
+                module.module, ty
+            ));
+        }
+    }
+    content.push_str("\n}\n");
+
+    content
+}
+
+/// Régénère `src/seeders/mod.rs` et retourne les noms des seeders
+pub(crate) async fn write_seeders_mod(seeders_dir: &Path) -> Result<Vec<String>> {
+    let modules = discover_seeders(seeders_dir).await?;
+    let mod_path = seeders_dir.join("mod.rs");
+    let content = render_seeders_mod(&modules);
+
+    output::update(&mod_path, &content)?;
+
+    Ok(modules.into_iter().flat_map(|m| m.types).collect())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use tempfile::TempDir;
+
+    const ROLES: &str = r#"
+use rustwork::{seeding::BoxFuture, AppResult, DatabaseConnection, Seeder};
+
+#[derive(Default)]
+pub struct RolesSeeder;
+
+impl Seeder for RolesSeeder {
+    fn run<'a>(&'a self, _db: &'a DatabaseConnection) -> BoxFuture<'a, AppResult<()>> {
+        Box::pin(async { Ok(()) })
+    }
+}
+
+pub struct NotASeeder;
+
+impl Default for NotASeeder {
+    fn default() -> Self {
+        Self
+    }
+}
+"#;
+
+    #[tokio::test]
+    async fn test_discover_seeders() {
+        let dir = TempDir::new().unwrap();
+        fs::write(dir.path().join("roles_seeder.rs"), ROLES)
+            .await
+            .unwrap();
+        fs::write(
+            dir.path().join("admin_seeder.rs"),
+            "pub struct AdminSeeder;\nimpl rustwork::Seeder for AdminSeeder {}\n",
+        )
+        .await
+        .unwrap();
+        fs::write(dir.path().join("helpers.rs"), "pub fn helper() {}\n")
+            .await
+            .unwrap();
+        fs::write(dir.path().join("mod.rs"), "impl Seeder for Ignored {}\n")
+            .await
+            .unwrap();
+
+        let modules = discover_seeders(dir.path()).await.unwrap();
+        assert_eq!(
+            modules,
+            vec![
+                ImplModule {
+                    module: "admin_seeder".to_string(),
+                    types: vec!["AdminSeeder".to_string()],
+                },
+                ImplModule {
+                    module: "roles_seeder".to_string(),
+                    types: vec!["RolesSeeder".to_string()],
+                },
+            ]
+        );
+    }
+
+    #[test]
+    fn test_render_seeders_mod() {
+        let content = render_seeders_mod(&[ImplModule {
+            module: "roles_seeder".to_string(),
+            types: vec!["RolesSeeder".to_string()],
+        }]);
+        assert!(content.contains("pub mod roles_seeder;\n"));
+        assert!(content.contains(".register(roles_seeder::RolesSeeder::default())"));
+        syn::parse_file(&content).unwrap();
+
+        let empty = render_seeders_mod(&[]);
+        assert!(empty.contains("SeederRegistry::new()\n}"));
+        syn::parse_file(&empty).unwrap();
+    }
+
+    #[tokio::test]
+    async fn test_write_seeders_mod() {
+        let dir = TempDir::new().unwrap();
+        fs::write(dir.path().join("roles_seeder.rs"), ROLES)
+            .await
+            .unwrap();
+
+        let names = write_seeders_mod(dir.path()).await.unwrap();
+        assert_eq!(names, vec!["RolesSeeder"]);
+
+        let content = fs::read_to_string(dir.path().join("mod.rs")).await.unwrap();
+        assert!(content.contains("pub mod roles_seeder;"));
+    }
+}
@@ -0,0 +1,23 @@
+/// Commandes de déploiement (`rustwork deploy`)
+mod generate;
+mod k8s;
+mod services;
+
+use anyhow::Result;
+use minijinja::Environment;
+
+use crate::templates::TemplateContext;
+
+pub use generate::execute as generate;
+pub use k8s::execute as k8s;
+
+/// Dossier des fichiers de déploiement, relatif au workspace
+pub const DEPLOY_DIR: &str = "deploy";
+
+/// Rend un template de déploiement, terminé par un retour à la ligne
+fn render(env: &Environment<'static>, template: &str, context: TemplateContext) -> Result<String> {
+    Ok(format!(
+        "{}\n",
+        env.get_template(template)?.render(context)?
+    ))
+}
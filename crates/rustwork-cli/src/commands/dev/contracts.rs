@@ -0,0 +1,170 @@
+//! Contrats gRPC (`grpc/*.rwk`) modifiés pendant `rustwork dev`
+//!
+//! Le code gRPC du service est régénéré avec le pipeline de `rustwork grpc build`, puis
+//! le service et ceux qui en dépendent sont recompilés et relancés. Un contrat invalide
+//! est signalé avec son contexte : le code généré précédent est conservé.
+
+use std::collections::BTreeSet;
+use std::path::PathBuf;
+
+use super::order::Dependencies;
+use super::watch::canonical;
+use crate::commands::grpc_build;
+use crate::commands::utils::RustworkService;
+
+/// Services dont un contrat `grpc/*.rwk` fait partie de `paths`
+pub fn changed_contracts<'a>(
+    services: &'a [RustworkService],
+    paths: &[PathBuf],
+) -> Vec<&'a RustworkService> {
+    let contract_dirs: BTreeSet<PathBuf> = paths
+        .iter()
+        .filter(|path| path.extension().and_then(|e| e.to_str()) == Some("rwk"))
+        .filter_map(|path| path.parent().map(canonical))
+        .collect();
+
+    services
+        .iter()
+        .filter(|service| contract_dirs.contains(&canonical(&service.path.join("grpc"))))
+        .collect()
+}
+
+/// Régénère le code gRPC des services dont un contrat a changé
+///
+/// Retourne les services à recompiler : ceux dont le code généré a changé, et les
+/// services qui en dépendent.
+pub fn regenerate(
+    services: &[RustworkService],
+    dependencies: &Dependencies,
+    paths: &[PathBuf],
+) -> BTreeSet<String> {
+    let mut rebuild = BTreeSet::new();
+
+    for service in changed_contracts(services, paths) {
+        println!("📜 gRPC contract changed, regenerating {}...", service.name);
+        match grpc_build::build_service(&service.name, &service.path) {
+            Ok(true) => {
+                rebuild.insert(service.name.clone());
+                let dependents: Vec<&String> = dependents(dependencies, &service.name).collect();
+                if !dependents.is_empty() {
+                    let names: Vec<&str> = dependents.iter().map(|s| s.as_str()).collect();
+                    println!(
+                        "🔗 Restarting dependents of {}: {}",
+                        service.name,
+                        names.join(", ")
+                    );
+                }
+                rebuild.extend(dependents.into_iter().cloned());
+            }
+            Ok(false) => println!("   Generated code of {} is unchanged", service.name),
+            Err(e) => eprintln!(
+                "❌ gRPC generation failed for {}: {:#}. Keeping the previous generated code.",
+                service.name, e
+            ),
+        }
+    }
+
+    rebuild
+}
+
+/// Services qui attendent `service` au démarrage (appels gRPC ou `depends_on`)
+fn dependents<'a>(
+    dependencies: &'a Dependencies,
+    service: &'a str,
+) -> impl Iterator<Item = &'a String> {
+    dependencies
+        .iter()
+        .filter(move |(_, deps)| deps.contains(service))
+        .map(|(dependent, _)| dependent)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::fs;
+    use std::path::Path;
+    use tempfile::TempDir;
+
+    const CONTRACT: &str = r#"
+service UserService
+
+rpc GetUser (GetUserRequest) returns (User)
+
+message GetUserRequest {
+  id: uuid
+}
+
+message User {
+  id: uuid
+  email: string
+}
+"#;
+
+    fn service(root: &Path, name: &str) -> RustworkService {
+        let path = root.join(name);
+        fs::create_dir_all(path.join("grpc")).unwrap();
+        fs::create_dir_all(path.join("src")).unwrap();
+        fs::write(
+            path.join("Cargo.toml"),
+            format!(
+                "[package]\nname = \"{}\"\nversion = \"0.1.0\"\nedition = \"2021\"\n\n[dependencies]\n",
+                name
+            ),
+        )
+        .unwrap();
+        RustworkService {
+            name: name.to_string(),
+            path,
+        }
+    }
+
+    #[test]
+    fn test_changed_contracts() {
+        let dir = TempDir::new().unwrap();
+        let services = vec![service(dir.path(), "users"), service(dir.path(), "billing")];
+        let root = canonical(dir.path());
+
+        let changed = |paths: &[&str]| {
+            let paths: Vec<PathBuf> = paths.iter().map(|p| root.join(p)).collect();
+            changed_contracts(&services, &paths)
+                .iter()
+                .map(|s| s.name.clone())
+                .collect::<Vec<_>>()
+        };
+
+        assert_eq!(changed(&["users/grpc/users.rwk"]), vec!["users"]);
+        assert!(changed(&["users/grpc/notes.md", "users/src/main.rs"]).is_empty());
+        assert!(changed(&["users/grpc/nested/users.rwk"]).is_empty());
+    }
+
+    #[test]
+    fn test_regenerate() {
+        let dir = TempDir::new().unwrap();
+        let services = vec![service(dir.path(), "users"), service(dir.path(), "billing")];
+        let contract = services[0].path.join("grpc/users.rwk");
+        let paths = vec![contract.clone()];
+        let mut dependencies = Dependencies::new();
+        dependencies.insert("billing".into(), ["users".to_string()].into());
+
+        fs::write(&contract, CONTRACT).unwrap();
+        let rebuild = regenerate(&services, &dependencies, &paths);
+        assert_eq!(
+            rebuild.into_iter().collect::<Vec<_>>(),
+            vec!["billing", "users"]
+        );
+        let generated = services[0].path.join("src/grpc/user_service.rs");
+        let before = fs::read_to_string(&generated).unwrap();
+
+        // Même code généré : rien à relancer
+        assert!(regenerate(&services, &dependencies, &paths).is_empty());
+
+        // Contrat invalide : code généré conservé
+        fs::write(
+            &contract,
+            "service UserService\n\nrpc GetUser (GetUserRequest\n",
+        )
+        .unwrap();
+        assert!(regenerate(&services, &dependencies, &paths).is_empty());
+        assert_eq!(fs::read_to_string(&generated).unwrap(), before);
+    }
+}
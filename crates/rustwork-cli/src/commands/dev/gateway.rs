@@ -0,0 +1,509 @@
+//! Gateway de développement : un seul port devant tous les services
+//!
+//! `/<service>/...` (ou `dev.gateway_prefix`) est transmis au port du service,
+//! préfixe retiré. Les upgrades WebSocket sont relayés tels quels.
+
+use anyhow::{Context, Result};
+use http_body_util::{combinators::BoxBody, BodyExt, Full};
+use hyper::body::{Bytes, Incoming};
+use hyper::header::{HeaderMap, HeaderValue, CONNECTION, CONTENT_TYPE, UPGRADE};
+use hyper::server::conn::http1;
+use hyper::service::service_fn;
+use hyper::{Request, Response, StatusCode};
+use hyper_util::rt::TokioIo;
+use rustwork::middleware::{incoming_request_id, REQUEST_ID_HEADER};
+use std::net::SocketAddr;
+use std::sync::Arc;
+use std::time::Instant;
+use tokio::net::{TcpListener, TcpStream};
+
+use super::logs::{Level, LogRecord, Logs};
+use super::manifest::DevManifest;
+use super::ports::PortMap;
+use crate::commands::utils::RustworkService;
+
+/// En-têtes propres à une connexion, non transmis
+const HOP_BY_HOP: &[&str] = &[
+    "connection",
+    "keep-alive",
+    "proxy-authenticate",
+    "proxy-authorization",
+    "proxy-connection",
+    "te",
+    "trailer",
+    "transfer-encoding",
+    "upgrade",
+];
+
+type ProxyBody = BoxBody<Bytes, hyper::Error>;
+
+/// Préfixe du gateway et service ciblé
+#[derive(Debug, Clone, PartialEq)]
+pub struct Route {
+    pub prefix: String,
+    pub service: String,
+    pub port: u16,
+}
+
+/// Routes du gateway : `/<service>` ou `dev.gateway_prefix` du manifest
+pub fn routes(services: &[RustworkService], ports: &PortMap) -> Result<Vec<Route>> {
+    let mut routes: Vec<Route> = Vec::new();
+
+    for service in services {
+        let Some(port) = ports.get(&service.name).and_then(|p| p.http) else {
+            println!(
+                "⚠️  No [server] port for {}, not exposed by the gateway",
+                service.name
+            );
+            continue;
+        };
+        let manifest = DevManifest::load(&service.path)?;
+        let prefix = format!(
+            "/{}",
+            manifest
+                .dev
+                .gateway_prefix
+                .as_deref()
+                .unwrap_or(&service.name)
+                .trim_matches('/')
+        );
+
+        if let Some(existing) = routes.iter().find(|r| r.prefix == prefix) {
+            anyhow::bail!(
+                "Gateway prefix '{}' is used by both {} and {}",
+                prefix,
+                existing.service,
+                service.name
+            );
+        }
+        routes.push(Route {
+            prefix,
+            service: service.name.clone(),
+            port,
+        });
+    }
+
+    // Préfixe le plus long d'abord (`/api/users` avant `/api`)
+    routes.sort_by_key(|route| std::cmp::Reverse(route.prefix.len()));
+    Ok(routes)
+}
+
+/// Lance le gateway sur 127.0.0.1:`port` en tâche de fond
+///
+/// Chaque requête est journalisée dans `logs` (service `gateway`) avec son `x-request-id`.
+pub async fn start(routes: Vec<Route>, port: u16, logs: Arc<Logs>) -> Result<()> {
+    let listener = TcpListener::bind(("127.0.0.1", port))
+        .await
+        .with_context(|| format!("Failed to start the dev gateway on port {}", port))?;
+
+    println!("🌐 Dev gateway on http://127.0.0.1:{}", port);
+    for route in &routes {
+        println!(
+            "   {} → {} (port {})",
+            route.prefix, route.service, route.port
+        );
+    }
+    println!();
+
+    tokio::spawn(serve(listener, Arc::new(routes), logs));
+    Ok(())
+}
+
+async fn serve(listener: TcpListener, routes: Arc<Vec<Route>>, logs: Arc<Logs>) {
+    loop {
+        let (stream, client) = match listener.accept().await {
+            Ok(accepted) => accepted,
+            Err(e) => {
+                eprintln!("[gateway] Failed to accept connection: {}", e);
+                continue;
+            }
+        };
+
+        let routes = routes.clone();
+        let logs = logs.clone();
+        tokio::spawn(async move {
+            let service = service_fn(move |req| proxy(req, routes.clone(), logs.clone(), client));
+            let _ = http1::Builder::new()
+                .serve_connection(TokioIo::new(stream), service)
+                .with_upgrades()
+                .await;
+        });
+    }
+}
+
+async fn proxy(
+    mut req: Request<Incoming>,
+    routes: Arc<Vec<Route>>,
+    logs: Arc<Logs>,
+    client: SocketAddr,
+) -> Result<Response<ProxyBody>, hyper::Error> {
+    let started = Instant::now();
+    let method = req.method().clone();
+    let uri = req.uri().clone();
+    let request_id = incoming_request_id(req.headers())
+        .map(str::to_string)
+        .unwrap_or_else(|| uuid::Uuid::new_v4().to_string());
+
+    let (mut response, target) = match match_route(&routes, uri.path()) {
+        Some((route, path)) => {
+            let path_and_query = match uri.query() {
+                Some(query) => format!("{}?{}", path, query),
+                None => path,
+            };
+            let upgrade = is_upgrade(req.headers());
+            prepare_request_headers(
+                req.headers_mut(),
+                &request_id,
+                &route.prefix,
+                client,
+                upgrade,
+            );
+
+            let response = match path_and_query.parse() {
+                Ok(forwarded_uri) => {
+                    *req.uri_mut() = forwarded_uri;
+                    forward(req, route.port, upgrade).await.unwrap_or_else(|e| {
+                        text_response(
+                            StatusCode::BAD_GATEWAY,
+                            format!(
+                                "{} is unavailable (port {}): {:#}",
+                                route.service, route.port, e
+                            ),
+                        )
+                    })
+                }
+                Err(_) => text_response(StatusCode::BAD_REQUEST, "Invalid path".to_string()),
+            };
+            (response, route.service.as_str())
+        }
+        None => {
+            let prefixes: Vec<&str> = routes.iter().map(|r| r.prefix.as_str()).collect();
+            let message = format!(
+                "No service for {}. Gateway prefixes: {}",
+                uri.path(),
+                prefixes.join(", ")
+            );
+            (text_response(StatusCode::NOT_FOUND, message), "-")
+        }
+    };
+
+    if let Ok(value) = HeaderValue::from_str(&request_id) {
+        response.headers_mut().insert(REQUEST_ID_HEADER, value);
+    }
+
+    let status = response.status();
+    let level = if status.is_server_error() {
+        Level::Warn
+    } else {
+        Level::Info
+    };
+    let message = format!(
+        "{} {} → {} {} ({} ms)",
+        method,
+        uri,
+        target,
+        status.as_u16(),
+        started.elapsed().as_millis()
+    );
+    let mut record = LogRecord::new("gateway", Some(level), message);
+    record.request_id = Some(request_id);
+    logs.emit(&record, false);
+
+    Ok(response)
+}
+
+/// Transmet la requête au service ; relaie la connexion après un `101 Switching Protocols`
+async fn forward(
+    mut req: Request<Incoming>,
+    port: u16,
+    upgrade: bool,
+) -> Result<Response<ProxyBody>> {
+    let stream = TcpStream::connect(("127.0.0.1", port)).await?;
+    let (mut sender, connection) =
+        hyper::client::conn::http1::handshake(TokioIo::new(stream)).await?;
+    tokio::spawn(connection.with_upgrades());
+
+    let client_upgrade = upgrade.then(|| hyper::upgrade::on(&mut req));
+    let mut response = sender.send_request(req).await?;
+
+    match client_upgrade {
+        Some(client_upgrade) if response.status() == StatusCode::SWITCHING_PROTOCOLS => {
+            let service_upgrade = hyper::upgrade::on(&mut response);
+            tokio::spawn(async move {
+                if let (Ok(client), Ok(service)) = (client_upgrade.await, service_upgrade.await) {
+                    let _ = tokio::io::copy_bidirectional(
+                        &mut TokioIo::new(client),
+                        &mut TokioIo::new(service),
+                    )
+                    .await;
+                }
+            });
+        }
+        _ => {
+            for name in HOP_BY_HOP {
+                response.headers_mut().remove(*name);
+            }
+        }
+    }
+
+    Ok(response.map(|body| body.boxed()))
+}
+
+/// Route d'un chemin et chemin transmis au service (préfixe retiré)
+fn match_route<'a>(routes: &'a [Route], path: &str) -> Option<(&'a Route, String)> {
+    routes.iter().find_map(|route| {
+        if route.prefix == "/" {
+            return Some((route, path.to_string()));
+        }
+        let rest = path.strip_prefix(&route.prefix)?;
+        if rest.is_empty() {
+            Some((route, "/".to_string()))
+        } else if rest.starts_with('/') {
+            Some((route, rest.to_string()))
+        } else {
+            None
+        }
+    })
+}
+
+fn is_upgrade(headers: &HeaderMap) -> bool {
+    headers.contains_key(UPGRADE)
+        && headers
+            .get_all(CONNECTION)
+            .iter()
+            .filter_map(|value| value.to_str().ok())
+            .flat_map(|value| value.split(','))
+            .any(|token| token.trim().eq_ignore_ascii_case("upgrade"))
+}
+
+fn prepare_request_headers(
+    headers: &mut HeaderMap,
+    request_id: &str,
+    prefix: &str,
+    client: SocketAddr,
+    upgrade: bool,
+) {
+    for name in HOP_BY_HOP {
+        // Un upgrade WebSocket doit garder `Connection` et `Upgrade`
+        if upgrade && matches!(*name, "connection" | "upgrade") {
+            continue;
+        }
+        headers.remove(*name);
+    }
+
+    let forwarded = [
+        (REQUEST_ID_HEADER, request_id.to_string()),
+        ("x-forwarded-for", client.ip().to_string()),
+        ("x-forwarded-prefix", prefix.to_string()),
+    ];
+    for (name, value) in forwarded {
+        if let Ok(value) = HeaderValue::from_str(&value) {
+            headers.insert(name, value);
+        }
+    }
+}
+
+fn text_response(status: StatusCode, message: String) -> Response<ProxyBody> {
+    let body = Full::new(Bytes::from(message))
+        .map_err(|never| match never {})
+        .boxed();
+    let mut response = Response::new(body);
+    *response.status_mut() = status;
+    response.headers_mut().insert(
+        CONTENT_TYPE,
+        HeaderValue::from_static("text/plain; charset=utf-8"),
+    );
+    response
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::commands::dev::logs::LogOptions;
+    use crate::commands::dev::ports::DevPorts;
+    use tokio::io::{AsyncReadExt, AsyncWriteExt};
+    use tokio::sync::mpsc;
+
+    fn route(prefix: &str, service: &str, port: u16) -> Route {
+        Route {
+            prefix: prefix.to_string(),
+            service: service.to_string(),
+            port,
+        }
+    }
+
+    #[test]
+    fn test_match_route() {
+        let routes = vec![
+            route("/api/users", "users", 3002),
+            route("/api", "api", 3001),
+        ];
+        let matched = |path: &str| {
+            match_route(&routes, path).map(|(route, path)| (route.service.as_str(), path))
+        };
+
+        assert_eq!(matched("/api/users/42"), Some(("users", "/42".to_string())));
+        assert_eq!(matched("/api/users"), Some(("users", "/".to_string())));
+        assert_eq!(
+            matched("/api/usersettings"),
+            Some(("api", "/usersettings".to_string()))
+        );
+        assert_eq!(matched("/other"), None);
+
+        let root = vec![route("/", "web", 3000)];
+        assert_eq!(
+            match_route(&root, "/assets/app.js").map(|(_, path)| path),
+            Some("/assets/app.js".to_string())
+        );
+    }
+
+    #[test]
+    fn test_routes_from_manifests() {
+        let dir = tempfile::TempDir::new().unwrap();
+        let mut ports = PortMap::new();
+        for (name, port, manifest) in [
+            ("auth", 3001, r#"{"type": "service"}"#),
+            (
+                "users",
+                3002,
+                r#"{"dev": {"gateway_prefix": "/api/users/"}}"#,
+            ),
+        ] {
+            let path = dir.path().join(name);
+            std::fs::create_dir_all(path.join(".rustwork")).unwrap();
+            std::fs::write(path.join(".rustwork/manifest.json"), manifest).unwrap();
+            ports.insert(
+                name.to_string(),
+                DevPorts {
+                    http: Some(port),
+                    ..Default::default()
+                },
+            );
+        }
+        let services: Vec<RustworkService> = ["auth", "users"]
+            .iter()
+            .map(|name| RustworkService {
+                name: name.to_string(),
+                path: dir.path().join(name),
+            })
+            .collect();
+
+        assert_eq!(
+            routes(&services, &ports).unwrap(),
+            vec![
+                route("/api/users", "users", 3002),
+                route("/auth", "auth", 3001)
+            ]
+        );
+
+        std::fs::write(
+            dir.path().join("users/.rustwork/manifest.json"),
+            r#"{"dev": {"gateway_prefix": "auth"}}"#,
+        )
+        .unwrap();
+        assert!(routes(&services, &ports).is_err());
+    }
+
+    /// Service minimal : renvoie la requête reçue sur `requests`, répond 200
+    /// ou passe en écho après un upgrade WebSocket
+    async fn backend() -> (u16, mpsc::UnboundedReceiver<String>) {
+        let listener = TcpListener::bind("127.0.0.1:0").await.unwrap();
+        let port = listener.local_addr().unwrap().port();
+        let (tx, rx) = mpsc::unbounded_channel();
+
+        tokio::spawn(async move {
+            while let Ok((mut stream, _)) = listener.accept().await {
+                let tx = tx.clone();
+                tokio::spawn(async move {
+                    let mut buffer = vec![0u8; 4096];
+                    let n = stream.read(&mut buffer).await.unwrap();
+                    let request = String::from_utf8_lossy(&buffer[..n]).to_string();
+                    let upgrade = request.to_lowercase().contains("upgrade: websocket");
+                    tx.send(request).unwrap();
+
+                    if upgrade {
+                        stream
+                            .write_all(b"HTTP/1.1 101 Switching Protocols\r\nConnection: upgrade\r\nUpgrade: websocket\r\n\r\n")
+                            .await
+                            .unwrap();
+                        let n = stream.read(&mut buffer).await.unwrap();
+                        stream.write_all(&buffer[..n]).await.unwrap();
+                    } else {
+                        stream
+                            .write_all(b"HTTP/1.1 200 OK\r\nContent-Length: 2\r\nConnection: close\r\n\r\nok")
+                            .await
+                            .unwrap();
+                    }
+                });
+            }
+        });
+
+        (port, rx)
+    }
+
+    async fn gateway(routes: Vec<Route>) -> u16 {
+        let listener = TcpListener::bind("127.0.0.1:0").await.unwrap();
+        let port = listener.local_addr().unwrap().port();
+        let logs = Logs::new(LogOptions::default(), true).unwrap();
+        tokio::spawn(serve(listener, Arc::new(routes), Arc::new(logs)));
+        port
+    }
+
+    #[tokio::test]
+    async fn test_proxy_request() {
+        let (backend_port, mut requests) = backend().await;
+        let port = gateway(vec![route("/users", "users", backend_port)]).await;
+
+        let mut stream = TcpStream::connect(("127.0.0.1", port)).await.unwrap();
+        stream
+            .write_all(b"GET /users/health?full=1 HTTP/1.1\r\nHost: localhost\r\nConnection: close\r\n\r\n")
+            .await
+            .unwrap();
+        let mut response = String::new();
+        stream.read_to_string(&mut response).await.unwrap();
+
+        assert!(response.starts_with("HTTP/1.1 200 OK"));
+        assert!(response.contains("x-request-id: "));
+        assert!(response.ends_with("ok"));
+
+        let forwarded = requests.recv().await.unwrap();
+        assert!(forwarded.starts_with("GET /health?full=1 HTTP/1.1"));
+        assert!(forwarded.contains("x-request-id: "));
+        assert!(forwarded.contains("x-forwarded-prefix: /users"));
+
+        // Service inconnu
+        let mut stream = TcpStream::connect(("127.0.0.1", port)).await.unwrap();
+        stream
+            .write_all(b"GET /billing HTTP/1.1\r\nHost: localhost\r\nx-request-id: abc\r\nConnection: close\r\n\r\n")
+            .await
+            .unwrap();
+        let mut response = String::new();
+        stream.read_to_string(&mut response).await.unwrap();
+        assert!(response.starts_with("HTTP/1.1 404"));
+        assert!(response.contains("x-request-id: abc"));
+    }
+
+    #[tokio::test]
+    async fn test_proxy_websocket_upgrade() {
+        let (backend_port, mut requests) = backend().await;
+        let port = gateway(vec![route("/chat", "chat", backend_port)]).await;
+
+        let mut stream = TcpStream::connect(("127.0.0.1", port)).await.unwrap();
+        stream
+            .write_all(b"GET /chat/ws HTTP/1.1\r\nHost: localhost\r\nConnection: Upgrade\r\nUpgrade: websocket\r\n\r\n")
+            .await
+            .unwrap();
+
+        let mut buffer = vec![0u8; 1024];
+        let n = stream.read(&mut buffer).await.unwrap();
+        assert!(String::from_utf8_lossy(&buffer[..n]).starts_with("HTTP/1.1 101"));
+
+        let forwarded = requests.recv().await.unwrap().to_lowercase();
+        assert!(forwarded.starts_with("get /ws http/1.1"));
+        assert!(forwarded.contains("upgrade: websocket"));
+
+        stream.write_all(b"ping").await.unwrap();
+        let n = stream.read(&mut buffer).await.unwrap();
+        assert_eq!(&buffer[..n], b"ping");
+    }
+}
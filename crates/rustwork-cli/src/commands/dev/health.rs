@@ -0,0 +1,95 @@
+//! Attente de la route de santé d'un service
+
+use std::io::{Read, Write};
+use std::net::{SocketAddr, TcpStream};
+use std::thread;
+use std::time::{Duration, Instant};
+
+/// Délai maximal d'une requête de santé
+const REQUEST_TIMEOUT: Duration = Duration::from_secs(2);
+
+/// Intervalle entre deux tentatives
+const POLL_INTERVAL: Duration = Duration::from_millis(250);
+
+/// Attend une réponse 2xx sur `GET path` ; `false` si `timeout` est dépassé
+pub fn wait_healthy(port: u16, path: &str, timeout: Duration) -> bool {
+    let deadline = Instant::now() + timeout;
+    let addr = SocketAddr::from(([127, 0, 0, 1], port));
+
+    loop {
+        if is_healthy(addr, path) {
+            return true;
+        }
+        if Instant::now() >= deadline {
+            return false;
+        }
+        thread::sleep(POLL_INTERVAL);
+    }
+}
+
+fn is_healthy(addr: SocketAddr, path: &str) -> bool {
+    let Ok(mut stream) = TcpStream::connect_timeout(&addr, REQUEST_TIMEOUT) else {
+        return false;
+    };
+    let _ = stream.set_read_timeout(Some(REQUEST_TIMEOUT));
+    let _ = stream.set_write_timeout(Some(REQUEST_TIMEOUT));
+
+    let request = format!(
+        "GET {} HTTP/1.1\r\nHost: {}\r\nConnection: close\r\n\r\n",
+        path, addr
+    );
+    if stream.write_all(request.as_bytes()).is_err() {
+        return false;
+    }
+
+    // Ligne de statut : "HTTP/1.1 200 OK"
+    let mut head = [0u8; 12];
+    if stream.read_exact(&mut head).is_err() {
+        return false;
+    }
+    String::from_utf8_lossy(&head)
+        .split_whitespace()
+        .nth(1)
+        .is_some_and(|status| status.starts_with('2'))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::net::TcpListener;
+
+    fn serve(status: &'static str) -> u16 {
+        let listener = TcpListener::bind("127.0.0.1:0").unwrap();
+        let port = listener.local_addr().unwrap().port();
+        thread::spawn(move || {
+            for mut stream in listener.incoming().flatten() {
+                let mut buffer = [0u8; 512];
+                let _ = stream.read(&mut buffer);
+                let _ = stream.write_all(format!("HTTP/1.1 {}\r\n\r\n", status).as_bytes());
+            }
+        });
+        port
+    }
+
+    #[test]
+    fn test_wait_healthy() {
+        assert!(wait_healthy(
+            serve("200 OK"),
+            "/health",
+            Duration::from_secs(1)
+        ));
+        assert!(!wait_healthy(
+            serve("503 Service Unavailable"),
+            "/health",
+            Duration::from_millis(300)
+        ));
+
+        // Port fermé
+        let port = TcpListener::bind("127.0.0.1:0")
+            .unwrap()
+            .local_addr()
+            .unwrap()
+            .port();
+        assert!(!wait_healthy(port, "/health", Duration::from_millis(300)));
+    }
+}
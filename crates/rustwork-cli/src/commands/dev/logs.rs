@@ -0,0 +1,424 @@
+//! Agrégation des logs de `rustwork dev`
+//!
+//! Les services sont lancés avec `RUSTWORK_LOG_FORMAT=json` : chaque ligne JSON de
+//! `tracing_subscriber` est décodée (niveau, cible, message, champs, `request_id` des
+//! spans), filtrée par `--log-level` / `--grep` et colorée par niveau et par service.
+//! Le fichier `--log-file` (JSONL, sans filtre) reçoit chaque ligne décodée, réécrite
+//! au format `LogRecord` avec le nom du service. Les autres lignes (panics, `println!`,
+//! format texte) sont affichées sans décodage et enregistrées comme simple message.
+
+use anyhow::{Context, Result};
+use serde::Serialize;
+use serde_json::{Map, Value};
+use std::fmt;
+use std::fs::{File, OpenOptions};
+use std::io::{IsTerminal, Write};
+use std::path::PathBuf;
+use std::str::FromStr;
+use std::sync::Mutex;
+
+/// Couleurs attribuées aux services et aux request ids
+const PALETTE: &[&str] = &["36", "35", "34", "33", "32", "96", "95", "94", "93", "92"];
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Serialize)]
+#[serde(rename_all = "UPPERCASE")]
+pub enum Level {
+    Trace,
+    Debug,
+    Info,
+    Warn,
+    Error,
+}
+
+impl Level {
+    fn color(self) -> &'static str {
+        match self {
+            Level::Trace => "90",
+            Level::Debug => "34",
+            Level::Info => "32",
+            Level::Warn => "33",
+            Level::Error => "1;31",
+        }
+    }
+}
+
+impl FromStr for Level {
+    type Err = anyhow::Error;
+
+    fn from_str(s: &str) -> Result<Self> {
+        match s.to_ascii_lowercase().as_str() {
+            "trace" => Ok(Level::Trace),
+            "debug" => Ok(Level::Debug),
+            "info" => Ok(Level::Info),
+            "warn" | "warning" => Ok(Level::Warn),
+            "error" => Ok(Level::Error),
+            _ => anyhow::bail!(
+                "Invalid log level '{}' (expected trace, debug, info, warn or error)",
+                s
+            ),
+        }
+    }
+}
+
+impl fmt::Display for Level {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let label = match self {
+            Level::Trace => "TRACE",
+            Level::Debug => "DEBUG",
+            Level::Info => "INFO",
+            Level::Warn => "WARN",
+            Level::Error => "ERROR",
+        };
+        f.pad(label)
+    }
+}
+
+/// Options `--log-level`, `--grep` et `--log-file` de `rustwork dev`
+#[derive(Debug, Clone, Default)]
+pub struct LogOptions {
+    /// Niveau minimal affiché (les lignes non structurées sont toujours affichées)
+    pub level: Option<Level>,
+    /// Texte recherché, sans tenir compte de la casse
+    pub grep: Option<String>,
+    /// Fichier JSONL qui reçoit toutes les lignes
+    pub file: Option<PathBuf>,
+}
+
+/// Ligne de log d'un service
+#[derive(Debug, Clone, PartialEq, Serialize)]
+pub struct LogRecord {
+    pub timestamp: String,
+    pub service: String,
+    pub level: Option<Level>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub target: Option<String>,
+    pub message: String,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub request_id: Option<String>,
+    #[serde(skip_serializing_if = "Map::is_empty")]
+    pub fields: Map<String, Value>,
+}
+
+impl LogRecord {
+    pub fn new(service: &str, level: Option<Level>, message: String) -> Self {
+        Self {
+            timestamp: chrono::Utc::now().to_rfc3339_opts(chrono::SecondsFormat::Micros, true),
+            service: service.to_string(),
+            level,
+            target: None,
+            message,
+            request_id: None,
+            fields: Map::new(),
+        }
+    }
+
+    /// Décode une ligne JSON de `tracing_subscriber` (sinon : message brut, sans niveau)
+    pub fn parse(service: &str, line: &str) -> Self {
+        let Ok(Value::Object(mut json)) = serde_json::from_str::<Value>(line) else {
+            return Self::new(service, None, line.to_string());
+        };
+        let Some(level) = json
+            .get("level")
+            .and_then(Value::as_str)
+            .and_then(|l| l.parse().ok())
+        else {
+            return Self::new(service, None, line.to_string());
+        };
+
+        let mut record = Self::new(service, Some(level), String::new());
+        if let Some(Value::String(timestamp)) = json.remove("timestamp") {
+            record.timestamp = timestamp;
+        }
+        if let Some(Value::String(target)) = json.remove("target") {
+            record.target = Some(target);
+        }
+        // `span` : span courant, `spans` : tous les spans ouverts (le plus externe d'abord)
+        let spans = json.remove("spans");
+        let span = json.remove("span");
+        record.request_id = span
+            .iter()
+            .chain(
+                spans
+                    .as_ref()
+                    .and_then(Value::as_array)
+                    .into_iter()
+                    .flatten(),
+            )
+            .find_map(|s| s.get("request_id").and_then(Value::as_str))
+            .map(str::to_string);
+        json.remove("level");
+        json.remove("threadName");
+        json.remove("threadId");
+
+        // Champs de l'évènement : à plat (`flatten_event`) ou sous `fields`
+        let mut fields = match json.remove("fields") {
+            Some(Value::Object(fields)) => fields,
+            _ => Map::new(),
+        };
+        fields.extend(json);
+        if let Some(message) = fields.remove("message") {
+            record.message = match message {
+                Value::String(message) => message,
+                other => other.to_string(),
+            };
+        }
+        if record.request_id.is_none() {
+            if let Some(Value::String(id)) = fields.remove("request_id") {
+                record.request_id = Some(id);
+            }
+        }
+        record.fields = fields;
+        record
+    }
+
+    fn fields_text(&self) -> String {
+        self.fields
+            .iter()
+            .map(|(key, value)| match value {
+                Value::String(s) => format!("{}={}", key, s),
+                other => format!("{}={}", key, other),
+            })
+            .collect::<Vec<_>>()
+            .join(" ")
+    }
+}
+
+/// Sortie commune des logs des services et du gateway
+pub struct Logs {
+    options: LogOptions,
+    grep: Option<String>,
+    /// Préfixe `[service]` (plusieurs services ou gateway)
+    prefixed: bool,
+    color: bool,
+    file: Option<Mutex<File>>,
+}
+
+impl Logs {
+    pub fn new(options: LogOptions, prefixed: bool) -> Result<Self> {
+        let file = match &options.file {
+            Some(path) => {
+                if let Some(parent) = path.parent().filter(|p| !p.as_os_str().is_empty()) {
+                    std::fs::create_dir_all(parent)?;
+                }
+                let file = OpenOptions::new()
+                    .create(true)
+                    .append(true)
+                    .open(path)
+                    .with_context(|| format!("Failed to open log file {}", path.display()))?;
+                Some(Mutex::new(file))
+            }
+            None => None,
+        };
+
+        Ok(Self {
+            grep: options.grep.as_ref().map(|g| g.to_lowercase()),
+            options,
+            prefixed,
+            color: std::io::stdout().is_terminal() && std::env::var_os("NO_COLOR").is_none(),
+            file,
+        })
+    }
+
+    pub fn prefixed(&self) -> bool {
+        self.prefixed
+    }
+
+    /// Ligne brute émise par un service
+    pub fn line(&self, service: &str, line: &str, stderr: bool) {
+        self.emit(&LogRecord::parse(service, line), stderr);
+    }
+
+    /// Enregistre la ligne dans le fichier, puis l'affiche si elle passe les filtres
+    pub fn emit(&self, record: &LogRecord, stderr: bool) {
+        if let Some(file) = &self.file {
+            if let (Ok(mut file), Ok(json)) = (file.lock(), serde_json::to_string(record)) {
+                let _ = writeln!(file, "{}", json);
+            }
+        }
+
+        if !self.matches(record) {
+            return;
+        }
+        let rendered = self.render(record);
+        if stderr {
+            eprintln!("{}", rendered);
+        } else {
+            println!("{}", rendered);
+        }
+    }
+
+    fn matches(&self, record: &LogRecord) -> bool {
+        if let (Some(min), Some(level)) = (self.options.level, record.level) {
+            if level < min {
+                return false;
+            }
+        }
+        match &self.grep {
+            Some(grep) => [
+                record.message.as_str(),
+                record.service.as_str(),
+                record.target.as_deref().unwrap_or_default(),
+                record.request_id.as_deref().unwrap_or_default(),
+                &record.fields_text(),
+            ]
+            .iter()
+            .any(|text| text.to_lowercase().contains(grep)),
+            None => true,
+        }
+    }
+
+    fn render(&self, record: &LogRecord) -> String {
+        let mut out = String::new();
+        if self.prefixed || record.service == "gateway" {
+            out.push_str(&self.paint(&format!("[{}]", record.service), palette(&record.service)));
+            out.push(' ');
+        }
+
+        let Some(level) = record.level else {
+            out.push_str(&record.message);
+            return out;
+        };
+
+        // Heure de l'horodatage RFC 3339 (`2026-01-16T10:42:03.123456Z`)
+        if let Some(time) = record.timestamp.get(11..19) {
+            out.push_str(&self.paint(time, "90"));
+            out.push(' ');
+        }
+        out.push_str(&self.paint(&format!("{:<5}", level), level.color()));
+        out.push(' ');
+        out.push_str(&record.message);
+
+        let fields = record.fields_text();
+        if !fields.is_empty() {
+            out.push(' ');
+            out.push_str(&self.paint(&fields, "2"));
+        }
+        // Même identifiant, même couleur, quel que soit le service
+        if let Some(id) = &record.request_id {
+            let short: String = id.chars().take(8).collect();
+            out.push(' ');
+            out.push_str(&self.paint(&format!("‹{}›", short), palette(id)));
+        }
+        out
+    }
+
+    fn paint(&self, text: &str, color: &str) -> String {
+        if self.color {
+            format!("\x1b[{}m{}\x1b[0m", color, text)
+        } else {
+            text.to_string()
+        }
+    }
+}
+
+/// Couleur stable pour un nom ou un identifiant
+fn palette(key: &str) -> &'static str {
+    let hash = key
+        .bytes()
+        .fold(0u32, |hash, b| hash.wrapping_mul(31).wrapping_add(b as u32));
+    PALETTE[hash as usize % PALETTE.len()]
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn logs(options: LogOptions, prefixed: bool) -> Logs {
+        let mut logs = Logs::new(options, prefixed).unwrap();
+        logs.color = false;
+        logs
+    }
+
+    #[test]
+    fn test_parse_tracing_json() {
+        let line = r#"{"timestamp":"2026-01-16T10:42:03.123456Z","level":"INFO","message":"Incoming request","method":"GET","uri":"/health","target":"rustwork::middleware::request_id","span":{"request_id":"9f8e7d6c-1234","name":"request"},"spans":[{"request_id":"9f8e7d6c-1234","name":"request"}]}"#;
+        let record = LogRecord::parse("users", line);
+
+        assert_eq!(record.level, Some(Level::Info));
+        assert_eq!(record.timestamp, "2026-01-16T10:42:03.123456Z");
+        assert_eq!(record.message, "Incoming request");
+        assert_eq!(record.request_id.as_deref(), Some("9f8e7d6c-1234"));
+        assert_eq!(record.fields_text(), "method=GET uri=/health");
+        assert_eq!(
+            logs(LogOptions::default(), true).render(&record),
+            "[users] 10:42:03 INFO  Incoming request method=GET uri=/health ‹9f8e7d6c›"
+        );
+
+        // Format non aplati : champs sous `fields`
+        let nested =
+            r#"{"level":"WARN","fields":{"message":"slow query","ms":512},"target":"users"}"#;
+        let record = LogRecord::parse("users", nested);
+        assert_eq!(record.message, "slow query");
+        assert_eq!(record.fields_text(), "ms=512");
+    }
+
+    #[test]
+    fn test_unstructured_lines() {
+        let panic = "thread 'main' panicked at src/main.rs:12:5";
+        let record = LogRecord::parse("users", panic);
+        assert_eq!(record.level, None);
+        assert_eq!(record.message, panic);
+        assert_eq!(
+            logs(LogOptions::default(), false).render(&record),
+            panic.to_string()
+        );
+        assert_eq!(LogRecord::parse("users", "[1, 2]").level, None);
+    }
+
+    #[test]
+    fn test_filters() {
+        let options = LogOptions {
+            level: Some(Level::Warn),
+            grep: Some("ORDER-42".to_string()),
+            file: None,
+        };
+        let logs = logs(options, true);
+
+        let mut record = LogRecord::new("billing", Some(Level::Error), "payment failed".into());
+        assert!(!logs.matches(&record));
+        record
+            .fields
+            .insert("order".into(), Value::String("order-42".into()));
+        assert!(logs.matches(&record));
+
+        record.level = Some(Level::Info);
+        assert!(!logs.matches(&record));
+
+        // Sans niveau : seul --grep s'applique
+        let raw = LogRecord::new("billing", None, "panicked on order-42".into());
+        assert!(logs.matches(&raw));
+
+        assert!("Warning".parse::<Level>().unwrap() == Level::Warn);
+        assert!("verbose".parse::<Level>().is_err());
+    }
+
+    #[test]
+    fn test_log_file_gets_every_line() {
+        let dir = tempfile::tempdir().unwrap();
+        let path = dir.path().join("logs/dev.jsonl");
+        let options = LogOptions {
+            level: Some(Level::Error),
+            grep: None,
+            file: Some(path.clone()),
+        };
+        let logs = logs(options, true);
+
+        logs.line(
+            "users",
+            r#"{"level":"DEBUG","message":"hidden","target":"users"}"#,
+            false,
+        );
+        logs.line("users", "plain line", true);
+
+        let content = std::fs::read_to_string(&path).unwrap();
+        let lines: Vec<Value> = content
+            .lines()
+            .map(|l| serde_json::from_str(l).unwrap())
+            .collect();
+        assert_eq!(lines.len(), 2);
+        assert_eq!(lines[0]["level"], "DEBUG");
+        assert_eq!(lines[0]["service"], "users");
+        assert_eq!(lines[1]["level"], Value::Null);
+        assert_eq!(lines[1]["message"], "plain line");
+    }
+}
@@ -0,0 +1,48 @@
+//! Réglages de `rustwork dev` lus dans `.rustwork/manifest.json`
+
+use anyhow::{Context, Result};
+use serde::Deserialize;
+use std::path::Path;
+
+/// Route de santé des services générés
+pub const DEFAULT_HEALTH_PATH: &str = "/health";
+
+/// Champs du manifest utilisés par `rustwork dev`
+#[derive(Debug, Default, Deserialize)]
+pub struct DevManifest {
+    /// Services à démarrer (et attendre) avant celui-ci
+    #[serde(default)]
+    pub depends_on: Vec<String>,
+    #[serde(default)]
+    pub dev: DevSection,
+}
+
+/// Section `dev`
+///
+/// `watch` remplace les motifs par défaut, `ignore` s'ajoute aux motifs ignorés.
+#[derive(Debug, Default, Deserialize)]
+pub struct DevSection {
+    pub watch: Option<Vec<String>>,
+    #[serde(default)]
+    pub ignore: Vec<String>,
+    /// Route de santé attendue par les services dépendants (défaut : `/health`)
+    pub health: Option<String>,
+    /// Préfixe du service sur le gateway de dev (défaut : `/<service>`)
+    pub gateway_prefix: Option<String>,
+}
+
+impl DevManifest {
+    /// Lit le manifest du crate (valeurs par défaut sans manifest)
+    pub fn load(crate_dir: &Path) -> Result<Self> {
+        let path = crate_dir.join(".rustwork/manifest.json");
+        match std::fs::read_to_string(&path) {
+            Ok(content) => serde_json::from_str(&content)
+                .with_context(|| format!("Invalid {}", path.display())),
+            Err(_) => Ok(Self::default()),
+        }
+    }
+
+    pub fn health_path(&self) -> &str {
+        self.dev.health.as_deref().unwrap_or(DEFAULT_HEALTH_PATH)
+    }
+}
@@ -0,0 +1,237 @@
+mod contracts;
+mod gateway;
+mod health;
+mod logs;
+pub(crate) mod manifest;
+mod order;
+mod ports;
+mod process;
+mod reload;
+mod supervisor;
+mod watch;
+
+use anyhow::Result;
+use std::path::Path;
+use std::sync::Arc;
+use std::thread;
+use std::time::Duration;
+
+use crate::commands::utils::{detect_rustwork_services, RustworkService};
+use crate::mcp::common::workspace_root::WorkspaceRoot;
+pub use logs::{Level as LogLevel, LogOptions};
+use order::{select_services, service_dependencies, startup_order, Dependencies};
+
+/// Options de `rustwork dev`
+#[derive(Debug, Default)]
+pub struct DevOptions {
+    pub mcp: bool,
+    /// Services à lancer (tous si vide)
+    pub only: Vec<String>,
+    /// Services à ne pas lancer
+    pub except: Vec<String>,
+    /// Port du gateway de dev (désactivé si `None`)
+    pub gateway: Option<u16>,
+    /// Remappe les ports en conflit au lieu de refuser de démarrer
+    pub remap_ports: bool,
+    /// Intervalle du tableau d'état, en secondes (0 : désactivé)
+    pub status_interval: u64,
+    /// Filtres et fichier des logs agrégés
+    pub logs: LogOptions,
+}
+
+impl DevOptions {
+    fn run_options(&self, prefixed: bool) -> Result<reload::RunOptions> {
+        Ok(reload::RunOptions {
+            logs: Arc::new(logs::Logs::new(self.logs.clone(), prefixed)?),
+            status_interval: (self.status_interval > 0)
+                .then(|| Duration::from_secs(self.status_interval)),
+        })
+    }
+}
+
+/// Lance un seul service (comportement classique)
+async fn run_single_service(
+    workspace_root: &WorkspaceRoot,
+    service: &RustworkService,
+    options: &DevOptions,
+) -> Result<()> {
+    println!("🔧 Starting development server with hot-reload...");
+    println!("📂 Workspace root: {}", workspace_root.path().display());
+
+    // Start MCP server in background only if enabled
+    if options.mcp {
+        let workspace_path = workspace_root.path().to_path_buf();
+        let mcp_port = 4000u16;
+
+        println!(
+            "🚀 Starting MCP server on 127.0.0.1:{}... (development only)",
+            mcp_port
+        );
+        println!("   Press Ctrl+C to stop\n");
+
+        // Start MCP in a separate thread to avoid Send issues with syn
+        thread::spawn(move || {
+            let rt = tokio::runtime::Runtime::new().unwrap();
+            rt.block_on(async move {
+                if let Err(e) = crate::mcp::run_server("127.0.0.1", mcp_port, workspace_path).await
+                {
+                    eprintln!("⚠️  MCP server error: {}", e);
+                }
+            });
+        });
+    } else {
+        println!("ℹ️  MCP server disabled. Use --mcp to enable it.\n");
+    }
+
+    let services = vec![service.clone()];
+    let ports = ports::resolve(workspace_root.path(), &services, options.remap_ports)?;
+    let run_options = options.run_options(false)?;
+    if let Some(port) = options.gateway {
+        let routes = gateway::routes(&services, &ports)?;
+        gateway::start(routes, port, run_options.logs.clone()).await?;
+    }
+    let shutdown = supervisor::shutdown_flag();
+    tokio::task::spawn_blocking(move || {
+        reload::run(services, Dependencies::new(), ports, run_options, shutdown)
+    })
+    .await?
+}
+
+/// Lance plusieurs services en parallèle
+async fn run_multiple_services(
+    workspace_root: &WorkspaceRoot,
+    services: Vec<RustworkService>,
+    options: &DevOptions,
+) -> Result<()> {
+    println!("🔧 Starting Rustwork microservices workspace...");
+    println!("📂 Workspace root: {}", workspace_root.path().display());
+    println!("📦 Services directory: {}", workspace_root.services_dir().display());
+    println!("🔍 Detected {} Rustwork service(s):", services.len());
+    for service in &services {
+        let relative_path = service
+            .path
+            .strip_prefix(workspace_root.path())
+            .unwrap_or(&service.path);
+        println!("   - {} ({})", service.name, relative_path.display());
+    }
+    println!();
+
+    // Start MCP server in background only if enabled
+    if options.mcp {
+        let mcp_port = 4000u16;
+
+        println!(
+            "🚀 Starting MCP server on 127.0.0.1:{}... (development only)",
+            mcp_port
+        );
+        println!(
+            "   MCP observing workspace: {}",
+            workspace_root.path().display()
+        );
+        println!("   Press Ctrl+C to stop\n");
+
+        // Start MCP in a separate thread to avoid Send issues with syn
+        let workspace_path = workspace_root.path().to_path_buf();
+        thread::spawn(move || {
+            let rt = tokio::runtime::Runtime::new().unwrap();
+            rt.block_on(async move {
+                if let Err(e) = crate::mcp::run_server("127.0.0.1", mcp_port, workspace_path).await
+                {
+                    eprintln!("⚠️  MCP server error: {}", e);
+                }
+            });
+        });
+    } else {
+        println!("ℹ️  MCP server disabled. Use --mcp to enable it.\n");
+    }
+
+    // Ordre de démarrage : `depends_on` des manifests et graphe d'appels gRPC
+    let dependencies = service_dependencies(workspace_root, &services)?;
+    let (services, cycle) = startup_order(services, &dependencies);
+    if !dependencies.is_empty() {
+        let order: Vec<&str> = services.iter().map(|s| s.name.as_str()).collect();
+        println!("🔗 Startup order: {}", order.join(" → "));
+        for (service, deps) in &dependencies {
+            let deps: Vec<&str> = deps.iter().map(String::as_str).collect();
+            println!("   - {} waits for {}", service, deps.join(", "));
+        }
+        println!();
+    }
+    if !cycle.is_empty() {
+        println!(
+            "⚠️  Dependency cycle, starting in name order: {}\n",
+            cycle.join(", ")
+        );
+    }
+
+    // Ports : le premier service lancé garde un port partagé
+    let ports = ports::resolve(workspace_root.path(), &services, options.remap_ports)?;
+
+    let run_options = options.run_options(true)?;
+    if let Some(port) = options.gateway {
+        let routes = gateway::routes(&services, &ports)?;
+        gateway::start(routes, port, run_options.logs.clone()).await?;
+    }
+
+    let shutdown = supervisor::shutdown_flag();
+    tokio::task::spawn_blocking(move || {
+        reload::run(services, dependencies, ports, run_options, shutdown)
+    })
+    .await?
+}
+
+pub async fn execute(explicit_path: Option<&Path>, options: &DevOptions) -> Result<()> {
+    let current_dir = std::env::current_dir()?;
+
+    // Step 1: Detect workspace root using the new robust detection logic
+    let workspace_root = if let Some(explicit) = explicit_path {
+        // Use explicit path if provided
+        WorkspaceRoot::detect_with_explicit(&current_dir, Some(explicit))?
+    } else {
+        // Auto-detect from current directory
+        WorkspaceRoot::detect(&current_dir)?
+    };
+
+    // Step 2: Detect all Rustwork services in the workspace
+    let services = detect_rustwork_services(workspace_root.path())?;
+
+    // Step 3: Keep the services selected with --only / --except
+    let detected = services.len();
+    let services = select_services(services, &options.only, &options.except)?;
+    if detected > 0 && services.is_empty() {
+        anyhow::bail!("No service left to start (check --only / --except)");
+    }
+
+    // Step 4: Determine execution mode based on number of services
+    match services.len() {
+        0 => {
+            // No services found - fail explicitly
+            anyhow::bail!(
+                "No Rustwork services found in workspace: {}\n\
+                 \n\
+                 Expected structure:\n\
+                 ./\n\
+                 └── Backend/\n\
+                     └── services/\n\
+                         ├── <service1>/\n\
+                         └── <service2>/\n\
+                 \n\
+                 A valid Rustwork service requires:\n\
+                 - .rustwork/manifest.json\n\
+                 - Cargo.toml\n\
+                 - src/main.rs\n\
+                 \n\
+                 Create a new workspace with: rustwork new auth,user,session",
+                workspace_root.path().display()
+            );
+        }
+        1 => {
+            // Single service mode
+            run_single_service(&workspace_root, &services[0], options).await
+        }
+        _ => {
+            // Multi-service mode (default for microservices)
+            run_multiple_services(&workspace_root, services, options).await
+        }
+    }
+}
@@ -0,0 +1,235 @@
+//! Sélection des services et ordre de démarrage de `rustwork dev`
+
+use anyhow::Result;
+use std::collections::{BTreeMap, BTreeSet};
+use std::sync::Arc;
+
+use super::manifest::DevManifest;
+use crate::commands::utils::RustworkService;
+use crate::mcp::common::workspace_root::WorkspaceRoot;
+use crate::mcp::rustwork_grpc_get_call_graph::workspace_call_graph;
+
+/// Service -> services à démarrer avant lui
+pub type Dependencies = BTreeMap<String, BTreeSet<String>>;
+
+/// Applique `--only` / `--except` (noms inconnus refusés)
+pub fn select_services(
+    services: Vec<RustworkService>,
+    only: &[String],
+    except: &[String],
+) -> Result<Vec<RustworkService>> {
+    for name in only.iter().chain(except) {
+        if !services.iter().any(|s| &s.name == name) {
+            let available: Vec<&str> = services.iter().map(|s| s.name.as_str()).collect();
+            anyhow::bail!(
+                "Unknown service '{}'. Available services: {}",
+                name,
+                available.join(", ")
+            );
+        }
+    }
+
+    Ok(services
+        .into_iter()
+        .filter(|s| only.is_empty() || only.contains(&s.name))
+        .filter(|s| !except.contains(&s.name))
+        .collect())
+}
+
+/// Dépendances déclarées (`depends_on` du manifest) et déduites du graphe d'appels gRPC
+pub fn service_dependencies(
+    workspace_root: &WorkspaceRoot,
+    services: &[RustworkService],
+) -> Result<Dependencies> {
+    let mut dependencies = Dependencies::new();
+
+    for service in services {
+        let manifest = DevManifest::load(&service.path)?;
+        for dependency in manifest.depends_on {
+            if !services.iter().any(|s| s.name == dependency) {
+                println!(
+                    "⚠️  {} depends on unknown service '{}' (ignored)",
+                    service.name, dependency
+                );
+                continue;
+            }
+            dependencies
+                .entry(service.name.clone())
+                .or_default()
+                .insert(dependency);
+        }
+    }
+
+    match workspace_call_graph(Arc::new(workspace_root.clone())) {
+        Ok(graph) => {
+            // Service gRPC -> service Rustwork qui contient son contrat .rwk
+            let owners: BTreeMap<String, String> = graph
+                .nodes
+                .iter()
+                .filter_map(|node| {
+                    let rwk = workspace_root.path().join(&node.source_file);
+                    services
+                        .iter()
+                        .find(|s| rwk.starts_with(&s.path))
+                        .map(|s| (node.service_name.clone(), s.name.clone()))
+                })
+                .collect();
+            let edges = graph
+                .edges
+                .iter()
+                .map(|edge| (edge.from_service.as_str(), edge.to_service.as_str()));
+
+            add_grpc_dependencies(&mut dependencies, &owners, edges);
+        }
+        Err(e) => println!("⚠️  gRPC call graph unavailable: {}", e),
+    }
+
+    Ok(dependencies)
+}
+
+fn add_grpc_dependencies<'a>(
+    dependencies: &mut Dependencies,
+    owners: &BTreeMap<String, String>,
+    edges: impl Iterator<Item = (&'a str, &'a str)>,
+) {
+    for (from, to) in edges {
+        let (Some(from), Some(to)) = (owners.get(from), owners.get(to)) else {
+            continue;
+        };
+        if from != to {
+            dependencies
+                .entry(from.clone())
+                .or_default()
+                .insert(to.clone());
+        }
+    }
+}
+
+/// Trie les services : chaque service après ses dépendances
+///
+/// Les services pris dans un cycle sont démarrés à la fin, par ordre alphabétique,
+/// et retournés en second.
+pub fn startup_order(
+    services: Vec<RustworkService>,
+    dependencies: &Dependencies,
+) -> (Vec<RustworkService>, Vec<String>) {
+    let mut pending: BTreeMap<String, RustworkService> =
+        services.into_iter().map(|s| (s.name.clone(), s)).collect();
+    let mut ordered: Vec<RustworkService> = Vec::new();
+
+    loop {
+        let ready: Vec<String> = pending
+            .keys()
+            .filter(|name| {
+                dependencies.get(*name).is_none_or(|deps| {
+                    deps.iter()
+                        .all(|dep| !pending.contains_key(dep) || dep == *name)
+                })
+            })
+            .cloned()
+            .collect();
+        if ready.is_empty() {
+            break;
+        }
+        for name in ready {
+            ordered.extend(pending.remove(&name));
+        }
+    }
+
+    let cycle: Vec<String> = pending.keys().cloned().collect();
+    ordered.extend(pending.into_values());
+    (ordered, cycle)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::path::PathBuf;
+
+    fn services(names: &[&str]) -> Vec<RustworkService> {
+        names
+            .iter()
+            .map(|name| RustworkService {
+                name: name.to_string(),
+                path: PathBuf::from("/ws/Backend/services").join(name),
+            })
+            .collect()
+    }
+
+    fn names(services: &[RustworkService]) -> Vec<&str> {
+        services.iter().map(|s| s.name.as_str()).collect()
+    }
+
+    fn deps(pairs: &[(&str, &str)]) -> Dependencies {
+        let mut dependencies = Dependencies::new();
+        for (from, to) in pairs {
+            dependencies
+                .entry(from.to_string())
+                .or_default()
+                .insert(to.to_string());
+        }
+        dependencies
+    }
+
+    #[test]
+    fn test_select_services() {
+        let all = services(&["auth", "billing", "user"]);
+        let only = select_services(all.clone(), &["user".into(), "auth".into()], &[]).unwrap();
+        assert_eq!(names(&only), vec!["auth", "user"]);
+
+        let except = select_services(all.clone(), &[], &["billing".into()]).unwrap();
+        assert_eq!(names(&except), vec!["auth", "user"]);
+
+        let err = select_services(all, &["orders".into()], &[]).unwrap_err();
+        assert!(err
+            .to_string()
+            .contains("Available services: auth, billing, user"));
+    }
+
+    #[test]
+    fn test_startup_order() {
+        let (ordered, cycle) = startup_order(
+            services(&["gateway", "auth", "user", "billing"]),
+            &deps(&[("gateway", "user"), ("user", "auth"), ("billing", "auth")]),
+        );
+        assert_eq!(names(&ordered), vec!["auth", "billing", "user", "gateway"]);
+        assert!(cycle.is_empty());
+
+        // Dépendance non sélectionnée : ignorée
+        let (ordered, _) = startup_order(services(&["user"]), &deps(&[("user", "auth")]));
+        assert_eq!(names(&ordered), vec!["user"]);
+
+        let (ordered, cycle) = startup_order(
+            services(&["a", "b", "c"]),
+            &deps(&[("a", "b"), ("b", "a"), ("c", "a")]),
+        );
+        assert_eq!(names(&ordered), vec!["a", "b", "c"]);
+        assert_eq!(cycle, vec!["a", "b", "c"]);
+    }
+
+    #[test]
+    fn test_add_grpc_dependencies() {
+        let owners: BTreeMap<String, String> = [
+            ("UserService", "user"),
+            ("AuthService", "auth"),
+            ("AuthAdmin", "auth"),
+        ]
+        .iter()
+        .map(|(grpc, service)| (grpc.to_string(), service.to_string()))
+        .collect();
+
+        let mut dependencies = deps(&[("user", "billing")]);
+        add_grpc_dependencies(
+            &mut dependencies,
+            &owners,
+            [
+                ("UserService", "AuthService"),
+                ("AuthAdmin", "AuthService"),
+                ("UserService", "External"),
+            ]
+            .into_iter(),
+        );
+
+        assert_eq!(dependencies, deps(&[("user", "billing"), ("user", "auth")]));
+    }
+}
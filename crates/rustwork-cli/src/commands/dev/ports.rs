@@ -0,0 +1,238 @@
+//! Conflits de ports au lancement de `rustwork dev`
+//!
+//! Deux services sur un même port, ou un port déjà pris sur la machine : refus avec un
+//! rapport, ou (`--remap-ports`) port libre pris dans la plage du registre et transmis
+//! au service par `APP__SERVER__PORT`.
+
+use anyhow::Result;
+use std::collections::{BTreeMap, BTreeSet};
+use std::fmt;
+use std::net::TcpListener;
+use std::path::Path;
+
+use crate::commands::ports::{PortRange, PortRegistry, REGISTRY_FILE};
+use crate::commands::utils::{read_service_port, RustworkService};
+
+/// Ports d'un service pendant `rustwork dev`
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct DevPorts {
+    pub http: Option<u16>,
+    /// Port différent de la config du service
+    pub remapped: bool,
+}
+
+impl DevPorts {
+    /// Variables d'environnement qui imposent le port remappé au service
+    pub fn env(&self) -> Vec<(&'static str, String)> {
+        match self.http {
+            Some(http) if self.remapped => vec![("APP__SERVER__PORT", http.to_string())],
+            _ => Vec::new(),
+        }
+    }
+}
+
+/// Service -> ports utilisés pendant `rustwork dev`
+pub type PortMap = BTreeMap<String, DevPorts>;
+
+/// Port demandé par un service mais indisponible
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Conflict {
+    pub service: String,
+    pub port: u16,
+    /// Service lancé avant qui utilise ce port (`None` : pris par un autre processus)
+    pub owner: Option<String>,
+    /// Port de remplacement (`--remap-ports`)
+    pub remapped_to: Option<u16>,
+}
+
+impl fmt::Display for Conflict {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}: http {}", self.service, self.port)?;
+        if let Some(port) = self.remapped_to {
+            write!(f, " → {}", port)?;
+        }
+        match &self.owner {
+            Some(owner) => write!(f, " (already used by {})", owner),
+            None => write!(f, " (already in use on this machine)"),
+        }
+    }
+}
+
+/// Ports des services (dans l'ordre de démarrage : le premier garde un port partagé)
+///
+/// Sans `remap`, tout conflit est une erreur qui les liste tous.
+pub fn resolve(
+    workspace_root: &Path,
+    services: &[RustworkService],
+    remap: bool,
+) -> Result<PortMap> {
+    let registry = PortRegistry::load(workspace_root, services)?;
+    let configured: Vec<(String, DevPorts)> = services
+        .iter()
+        .map(|service| {
+            let ports = DevPorts {
+                http: read_service_port(&service.path).and_then(|p| u16::try_from(p).ok()),
+                remapped: false,
+            };
+            (service.name.clone(), ports)
+        })
+        .collect();
+
+    let (ports, conflicts) = assign(&configured, &registry, remap, port_is_free);
+    if conflicts.is_empty() {
+        return Ok(ports);
+    }
+
+    let report: Vec<String> = conflicts.iter().map(|c| format!("   - {}", c)).collect();
+    if !remap || conflicts.iter().any(|c| c.remapped_to.is_none()) {
+        let hint = if remap {
+            format!("No free port left in the ranges of {}.", REGISTRY_FILE)
+        } else {
+            format!(
+                "Give each service the port assigned in {} ([server] port in\n\
+                 config/default.toml and config/dev.toml), or run `rustwork dev --remap-ports`.",
+                REGISTRY_FILE
+            )
+        };
+        anyhow::bail!(
+            "Port conflicts detected:\n{}\n\n{}",
+            report.join("\n"),
+            hint
+        );
+    }
+
+    println!("🔀 Port conflicts remapped for this session:");
+    println!("{}", report.join("\n"));
+    println!();
+    Ok(ports)
+}
+
+fn port_is_free(port: u16) -> bool {
+    TcpListener::bind(("0.0.0.0", port)).is_ok()
+}
+
+/// Attribue les ports demandés, en détectant (et remappant) les conflits
+fn assign(
+    configured: &[(String, DevPorts)],
+    registry: &PortRegistry,
+    remap: bool,
+    is_free: impl Fn(u16) -> bool,
+) -> (PortMap, Vec<Conflict>) {
+    // Un port de remplacement n'est pris à aucun service du workspace
+    let mut reserved = registry.used_ports();
+    reserved.extend(configured.iter().filter_map(|(_, ports)| ports.http));
+
+    let mut claimed: BTreeMap<u16, String> = BTreeMap::new();
+    let mut ports = PortMap::new();
+    let mut conflicts = Vec::new();
+
+    for (service, wanted) in configured {
+        let mut assigned = *wanted;
+        if let Some(port) = wanted.http {
+            let owner = claimed.get(&port).cloned();
+            if owner.is_none() && is_free(port) {
+                claimed.insert(port, service.clone());
+            } else {
+                let remapped_to = if remap {
+                    free_port(registry.ranges.http, &reserved, &is_free)
+                } else {
+                    None
+                };
+                if let Some(new_port) = remapped_to {
+                    assigned.http = Some(new_port);
+                    reserved.insert(new_port);
+                    claimed.insert(new_port, service.clone());
+                    assigned.remapped = true;
+                }
+                conflicts.push(Conflict {
+                    service: service.clone(),
+                    port,
+                    owner,
+                    remapped_to,
+                });
+            }
+        }
+        ports.insert(service.clone(), assigned);
+    }
+
+    (ports, conflicts)
+}
+
+fn free_port(
+    range: PortRange,
+    reserved: &BTreeSet<u16>,
+    is_free: impl Fn(u16) -> bool,
+) -> Option<u16> {
+    let mut skipped = reserved.clone();
+    while let Some(port) = range.next_free(&skipped) {
+        if is_free(port) {
+            return Some(port);
+        }
+        skipped.insert(port);
+    }
+    None
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn configured(entries: &[(&str, u16)]) -> Vec<(String, DevPorts)> {
+        entries
+            .iter()
+            .map(|(name, http)| {
+                let ports = DevPorts {
+                    http: Some(*http),
+                    remapped: false,
+                };
+                (name.to_string(), ports)
+            })
+            .collect()
+    }
+
+    #[test]
+    fn test_assign_detects_conflicts() {
+        let services = configured(&[("auth", 3001), ("user", 3001), ("billing", 3003)]);
+        let is_free = |port: u16| port != 3003;
+
+        let (ports, conflicts) = assign(&services, &PortRegistry::default(), false, is_free);
+        assert_eq!(ports["user"].http, Some(3001));
+        assert!(!ports["user"].remapped);
+        let report: Vec<String> = conflicts.iter().map(|c| c.to_string()).collect();
+        assert_eq!(
+            report,
+            vec![
+                "user: http 3001 (already used by auth)",
+                "billing: http 3003 (already in use on this machine)",
+            ]
+        );
+    }
+
+    #[test]
+    fn test_assign_remaps() {
+        let services = configured(&[("auth", 3001), ("user", 3001)]);
+        let mut registry = PortRegistry::default();
+        registry.allocate("billing").unwrap();
+        registry.allocate("auth").unwrap();
+
+        // 3003 est pris par un autre processus
+        let (ports, conflicts) = assign(&services, &registry, true, |p| p != 3003);
+        assert_eq!(conflicts.len(), 1);
+        assert_eq!(
+            conflicts[0].to_string(),
+            "user: http 3001 → 3004 (already used by auth)"
+        );
+        assert_eq!(
+            ports["user"],
+            DevPorts {
+                http: Some(3004),
+                remapped: true
+            }
+        );
+        assert_eq!(
+            ports["user"].env(),
+            vec![("APP__SERVER__PORT", "3004".to_string())]
+        );
+        assert!(ports["auth"].env().is_empty());
+    }
+}
@@ -0,0 +1,205 @@
+//! Compilation, lancement et arrêt des services de `rustwork dev`
+
+use anyhow::{Context, Result};
+use std::io::{BufRead, BufReader, Read};
+use std::path::{Path, PathBuf};
+use std::process::{Child, Command, Stdio};
+use std::sync::Arc;
+use std::thread;
+use std::time::Duration;
+#[cfg(unix)]
+use std::time::Instant;
+
+use super::logs::Logs;
+use crate::commands::utils::RustworkService;
+
+/// Compile le service et retourne le chemin de son exécutable
+///
+/// Les diagnostics de cargo sont affichés avec `prefix`.
+pub fn build(service: &RustworkService, prefix: &str) -> Result<PathBuf> {
+    let manifest_path = service
+        .path
+        .join("Cargo.toml")
+        .canonicalize()
+        .with_context(|| format!("Missing Cargo.toml for service {}", service.name))?;
+
+    let mut child = Command::new("cargo")
+        .args(["build", "--message-format=json-render-diagnostics"])
+        .current_dir(&service.path)
+        .stdout(Stdio::piped())
+        .stderr(Stdio::piped())
+        .spawn()
+        .with_context(|| format!("Failed to build service: {}", service.name))?;
+
+    let stderr = child.stderr.take().map(|err| stream(err, prefix, true));
+
+    // stdout : messages JSON de cargo, dont l'artefact exécutable du service
+    let mut executable = None;
+    if let Some(stdout) = child.stdout.take() {
+        for line in BufReader::new(stdout).lines().map_while(Result::ok) {
+            if let Some(path) = artifact_executable(&line, &manifest_path) {
+                executable = Some(path);
+            }
+        }
+    }
+
+    let status = child.wait()?;
+    if let Some(handle) = stderr {
+        let _ = handle.join();
+    }
+    if !status.success() {
+        anyhow::bail!("Build failed for service {}", service.name);
+    }
+
+    executable.with_context(|| format!("No binary built for service {}", service.name))
+}
+
+/// Lance l'exécutable compilé depuis le dossier du service
+///
+/// Le service logue en JSON (`RUSTWORK_LOG_FORMAT=json`), ses sorties passent par `logs`.
+/// Sous Unix, le service a son propre groupe de processus : Ctrl+C n'atteint que
+/// `rustwork dev`, qui l'arrête proprement.
+pub fn spawn(
+    service: &RustworkService,
+    executable: &Path,
+    env: &[(&str, String)],
+    logs: &Arc<Logs>,
+) -> Result<Child> {
+    let mut command = Command::new(executable);
+    command
+        .current_dir(&service.path)
+        .env(rustwork::logging::LOG_FORMAT_ENV, "json")
+        .envs(env.iter().map(|(key, value)| (key, value)))
+        .stdout(Stdio::piped())
+        .stderr(Stdio::piped());
+    #[cfg(unix)]
+    {
+        use std::os::unix::process::CommandExt;
+        command.process_group(0);
+    }
+    let mut child = command
+        .spawn()
+        .with_context(|| format!("Failed to start service: {}", service.name))?;
+
+    if let Some(stdout) = child.stdout.take() {
+        stream_logs(stdout, &service.name, logs, false);
+    }
+    if let Some(stderr) = child.stderr.take() {
+        stream_logs(stderr, &service.name, logs, true);
+    }
+
+    Ok(child)
+}
+
+/// Arrête le processus : SIGTERM, puis kill s'il tourne encore après `timeout`
+#[cfg_attr(not(unix), allow(unused_variables))]
+pub fn stop(child: &mut Child, timeout: Duration) {
+    if matches!(child.try_wait(), Ok(Some(_))) {
+        return;
+    }
+
+    #[cfg(unix)]
+    if terminate(child, timeout) {
+        return;
+    }
+
+    let _ = child.kill();
+    let _ = child.wait();
+}
+
+/// Envoie SIGTERM et attend la fin du processus ; `false` s'il tourne encore après `timeout`
+#[cfg(unix)]
+fn terminate(child: &mut Child, timeout: Duration) -> bool {
+    // SAFETY: le pid appartient à un enfant qui n'a pas encore été attendu
+    unsafe {
+        libc::kill(child.id() as libc::pid_t, libc::SIGTERM);
+    }
+
+    let deadline = Instant::now() + timeout;
+    while Instant::now() < deadline {
+        if matches!(child.try_wait(), Ok(Some(_))) {
+            return true;
+        }
+        thread::sleep(Duration::from_millis(50));
+    }
+    false
+}
+
+/// Exécutable d'un message `compiler-artifact` de cargo pour le crate `manifest_path`
+fn artifact_executable(line: &str, manifest_path: &Path) -> Option<PathBuf> {
+    let message: serde_json::Value = serde_json::from_str(line).ok()?;
+    if message.get("reason")?.as_str()? != "compiler-artifact" {
+        return None;
+    }
+    if Path::new(message.get("manifest_path")?.as_str()?) != manifest_path {
+        return None;
+    }
+
+    message.get("executable")?.as_str().map(PathBuf::from)
+}
+
+/// Affiche chaque ligne de `reader` avec le préfixe du service (sortie de cargo)
+fn stream<R: Read + Send + 'static>(
+    reader: R,
+    prefix: &str,
+    to_stderr: bool,
+) -> thread::JoinHandle<()> {
+    let prefix = prefix.to_string();
+    thread::spawn(move || {
+        for line in BufReader::new(reader).lines().map_while(Result::ok) {
+            if to_stderr {
+                eprintln!("{}{}", prefix, line);
+            } else {
+                println!("{}{}", prefix, line);
+            }
+        }
+    })
+}
+
+/// Transmet chaque ligne de `reader` à l'agrégateur de logs
+fn stream_logs<R: Read + Send + 'static>(reader: R, service: &str, logs: &Arc<Logs>, stderr: bool) {
+    let service = service.to_string();
+    let logs = logs.clone();
+    thread::spawn(move || {
+        for line in BufReader::new(reader).lines().map_while(Result::ok) {
+            logs.line(&service, &line, stderr);
+        }
+    });
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_artifact_executable() {
+        let manifest = Path::new("/ws/services/users/Cargo.toml");
+        let artifact = r#"{"reason":"compiler-artifact","manifest_path":"/ws/services/users/Cargo.toml","target":{"kind":["bin"]},"executable":"/ws/services/users/target/debug/users"}"#;
+        assert_eq!(
+            artifact_executable(artifact, manifest),
+            Some(PathBuf::from("/ws/services/users/target/debug/users"))
+        );
+
+        // Dépendance (shared), bibliothèque sans exécutable, autres messages
+        let shared = r#"{"reason":"compiler-artifact","manifest_path":"/ws/services/shared/Cargo.toml","executable":"/ws/target/debug/shared"}"#;
+        let lib = r#"{"reason":"compiler-artifact","manifest_path":"/ws/services/users/Cargo.toml","executable":null}"#;
+        let finished = r#"{"reason":"build-finished","success":true}"#;
+        for line in [shared, lib, finished, "   Compiling users v0.1.0"] {
+            assert_eq!(artifact_executable(line, manifest), None);
+        }
+    }
+
+    #[cfg(unix)]
+    #[test]
+    fn test_stop_sends_sigterm() {
+        use std::os::unix::process::ExitStatusExt;
+
+        let mut child = Command::new("sleep").arg("30").spawn().unwrap();
+        let started = Instant::now();
+        stop(&mut child, Duration::from_secs(5));
+
+        assert!(started.elapsed() < Duration::from_secs(5));
+        let status = child.try_wait().unwrap().unwrap();
+        assert_eq!(status.signal(), Some(libc::SIGTERM));
+    }
+}
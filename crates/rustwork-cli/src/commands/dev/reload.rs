@@ -0,0 +1,209 @@
+//! Boucle de rechargement de `rustwork dev`
+
+use anyhow::{Context, Result};
+use notify::{Event, EventKind, RecursiveMode, Watcher};
+use std::collections::BTreeSet;
+use std::path::PathBuf;
+use std::sync::atomic::{AtomicBool, Ordering};
+use std::sync::mpsc::{self, RecvTimeoutError};
+use std::sync::Arc;
+use std::time::{Duration, Instant};
+
+use super::contracts;
+use super::health::wait_healthy;
+use super::logs::Logs;
+use super::manifest::DevManifest;
+use super::order::Dependencies;
+use super::ports::PortMap;
+use super::process;
+use super::supervisor::Supervisor;
+use super::watch::{affected_services, watched_crates, WatchedCrate};
+use crate::commands::utils::RustworkService;
+
+/// Délai sans nouvel événement avant de relancer (sauvegardes groupées des éditeurs)
+const DEBOUNCE: Duration = Duration::from_millis(300);
+
+/// Intervalle de surveillance des processus
+const TICK: Duration = Duration::from_millis(200);
+
+/// Délai d'attente de la route de santé d'une dépendance
+const HEALTH_TIMEOUT: Duration = Duration::from_secs(30);
+
+/// Réglages de la boucle de `rustwork dev`
+#[derive(Clone)]
+pub struct RunOptions {
+    /// Sortie des logs des services
+    pub logs: Arc<Logs>,
+    /// Intervalle d'affichage du tableau d'état (désactivé si `None`)
+    pub status_interval: Option<Duration>,
+}
+
+/// Compile et lance les services, puis les relance à chaque modification
+///
+/// `services` est dans l'ordre de démarrage : chaque service attend que ses
+/// `dependencies` lancées répondent sur leur route de santé, sur le port de `ports`.
+/// Un service qui crashe est relancé par le [`Supervisor`]. Bloquant : rend la main quand
+/// `shutdown` passe à `true`, après avoir arrêté les services et affiché le bilan.
+pub fn run(
+    services: Vec<RustworkService>,
+    dependencies: Dependencies,
+    ports: PortMap,
+    options: RunOptions,
+    shutdown: Arc<AtomicBool>,
+) -> Result<()> {
+    let crates = watched_crates(&services)?;
+
+    let (tx, rx) = mpsc::channel::<Vec<PathBuf>>();
+    let mut watcher = notify::recommended_watcher(move |res: notify::Result<Event>| {
+        if let Ok(event) = res {
+            if matches!(
+                event.kind,
+                EventKind::Create(_) | EventKind::Modify(_) | EventKind::Remove(_)
+            ) {
+                let _ = tx.send(event.paths);
+            }
+        }
+    })
+    .context("Failed to start file watcher")?;
+    for watched in &crates {
+        watcher
+            .watch(&watched.dir, RecursiveMode::Recursive)
+            .with_context(|| format!("Failed to watch {}", watched.dir.display()))?;
+    }
+
+    let mut supervisor = Supervisor::new(services.clone(), ports.clone(), options.logs.clone());
+    let mut waited: BTreeSet<String> = BTreeSet::new();
+    for service in &services {
+        if shutdown.load(Ordering::SeqCst) {
+            break;
+        }
+        println!("▶ Building {}...", service.name);
+        // Compiler pendant que les dépendances démarrent
+        let executable = match process::build(service, &supervisor.prefix(&service.name)) {
+            Ok(executable) => executable,
+            Err(e) => {
+                eprintln!("⚠️  Failed to start {}: {:#}", service.name, e);
+                eprintln!("   It will be rebuilt on the next change.");
+                supervisor.mark_failed(&service.name);
+                continue;
+            }
+        };
+
+        for dependency in dependencies.get(&service.name).into_iter().flatten() {
+            if supervisor.is_running(dependency) && waited.insert(dependency.clone()) {
+                if let Some(dependency) = services.iter().find(|s| &s.name == dependency) {
+                    wait_for(dependency, &ports, &service.name);
+                }
+            }
+        }
+
+        if let Err(e) = supervisor.start(&service.name, executable) {
+            eprintln!("⚠️  Failed to start {}: {:#}", service.name, e);
+        }
+    }
+
+    if !shutdown.load(Ordering::SeqCst) {
+        println!("\n👀 Watching for changes. Press Ctrl+C to stop.\n");
+    }
+
+    let mut last_status = Instant::now();
+    while !shutdown.load(Ordering::SeqCst) {
+        match rx.recv_timeout(TICK) {
+            Ok(mut changed) => {
+                // Regrouper les événements rapprochés
+                while let Ok(paths) = rx.recv_timeout(DEBOUNCE) {
+                    changed.extend(paths);
+                }
+                // Contrats gRPC : régénérer, les fichiers générés rejoignent ce lot
+                let regenerated = contracts::regenerate(&services, &dependencies, &changed);
+                if !regenerated.is_empty() {
+                    while let Ok(paths) = rx.recv_timeout(DEBOUNCE) {
+                        changed.extend(paths);
+                    }
+                }
+                reload(&mut supervisor, &services, &crates, &changed, &regenerated);
+            }
+            Err(RecvTimeoutError::Timeout) => {}
+            Err(RecvTimeoutError::Disconnected) => break,
+        }
+
+        supervisor.tick();
+        if let Some(interval) = options.status_interval {
+            if last_status.elapsed() >= interval {
+                print!("\n📋 Services:\n{}", supervisor.table());
+                last_status = Instant::now();
+            }
+        }
+    }
+
+    supervisor.stop_all();
+    print!("\n{}", supervisor.summary());
+
+    Ok(())
+}
+
+/// Recompile et relance les services touchés par `changed`, plus `extra`
+///
+/// Dans l'ordre de démarrage : une dépendance est relancée avant ses dépendants.
+fn reload(
+    supervisor: &mut Supervisor,
+    services: &[RustworkService],
+    crates: &[WatchedCrate],
+    changed: &[PathBuf],
+    extra: &BTreeSet<String>,
+) {
+    let mut affected = affected_services(crates, changed);
+    affected.extend(extra.iter().cloned());
+
+    for service in services.iter().filter(|s| affected.contains(&s.name)) {
+        let name = service.name.clone();
+
+        println!("🔄 Change detected, rebuilding {}...", name);
+        // Garder l'ancienne version tant que la nouvelle ne compile pas
+        let executable = match process::build(service, &supervisor.prefix(&name)) {
+            Ok(executable) => executable,
+            Err(e) => {
+                if supervisor.is_running(&name) {
+                    eprintln!("❌ {:#}. Keeping the previous version running.", e);
+                } else {
+                    eprintln!("❌ {:#}", e);
+                    supervisor.mark_failed(&name);
+                }
+                continue;
+            }
+        };
+
+        match supervisor.start(&name, executable) {
+            Ok(()) => println!("✅ {} restarted", name),
+            Err(e) => eprintln!("❌ {:#}", e),
+        }
+    }
+}
+
+/// Attend la route de santé de `dependency` avant de lancer `dependent`
+fn wait_for(dependency: &RustworkService, ports: &PortMap, dependent: &str) {
+    let Some(port) = ports.get(&dependency.name).and_then(|p| p.http) else {
+        println!(
+            "⚠️  No [server] port for {}, starting {} without waiting",
+            dependency.name, dependent
+        );
+        return;
+    };
+    let manifest = DevManifest::load(&dependency.path).unwrap_or_default();
+    let path = manifest.health_path();
+
+    println!(
+        "⏳ Waiting for {} (http://127.0.0.1:{}{})...",
+        dependency.name, port, path
+    );
+    if wait_healthy(port, path, HEALTH_TIMEOUT) {
+        println!("✅ {} is healthy", dependency.name);
+    } else {
+        println!(
+            "⚠️  {} is not healthy after {}s, starting {} anyway",
+            dependency.name,
+            HEALTH_TIMEOUT.as_secs(),
+            dependent
+        );
+    }
+}
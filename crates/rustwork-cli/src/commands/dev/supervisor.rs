@@ -0,0 +1,502 @@
+//! Supervision des services de `rustwork dev`
+//!
+//! Un service qui s'arrête seul est relancé avec un délai exponentiel (1 s, 2 s, 4 s…
+//! jusqu'à 30 s). Après 5 relances sans succès, il est marqué `failed` jusqu'à la
+//! prochaine modification. Un service resté 30 s en vie repart d'un délai d'une seconde.
+
+use anyhow::Result;
+use std::collections::BTreeMap;
+use std::fmt;
+use std::path::PathBuf;
+use std::process::{Child, ExitStatus};
+use std::sync::atomic::{AtomicBool, Ordering};
+use std::sync::Arc;
+use std::time::{Duration, Instant};
+
+use super::logs::Logs;
+use super::ports::{DevPorts, PortMap};
+use super::process;
+use crate::commands::utils::RustworkService;
+
+/// Premier délai avant une relance
+const BACKOFF_START: Duration = Duration::from_secs(1);
+
+/// Délai maximal entre deux relances
+const BACKOFF_MAX: Duration = Duration::from_secs(30);
+
+/// Durée de vie au-delà de laquelle un crash n'est plus compté comme consécutif
+const STABLE_AFTER: Duration = Duration::from_secs(30);
+
+/// Relances consécutives avant d'abandonner
+const MAX_ATTEMPTS: u32 = 5;
+
+/// Délai laissé à un service pour s'arrêter après SIGTERM
+const STOP_TIMEOUT: Duration = Duration::from_secs(5);
+
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub enum Status {
+    Up,
+    Restarting,
+    Failed,
+    #[default]
+    Stopped,
+}
+
+impl fmt::Display for Status {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let label = match self {
+            Status::Up => "up",
+            Status::Restarting => "restarting",
+            Status::Failed => "failed",
+            Status::Stopped => "stopped",
+        };
+        f.pad(label)
+    }
+}
+
+/// État d'un service supervisé
+#[derive(Debug, Default)]
+struct Supervised {
+    child: Option<Child>,
+    executable: Option<PathBuf>,
+    status: Status,
+    pid: Option<u32>,
+    started: Option<Instant>,
+    last_exit: Option<String>,
+    /// Crashs d'affilée (remis à zéro par une modification)
+    attempts: u32,
+    restart_at: Option<Instant>,
+    crashes: u32,
+    restarts: u32,
+    reloads: u32,
+    /// Durée de vie cumulée des processus terminés
+    uptime: Duration,
+}
+
+impl Supervised {
+    fn current_uptime(&self) -> Duration {
+        match (&self.child, self.started) {
+            (Some(_), Some(started)) => started.elapsed(),
+            _ => Duration::ZERO,
+        }
+    }
+}
+
+/// Processus des services lancés par `rustwork dev`
+pub struct Supervisor {
+    services: Vec<RustworkService>,
+    ports: PortMap,
+    logs: Arc<Logs>,
+    states: BTreeMap<String, Supervised>,
+    started: Instant,
+}
+
+impl Supervisor {
+    pub fn new(services: Vec<RustworkService>, ports: PortMap, logs: Arc<Logs>) -> Self {
+        let states = services
+            .iter()
+            .map(|s| (s.name.clone(), Supervised::default()))
+            .collect();
+        Self {
+            services,
+            ports,
+            logs,
+            states,
+            started: Instant::now(),
+        }
+    }
+
+    /// Préfixe de la sortie de compilation du service (`[service] ` en mode multi-services)
+    pub fn prefix(&self, name: &str) -> String {
+        if self.logs.prefixed() {
+            format!("[{}] ", name)
+        } else {
+            String::new()
+        }
+    }
+
+    pub fn is_running(&self, name: &str) -> bool {
+        self.states.get(name).is_some_and(|s| s.child.is_some())
+    }
+
+    /// Lance `executable`, après avoir arrêté la version en cours (modification)
+    pub fn start(&mut self, name: &str, executable: PathBuf) -> Result<()> {
+        let Some(service) = self.services.iter().find(|s| s.name == name) else {
+            return Ok(());
+        };
+        let env = self.ports.get(name).map(DevPorts::env).unwrap_or_default();
+        let Some(state) = self.states.get_mut(name) else {
+            return Ok(());
+        };
+
+        if let Some(mut child) = state.child.take() {
+            process::stop(&mut child, STOP_TIMEOUT);
+            state.uptime += state.started.map_or(Duration::ZERO, |s| s.elapsed());
+            state.reloads += 1;
+        }
+        state.attempts = 0;
+        state.restart_at = None;
+        state.executable = Some(executable.clone());
+
+        match process::spawn(service, &executable, &env, &self.logs) {
+            Ok(child) => {
+                state.pid = Some(child.id());
+                state.child = Some(child);
+                state.started = Some(Instant::now());
+                state.status = Status::Up;
+                Ok(())
+            }
+            Err(e) => {
+                state.status = Status::Failed;
+                Err(e)
+            }
+        }
+    }
+
+    /// Service qui n'a pas pu être compilé ni lancé
+    pub fn mark_failed(&mut self, name: &str) {
+        if let Some(state) = self.states.get_mut(name) {
+            if state.child.is_none() {
+                state.status = Status::Failed;
+                state.restart_at = None;
+            }
+        }
+    }
+
+    /// Détecte les services arrêtés et relance ceux dont le délai est écoulé
+    pub fn tick(&mut self) {
+        let now = Instant::now();
+
+        for (name, state) in &mut self.states {
+            let Some(child) = state.child.as_mut() else {
+                continue;
+            };
+            let Ok(Some(exit)) = child.try_wait() else {
+                continue;
+            };
+
+            let lifetime = state.started.map_or(Duration::ZERO, |s| s.elapsed());
+            state.child = None;
+            state.uptime += lifetime;
+            state.last_exit = Some(describe_exit(exit));
+            state.crashes += 1;
+            state.attempts = if lifetime >= STABLE_AFTER {
+                1
+            } else {
+                state.attempts + 1
+            };
+
+            if state.attempts > MAX_ATTEMPTS {
+                state.status = Status::Failed;
+                eprintln!(
+                    "❌ {} exited ({}) after {} restarts, giving up until the next change",
+                    name,
+                    exit_label(&state.last_exit),
+                    MAX_ATTEMPTS
+                );
+                continue;
+            }
+
+            let delay = backoff(state.attempts);
+            state.status = Status::Restarting;
+            state.restart_at = Some(now + delay);
+            eprintln!(
+                "💥 {} exited ({}), restarting in {} (attempt {}/{})",
+                name,
+                exit_label(&state.last_exit),
+                format_duration(delay),
+                state.attempts,
+                MAX_ATTEMPTS
+            );
+        }
+
+        let due: Vec<String> = self
+            .states
+            .iter()
+            .filter(|(_, s)| s.restart_at.is_some_and(|at| at <= now))
+            .map(|(name, _)| name.clone())
+            .collect();
+        for name in due {
+            self.restart(&name);
+        }
+    }
+
+    fn restart(&mut self, name: &str) {
+        let Some(service) = self.services.iter().find(|s| s.name == name) else {
+            return;
+        };
+        let env = self.ports.get(name).map(DevPorts::env).unwrap_or_default();
+        let Some(state) = self.states.get_mut(name) else {
+            return;
+        };
+        state.restart_at = None;
+        let Some(executable) = state.executable.clone() else {
+            state.status = Status::Failed;
+            return;
+        };
+
+        match process::spawn(service, &executable, &env, &self.logs) {
+            Ok(child) => {
+                println!("🔁 {} restarted", name);
+                state.pid = Some(child.id());
+                state.child = Some(child);
+                state.started = Some(Instant::now());
+                state.status = Status::Up;
+                state.restarts += 1;
+            }
+            Err(e) => {
+                eprintln!("❌ {:#}", e);
+                state.status = Status::Failed;
+            }
+        }
+    }
+
+    /// Arrête tous les services (SIGTERM, puis kill)
+    pub fn stop_all(&mut self) {
+        for state in self.states.values_mut() {
+            if let Some(mut child) = state.child.take() {
+                process::stop(&mut child, STOP_TIMEOUT);
+                state.uptime += state.started.map_or(Duration::ZERO, |s| s.elapsed());
+            }
+            state.restart_at = None;
+        }
+    }
+
+    /// Tableau d'état : statut, pid, port, dernier code de sortie, uptime
+    pub fn table(&self) -> String {
+        let rows = self
+            .states
+            .iter()
+            .map(|(name, state)| {
+                vec![
+                    name.clone(),
+                    state.status.to_string(),
+                    match (&state.child, state.pid) {
+                        (Some(_), Some(pid)) => pid.to_string(),
+                        _ => "-".to_string(),
+                    },
+                    self.port_label(name),
+                    exit_label(&state.last_exit).to_string(),
+                    match state.child {
+                        Some(_) => format_duration(state.current_uptime()),
+                        None => "-".to_string(),
+                    },
+                ]
+            })
+            .collect();
+
+        render_table(
+            &["SERVICE", "STATUS", "PID", "PORT", "LAST EXIT", "UPTIME"],
+            rows,
+        )
+    }
+
+    /// Bilan de la session, affiché à l'arrêt
+    pub fn summary(&self) -> String {
+        let rows = self
+            .states
+            .iter()
+            .map(|(name, state)| {
+                vec![
+                    name.clone(),
+                    state.status.to_string(),
+                    state.reloads.to_string(),
+                    state.crashes.to_string(),
+                    state.restarts.to_string(),
+                    exit_label(&state.last_exit).to_string(),
+                    format_duration(state.uptime + state.current_uptime()),
+                ]
+            })
+            .collect();
+
+        format!(
+            "📊 Session summary ({}):\n{}",
+            format_duration(self.started.elapsed()),
+            render_table(
+                &[
+                    "SERVICE",
+                    "LAST STATUS",
+                    "RELOADS",
+                    "CRASHES",
+                    "RESTARTS",
+                    "LAST EXIT",
+                    "UPTIME",
+                ],
+                rows,
+            )
+        )
+    }
+
+    fn port_label(&self, name: &str) -> String {
+        match self.ports.get(name) {
+            Some(DevPorts {
+                http: Some(http), ..
+            }) => http.to_string(),
+            _ => "-".to_string(),
+        }
+    }
+}
+
+/// Passe à `true` au premier Ctrl+C (ou SIGTERM) ; un second quitte immédiatement
+pub fn shutdown_flag() -> Arc<AtomicBool> {
+    let flag = Arc::new(AtomicBool::new(false));
+    let shutdown = flag.clone();
+    tokio::spawn(async move {
+        wait_for_signal().await;
+        shutdown.store(true, Ordering::SeqCst);
+        eprintln!("\n🛑 Stopping services... (press Ctrl+C again to force quit)");
+        wait_for_signal().await;
+        std::process::exit(130);
+    });
+    flag
+}
+
+async fn wait_for_signal() {
+    #[cfg(unix)]
+    {
+        use tokio::signal::unix::{signal, SignalKind};
+        if let Ok(mut terminate) = signal(SignalKind::terminate()) {
+            tokio::select! {
+                _ = tokio::signal::ctrl_c() => {}
+                _ = terminate.recv() => {}
+            }
+            return;
+        }
+    }
+    let _ = tokio::signal::ctrl_c().await;
+}
+
+/// Délai avant la relance n° `attempt` (1 s, 2 s, 4 s… plafonné)
+fn backoff(attempt: u32) -> Duration {
+    BACKOFF_START
+        .saturating_mul(2u32.saturating_pow(attempt.saturating_sub(1)))
+        .min(BACKOFF_MAX)
+}
+
+fn describe_exit(status: ExitStatus) -> String {
+    #[cfg(unix)]
+    {
+        use std::os::unix::process::ExitStatusExt;
+        if let Some(signal) = status.signal() {
+            return format!("signal {}", signal);
+        }
+    }
+    match status.code() {
+        Some(code) => format!("code {}", code),
+        None => "unknown".to_string(),
+    }
+}
+
+fn exit_label(last_exit: &Option<String>) -> &str {
+    last_exit.as_deref().unwrap_or("-")
+}
+
+/// `45s`, `3m12s`, `1h02m`
+fn format_duration(duration: Duration) -> String {
+    let secs = duration.as_secs();
+    match secs {
+        0..=59 => format!("{}s", secs),
+        60..=3599 => format!("{}m{:02}s", secs / 60, secs % 60),
+        _ => format!("{}h{:02}m", secs / 3600, secs % 3600 / 60),
+    }
+}
+
+fn render_table(headers: &[&str], rows: Vec<Vec<String>>) -> String {
+    let mut widths: Vec<usize> = headers.iter().map(|h| h.chars().count()).collect();
+    for row in &rows {
+        for (width, cell) in widths.iter_mut().zip(row) {
+            *width = (*width).max(cell.chars().count());
+        }
+    }
+
+    let line = |cells: Vec<&str>| {
+        let padded: Vec<String> = cells
+            .iter()
+            .zip(&widths)
+            .map(|(cell, width)| format!("{:<width$}", cell, width = width))
+            .collect();
+        format!("   {}\n", padded.join("  ").trim_end())
+    };
+
+    let mut table = line(headers.to_vec());
+    for row in &rows {
+        table.push_str(&line(row.iter().map(String::as_str).collect()));
+    }
+    table
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_backoff() {
+        let delays: Vec<u64> = (1..=7).map(|n| backoff(n).as_secs()).collect();
+        assert_eq!(delays, vec![1, 2, 4, 8, 16, 30, 30]);
+        assert_eq!(backoff(100), BACKOFF_MAX);
+    }
+
+    #[test]
+    fn test_format_duration() {
+        assert_eq!(format_duration(Duration::from_secs(45)), "45s");
+        assert_eq!(format_duration(Duration::from_secs(192)), "3m12s");
+        assert_eq!(format_duration(Duration::from_secs(3720)), "1h02m");
+    }
+
+    #[test]
+    fn test_render_table() {
+        let table = render_table(
+            &["SERVICE", "STATUS"],
+            vec![
+                vec!["auth".to_string(), "up".to_string()],
+                vec!["notifications".to_string(), "restarting".to_string()],
+            ],
+        );
+        assert_eq!(
+            table,
+            "   SERVICE        STATUS\n   auth           up\n   notifications  restarting\n"
+        );
+    }
+
+    #[cfg(unix)]
+    #[test]
+    fn test_crash_is_restarted_with_backoff() {
+        use std::os::unix::fs::PermissionsExt;
+
+        let dir = tempfile::tempdir().unwrap();
+        let script = dir.path().join("crash.sh");
+        std::fs::write(&script, "#!/bin/sh\nexit 3\n").unwrap();
+        std::fs::set_permissions(&script, std::fs::Permissions::from_mode(0o755)).unwrap();
+
+        let service = RustworkService {
+            name: "worker".to_string(),
+            path: dir.path().to_path_buf(),
+        };
+        let logs = Arc::new(Logs::new(Default::default(), false).unwrap());
+        let mut supervisor = Supervisor::new(vec![service], PortMap::new(), logs);
+        supervisor.start("worker", script).unwrap();
+        assert!(supervisor.is_running("worker"));
+
+        let deadline = Instant::now() + Duration::from_secs(5);
+        while supervisor.is_running("worker") && Instant::now() < deadline {
+            supervisor.tick();
+            std::thread::sleep(Duration::from_millis(20));
+        }
+
+        let state = &supervisor.states["worker"];
+        assert_eq!(state.status, Status::Restarting);
+        assert_eq!(state.last_exit.as_deref(), Some("code 3"));
+        assert_eq!((state.crashes, state.attempts), (1, 1));
+        assert!(supervisor
+            .table()
+            .contains("worker   restarting  -    -     code 3"));
+
+        // Relancé une fois le délai écoulé
+        std::thread::sleep(BACKOFF_START);
+        supervisor.tick();
+        assert_eq!(supervisor.states["worker"].restarts, 1);
+
+        supervisor.stop_all();
+        assert!(supervisor.summary().contains("Session summary"));
+    }
+}
@@ -0,0 +1,253 @@
+//! Fichiers surveillés par `rustwork dev` et services à relancer
+
+use anyhow::{Context, Result};
+use glob::{MatchOptions, Pattern};
+use std::collections::BTreeSet;
+use std::path::{Path, PathBuf};
+
+use super::manifest::DevManifest;
+use crate::commands::utils::RustworkService;
+
+/// Motifs surveillés par défaut, relatifs au dossier du crate
+const DEFAULT_WATCH: &[&str] = &["src/**", "config/**", "Cargo.toml", "build.rs", ".env"];
+
+/// Motifs toujours ignorés (build, fichiers temporaires des éditeurs)
+const DEFAULT_IGNORE: &[&str] = &["target/**", "**/*.swp", "**/*~", "**/.#*", "**/.DS_Store"];
+
+/// Motifs de surveillance d'un crate
+#[derive(Debug, Clone)]
+pub struct WatchConfig {
+    watch: Vec<Pattern>,
+    ignore: Vec<Pattern>,
+}
+
+impl WatchConfig {
+    /// Lit la section `dev` du manifest du crate (motifs par défaut sans manifest)
+    pub fn load(crate_dir: &Path) -> Result<Self> {
+        let manifest = DevManifest::load(crate_dir)?;
+
+        let watch = manifest
+            .dev
+            .watch
+            .unwrap_or_else(|| DEFAULT_WATCH.iter().map(|s| s.to_string()).collect());
+        let ignore: Vec<String> = DEFAULT_IGNORE
+            .iter()
+            .map(|s| s.to_string())
+            .chain(manifest.dev.ignore)
+            .collect();
+
+        Self::from_globs(&watch, &ignore).with_context(|| {
+            format!(
+                "Invalid dev globs in {}",
+                crate_dir.join(".rustwork/manifest.json").display()
+            )
+        })
+    }
+
+    fn from_globs(watch: &[String], ignore: &[String]) -> Result<Self> {
+        let compile = |globs: &[String]| -> Result<Vec<Pattern>> {
+            globs
+                .iter()
+                .map(|glob| Pattern::new(glob).with_context(|| format!("Invalid glob '{}'", glob)))
+                .collect()
+        };
+
+        Ok(Self {
+            watch: compile(watch)?,
+            ignore: compile(ignore)?,
+        })
+    }
+
+    /// `relative` : chemin relatif au dossier du crate
+    pub fn matches(&self, relative: &Path) -> bool {
+        let options = MatchOptions {
+            require_literal_separator: true,
+            ..MatchOptions::new()
+        };
+        let matches = |patterns: &[Pattern]| {
+            patterns
+                .iter()
+                .any(|pattern| pattern.matches_path_with(relative, options))
+        };
+
+        matches(&self.watch) && !matches(&self.ignore)
+    }
+}
+
+/// Crate surveillé (service ou dépendance locale) et services à relancer quand il change
+#[derive(Debug)]
+pub struct WatchedCrate {
+    pub dir: PathBuf,
+    pub config: WatchConfig,
+    pub services: Vec<String>,
+}
+
+/// Services et leurs dépendances `path = "..."` (dont `shared/`), récursivement
+pub fn watched_crates(services: &[RustworkService]) -> Result<Vec<WatchedCrate>> {
+    let mut crates: Vec<WatchedCrate> = Vec::new();
+
+    for service in services {
+        let mut pending = vec![canonical(&service.path)];
+        let mut visited = BTreeSet::new();
+
+        while let Some(dir) = pending.pop() {
+            if !visited.insert(dir.clone()) {
+                continue;
+            }
+            pending.extend(path_dependencies(&dir));
+
+            match crates.iter_mut().find(|c| c.dir == dir) {
+                Some(watched) => watched.services.push(service.name.clone()),
+                None => crates.push(WatchedCrate {
+                    config: WatchConfig::load(&dir)?,
+                    dir,
+                    services: vec![service.name.clone()],
+                }),
+            }
+        }
+    }
+
+    Ok(crates)
+}
+
+/// Services à relancer pour une liste de fichiers modifiés
+pub fn affected_services(crates: &[WatchedCrate], paths: &[PathBuf]) -> BTreeSet<String> {
+    let mut affected = BTreeSet::new();
+
+    for path in paths {
+        for watched in crates {
+            let Ok(relative) = path.strip_prefix(&watched.dir) else {
+                continue;
+            };
+            if watched.config.matches(relative) {
+                affected.extend(watched.services.iter().cloned());
+            }
+        }
+    }
+
+    affected
+}
+
+/// Dossiers des dépendances locales (`[dependencies]`, `[build-dependencies]`) d'un crate
+fn path_dependencies(crate_dir: &Path) -> Vec<PathBuf> {
+    let Ok(content) = std::fs::read_to_string(crate_dir.join("Cargo.toml")) else {
+        return Vec::new();
+    };
+    let Ok(manifest) = content.parse::<toml::Table>() else {
+        return Vec::new();
+    };
+
+    ["dependencies", "build-dependencies"]
+        .iter()
+        .filter_map(|section| manifest.get(*section)?.as_table())
+        .flat_map(|deps| deps.values())
+        .filter_map(|dep| dep.get("path")?.as_str())
+        .map(|path| canonical(&crate_dir.join(path)))
+        .filter(|dir| dir.join("Cargo.toml").is_file())
+        .collect()
+}
+
+/// Chemin canonique, ou le chemin tel quel s'il n'existe pas
+pub(super) fn canonical(path: &Path) -> PathBuf {
+    path.canonicalize().unwrap_or_else(|_| path.to_path_buf())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::fs;
+    use tempfile::TempDir;
+
+    fn config(watch: &[&str], ignore: &[&str]) -> WatchConfig {
+        let strings = |globs: &[&str]| globs.iter().map(|s| s.to_string()).collect::<Vec<_>>();
+        WatchConfig::from_globs(&strings(watch), &strings(ignore)).unwrap()
+    }
+
+    #[test]
+    fn test_watch_config_matches() {
+        let defaults = config(DEFAULT_WATCH, DEFAULT_IGNORE);
+        assert!(defaults.matches(Path::new("src/main.rs")));
+        assert!(defaults.matches(Path::new("src/controllers/users.rs")));
+        assert!(defaults.matches(Path::new("config/dev.toml")));
+        assert!(defaults.matches(Path::new("Cargo.toml")));
+        assert!(!defaults.matches(Path::new("README.md")));
+        assert!(!defaults.matches(Path::new("data/dev.db")));
+        assert!(!defaults.matches(Path::new("src/.main.rs.swp")));
+        assert!(!defaults.matches(Path::new("src/main.rs~")));
+
+        let custom = config(&["src/**/*.rs", "templates/*.html"], &["src/generated/**"]);
+        assert!(custom.matches(Path::new("src/lib.rs")));
+        assert!(custom.matches(Path::new("templates/index.html")));
+        assert!(!custom.matches(Path::new("templates/emails/welcome.html")));
+        assert!(!custom.matches(Path::new("src/generated/users.rs")));
+        assert!(!custom.matches(Path::new("config/dev.toml")));
+    }
+
+    #[test]
+    fn test_load_from_manifest() {
+        let dir = TempDir::new().unwrap();
+        fs::create_dir_all(dir.path().join(".rustwork")).unwrap();
+        fs::write(
+            dir.path().join(".rustwork/manifest.json"),
+            r#"{"type": "service", "dev": {"ignore": ["src/generated/**"]}}"#,
+        )
+        .unwrap();
+
+        let config = WatchConfig::load(dir.path()).unwrap();
+        assert!(config.matches(Path::new("src/main.rs")));
+        assert!(!config.matches(Path::new("src/generated/users.rs")));
+        assert!(!config.matches(Path::new("target/debug/users")));
+
+        fs::write(
+            dir.path().join(".rustwork/manifest.json"),
+            r#"{"dev": {"watch": ["src/[invalid"]}}"#,
+        )
+        .unwrap();
+        assert!(WatchConfig::load(dir.path()).is_err());
+    }
+
+    #[test]
+    fn test_affected_services_follow_path_dependencies() {
+        let dir = TempDir::new().unwrap();
+        let services_dir = dir.path().join("services");
+        for (name, deps) in [
+            ("users", "shared = { path = \"../shared\" }\n"),
+            ("billing", "shared = { path = \"../shared\" }\n"),
+            ("gateway", ""),
+            ("shared", ""),
+        ] {
+            fs::create_dir_all(services_dir.join(name).join("src")).unwrap();
+            fs::write(
+                services_dir.join(name).join("Cargo.toml"),
+                format!("[package]\nname = \"{}\"\n\n[dependencies]\n{}", name, deps),
+            )
+            .unwrap();
+        }
+        let services: Vec<RustworkService> = ["users", "billing", "gateway"]
+            .iter()
+            .map(|name| RustworkService {
+                name: name.to_string(),
+                path: services_dir.join(name),
+            })
+            .collect();
+
+        let crates = watched_crates(&services).unwrap();
+        assert_eq!(crates.len(), 4);
+
+        let root = canonical(&services_dir);
+        let changed = |paths: &[&str]| {
+            let paths: Vec<PathBuf> = paths.iter().map(|p| root.join(p)).collect();
+            affected_services(&crates, &paths)
+                .into_iter()
+                .collect::<Vec<_>>()
+        };
+
+        assert_eq!(changed(&["users/src/main.rs"]), vec!["users"]);
+        assert_eq!(changed(&["shared/src/lib.rs"]), vec!["billing", "users"]);
+        assert_eq!(
+            changed(&["gateway/Cargo.toml", "users/README.md"]),
+            vec!["gateway"]
+        );
+        assert!(changed(&["users/target/debug/users", "other/src/main.rs"]).is_empty());
+    }
+}
@@ -0,0 +1,253 @@
+//! Cohérence des versions de dépendances entre les crates du workspace
+
+use std::collections::BTreeMap;
+use std::path::PathBuf;
+
+use super::{Category, Check, Report};
+use crate::commands::utils::RustworkService;
+use crate::mcp::common::workspace_root::WorkspaceRoot;
+
+/// Version de prost attendue par chaque version de tonic
+const TONIC_PROST: [(&str, &str); 6] = [
+    ("0.9", "0.11"),
+    ("0.10", "0.12"),
+    ("0.11", "0.12"),
+    ("0.12", "0.13"),
+    ("0.13", "0.13"),
+    ("0.14", "0.14"),
+];
+
+pub fn check(report: &mut Report, root: &WorkspaceRoot, services: &[RustworkService]) {
+    let mut crates: Vec<(String, PathBuf)> = services
+        .iter()
+        .map(|s| (s.name.clone(), s.path.clone()))
+        .collect();
+    let shared = root.services_dir().join("shared");
+    if shared.join("Cargo.toml").exists() {
+        crates.push(("shared".to_string(), shared));
+    }
+    if crates.is_empty() {
+        return;
+    }
+
+    // Dépendance -> version compatible -> (exigence, crates)
+    let mut versions: BTreeMap<String, BTreeMap<String, (String, Vec<String>)>> = BTreeMap::new();
+    let before = report.checks.len();
+
+    for (name, path) in &crates {
+        let Ok(content) = std::fs::read_to_string(path.join("Cargo.toml")) else {
+            continue;
+        };
+        let requirements = requirements(&content);
+        check_grpc_versions(report, name, &requirements);
+
+        for (dependency, requirement) in requirements {
+            versions
+                .entry(dependency)
+                .or_default()
+                .entry(compatible(&requirement))
+                .or_insert_with(|| (requirement, Vec::new()))
+                .1
+                .push(name.clone());
+        }
+    }
+
+    for (dependency, requirements) in versions.iter().filter(|(_, r)| r.len() > 1) {
+        let used: Vec<String> = requirements
+            .values()
+            .map(|(requirement, crates)| format!("{} ({})", requirement, crates.join(", ")))
+            .collect();
+        let latest = requirements
+            .values()
+            .map(|(requirement, _)| requirement)
+            .max_by_key(|requirement| version_key(requirement))
+            .cloned()
+            .unwrap_or_default();
+        report.push(
+            Check::warning(
+                Category::Dependencies,
+                dependency,
+                format!("different versions: {}", used.join(", ")),
+            )
+            .with_fix(format!(
+                "Use one version in every crate, e.g. {} = \"{}\" in [workspace.dependencies]\nand {} = {{ workspace = true }} in each Cargo.toml",
+                dependency, latest, dependency
+            )),
+        );
+    }
+
+    if report.checks.len() == before {
+        report.push(Check::ok(
+            Category::Dependencies,
+            "versions",
+            format!("consistent across {} crate(s)", crates.len()),
+        ));
+    }
+}
+
+/// tonic, tonic-build et prost d'un même crate doivent aller ensemble
+fn check_grpc_versions(report: &mut Report, name: &str, requirements: &BTreeMap<String, String>) {
+    let Some(tonic) = requirements.get("tonic") else {
+        return;
+    };
+    let tonic = compatible(tonic);
+
+    if let Some(build) = requirements.get("tonic-build") {
+        if compatible(build) != tonic {
+            report.push(
+                Check::error(
+                    Category::Dependencies,
+                    name,
+                    format!("tonic {} but tonic-build {}", tonic, build),
+                )
+                .with_fix(format!(
+                    "Set tonic-build = \"{}\" in [build-dependencies]",
+                    tonic
+                )),
+            );
+        }
+    }
+
+    let expected = TONIC_PROST
+        .iter()
+        .find(|(t, _)| *t == tonic)
+        .map(|(_, prost)| *prost);
+    if let (Some(prost), Some(expected)) = (requirements.get("prost"), expected) {
+        if compatible(prost) != expected {
+            report.push(
+                Check::error(
+                    Category::Dependencies,
+                    name,
+                    format!(
+                        "tonic {} expects prost {}, found {}",
+                        tonic, expected, prost
+                    ),
+                )
+                .with_fix(format!("Set prost = \"{}\" in [dependencies]", expected)),
+            );
+        }
+    }
+}
+
+/// Exigences de version de `[dependencies]` et `[build-dependencies]`
+///
+/// Les dépendances `workspace = true` ou sans version (`path`, `git`) sont ignorées.
+fn requirements(cargo_toml: &str) -> BTreeMap<String, String> {
+    let Ok(table) = cargo_toml.parse::<toml::Table>() else {
+        return BTreeMap::new();
+    };
+
+    let mut requirements = BTreeMap::new();
+    for section in ["dependencies", "build-dependencies"] {
+        let Some(dependencies) = table.get(section).and_then(|s| s.as_table()) else {
+            continue;
+        };
+        for (name, value) in dependencies {
+            let version = match value {
+                toml::Value::String(version) => Some(version.as_str()),
+                toml::Value::Table(table) => table.get("version").and_then(|v| v.as_str()),
+                _ => None,
+            };
+            if let Some(version) = version {
+                requirements.insert(name.clone(), version.to_string());
+            }
+        }
+    }
+    requirements
+}
+
+/// Plage semver compatible d'une exigence : `0.11.2` → `0.11`, `^1.2` → `1`
+fn compatible(requirement: &str) -> String {
+    let version = requirement.trim_start_matches(['^', '=', '~', ' ']);
+    let mut parts = version.split('.');
+    match (parts.next(), parts.next()) {
+        (Some("0"), Some(minor)) => format!("0.{}", minor),
+        (Some(major), _) => major.to_string(),
+        _ => version.to_string(),
+    }
+}
+
+fn version_key(requirement: &str) -> Vec<u64> {
+    requirement
+        .trim_start_matches(['^', '=', '~', ' '])
+        .split('.')
+        .map(|part| part.parse().unwrap_or(0))
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_requirements() {
+        let requirements = requirements(
+            r#"
+[dependencies]
+tonic = "0.12"
+serde = { version = "1.0", features = ["derive"] }
+rustwork = { path = "../../../crates/rustwork" }
+tokio = { workspace = true }
+
+[build-dependencies]
+tonic-build = "0.11"
+"#,
+        );
+        assert_eq!(
+            requirements.into_iter().collect::<Vec<_>>(),
+            vec![
+                ("serde".to_string(), "1.0".to_string()),
+                ("tonic".to_string(), "0.12".to_string()),
+                ("tonic-build".to_string(), "0.11".to_string()),
+            ]
+        );
+
+        assert_eq!(compatible("0.11.2"), "0.11");
+        assert_eq!(compatible("^1.2"), "1");
+        assert_eq!(compatible("1"), "1");
+    }
+
+    #[test]
+    fn test_check_grpc_versions() {
+        let versions = |pairs: &[(&str, &str)]| {
+            pairs
+                .iter()
+                .map(|(n, v)| (n.to_string(), v.to_string()))
+                .collect::<BTreeMap<_, _>>()
+        };
+
+        let mut report = Report::default();
+        check_grpc_versions(
+            &mut report,
+            "auth",
+            &versions(&[
+                ("tonic", "0.11"),
+                ("prost", "0.12"),
+                ("tonic-build", "0.11.0"),
+            ]),
+        );
+        assert!(report.checks.is_empty());
+
+        check_grpc_versions(
+            &mut report,
+            "auth",
+            &versions(&[
+                ("tonic", "0.12"),
+                ("prost", "0.12"),
+                ("tonic-build", "0.11"),
+            ]),
+        );
+        let messages: Vec<_> = report.checks.iter().map(|c| c.message.as_str()).collect();
+        assert_eq!(
+            messages,
+            vec![
+                "tonic 0.12 but tonic-build 0.11",
+                "tonic 0.12 expects prost 0.13, found 0.12"
+            ]
+        );
+        assert_eq!(
+            report.checks[0].fix.as_deref(),
+            Some("Set tonic-build = \"0.12\" in [build-dependencies]")
+        );
+    }
+}
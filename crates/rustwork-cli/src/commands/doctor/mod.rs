@@ -0,0 +1,251 @@
+//! Diagnostic de l'environnement et du workspace (`rustwork doctor`)
+//!
+//! Chaque vérification produit un [`Check`] avec, en cas de problème, la correction à
+//! appliquer. `--json` affiche le rapport complet pour la CI ; la commande échoue dès
+//! qu'une vérification est en erreur.
+
+mod database;
+mod dependencies;
+mod toolchain;
+mod workspace;
+
+use anyhow::Result;
+use serde::Serialize;
+use std::path::Path;
+
+use crate::mcp::common::workspace_root::WorkspaceRoot;
+
+/// Résultat d'une vérification
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize)]
+#[serde(rename_all = "lowercase")]
+pub enum Status {
+    Ok,
+    Warning,
+    Error,
+}
+
+impl Status {
+    fn icon(self) -> &'static str {
+        match self {
+            Status::Ok => "✅",
+            Status::Warning => "⚠️ ",
+            Status::Error => "❌",
+        }
+    }
+}
+
+/// Groupe de vérifications, dans l'ordre d'affichage
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Serialize)]
+#[serde(rename_all = "lowercase")]
+pub enum Category {
+    Toolchain,
+    Workspace,
+    Services,
+    Dependencies,
+    Database,
+}
+
+impl Category {
+    fn title(self) -> &'static str {
+        match self {
+            Category::Toolchain => "Toolchain",
+            Category::Workspace => "Workspace",
+            Category::Services => "Services",
+            Category::Dependencies => "Dependencies",
+            Category::Database => "Databases",
+        }
+    }
+}
+
+/// Une vérification et la correction proposée
+#[derive(Debug, Clone, PartialEq, Serialize)]
+pub struct Check {
+    pub category: Category,
+    pub name: String,
+    pub status: Status,
+    pub message: String,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub fix: Option<String>,
+}
+
+impl Check {
+    pub fn ok(category: Category, name: impl Into<String>, message: impl Into<String>) -> Self {
+        Self::new(category, name, Status::Ok, message)
+    }
+
+    pub fn warning(
+        category: Category,
+        name: impl Into<String>,
+        message: impl Into<String>,
+    ) -> Self {
+        Self::new(category, name, Status::Warning, message)
+    }
+
+    pub fn error(category: Category, name: impl Into<String>, message: impl Into<String>) -> Self {
+        Self::new(category, name, Status::Error, message)
+    }
+
+    fn new(
+        category: Category,
+        name: impl Into<String>,
+        status: Status,
+        message: impl Into<String>,
+    ) -> Self {
+        Self {
+            category,
+            name: name.into(),
+            status,
+            message: message.into(),
+            fix: None,
+        }
+    }
+
+    pub fn with_fix(mut self, fix: impl Into<String>) -> Self {
+        self.fix = Some(fix.into());
+        self
+    }
+}
+
+/// Rapport de `rustwork doctor`
+#[derive(Debug, Default, Serialize)]
+pub struct Report {
+    pub checks: Vec<Check>,
+}
+
+impl Report {
+    pub fn push(&mut self, check: Check) {
+        self.checks.push(check);
+    }
+
+    pub fn count(&self, status: Status) -> usize {
+        self.checks.iter().filter(|c| c.status == status).count()
+    }
+
+    /// Rapport JSON : vérifications et totaux par statut
+    pub fn to_json(&self) -> String {
+        let report = serde_json::json!({
+            "checks": self.checks,
+            "summary": {
+                "ok": self.count(Status::Ok),
+                "warning": self.count(Status::Warning),
+                "error": self.count(Status::Error),
+            },
+        });
+        serde_json::to_string_pretty(&report).unwrap_or_default()
+    }
+
+    /// Rapport lisible, groupé par catégorie
+    pub fn render(&self) -> String {
+        let mut categories: Vec<Category> = self.checks.iter().map(|c| c.category).collect();
+        categories.sort();
+        categories.dedup();
+
+        let mut out = String::new();
+        for category in categories {
+            out.push_str(&format!("\n{}\n", category.title()));
+            for check in self.checks.iter().filter(|c| c.category == category) {
+                out.push_str(&format!(
+                    "  {} {}: {}\n",
+                    check.status.icon(),
+                    check.name,
+                    check.message
+                ));
+                if let Some(fix) = &check.fix {
+                    for line in fix.lines() {
+                        out.push_str(&format!("     → {}\n", line));
+                    }
+                }
+            }
+        }
+        out.push_str(&format!(
+            "\n{} ok, {} warning(s), {} error(s)\n",
+            self.count(Status::Ok),
+            self.count(Status::Warning),
+            self.count(Status::Error)
+        ));
+        out
+    }
+}
+
+/// Point d'entrée de `rustwork doctor`
+pub async fn execute(project: Option<String>, json: bool) -> Result<()> {
+    let current_dir = std::env::current_dir()?;
+    let workspace_root = match project {
+        Some(path) => WorkspaceRoot::detect_with_explicit(&current_dir, Some(Path::new(&path))),
+        None => WorkspaceRoot::detect(&current_dir),
+    };
+
+    if !json {
+        println!("🩺 Checking the Rustwork environment...");
+    }
+
+    let mut report = Report::default();
+    let needs_protoc = workspace_root
+        .as_ref()
+        .is_ok_and(workspace::has_grpc_contracts);
+    toolchain::check(&mut report, needs_protoc);
+
+    match workspace_root {
+        Ok(root) => {
+            let services = workspace::check(&mut report, &root);
+            dependencies::check(&mut report, &root, &services);
+            database::check(&mut report, &services).await;
+        }
+        Err(e) => report.push(
+            // Première ligne : la suite décrit la structure attendue
+            Check::error(
+                Category::Workspace,
+                "workspace root",
+                e.to_string().lines().next().unwrap_or_default(),
+            )
+            .with_fix("Run rustwork doctor from a Rustwork workspace, or pass --project <path>"),
+        ),
+    }
+
+    if json {
+        println!("{}", report.to_json());
+    } else {
+        print!("{}", report.render());
+    }
+
+    let errors = report.count(Status::Error);
+    if errors > 0 {
+        anyhow::bail!("rustwork doctor found {} error(s)", errors);
+    }
+
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn report() -> Report {
+        let mut report = Report::default();
+        report.push(Check::ok(Category::Workspace, "services", "2 service(s)"));
+        report.push(
+            Check::error(Category::Toolchain, "protoc", "not found")
+                .with_fix("Install protoc\nor set PROTOC"),
+        );
+        report
+    }
+
+    #[test]
+    fn test_render() {
+        let content = report().render();
+        assert!(content.starts_with(
+            "\nToolchain\n  ❌ protoc: not found\n     → Install protoc\n     → or set PROTOC\n\nWorkspace\n  ✅ services: 2 service(s)\n"
+        ));
+        assert!(content.ends_with("\n1 ok, 0 warning(s), 1 error(s)\n"));
+    }
+
+    #[test]
+    fn test_to_json() {
+        let json: serde_json::Value = serde_json::from_str(&report().to_json()).unwrap();
+        assert_eq!(json["summary"]["error"], 1);
+        assert_eq!(json["checks"][0]["category"], "workspace");
+        assert!(json["checks"][0].get("fix").is_none());
+        assert_eq!(json["checks"][1]["status"], "error");
+        assert_eq!(json["checks"][1]["fix"], "Install protoc\nor set PROTOC");
+    }
+}
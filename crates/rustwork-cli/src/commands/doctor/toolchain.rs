@@ -0,0 +1,111 @@
+//! Outils requis : cargo, rustc, protoc
+
+use std::process::Command;
+
+use super::{Category, Check, Report};
+
+/// Version minimale de Rust (sqlx 0.8)
+const MIN_RUST_VERSION: (u32, u32) = (1, 80);
+
+pub fn check(report: &mut Report, needs_protoc: bool) {
+    match command_version("cargo", &["--version"]) {
+        Some(version) => report.push(Check::ok(Category::Toolchain, "cargo", version)),
+        None => report.push(
+            Check::error(Category::Toolchain, "cargo", "not found")
+                .with_fix("Install Rust with rustup: https://rustup.rs"),
+        ),
+    }
+
+    match command_version("rustc", &["--version"]) {
+        Some(version) => match parse_version(&version) {
+            Some((major, minor, _)) if (major, minor) < MIN_RUST_VERSION => report.push(
+                Check::error(
+                    Category::Toolchain,
+                    "rustc",
+                    format!(
+                        "{} is older than {}.{}",
+                        version, MIN_RUST_VERSION.0, MIN_RUST_VERSION.1
+                    ),
+                )
+                .with_fix("rustup update stable"),
+            ),
+            _ => report.push(Check::ok(Category::Toolchain, "rustc", version)),
+        },
+        None => report.push(
+            Check::error(Category::Toolchain, "rustc", "not found")
+                .with_fix("Install Rust with rustup: https://rustup.rs"),
+        ),
+    }
+
+    // tonic-build utilise $PROTOC s'il est défini
+    let protoc = std::env::var("PROTOC").unwrap_or_else(|_| "protoc".to_string());
+    match command_version(&protoc, &["--version"]) {
+        Some(version) => report.push(Check::ok(Category::Toolchain, "protoc", version)),
+        None if needs_protoc => report.push(
+            Check::error(
+                Category::Toolchain,
+                "protoc",
+                "not found, required to build the gRPC services",
+            )
+            .with_fix(
+                "Install protoc (apt install protobuf-compiler, brew install protobuf)\nor set PROTOC to its path",
+            ),
+        ),
+        None => report.push(Check::ok(
+            Category::Toolchain,
+            "protoc",
+            "not installed (no gRPC contract in the workspace)",
+        )),
+    }
+
+    match command_version("cargo", &["watch", "--version"]) {
+        Some(version) => report.push(Check::ok(Category::Toolchain, "cargo-watch", version)),
+        None => report.push(Check::ok(
+            Category::Toolchain,
+            "cargo-watch",
+            "not installed (optional: rustwork dev rebuilds the services itself)",
+        )),
+    }
+}
+
+/// Première ligne de `<program> <args>`, si la commande réussit
+fn command_version(program: &str, args: &[&str]) -> Option<String> {
+    let output = Command::new(program).args(args).output().ok()?;
+    if !output.status.success() {
+        return None;
+    }
+    String::from_utf8_lossy(&output.stdout)
+        .lines()
+        .next()
+        .map(|line| line.trim().to_string())
+}
+
+/// Première version `x.y.z` d'une sortie `--version`
+fn parse_version(text: &str) -> Option<(u32, u32, u32)> {
+    text.split_whitespace().find_map(|word| {
+        let mut parts = word.split(['.', '-']);
+        let major = parts.next()?.parse().ok()?;
+        let minor = parts.next()?.parse().ok()?;
+        let patch = parts.next().and_then(|p| p.parse().ok()).unwrap_or(0);
+        Some((major, minor, patch))
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_parse_version() {
+        assert_eq!(
+            parse_version("rustc 1.82.0 (f6e511eec 2024-10-15)"),
+            Some((1, 82, 0))
+        );
+        assert_eq!(
+            parse_version("rustc 1.84.0-nightly (abc 2024-11-01)"),
+            Some((1, 84, 0))
+        );
+        assert_eq!(parse_version("libprotoc 3.21"), Some((3, 21, 0)));
+        assert_eq!(parse_version("cargo-watch"), None);
+    }
+}
@@ -0,0 +1,458 @@
+//! Structure du workspace et validité de chaque service
+
+use std::collections::BTreeMap;
+use std::path::{Path, PathBuf};
+
+use super::{Category, Check, Report};
+use crate::commands::dev::manifest::DevManifest;
+use crate::commands::ports::PortRegistry;
+use crate::commands::utils::{detect_rustwork_services, RustworkService};
+use crate::mcp::common::workspace_root::WorkspaceRoot;
+
+/// Fichiers sans lesquels un dossier n'est pas détecté comme service
+const SERVICE_FILES: [&str; 3] = [".rustwork/manifest.json", "Cargo.toml", "src/main.rs"];
+
+/// Vérifie la structure du workspace et retourne les services détectés
+pub fn check(report: &mut Report, root: &WorkspaceRoot) -> Vec<RustworkService> {
+    report.push(Check::ok(
+        Category::Workspace,
+        "workspace root",
+        root.path().display().to_string(),
+    ));
+
+    let services_dir = root.services_dir();
+    if !services_dir.is_dir() {
+        report.push(
+            Check::error(Category::Workspace, "services", "no services/ directory")
+                .with_fix("Create a workspace with rustwork new <name>"),
+        );
+        return Vec::new();
+    }
+    check_service_dirs(report, &services_dir);
+
+    let services = match detect_rustwork_services(root.path()) {
+        Ok(services) => services,
+        Err(e) => {
+            report.push(Check::error(
+                Category::Workspace,
+                "services",
+                format!("{:#}", e),
+            ));
+            return Vec::new();
+        }
+    };
+    if services.is_empty() {
+        report.push(
+            Check::error(Category::Workspace, "services", "no Rustwork service found")
+                .with_fix("rustwork add-service <name>"),
+        );
+        return services;
+    }
+    let names: Vec<&str> = services.iter().map(|s| s.name.as_str()).collect();
+    report.push(Check::ok(
+        Category::Workspace,
+        "services",
+        format!("{} service(s): {}", services.len(), names.join(", ")),
+    ));
+
+    check_cargo_members(report, root, &services);
+    check_ports(report, root, &services);
+    for service in &services {
+        check_service(report, service, &services);
+    }
+
+    services
+}
+
+/// Au moins un service du workspace déclare un contrat gRPC
+pub fn has_grpc_contracts(root: &WorkspaceRoot) -> bool {
+    detect_rustwork_services(root.path())
+        .unwrap_or_default()
+        .iter()
+        .any(|service| !contracts(&service.path).is_empty())
+}
+
+/// Contrats `grpc/*.rwk` d'un service
+fn contracts(service_path: &Path) -> Vec<PathBuf> {
+    std::fs::read_dir(service_path.join("grpc"))
+        .into_iter()
+        .flatten()
+        .flatten()
+        .map(|entry| entry.path())
+        .filter(|path| path.extension().and_then(|e| e.to_str()) == Some("rwk"))
+        .collect()
+}
+
+/// Dossiers de `services/` ignorés faute d'un fichier requis
+fn check_service_dirs(report: &mut Report, services_dir: &Path) {
+    let mut dirs: Vec<PathBuf> = std::fs::read_dir(services_dir)
+        .into_iter()
+        .flatten()
+        .flatten()
+        .map(|entry| entry.path())
+        .filter(|path| path.is_dir())
+        .collect();
+    dirs.sort();
+
+    for dir in dirs {
+        let Some(name) = dir.file_name().map(|n| n.to_string_lossy().to_string()) else {
+            continue;
+        };
+        if name == "shared" || name == "target" || name.starts_with('.') {
+            continue;
+        }
+        let missing = missing_service_files(&dir);
+        if missing.is_empty() {
+            continue;
+        }
+
+        let fix = if missing == [SERVICE_FILES[0]] {
+            format!(
+                "Create {} containing {{\"version\": \"0.1.0\", \"type\": \"service\"}}",
+                dir.join(SERVICE_FILES[0]).display()
+            )
+        } else {
+            "Add the missing files, or remove the directory if it is not a service".to_string()
+        };
+        report.push(
+            Check::error(
+                Category::Services,
+                name,
+                format!(
+                    "ignored by rustwork dev and the MCP server: missing {}",
+                    missing.join(", ")
+                ),
+            )
+            .with_fix(fix),
+        );
+    }
+}
+
+fn missing_service_files(dir: &Path) -> Vec<&'static str> {
+    SERVICE_FILES
+        .into_iter()
+        .filter(|file| !dir.join(file).exists())
+        .collect()
+}
+
+/// Services (et crate partagée) déclarés dans `[workspace] members`
+fn check_cargo_members(report: &mut Report, root: &WorkspaceRoot, services: &[RustworkService]) {
+    let cargo_dir = root.cargo_workspace_dir();
+    let cargo_toml = cargo_dir.join("Cargo.toml");
+    let Some(members) = std::fs::read_to_string(&cargo_toml)
+        .ok()
+        .and_then(|content| content.parse::<toml::Table>().ok())
+        .and_then(|table| workspace_members(&table))
+    else {
+        return;
+    };
+
+    let mut crates: Vec<PathBuf> = services.iter().map(|s| s.path.clone()).collect();
+    if root.has_shared() {
+        crates.push(root.services_dir().join("shared"));
+    }
+
+    let mut missing = Vec::new();
+    for path in &crates {
+        let relative = path
+            .strip_prefix(&cargo_dir)
+            .unwrap_or(path)
+            .to_string_lossy()
+            .replace('\\', "/");
+        if !is_member(&members, &relative) {
+            missing.push(relative);
+        }
+    }
+
+    if missing.is_empty() {
+        report.push(Check::ok(
+            Category::Workspace,
+            "cargo workspace",
+            format!("{} member(s) in {}", crates.len(), cargo_toml.display()),
+        ));
+    } else {
+        let entries: Vec<String> = missing.iter().map(|m| format!("\"{}\"", m)).collect();
+        report.push(
+            Check::error(
+                Category::Workspace,
+                "cargo workspace",
+                format!("not in [workspace] members: {}", missing.join(", ")),
+            )
+            .with_fix(format!(
+                "Add {} to [workspace] members in {}",
+                entries.join(", "),
+                cargo_toml.display()
+            )),
+        );
+    }
+}
+
+fn workspace_members(table: &toml::Table) -> Option<Vec<String>> {
+    let members = table.get("workspace")?.get("members")?.as_array()?;
+    Some(
+        members
+            .iter()
+            .filter_map(|member| member.as_str())
+            .map(|member| member.trim_end_matches('/').to_string())
+            .collect(),
+    )
+}
+
+fn is_member(members: &[String], relative: &str) -> bool {
+    members.iter().any(|member| {
+        member == relative
+            || glob::Pattern::new(member).is_ok_and(|pattern| pattern.matches(relative))
+    })
+}
+
+/// Ports HTTP uniques entre les services
+fn check_ports(report: &mut Report, root: &WorkspaceRoot, services: &[RustworkService]) {
+    let registry = match PortRegistry::load(root.path(), services) {
+        Ok(registry) => registry,
+        Err(e) => {
+            report.push(
+                Check::error(Category::Workspace, "ports", format!("{:#}", e)).with_fix(format!(
+                    "Fix or delete {} (it is rebuilt from config/default.toml)",
+                    PortRegistry::path(root.path()).display()
+                )),
+            );
+            return;
+        }
+    };
+
+    let mut users: BTreeMap<u16, Vec<&str>> = BTreeMap::new();
+    for (service, ports) in &registry.services {
+        users.entry(ports.http).or_default().push(service);
+    }
+
+    let mut ok = true;
+    for (port, services) in users.iter().filter(|(_, s)| s.len() > 1) {
+        ok = false;
+        report.push(
+            Check::error(
+                Category::Workspace,
+                "ports",
+                format!("port {} is used by {}", port, services.join(", ")),
+            )
+            .with_fix(
+                "Change [server] port in config/default.toml of one service\nor start with rustwork dev --remap-ports",
+            ),
+        );
+    }
+    for service in services {
+        if !registry.services.contains_key(&service.name) {
+            ok = false;
+            report.push(
+                Check::warning(
+                    Category::Workspace,
+                    "ports",
+                    format!("no [server] port for {}", service.name),
+                )
+                .with_fix(format!(
+                    "Set [server] port in {}",
+                    service.path.join("config/default.toml").display()
+                )),
+            );
+        }
+    }
+
+    if ok {
+        report.push(Check::ok(Category::Workspace, "ports", "no conflict"));
+    }
+}
+
+/// Manifest, configuration et contrats gRPC d'un service
+fn check_service(report: &mut Report, service: &RustworkService, services: &[RustworkService]) {
+    let before = report.checks.len();
+    let name = &service.name;
+
+    match DevManifest::load(&service.path) {
+        Ok(manifest) => {
+            for dependency in &manifest.depends_on {
+                if !services.iter().any(|s| &s.name == dependency) {
+                    report.push(
+                        Check::warning(
+                            Category::Services,
+                            name,
+                            format!("depends_on unknown service '{}'", dependency),
+                        )
+                        .with_fix(format!(
+                            "Remove '{}' from depends_on in .rustwork/manifest.json",
+                            dependency
+                        )),
+                    );
+                }
+            }
+        }
+        Err(e) => report.push(
+            Check::error(Category::Services, name, format!("{:#}", e)).with_fix(
+                "Fix .rustwork/manifest.json: depends_on is a list of service names, dev an object",
+            ),
+        ),
+    }
+
+    let config = service.path.join("config/default.toml");
+    match std::fs::read_to_string(&config) {
+        Ok(content) => {
+            if let Err(e) = content.parse::<toml::Table>() {
+                report.push(
+                    Check::error(
+                        Category::Services,
+                        name,
+                        format!("invalid config/default.toml: {}", e.message()),
+                    )
+                    .with_fix(format!("Fix the TOML syntax of {}", config.display())),
+                );
+            }
+        }
+        Err(_) => report.push(
+            Check::error(Category::Services, name, "missing config/default.toml").with_fix(
+                format!(
+                    "Create {} with [server] and [database] sections",
+                    config.display()
+                ),
+            ),
+        ),
+    }
+
+    let contracts = contracts(&service.path);
+    if !contracts.is_empty() {
+        let cargo_toml =
+            std::fs::read_to_string(service.path.join("Cargo.toml")).unwrap_or_default();
+        let mut missing = Vec::new();
+        if !service.path.join("build.rs").exists() {
+            missing.push("build.rs");
+        }
+        for dependency in ["tonic", "prost", "tonic-build"] {
+            if !cargo_toml.contains(&format!("{} = ", dependency)) {
+                missing.push(dependency);
+            }
+        }
+        if !missing.is_empty() {
+            report.push(
+                Check::error(
+                    Category::Services,
+                    name,
+                    format!(
+                        "{} gRPC contract(s) but no {}",
+                        contracts.len(),
+                        missing.join(", ")
+                    ),
+                )
+                .with_fix(format!("rustwork grpc build --service {}", name)),
+            );
+        }
+    }
+
+    if report.checks.len() == before {
+        let grpc = match contracts.len() {
+            0 => String::new(),
+            count => format!(", {} gRPC contract(s)", count),
+        };
+        report.push(Check::ok(
+            Category::Services,
+            name,
+            format!("manifest and config valid{}", grpc),
+        ));
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::fs;
+    use tempfile::TempDir;
+
+    fn service(root: &Path, name: &str, manifest: Option<&str>) -> PathBuf {
+        let path = root.join("Backend/services").join(name);
+        fs::create_dir_all(path.join("src")).unwrap();
+        fs::create_dir_all(path.join("config")).unwrap();
+        fs::write(path.join("Cargo.toml"), "[package]\nname = \"x\"\n").unwrap();
+        fs::write(path.join("src/main.rs"), "fn main() {}").unwrap();
+        if let Some(manifest) = manifest {
+            fs::create_dir_all(path.join(".rustwork")).unwrap();
+            fs::write(path.join(".rustwork/manifest.json"), manifest).unwrap();
+        }
+        path
+    }
+
+    fn problems(report: &Report) -> Vec<(String, String)> {
+        report
+            .checks
+            .iter()
+            .filter(|c| c.status != super::super::Status::Ok)
+            .map(|c| (c.name.clone(), c.message.clone()))
+            .collect()
+    }
+
+    #[test]
+    fn test_check_workspace() {
+        let dir = TempDir::new().unwrap();
+        fs::create_dir_all(dir.path().join("Backend")).unwrap();
+        fs::write(
+            dir.path().join("Backend/Cargo.toml"),
+            "[workspace]\nmembers = [\"services/auth\", \"services/billing\"]\n",
+        )
+        .unwrap();
+
+        let auth = service(dir.path(), "auth", Some("{}"));
+        fs::write(auth.join("config/default.toml"), "[server]\nport = 3001\n").unwrap();
+        let billing = service(
+            dir.path(),
+            "billing",
+            Some("{\"depends_on\": [\"payments\"]}"),
+        );
+        fs::write(
+            billing.join("config/default.toml"),
+            "[server]\nport = 3001\n",
+        )
+        .unwrap();
+        let orders = service(dir.path(), "orders", Some("{\"depends_on\": \"auth\"}"));
+        fs::create_dir_all(orders.join("grpc")).unwrap();
+        fs::write(orders.join("grpc/orders.rwk"), "service Orders").unwrap();
+        service(dir.path(), "legacy", None);
+
+        let root = WorkspaceRoot::detect_with_explicit(dir.path(), Some(dir.path())).unwrap();
+        assert!(has_grpc_contracts(&root));
+
+        let mut report = Report::default();
+        let services = check(&mut report, &root);
+        assert_eq!(services.len(), 3);
+
+        let problems = problems(&report);
+        let expected = [
+            (
+                "legacy",
+                "ignored by rustwork dev and the MCP server: missing .rustwork/manifest.json",
+            ),
+            (
+                "cargo workspace",
+                "not in [workspace] members: services/orders",
+            ),
+            ("ports", "port 3001 is used by auth, billing"),
+            ("ports", "no [server] port for orders"),
+            ("billing", "depends_on unknown service 'payments'"),
+            ("orders", "missing config/default.toml"),
+            (
+                "orders",
+                "1 gRPC contract(s) but no build.rs, tonic, prost, tonic-build",
+            ),
+        ];
+        for (name, message) in expected {
+            assert!(
+                problems.iter().any(|(n, m)| n == name && m == message),
+                "{} / {} not in {:?}",
+                name,
+                message,
+                problems
+            );
+        }
+        assert!(problems
+            .iter()
+            .any(|(n, m)| n == "orders" && m.contains("invalid type")));
+        assert!(report
+            .checks
+            .iter()
+            .any(|c| c.name == "auth" && c.message == "manifest and config valid"));
+    }
+}
@@ -1,6 +1,7 @@
 use super::utils::{detect_rustwork_services, to_snake_case};
 use crate::grpc;
 use crate::mcp::common::workspace_root::WorkspaceRoot;
+use crate::templates::output;
 /// Commande `rustwork grpc build`
 use anyhow::{Context, Result};
 use std::fs;
@@ -103,45 +104,70 @@ fn find_service_grpc_configs(services_dir: &Path) -> Result<Vec<ServiceGrpcConfi
             .ok_or_else(|| anyhow::anyhow!("Nom de service invalide"))?
             .to_string();
 
-        let grpc_dir = service_path.join("grpc");
-        if !grpc_dir.exists() || !grpc_dir.is_dir() {
-            continue;
+        if let Some(config) = service_grpc_config(&service_name, &service_path)? {
+            configs.push(config);
         }
+    }
 
-        let mut rwk_files = Vec::new();
-        for rwk_entry in fs::read_dir(&grpc_dir)? {
-            let rwk_entry = rwk_entry?;
-            let path = rwk_entry.path();
+    Ok(configs)
+}
 
-            if path.is_file() && path.extension().and_then(|s| s.to_str()) == Some("rwk") {
-                rwk_files.push(path);
-            }
-        }
+/// Fichiers .rwk de `service_path/grpc/` (`None` si le service n'en a pas)
+fn service_grpc_config(
+    service_name: &str,
+    service_path: &Path,
+) -> Result<Option<ServiceGrpcConfig>> {
+    let grpc_dir = service_path.join("grpc");
+    if !grpc_dir.exists() || !grpc_dir.is_dir() {
+        return Ok(None);
+    }
 
-        if !rwk_files.is_empty() {
-            configs.push(ServiceGrpcConfig {
-                service_name,
-                service_path,
-                rwk_files,
-            });
+    let mut rwk_files = Vec::new();
+    for rwk_entry in fs::read_dir(&grpc_dir)? {
+        let rwk_entry = rwk_entry?;
+        let path = rwk_entry.path();
+
+        if path.is_file() && path.extension().and_then(|s| s.to_str()) == Some("rwk") {
+            rwk_files.push(path);
         }
     }
+    rwk_files.sort();
 
-    Ok(configs)
+    if rwk_files.is_empty() {
+        return Ok(None);
+    }
+    Ok(Some(ServiceGrpcConfig {
+        service_name: service_name.to_string(),
+        service_path: service_path.to_path_buf(),
+        rwk_files,
+    }))
+}
+
+/// Régénère le code gRPC d'un seul service (`rustwork dev`)
+///
+/// Retourne `true` si un fichier généré a changé. Une erreur de parsing est affichée
+/// avec son contexte et rien n'est écrit.
+pub fn build_service(service_name: &str, service_path: &Path) -> Result<bool> {
+    match service_grpc_config(service_name, service_path)? {
+        Some(config) => process_service_grpc(service_path, &config),
+        None => Ok(false),
+    }
 }
 
 /// Traite un service gRPC de manière isolée
-fn process_service_grpc(_project_root: &Path, config: &ServiceGrpcConfig) -> Result<()> {
+///
+/// Tous les contrats sont parsés avant d'écrire quoi que ce soit.
+fn process_service_grpc(_project_root: &Path, config: &ServiceGrpcConfig) -> Result<bool> {
     println!("🔧 Traitement du service '{}'...", config.service_name);
 
     // 1. Créer le dossier target pour les .proto de CE service
     let proto_dir = config.service_path.join("target/rustwork/grpc");
-    fs::create_dir_all(&proto_dir).context("Impossible de créer target/rustwork/grpc")?;
+    output::create_dir_all(&proto_dir).context("Impossible de créer target/rustwork/grpc")?;
 
     let mut contracts = Vec::new();
     let mut service_names = Vec::new();
 
-    // 2. Parser et générer les .proto pour CE service uniquement
+    // 2. Parser tous les contrats de CE service avant d'écrire
     for rwk_file in &config.rwk_files {
         let source = fs::read_to_string(rwk_file)
             .with_context(|| format!("Erreur lecture {}", rwk_file.display()))?;
@@ -153,17 +179,23 @@ fn process_service_grpc(_project_root: &Path, config: &ServiceGrpcConfig) -> Res
         })?;
 
         service_names.push(contract.service.name.clone());
+        contracts.push(contract);
+    }
 
-        let proto_content = grpc::generate_proto(&contract).context("Erreur génération proto")?;
-
-        let proto_filename = format!("{}_service.proto", to_snake_case(&contract.service.name));
-        let proto_path = proto_dir.join(&proto_filename);
+    let mut changed = false;
+    let mut write = |path: &Path, content: &str| -> Result<()> {
+        let outcome = output::update(path, content)
+            .with_context(|| format!("Erreur écriture {}", path.display()))?;
+        changed |= outcome != output::WriteOutcome::Unchanged;
+        Ok(())
+    };
 
-        fs::write(&proto_path, proto_content)
-            .with_context(|| format!("Erreur écriture {}", proto_path.display()))?;
+    // Générer les .proto
+    for contract in &contracts {
+        let proto_content = grpc::generate_proto(contract).context("Erreur génération proto")?;
 
-        println!("  ✓ Généré: {}", proto_filename);
-        contracts.push(contract);
+        let proto_filename = format!("{}_service.proto", to_snake_case(&contract.service.name));
+        write(&proto_dir.join(&proto_filename), &proto_content)?;
     }
 
     // 3. Vérifier/ajouter les dépendances gRPC dans le Cargo.toml du service
@@ -176,14 +208,13 @@ fn process_service_grpc(_project_root: &Path, config: &ServiceGrpcConfig) -> Res
 
     // 5. Créer le dossier pour le code généré du service
     let grpc_src_dir = config.service_path.join("src/grpc");
-    fs::create_dir_all(&grpc_src_dir).context("Impossible de créer src/grpc")?;
+    output::create_dir_all(&grpc_src_dir).context("Impossible de créer src/grpc")?;
 
     // 6. Générer mod.rs
     let mod_content =
         grpc::generate_grpc_mod(&service_names).context("Erreur génération mod.rs")?;
 
-    fs::write(grpc_src_dir.join("mod.rs"), mod_content)
-        .context("Erreur écriture src/grpc/mod.rs")?;
+    write(&grpc_src_dir.join("mod.rs"), &mod_content)?;
 
     // 7. Générer le code Rust pour chaque contrat
     for contract in &contracts {
@@ -191,16 +222,11 @@ fn process_service_grpc(_project_root: &Path, config: &ServiceGrpcConfig) -> Res
             .context("Erreur génération code Rust")?;
 
         let rust_filename = format!("{}.rs", to_snake_case(&contract.service.name));
-        let rust_path = grpc_src_dir.join(&rust_filename);
-
-        fs::write(&rust_path, rust_content)
-            .with_context(|| format!("Erreur écriture {}", rust_path.display()))?;
-
-        println!("  ✓ Généré: src/grpc/{}", rust_filename);
+        write(&grpc_src_dir.join(&rust_filename), &rust_content)?;
     }
 
     println!("  ✅ Service '{}' traité", config.service_name);
     println!();
 
-    Ok(())
+    Ok(changed)
 }
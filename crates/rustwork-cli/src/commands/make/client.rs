@@ -0,0 +1,695 @@
+use anyhow::{Context, Result};
+use serde::Serialize;
+use std::collections::{BTreeMap, BTreeSet, HashMap};
+use std::path::{Path, PathBuf};
+use tokio::fs;
+
+use crate::commands::openapi::{
+    find_handler, operation_id, parse_type, to_openapi_path, type_segment,
+};
+use crate::commands::utils::{
+    detect_rustwork_services, read_service_port, service_source_prefix, to_pascal_case,
+    to_snake_case,
+};
+use crate::mcp::common::indexer::{scan_project, SourceFile};
+use crate::mcp::common::routes::{scan_routes, HttpMethod, RouteInfo};
+use crate::mcp::common::workspace_root::WorkspaceRoot;
+use crate::mcp::rustwork_get_models::{parse_service_models, RustModel};
+use crate::templates::{create_micro_env, output, TemplateContext};
+
+/// Génère un client HTTP typé pour un service dans la librairie shared/
+pub async fn execute(service_name: &str, project: Option<&str>) -> Result<()> {
+    let current_dir = std::env::current_dir()?;
+    let workspace_root = if let Some(path) = project {
+        WorkspaceRoot::detect_with_explicit(&current_dir, Some(&PathBuf::from(path)))?
+    } else {
+        WorkspaceRoot::detect(&current_dir)?
+    };
+    let root = workspace_root.path();
+
+    let services = detect_rustwork_services(root)?;
+    let service = services
+        .iter()
+        .find(|s| s.name == service_name)
+        .with_context(|| {
+            format!(
+                "Service '{}' not found. Available services: {}",
+                service_name,
+                services
+                    .iter()
+                    .map(|s| s.name.as_str())
+                    .collect::<Vec<_>>()
+                    .join(", ")
+            )
+        })?;
+
+    if !workspace_root.has_shared() {
+        anyhow::bail!(
+            "No shared/ library found in {}.\n\
+             Clients are generated into shared/ so every service can use them.",
+            workspace_root.services_dir().display()
+        );
+    }
+    let shared_dir = workspace_root.services_dir().join("shared");
+
+    println!("📝 Generating HTTP client for service: {}", service_name);
+
+    // Routes + handlers du service cible
+    let prefix = service_source_prefix(root, &service.path);
+    let registry = scan_routes(root).await.context("Failed to scan routes")?;
+    let index = scan_project(root)
+        .await
+        .context("Failed to index sources")?;
+
+    let routes: Vec<&RouteInfo> = registry
+        .routes
+        .iter()
+        .filter(|r| r.file.starts_with(&prefix))
+        .collect();
+    let mut files: Vec<&SourceFile> = index
+        .files
+        .values()
+        .filter(|f| f.relative_path.starts_with(&prefix))
+        .collect();
+    files.sort_by(|a, b| a.relative_path.cmp(&b.relative_path));
+
+    // DTOs du service (parser MCP rustwork_get_models), repli sur l'index si src/models est invalide
+    let models = match parse_service_models(&service.path, service_name).await {
+        Ok(models) => models,
+        Err(e) => {
+            eprintln!("⚠️  Failed to parse models: {}", e.message);
+            Vec::new()
+        }
+    };
+
+    let mut builder = ClientBuilder::new(&models, &files);
+    let endpoints = builder.endpoints(&routes, &files);
+    let models = builder.rendered_models();
+
+    // Rendu
+    let client_name = format!("{}Client", to_pascal_case(service_name));
+    let module_name = to_snake_case(service_name);
+
+    let mut context = TemplateContext::new();
+    context.insert("service_name".to_string(), serde_json::json!(service_name));
+    context.insert("client_name".to_string(), serde_json::json!(client_name));
+    context.insert(
+        "service_port".to_string(),
+        serde_json::json!(read_service_port(&service.path).unwrap_or(3000)),
+    );
+    context.insert("endpoints".to_string(), serde_json::json!(endpoints));
+    context.insert("models".to_string(), serde_json::json!(models));
+
+    let env = create_micro_env();
+    let clients_dir = shared_dir.join("src/clients");
+
+    // Régénérés à chaque appel : toujours remplacés
+    let client_path = clients_dir.join(format!("{}.rs", module_name));
+    let content = env.get_template("client.rs")?.render(&context)?;
+    output::update(&client_path, &content)?;
+
+    let support_path = clients_dir.join("support.rs");
+    let content = env.get_template("client_support.rs")?.render(&context)?;
+    output::update(&support_path, &content)?;
+
+    // clients/mod.rs + lib.rs
+    let clients_mod = clients_dir.join("mod.rs");
+    if !output::exists(&clients_mod) {
+        output::create(
+            &clients_mod,
+            "//! Typed HTTP clients generated by `rustwork make client`\n\nmod support;\n\n// Add your modules here\n",
+        )?;
+    }
+    super::common::update_mod_file(&clients_mod.to_string_lossy(), &module_name).await?;
+    ensure_lib_module(&shared_dir.join("src/lib.rs"), "clients").await?;
+
+    // Dépendances du crate shared
+    let mut deps = vec![
+        ("rustwork", rustwork_dependency(&service.path).await),
+        (
+            "reqwest",
+            r#"reqwest = { version = "0.12", features = ["json"] }"#.to_string(),
+        ),
+        ("serde_json", r#"serde_json = "1.0""#.to_string()),
+    ];
+    if builder.uses_chrono {
+        deps.push((
+            "chrono",
+            r#"chrono = { version = "0.4", features = ["serde"] }"#.to_string(),
+        ));
+    }
+    if builder.uses_uuid {
+        deps.push((
+            "uuid",
+            r#"uuid = { version = "1", features = ["serde"] }"#.to_string(),
+        ));
+    }
+    let added = super::common::add_dependencies(&shared_dir.join("Cargo.toml"), &deps).await?;
+    if !added.is_empty() {
+        println!("  Updated: shared/Cargo.toml ({})", added.join(", "));
+    }
+
+    println!(
+        "✅ Client '{}' created with {} endpoint(s) and {} DTO(s)",
+        client_name,
+        endpoints.len(),
+        models.len()
+    );
+    println!("\nUsage:");
+    println!(
+        "  let client = shared::clients::{}::{}::new(\"http://localhost:{}\");",
+        module_name,
+        client_name,
+        read_service_port(&service.path).unwrap_or(3000)
+    );
+
+    Ok(())
+}
+
+/// Endpoint rendu dans le template `client.rs`
+#[derive(Debug, Serialize)]
+struct Endpoint {
+    fn_name: String,
+    #[serde(skip)]
+    operation_id: String,
+    method: String,
+    http_method: String,
+    path: String,
+    path_format: String,
+    path_args: Vec<String>,
+    args: Vec<EndpointArg>,
+    query: Option<String>,
+    body: Option<String>,
+    response_type: String,
+    sender: String,
+}
+
+#[derive(Debug, Serialize)]
+struct EndpointArg {
+    name: String,
+    ty: String,
+}
+
+/// DTO rendu dans le template `client.rs`
+#[derive(Debug, Serialize)]
+struct ClientModel {
+    name: String,
+    fields: Vec<ClientField>,
+}
+
+#[derive(Debug, Serialize)]
+struct ClientField {
+    name: String,
+    rust_type: String,
+    attributes: Vec<String>,
+}
+
+/// Champ de DTO tel que connu avant rendu (type brut + nullabilité + attributs serde)
+struct SourceField {
+    name: String,
+    type_name: String,
+    nullable: bool,
+    attributes: Vec<String>,
+}
+
+/// Construit les endpoints et collecte les DTOs référencés
+struct ClientBuilder {
+    structs: HashMap<String, Vec<SourceField>>,
+    used: BTreeSet<String>,
+    uses_chrono: bool,
+    uses_uuid: bool,
+}
+
+impl ClientBuilder {
+    fn new(models: &[RustModel], files: &[&SourceFile]) -> Self {
+        let mut structs: HashMap<String, Vec<SourceField>> = HashMap::new();
+
+        for model in models {
+            structs.entry(model.name.clone()).or_insert_with(|| {
+                model
+                    .fields
+                    .iter()
+                    .map(|f| SourceField {
+                        name: f.name.clone(),
+                        type_name: f.rust_type.clone(),
+                        nullable: f.nullable,
+                        attributes: f
+                            .attributes
+                            .iter()
+                            .filter(|a| a.replace(' ', "").starts_with("#[serde"))
+                            .map(|a| a.replace(' ', ""))
+                            .collect(),
+                    })
+                    .collect()
+            });
+        }
+
+        // Structs hors src/models (ex: réponses définies dans les controllers)
+        for file in files {
+            for info in &file.structs {
+                structs.entry(info.name.clone()).or_insert_with(|| {
+                    info.fields
+                        .iter()
+                        .map(|f| SourceField {
+                            name: f.name.clone(),
+                            type_name: f.type_name.clone(),
+                            nullable: false,
+                            attributes: Vec::new(),
+                        })
+                        .collect()
+                });
+            }
+        }
+
+        Self {
+            structs,
+            used: BTreeSet::new(),
+            uses_chrono: false,
+            uses_uuid: false,
+        }
+    }
+
+    fn endpoints(&mut self, routes: &[&RouteInfo], files: &[&SourceFile]) -> Vec<Endpoint> {
+        let mut endpoints: Vec<Endpoint> = routes
+            .iter()
+            .filter_map(|route| {
+                let http_method = reqwest_method(&route.method)?;
+                let handler = find_handler(files, route);
+                Some(self.endpoint(route, http_method, handler))
+            })
+            .collect();
+
+        // Handlers homonymes (posts::index / users::index) : qualifiés par leur module
+        // (ou à défaut par la ressource), puis suffixés par la méthode
+        for endpoint in duplicates(&mut endpoints) {
+            if endpoint.operation_id != endpoint.fn_name {
+                endpoint.fn_name = endpoint.operation_id.clone();
+                continue;
+            }
+            let resource = resource_name(&endpoint.path);
+            if !resource.is_empty() {
+                endpoint.fn_name = format!("{}_{}", resource, endpoint.fn_name);
+            }
+        }
+        for endpoint in duplicates(&mut endpoints) {
+            endpoint.fn_name = format!("{}_{}", endpoint.fn_name, endpoint.http_method);
+        }
+
+        endpoints.sort_by(|a, b| a.path.cmp(&b.path).then(a.fn_name.cmp(&b.fn_name)));
+        endpoints
+    }
+
+    fn endpoint(
+        &mut self,
+        route: &RouteInfo,
+        http_method: &str,
+        handler: Option<(&SourceFile, &crate::mcp::common::indexer::FunctionInfo)>,
+    ) -> Endpoint {
+        let path = to_openapi_path(&route.path);
+        let path_names: Vec<String> = path
+            .split('/')
+            .filter_map(|s| s.strip_prefix('{').and_then(|s| s.strip_suffix('}')))
+            .map(str::to_string)
+            .collect();
+
+        let mut path_types: BTreeMap<String, String> = BTreeMap::new();
+        let mut query = None;
+        let mut body = None;
+        let mut response = ("send_empty".to_string(), "()".to_string());
+
+        if let Some((_, function)) = handler {
+            for param in &function.parameters {
+                let Some(ty) = parse_type(&param.type_name) else {
+                    continue;
+                };
+                let Some((extractor, args)) = type_segment(&ty) else {
+                    continue;
+                };
+                let Some(inner) = args.first().copied() else {
+                    continue;
+                };
+
+                match extractor.as_str() {
+                    "Path" => self.path_types(inner, &path_names, &mut path_types),
+                    "Query" => query = Some(self.render_type(inner)),
+                    "Json" | "Form" => body = Some(self.render_type(inner)),
+                    _ => {}
+                }
+            }
+
+            if let Some(ty) = function.return_type.as_deref().and_then(parse_type) {
+                if let Some(found) = self.response(&ty) {
+                    response = found;
+                }
+            }
+        }
+
+        let mut args: Vec<EndpointArg> = path_names
+            .iter()
+            .map(|name| {
+                let ty = path_types
+                    .remove(name)
+                    .unwrap_or_else(|| "String".to_string());
+                EndpointArg {
+                    name: name.clone(),
+                    ty: if ty == "String" {
+                        "&str".to_string()
+                    } else {
+                        ty
+                    },
+                }
+            })
+            .collect();
+        if let Some(ref ty) = query {
+            args.push(EndpointArg {
+                name: "query".to_string(),
+                ty: format!("&{}", ty),
+            });
+        }
+        if let Some(ref ty) = body {
+            args.push(EndpointArg {
+                name: "body".to_string(),
+                ty: format!("&{}", ty),
+            });
+        }
+
+        let path_format = path
+            .split('/')
+            .map(|s| if s.starts_with('{') { "{}" } else { s })
+            .collect::<Vec<_>>()
+            .join("/");
+
+        Endpoint {
+            fn_name: route.handler.clone(),
+            operation_id: operation_id(route, handler),
+            method: format!("{:?}", route.method),
+            http_method: http_method.to_string(),
+            path,
+            path_format,
+            path_args: path_names,
+            args,
+            query: query.map(|_| "query".to_string()),
+            body: body.map(|_| "body".to_string()),
+            response_type: response.1,
+            sender: response.0,
+        }
+    }
+
+    /// Types Rust des paramètres de chemin (`Path<i64>`, `Path<(i64, String)>`)
+    fn path_types(
+        &mut self,
+        inner: &syn::Type,
+        names: &[String],
+        types: &mut BTreeMap<String, String>,
+    ) {
+        if let syn::Type::Tuple(tuple) = inner {
+            for (name, ty) in names.iter().zip(tuple.elems.iter()) {
+                let rendered = self.render_type(ty);
+                types.insert(name.clone(), rendered);
+            }
+        } else if let Some(name) = names.first() {
+            let rendered = self.render_type(inner);
+            types.insert(name.clone(), rendered);
+        }
+    }
+
+    /// (fonction d'envoi, type de retour) selon la forme de la réponse du handler
+    fn response(&mut self, ty: &syn::Type) -> Option<(String, String)> {
+        if let syn::Type::Tuple(tuple) = ty {
+            return tuple.elems.iter().rev().find_map(|e| self.response(e));
+        }
+
+        let (name, args) = type_segment(ty)?;
+        match (name.as_str(), args.as_slice()) {
+            ("Result" | "AppResult", [ok, ..]) => self.response(ok),
+            ("Json", [inner]) => match type_segment(inner) {
+                Some((wrapper, wrapped)) if wrapper == "ApiResponse" && wrapped.len() == 1 => {
+                    Some(("send_api".to_string(), self.render_type(wrapped[0])))
+                }
+                _ => Some(("send_json".to_string(), self.render_type(inner))),
+            },
+            _ => None,
+        }
+    }
+
+    /// Rend un type Rust utilisable dans le crate shared
+    fn render_type(&mut self, ty: &syn::Type) -> String {
+        match ty {
+            syn::Type::Reference(reference) => self.render_type(&reference.elem),
+            syn::Type::Paren(paren) => self.render_type(&paren.elem),
+            syn::Type::Tuple(tuple) if tuple.elems.is_empty() => "()".to_string(),
+            syn::Type::Tuple(tuple) => {
+                let items: Vec<String> = tuple.elems.iter().map(|t| self.render_type(t)).collect();
+                format!("({})", items.join(", "))
+            }
+            syn::Type::Path(_) => self.render_path_type(ty),
+            _ => "serde_json::Value".to_string(),
+        }
+    }
+
+    fn render_path_type(&mut self, ty: &syn::Type) -> String {
+        let Some((name, args)) = type_segment(ty) else {
+            return "serde_json::Value".to_string();
+        };
+
+        match (name.as_str(), args.as_slice()) {
+            (
+                "String" | "bool" | "char" | "i8" | "i16" | "i32" | "i64" | "i128" | "isize" | "u8"
+                | "u16" | "u32" | "u64" | "u128" | "usize" | "f32" | "f64",
+                _,
+            ) => name,
+            ("str", _) => "String".to_string(),
+            ("Uuid", _) => {
+                self.uses_uuid = true;
+                "uuid::Uuid".to_string()
+            }
+            ("DateTime", _) => {
+                self.uses_chrono = true;
+                "chrono::DateTime<chrono::Utc>".to_string()
+            }
+            ("NaiveDateTime" | "NaiveDate" | "NaiveTime", _) => {
+                self.uses_chrono = true;
+                format!("chrono::{}", name)
+            }
+            ("Value", _) => "serde_json::Value".to_string(),
+            ("Paginator", _) => "rustwork::Paginator".to_string(),
+            ("Option" | "Vec" | "Box" | "VecDeque" | "HashSet" | "BTreeSet", [inner]) => {
+                format!("{}<{}>", name, self.render_type(inner))
+            }
+            ("HashMap" | "BTreeMap", [key, value]) => format!(
+                "std::collections::{}<{}, {}>",
+                name,
+                self.render_type(key),
+                self.render_type(value)
+            ),
+            ("Arc" | "Rc" | "Cow" | "Json", [.., inner]) => self.render_type(inner),
+            (other, _) if self.structs.contains_key(other) => {
+                self.used.insert(other.to_string());
+                other.to_string()
+            }
+            _ => "serde_json::Value".to_string(),
+        }
+    }
+
+    /// DTOs référencés par les endpoints (et leurs dépendances), dans l'ordre alphabétique
+    fn rendered_models(&mut self) -> Vec<ClientModel> {
+        let mut rendered: BTreeMap<String, ClientModel> = BTreeMap::new();
+
+        while let Some(name) = self
+            .used
+            .iter()
+            .find(|n| !rendered.contains_key(*n))
+            .cloned()
+        {
+            let source: Vec<(String, String, bool, Vec<String>)> = self.structs[&name]
+                .iter()
+                .map(|f| {
+                    (
+                        f.name.clone(),
+                        f.type_name.clone(),
+                        f.nullable,
+                        f.attributes.clone(),
+                    )
+                })
+                .collect();
+
+            let fields = source
+                .into_iter()
+                .map(|(field_name, type_name, nullable, attributes)| {
+                    let rendered_type = parse_type(&type_name)
+                        .map(|ty| self.render_type(&ty))
+                        .unwrap_or_else(|| "serde_json::Value".to_string());
+                    ClientField {
+                        name: field_name,
+                        rust_type: if nullable {
+                            format!("Option<{}>", rendered_type)
+                        } else {
+                            rendered_type
+                        },
+                        attributes,
+                    }
+                })
+                .collect();
+
+            rendered.insert(name.clone(), ClientModel { name, fields });
+        }
+
+        rendered.into_values().collect()
+    }
+}
+
+/// Endpoints dont le nom de méthode n'est pas unique
+fn duplicates(endpoints: &mut [Endpoint]) -> impl Iterator<Item = &mut Endpoint> {
+    let mut counts: HashMap<String, usize> = HashMap::new();
+    for endpoint in endpoints.iter() {
+        *counts.entry(endpoint.fn_name.clone()).or_default() += 1;
+    }
+    endpoints.iter_mut().filter(move |e| counts[&e.fn_name] > 1)
+}
+
+/// Dernier segment statique du chemin (`/api/posts/{id}` → `posts`)
+fn resource_name(path: &str) -> String {
+    path.split('/')
+        .rfind(|s| !s.is_empty() && !s.starts_with('{'))
+        .map(to_snake_case)
+        .unwrap_or_default()
+}
+
+fn reqwest_method(method: &HttpMethod) -> Option<&'static str> {
+    match method {
+        HttpMethod::GET => Some("get"),
+        HttpMethod::POST => Some("post"),
+        HttpMethod::PUT => Some("put"),
+        HttpMethod::PATCH => Some("patch"),
+        HttpMethod::DELETE => Some("delete"),
+        HttpMethod::HEAD => Some("head"),
+        HttpMethod::OPTIONS => None,
+    }
+}
+
+/// Ajoute `pub mod <name>;` au lib.rs du crate shared
+async fn ensure_lib_module(lib_path: &Path, module_name: &str) -> Result<()> {
+    super::common::update_mod_file(&lib_path.to_string_lossy(), module_name).await
+}
+
+/// Ligne de dépendance rustwork, reprise du Cargo.toml du service cible
+async fn rustwork_dependency(service_path: &Path) -> String {
+    fs::read_to_string(service_path.join("Cargo.toml"))
+        .await
+        .ok()
+        .and_then(|content| {
+            content
+                .lines()
+                .find(|l| l.trim_start().starts_with("rustwork ="))
+                .map(|l| l.trim().to_string())
+        })
+        .unwrap_or_else(|| {
+            r#"rustwork = { git = "https://github.com/entcorporg/rustwork.git", branch = "main" }"#
+                .to_string()
+        })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_resource_name() {
+        assert_eq!(resource_name("/api/posts/{id}"), "posts");
+        assert_eq!(resource_name("/api/orgs/{org}/members"), "members");
+        assert_eq!(resource_name("/{id}"), "");
+    }
+
+    #[test]
+    fn test_render_type_maps_external_types() {
+        let mut builder = ClientBuilder::new(&[], &[]);
+        let ty = parse_type("Option < chrono :: DateTime < chrono :: Utc > >").unwrap();
+        assert_eq!(
+            builder.render_type(&ty),
+            "Option<chrono::DateTime<chrono::Utc>>"
+        );
+        assert!(builder.uses_chrono);
+
+        let ty = parse_type("Vec < crate :: models :: Unknown >").unwrap();
+        assert_eq!(builder.render_type(&ty), "Vec<serde_json::Value>");
+    }
+
+    #[tokio::test]
+    async fn test_endpoints_resolve_homonymous_handlers() {
+        let dir = tempfile::tempdir().unwrap();
+        let src = dir.path().join("src");
+        std::fs::create_dir_all(src.join("controllers")).unwrap();
+        std::fs::write(
+            src.join("routes.rs"),
+            r#"
+pub fn create_routes() -> Router<AppState> {
+    Router::new()
+        .route("/api/users", get(controllers::users::index))
+        .route("/api/posts", get(controllers::posts::index))
+}
+"#,
+        )
+        .unwrap();
+        std::fs::write(
+            src.join("controllers/posts.rs"),
+            r#"
+pub struct Post { pub title: String }
+pub async fn index() -> AppResult<Json<ApiResponse<Vec<Post>>>> { todo!() }
+"#,
+        )
+        .unwrap();
+        std::fs::write(
+            src.join("controllers/users.rs"),
+            r#"
+pub struct User { pub email: String }
+pub async fn index(Query(p): Query<Paginator>) -> AppResult<Json<ApiResponse<Vec<User>>>> { todo!() }
+"#,
+        )
+        .unwrap();
+
+        let registry = scan_routes(dir.path()).await.unwrap();
+        let index = scan_project(dir.path()).await.unwrap();
+        let routes: Vec<&RouteInfo> = registry.routes.iter().collect();
+        let files: Vec<&SourceFile> = index.files.values().collect();
+
+        let mut builder = ClientBuilder::new(&[], &files);
+        let endpoints = builder.endpoints(&routes, &files);
+        let summary: Vec<(&str, &str, Option<&str>)> = endpoints
+            .iter()
+            .map(|e| {
+                (
+                    e.fn_name.as_str(),
+                    e.response_type.as_str(),
+                    e.query.as_deref(),
+                )
+            })
+            .collect();
+        assert_eq!(
+            summary,
+            vec![
+                ("posts_index", "Vec<Post>", None),
+                ("users_index", "Vec<User>", Some("query")),
+            ]
+        );
+    }
+
+    #[tokio::test]
+    async fn test_ensure_lib_module_inserts_after_last_mod() {
+        let dir = tempfile::tempdir().unwrap();
+        let lib = dir.path().join("lib.rs");
+        std::fs::write(
+            &lib,
+            "pub mod types;\npub mod utils;\n\npub use types::*;\n",
+        )
+        .unwrap();
+
+        ensure_lib_module(&lib, "clients").await.unwrap();
+        ensure_lib_module(&lib, "clients").await.unwrap();
+
+        let content = std::fs::read_to_string(&lib).unwrap();
+        assert_eq!(
+            content,
+            "pub mod types;\npub mod utils;\npub mod clients;\n\npub use types::*;\n"
+        );
+    }
+}
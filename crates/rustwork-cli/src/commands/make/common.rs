@@ -1,44 +1,81 @@
-use anyhow::Result;
+use anyhow::{Context, Result};
 use std::path::Path;
-use tokio::fs;
+
+use super::source_edit::{add_mod_declaration, add_router_calls, RouterCall};
+use crate::templates::output;
 
 /// Met à jour un fichier mod.rs pour ajouter un module
 pub(crate) async fn update_mod_file(path: &str, module_name: &str) -> Result<()> {
     let mod_path = Path::new(path);
-    let content = fs::read_to_string(mod_path)
-        .await
-        .unwrap_or_else(|_| String::new());
+    let content = output::read_to_string(mod_path).unwrap_or_default();
 
-    let mod_line = format!("pub mod {};\n", module_name);
+    let updated = add_mod_declaration(&content, module_name)
+        .map_err(|e| anyhow::anyhow!("Cannot update {} safely: {}", path, e))?;
 
-    if content.contains(&mod_line) {
-        return Ok(()); // Already exists
+    if let Some(new_content) = updated {
+        output::update(mod_path, &new_content)?;
     }
 
-    let new_content = if content.contains("// Add your modules here") {
-        content.replace(
-            "// Add your modules here\n",
-            &format!("pub mod {};\n// Add your modules here\n", module_name),
-        )
-    } else {
-        format!("{}{}", content, mod_line)
-    };
+    Ok(())
+}
 
-    fs::write(mod_path, new_content).await?;
-    println!("  Updated: {}", path);
+/// Enregistre des routes dans la chaîne `Router::new()` de `fn <fn_name>`
+///
+/// Retourne `false` si toutes les routes existaient déjà.
+pub(crate) async fn update_routes_file(
+    path: &str,
+    fn_name: &str,
+    calls: &[RouterCall],
+) -> Result<bool> {
+    let content = output::read_to_string(Path::new(path))
+        .with_context(|| format!("Failed to read {}", path))?;
 
-    Ok(())
+    let updated = add_router_calls(&content, fn_name, calls)
+        .map_err(|e| anyhow::anyhow!("Cannot update {} safely: {}", path, e))?;
+
+    match updated {
+        Some(new_content) => {
+            output::update(Path::new(path), &new_content)?;
+            Ok(true)
+        }
+        None => Ok(false),
+    }
+}
+
+/// Routes CRUD `/api/<plural>` et `/api/<plural>/{id}` d'un contrôleur
+pub(crate) fn resource_routes(module: &str, plural: &str) -> Vec<RouterCall> {
+    let handler = |action: &str| format!("crate::controllers::{}::{}", module, action);
+
+    vec![
+        RouterCall::route(
+            &format!("/api/{}", plural),
+            format!(
+                "axum::routing::get({}).post({})",
+                handler("index"),
+                handler("create")
+            ),
+        ),
+        RouterCall::route(
+            &format!("/api/{}/{{id}}", plural),
+            format!(
+                "axum::routing::get({}).put({}).delete({})",
+                handler("show"),
+                handler("update"),
+                handler("delete")
+            ),
+        ),
+    ]
 }
 
 /// Met à jour le fichier manifest.json
 pub(crate) async fn update_manifest(key: &str, value: &str) -> Result<()> {
     let manifest_path = Path::new(".rustwork/manifest.json");
 
-    if !manifest_path.exists() {
+    if !output::exists(manifest_path) {
         return Ok(());
     }
 
-    let content = fs::read_to_string(manifest_path).await?;
+    let content = output::read_to_string(manifest_path)?;
     let mut manifest: serde_json::Value = serde_json::from_str(&content)?;
 
     if let Some(arr) = manifest.get_mut(key).and_then(|v| v.as_array_mut()) {
@@ -47,7 +84,208 @@ pub(crate) async fn update_manifest(key: &str, value: &str) -> Result<()> {
         }
     }
 
-    fs::write(manifest_path, serde_json::to_string_pretty(&manifest)?).await?;
+    output::update(manifest_path, &serde_json::to_string_pretty(&manifest)?)?;
 
     Ok(())
 }
+
+/// Ajoute des dépendances à la section `[dependencies]` d'un Cargo.toml
+///
+/// Les dépendances déjà présentes ne sont pas modifiées. Retourne la liste des ajouts.
+pub(crate) async fn add_dependencies(
+    cargo_toml_path: &Path,
+    deps: &[(&str, String)],
+) -> Result<Vec<String>> {
+    let content = output::read_to_string(cargo_toml_path)?;
+    let mut lines: Vec<String> = content.lines().map(|s| s.to_string()).collect();
+
+    let deps_start = match lines.iter().position(|l| l.trim() == "[dependencies]") {
+        Some(idx) => idx,
+        None => {
+            lines.push(String::new());
+            lines.push("[dependencies]".to_string());
+            lines.len() - 1
+        }
+    };
+
+    let mut added = Vec::new();
+    for (name, line) in deps {
+        let already_present = content.lines().any(|l| {
+            l.trim_start()
+                .strip_prefix(name)
+                .is_some_and(|rest| rest.trim_start().starts_with('='))
+        });
+        if already_present {
+            continue;
+        }
+
+        // Fin de la section [dependencies]
+        let mut insert_pos = deps_start + 1;
+        while insert_pos < lines.len() {
+            let trimmed = lines[insert_pos].trim();
+            if trimmed.is_empty() || trimmed.starts_with('[') {
+                break;
+            }
+            insert_pos += 1;
+        }
+
+        lines.insert(insert_pos, line.clone());
+        added.push(name.to_string());
+    }
+
+    if !added.is_empty() {
+        output::update(cargo_toml_path, &(lines.join("\n") + "\n"))?;
+    }
+
+    Ok(added)
+}
+
+/// Active des features d'une dépendance inline (`dep = { ..., features = [...] }`)
+///
+/// Retourne les features ajoutées ; la dépendance doit exister dans `[dependencies]`.
+pub(crate) async fn add_dependency_features(
+    cargo_toml_path: &Path,
+    dependency: &str,
+    features: &[&str],
+) -> Result<Vec<String>> {
+    let content = output::read_to_string(cargo_toml_path)?;
+    let mut lines: Vec<String> = content.lines().map(|s| s.to_string()).collect();
+
+    let mut in_dependencies = false;
+    let mut target = None;
+    for (i, line) in lines.iter().enumerate() {
+        let trimmed = line.trim();
+        if trimmed.starts_with('[') {
+            in_dependencies = trimmed == "[dependencies]";
+        } else if in_dependencies
+            && trimmed
+                .strip_prefix(dependency)
+                .is_some_and(|rest| rest.trim_start().starts_with('='))
+        {
+            target = Some(i);
+            break;
+        }
+    }
+    let Some(index) = target else {
+        anyhow::bail!(
+            "Dependency '{}' not found in {}",
+            dependency,
+            cargo_toml_path.display()
+        );
+    };
+
+    let line = &lines[index];
+    let value = line
+        .split_once('=')
+        .map(|(_, v)| v.trim())
+        .unwrap_or_default();
+    let (version_part, existing): (String, Vec<String>) = if let Some(start) = line.find("features")
+    {
+        let open = line[start..].find('[').map(|i| start + i);
+        let close = open.and_then(|open| line[open..].find(']').map(|i| open + i));
+        let (Some(open), Some(close)) = (open, close) else {
+            anyhow::bail!("Unsupported '{}' declaration: {}", dependency, line);
+        };
+        let existing = line[open + 1..close]
+            .split(',')
+            .map(|f| f.trim().trim_matches('"').to_string())
+            .filter(|f| !f.is_empty())
+            .collect();
+        (String::new(), existing)
+    } else if value.starts_with('"') {
+        (value.to_string(), Vec::new())
+    } else if value.starts_with('{') {
+        (String::new(), Vec::new())
+    } else {
+        anyhow::bail!("Unsupported '{}' declaration: {}", dependency, line);
+    };
+
+    let added: Vec<String> = features
+        .iter()
+        .filter(|f| !existing.iter().any(|e| e == *f))
+        .map(|f| f.to_string())
+        .collect();
+    if added.is_empty() {
+        return Ok(added);
+    }
+
+    let all: Vec<String> = existing
+        .iter()
+        .chain(added.iter())
+        .map(|f| format!("\"{}\"", f))
+        .collect();
+    let features_value = format!("features = [{}]", all.join(", "));
+
+    lines[index] = if !version_part.is_empty() {
+        // `dep = "x.y"` → table inline
+        format!(
+            "{} = {{ version = {}, {} }}",
+            dependency, version_part, features_value
+        )
+    } else if let Some(start) = line.find("features") {
+        let close = start + line[start..].find(']').unwrap_or(0);
+        format!("{}{}{}", &line[..start], features_value, &line[close + 1..])
+    } else {
+        let close = line.rfind('}').unwrap_or(line.len());
+        format!(
+            "{}, {} }}",
+            line[..close].trim_end().trim_end_matches(','),
+            features_value
+        )
+    };
+
+    output::update(cargo_toml_path, &(lines.join("\n") + "\n"))?;
+
+    Ok(added)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use tempfile::TempDir;
+    use tokio::fs;
+
+    async fn features_after(cargo_toml: &str, features: &[&str]) -> String {
+        let dir = TempDir::new().unwrap();
+        let path = dir.path().join("Cargo.toml");
+        fs::write(&path, cargo_toml).await.unwrap();
+        add_dependency_features(&path, "sqlx", features)
+            .await
+            .unwrap();
+        fs::read_to_string(&path).await.unwrap()
+    }
+
+    #[tokio::test]
+    async fn test_add_dependency_features() {
+        let content = features_after(
+            "[dependencies]\nsqlx = { version = \"0.8\", features = [\"sqlite\", \"chrono\"] }\n",
+            &["chrono", "uuid"],
+        )
+        .await;
+        assert!(content.contains(
+            "sqlx = { version = \"0.8\", features = [\"sqlite\", \"chrono\", \"uuid\"] }"
+        ));
+
+        let content = features_after("[dependencies]\nsqlx = \"0.8\"\n", &["json"]).await;
+        assert!(content.contains("sqlx = { version = \"0.8\", features = [\"json\"] }"));
+
+        let content =
+            features_after("[dependencies]\nsqlx = { version = \"0.8\" }\n", &["uuid"]).await;
+        assert!(content.contains("sqlx = { version = \"0.8\", features = [\"uuid\"] }"));
+    }
+
+    #[tokio::test]
+    async fn test_add_dependency_features_missing_dependency() {
+        let dir = TempDir::new().unwrap();
+        let path = dir.path().join("Cargo.toml");
+        fs::write(
+            &path,
+            "[dependencies]\nserde = \"1\"\n[dev-dependencies]\nsqlx = \"0.8\"\n",
+        )
+        .await
+        .unwrap();
+        assert!(add_dependency_features(&path, "sqlx", &["uuid"])
+            .await
+            .is_err());
+    }
+}
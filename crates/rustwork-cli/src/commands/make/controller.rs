@@ -1,9 +1,9 @@
 use anyhow::Result;
 use std::path::Path;
-use tokio::fs;
 
-use crate::commands::utils::{ensure_parent_dir, is_rustwork_project, to_snake_case};
-use crate::templates::{create_micro_env, TemplateContext};
+use crate::commands::utils::{is_rustwork_project, to_snake_case};
+use crate::inflection::pluralize;
+use crate::templates::{create_micro_env, output, TemplateContext};
 
 /// Génère un contrôleur
 pub async fn execute(name: &str) -> Result<()> {
@@ -12,7 +12,7 @@ pub async fn execute(name: &str) -> Result<()> {
     }
 
     let snake_name = to_snake_case(name);
-    let plural_name = format!("{}s", snake_name); // Simple pluralization
+    let plural_name = pluralize(&snake_name);
 
     println!("📝 Generating controller: {}", name);
 
@@ -28,87 +28,27 @@ pub async fn execute(name: &str) -> Result<()> {
     let template = env.get_template("controller.rs")?;
     let content = template.render(&context)?;
 
-    ensure_parent_dir(&controller_path).await?;
-    fs::write(&controller_path, content).await?;
-
-    println!("  Created: {}", controller_path.display());
+    output::create(&controller_path, &content)?;
 
     // Update controllers/mod.rs
     super::common::update_mod_file("src/controllers/mod.rs", &snake_name).await?;
 
     // Update routes.rs to add the new routes
-    update_routes_file(&snake_name, &plural_name).await?;
+    let routes = super::common::resource_routes(&snake_name, &plural_name);
+    if !super::common::update_routes_file("src/routes.rs", "create_routes", &routes).await? {
+        println!("  Routes already exist in src/routes.rs");
+    }
 
     // Update manifest
     super::common::update_manifest("controllers", name).await?;
 
     println!("✅ Controller '{}' created successfully!", name);
-    println!("\nRoutes added to src/routes.rs:");
+    println!("\nRoutes registered in src/routes.rs:");
     println!("  GET    /api/{}", plural_name);
-    println!("  GET    /api/{}/:id", plural_name);
     println!("  POST   /api/{}", plural_name);
-    println!("  PUT    /api/{}/:id", plural_name);
-    println!("  DELETE /api/{}/:id", plural_name);
-
-    Ok(())
-}
-
-async fn update_routes_file(snake_name: &str, plural_name: &str) -> Result<()> {
-    let routes_path = Path::new("src/routes.rs");
-    let content = fs::read_to_string(routes_path).await?;
-
-    // Check if already exists
-    if content.contains(&format!("controllers::{}", snake_name)) {
-        println!("  Routes already exist in src/routes.rs");
-        return Ok(());
-    }
-
-    // Add use statement
-    let use_line = format!("use crate::controllers::{};\n", snake_name);
-    let new_content = if content.contains("use crate::controllers::health;") {
-        content.replace(
-            "use crate::controllers::health;\n",
-            &format!("use crate::controllers::health;\n{}", use_line),
-        )
-    } else {
-        content.replace(
-            "use crate::controllers",
-            &format!("{}use crate::controllers", use_line),
-        )
-    };
-
-    // Add routes
-    let routes_block = format!(
-        r#"        .route("/api/{}", get({}::index).post({}::create))
-        .route("/api/{}/:id", get({}::show).put({}::update).delete({}::delete))"#,
-        plural_name, snake_name, snake_name, plural_name, snake_name, snake_name, snake_name
-    );
-
-    let final_content = if new_content.contains("// Add your routes here") {
-        new_content.replace(
-            "// Add your routes here",
-            &format!("{}\n        // Add your routes here", routes_block),
-        )
-    } else {
-        // Insert before .with_state
-        new_content.replace(
-            ".with_state(state)",
-            &format!("{}\n        .with_state(state)", routes_block),
-        )
-    };
-
-    // Add routing imports if not present
-    let final_content = if !final_content.contains("routing::{get, post, put, delete}") {
-        final_content.replace(
-            "use axum::{Router, routing::get};",
-            "use axum::{Router, routing::{get, post, put, delete}};",
-        )
-    } else {
-        final_content
-    };
-
-    fs::write(routes_path, final_content).await?;
-    println!("  Updated: src/routes.rs");
+    println!("  GET    /api/{}/{{id}}", plural_name);
+    println!("  PUT    /api/{}/{{id}}", plural_name);
+    println!("  DELETE /api/{}/{{id}}", plural_name);
 
     Ok(())
 }
@@ -0,0 +1,278 @@
+use anyhow::Result;
+use rustwork::DbConnection;
+use std::path::Path;
+
+use super::model::{detect_dialect, next_migration_version};
+use crate::commands::db::seeders::{discover_impls, ImplModule};
+use crate::commands::utils::{is_rustwork_project, to_snake_case};
+use crate::templates::{create_micro_env, output, TemplateContext};
+
+/// Ligne de `main.rs` après laquelle le worker est lancé
+const APP_STATE_LINE: &str = "let state = AppState::new(db, config.clone());";
+
+const WORKER_HOOK: &str = "
+    // Background jobs (`rustwork make job`)
+    tokio::spawn(rustwork::Worker::new(state.clone(), jobs::registry()).run());
+";
+
+/// Options de `rustwork make job`
+#[derive(Debug)]
+pub struct JobOptions {
+    pub queue: Option<String>,
+    pub max_attempts: u32,
+}
+
+/// Génère un job dans `src/jobs/` et branche le worker du service
+pub async fn execute(name: &str, options: &JobOptions) -> Result<()> {
+    if !is_rustwork_project() {
+        anyhow::bail!("Not in a Rustwork project. Run this command from a project created with 'rustwork new'");
+    }
+
+    let snake_name = to_snake_case(name);
+    println!("⚙️  Generating job: {}", name);
+
+    let job_path = Path::new("src/jobs").join(format!("{}.rs", snake_name));
+
+    let mut context = TemplateContext::new();
+    context.insert("struct_name".to_string(), serde_json::json!(name));
+    context.insert("queue".to_string(), serde_json::json!(options.queue));
+    context.insert(
+        "max_attempts".to_string(),
+        serde_json::json!(options.max_attempts.max(1)),
+    );
+
+    let env = create_micro_env();
+    let content = env.get_template("job.rs")?.render(&context)?;
+    output::create(&job_path, &content)?;
+
+    let module = ImplModule {
+        module: snake_name.clone(),
+        types: vec![name.to_string()],
+    };
+    write_jobs_mod(Path::new("src/jobs"), module).await?;
+
+    // Table `rustwork_jobs` (une seule migration par service)
+    let migrations_dir = Path::new("migrations");
+    if !has_jobs_migration(migrations_dir) {
+        let dialect = detect_dialect(Path::new("config/default.toml")).await;
+        create_jobs_migration(&dialect, migrations_dir).await?;
+    }
+
+    // Lancer le worker dans main.rs
+    let main_path = Path::new("src/main.rs");
+    let main_rs = output::read_to_string(main_path)?;
+    match add_worker_hook(&main_rs) {
+        Some(updated) => {
+            output::update(main_path, &updated)?;
+        }
+        None => {
+            println!("\n⚠️  Could not update src/main.rs automatically. Add:");
+            println!("  mod jobs;");
+            println!("and, once the AppState is created:{}", WORKER_HOOK);
+        }
+    }
+
+    println!("✅ Job '{}' created successfully!", name);
+    println!("\nNext steps:");
+    println!(
+        "  1. Add the job arguments and work in {}",
+        job_path.display()
+    );
+    println!("  2. Apply the migration: sqlx migrate run (see migrations/README.md)");
+    println!(
+        "  3. Dispatch it: rustwork::JobQueue::new(state.db.clone()).push(&{} {{ .. }}).await?",
+        name
+    );
+
+    Ok(())
+}
+
+/// Contenu généré de `src/jobs/mod.rs`
+fn render_jobs_mod(modules: &[ImplModule]) -> String {
+    let mut content = String::from(
+        "//! Background jobs of this service\n\
+         //!\n\
+         //! Generated by `rustwork make job`.\n\
+         //! Do not edit by hand: every `impl Job for X` in this directory is registered.\n\
+         \n\
+         use rustwork::JobRegistry;\n",
+    );
+
+    if !modules.is_empty() {
+        content.push('\n');
+    }
+    for module in modules {
+        content.push_str(&format!("pub mod {};\n", module.module));
+    }
+
+    content.push_str("\npub fn registry() -> JobRegistry {\n    JobRegistry::new()");
+    for module in modules {
+        for ty in &module.types {
+            content.push_str(&format!(
+                "\n        .register::<{}::{}>()",
+                module.module, ty
+            ));
+        }
+    }
+    content.push_str("\n}\n");
+
+    content
+}
+
+/// Régénère `src/jobs/mod.rs` avec les jobs du dossier et `generated`
+async fn write_jobs_mod(jobs_dir: &Path, generated: ImplModule) -> Result<()> {
+    let mut modules = if jobs_dir.is_dir() {
+        discover_impls(jobs_dir, "Job").await?
+    } else {
+        Vec::new()
+    };
+    // En dry-run (ou si le fichier existant a été conservé), le job n'est pas sur le disque
+    if !modules.iter().any(|m| m.module == generated.module) {
+        modules.push(generated);
+        modules.sort_by(|a, b| a.module.cmp(&b.module));
+    }
+
+    output::update(&jobs_dir.join("mod.rs"), &render_jobs_mod(&modules))?;
+    Ok(())
+}
+
+fn has_jobs_migration(migrations_dir: &Path) -> bool {
+    let Ok(entries) = std::fs::read_dir(migrations_dir) else {
+        return false;
+    };
+
+    entries.flatten().any(|entry| {
+        entry
+            .file_name()
+            .to_str()
+            .is_some_and(|name| name.ends_with("_create_rustwork_jobs.up.sql"))
+    })
+}
+
+/// Écrit `migrations/<timestamp>_create_rustwork_jobs.{up,down}.sql`
+async fn create_jobs_migration(dialect: &DbConnection, migrations_dir: &Path) -> Result<String> {
+    let migration_name = format!(
+        "{}_create_rustwork_jobs",
+        next_migration_version(migrations_dir).await
+    );
+    let dialect_name = serde_json::to_value(dialect)?;
+    let dialect_name = dialect_name.as_str().unwrap_or_default();
+
+    for (suffix, sql) in [
+        ("up", rustwork::jobs::create_table_sql(dialect)),
+        ("down", rustwork::jobs::drop_table_sql()),
+    ] {
+        let content = format!(
+            "-- Migration {}: create_rustwork_jobs\n\
+             -- Generated by `rustwork make job` ({})\n\n{}",
+            suffix.to_uppercase(),
+            dialect_name,
+            sql
+        );
+        let path = migrations_dir.join(format!("{}.{}.sql", migration_name, suffix));
+        output::create(&path, &content)?;
+    }
+
+    Ok(migration_name)
+}
+
+/// Ajoute `mod jobs;` et le lancement du worker à `main.rs` ; `None` si l'AppState est introuvable
+fn add_worker_hook(main_rs: &str) -> Option<String> {
+    let mut content = main_rs.to_string();
+
+    if !content.contains("jobs::registry()") {
+        let pos = content.find(APP_STATE_LINE)? + APP_STATE_LINE.len();
+        let pos = content[pos..]
+            .find('\n')
+            .map_or(content.len(), |i| pos + i + 1);
+        content.insert_str(pos, WORKER_HOOK);
+    }
+
+    if !content.lines().any(|l| l.trim() == "mod jobs;") {
+        // Après la dernière déclaration `mod x;` de tête
+        let mut pos = 0;
+        let mut offset = 0;
+        for line in content.lines() {
+            offset += line.len() + 1;
+            if line.starts_with("mod ") && line.trim_end().ends_with(';') {
+                pos = offset;
+            }
+        }
+        content.insert_str(pos.min(content.len()), "mod jobs;\n");
+    }
+
+    Some(content)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use tempfile::TempDir;
+
+    const MAIN_RS: &str = r#"use rustwork::{AppConfig, AppState, connect_db};
+
+mod app;
+mod seeders;
+
+#[tokio::main]
+async fn main() -> anyhow::Result<()> {
+    let config = AppConfig::load()?;
+    let db = connect_db(&config.database).await?;
+
+    // Create application state
+    let state = AppState::new(db, config.clone());
+
+    // Build router with custom routes
+    let app = app::build_app_router(state);
+    Ok(())
+}
+"#;
+
+    #[test]
+    fn test_add_worker_hook() {
+        let updated = add_worker_hook(MAIN_RS).unwrap();
+
+        assert!(updated.contains("mod seeders;\nmod jobs;\n"));
+        assert!(updated.contains(
+            "config.clone());\n\n    // Background jobs (`rustwork make job`)\n    tokio::spawn("
+        ));
+        assert!(updated.find("jobs::registry()") < updated.find("build_app_router"));
+        syn::parse_file(&updated).unwrap();
+
+        // Idempotent
+        assert_eq!(add_worker_hook(&updated).unwrap(), updated);
+        assert!(add_worker_hook("mod app;\n\nfn main() {}\n").is_none());
+    }
+
+    #[test]
+    fn test_render_jobs_mod() {
+        let content = render_jobs_mod(&[ImplModule {
+            module: "send_welcome_email".to_string(),
+            types: vec!["SendWelcomeEmail".to_string()],
+        }]);
+        assert!(content.contains("pub mod send_welcome_email;\n"));
+        assert!(content.contains(".register::<send_welcome_email::SendWelcomeEmail>()"));
+        syn::parse_file(&content).unwrap();
+
+        let empty = render_jobs_mod(&[]);
+        assert!(empty.contains("JobRegistry::new()\n}"));
+        syn::parse_file(&empty).unwrap();
+    }
+
+    #[tokio::test]
+    async fn test_create_jobs_migration() {
+        let dir = TempDir::new().unwrap();
+        assert!(!has_jobs_migration(dir.path()));
+
+        let name = create_jobs_migration(&DbConnection::Postgres, dir.path())
+            .await
+            .unwrap();
+        assert!(has_jobs_migration(dir.path()));
+
+        let up = std::fs::read_to_string(dir.path().join(format!("{}.up.sql", name))).unwrap();
+        assert!(up.starts_with("-- Migration UP: create_rustwork_jobs\n-- Generated by `rustwork make job` (postgres)\n\n"));
+        assert!(up.contains("id BIGSERIAL PRIMARY KEY"));
+        let down = std::fs::read_to_string(dir.path().join(format!("{}.down.sql", name))).unwrap();
+        assert!(down.ends_with("DROP TABLE IF EXISTS rustwork_jobs;\n"));
+    }
+}
@@ -1,7 +1,19 @@
 /// Commandes de génération (make)
-mod common;
+mod client;
+pub(crate) mod common;
 mod controller;
+mod job;
 mod model;
+mod scaffold;
+mod schema;
+mod seeder;
+mod source_edit;
+mod target;
 
+pub use client::execute as make_client;
 pub use controller::execute as make_controller;
-pub use model::execute as make_model;
+pub use job::{execute as make_job, JobOptions};
+pub use model::{execute as make_model, ModelOptions};
+pub use scaffold::execute as make_scaffold;
+pub use seeder::execute as make_seeder;
+pub use target::enter_service;
@@ -1,177 +1,394 @@
 use anyhow::Result;
 use chrono::Utc;
+use rustwork::DbConnection;
 use std::path::Path;
 use tokio::fs;
 
-use crate::commands::utils::{ensure_parent_dir, is_rustwork_project, to_snake_case};
-use crate::templates::{create_micro_env, TemplateContext};
+use super::schema::{ModelSchema, ParentModel};
+use crate::commands::utils::{db_connection_from_config, is_rustwork_project, to_pascal_case};
+use crate::inflection::singularize;
+use crate::templates::{create_micro_env, output, TemplateContext};
+
+/// Options de `rustwork make model`
+#[derive(Debug, Default)]
+pub struct ModelOptions {
+    /// Champs `name:type[?][:unique][:index]`
+    pub fields: Vec<String>,
+    pub soft_deletes: bool,
+    pub uuid_pk: bool,
+}
 
-/// Génère un modèle
-pub async fn execute(name: &str) -> Result<()> {
+/// Génère un modèle, son service et sa migration SQL
+pub async fn execute(name: &str, options: &ModelOptions) -> Result<()> {
     if !is_rustwork_project() {
         anyhow::bail!("Not in a Rustwork project. Run this command from a project created with 'rustwork new'");
     }
 
-    let snake_name = to_snake_case(name);
-    let table_name = format!("{}s", snake_name); // Simple pluralization
+    let schema = prepare_schema(name, options).await?;
 
     println!("📝 Generating model: {}", name);
 
-    let mut context = TemplateContext::new();
-    context.insert("struct_name".to_string(), serde_json::json!(name));
-    context.insert("snake_name".to_string(), serde_json::json!(snake_name));
-    context.insert("table_name".to_string(), serde_json::json!(table_name));
+    let migration = write_model(&schema, false).await?;
+
+    // Update manifest
+    super::common::update_manifest("models", name).await?;
+
+    println!("✅ Model '{}' created successfully!", name);
+    println!("\nGenerated files:");
+    println!("  - src/models/{}.rs", schema.snake_name);
+    println!("  - src/services/{}_service.rs", schema.snake_name);
+    println!("  - migrations/{}.{{up,down}}.sql", migration);
+
+    let unresolved: Vec<_> = schema.unresolved_references().collect();
+    if !unresolved.is_empty() {
+        println!("\n⚠️  Parent models not found in src/models/, relations not generated:");
+        for field in unresolved {
+            println!(
+                "  - {} → {}",
+                field.name,
+                field.references().unwrap_or_default()
+            );
+        }
+    }
+
+    println!("\nNext steps:");
+    println!(
+        "  1. Review the migration in migrations/{}.up.sql",
+        migration
+    );
+    println!("  2. Apply it: sqlx migrate run (see migrations/README.md)");
+
+    Ok(())
+}
+
+/// Parse les champs et résout les modèles parents présents dans `src/models/`
+pub(super) async fn prepare_schema(name: &str, options: &ModelOptions) -> Result<ModelSchema> {
+    let mut schema =
+        ModelSchema::parse(name, &options.fields, options.uuid_pk, options.soft_deletes)?;
+    resolve_parents(&mut schema, Path::new("src/models")).await;
+    Ok(schema)
+}
+
+/// Écrit modèle, service (CRUD complet si `crud`) et migration ; retourne le nom de la migration
+pub(super) async fn write_model(schema: &ModelSchema, crud: bool) -> Result<String> {
+    let dialect = detect_dialect(Path::new("config/default.toml")).await;
 
+    let mut context: TemplateContext = serde_json::from_value(schema.template_context())?;
+    context.insert("crud".to_string(), serde_json::json!(crud));
     let env = create_micro_env();
 
     // Create model file
-    let model_path = Path::new("src/models").join(format!("{}.rs", snake_name));
+    let model_path = Path::new("src/models").join(format!("{}.rs", schema.snake_name));
     let template = env.get_template("model.rs")?;
     let content = template.render(&context)?;
 
-    ensure_parent_dir(&model_path).await?;
-    fs::write(&model_path, content).await?;
-    println!("  Created: {}", model_path.display());
+    output::create(&model_path, &content)?;
 
     // Update models/mod.rs
-    super::common::update_mod_file("src/models/mod.rs", &snake_name).await?;
+    super::common::update_mod_file("src/models/mod.rs", &schema.snake_name).await?;
 
     // Create service file
-    let service_path = Path::new("src/services").join(format!("{}_service.rs", snake_name));
+    let service_path = Path::new("src/services").join(format!("{}_service.rs", schema.snake_name));
     let template = env.get_template("service.rs")?;
     let content = template.render(&context)?;
 
-    ensure_parent_dir(&service_path).await?;
-    fs::write(&service_path, content).await?;
-    println!("  Created: {}", service_path.display());
+    output::create(&service_path, &content)?;
 
     // Update services/mod.rs
-    super::common::update_mod_file("src/services/mod.rs", &format!("{}_service", snake_name))
-        .await?;
+    super::common::update_mod_file(
+        "src/services/mod.rs",
+        &format!("{}_service", schema.snake_name),
+    )
+    .await?;
 
     // Create migration
-    create_migration(name, &snake_name, &table_name, &context).await?;
+    let migration = create_migration(schema, &dialect, Path::new("migrations")).await?;
 
-    // Update manifest
-    super::common::update_manifest("models", name).await?;
+    // Dépendances des types utilisés par le modèle
+    update_cargo_toml(schema, Path::new("Cargo.toml")).await?;
 
-    println!("✅ Model '{}' created successfully!", name);
-    println!("\nGenerated files:");
-    println!("  - src/models/{}.rs", snake_name);
-    println!("  - src/services/{}_service.rs", snake_name);
-    println!("  - migration/src/m<timestamp>_create_{}.rs", table_name);
-    println!("\nNext steps:");
-    println!(
-        "  1. Customize the model fields in src/models/{}.rs",
-        snake_name
-    );
-    println!("  2. Update the migration if needed in migration/src/");
-    println!("  3. Run migrations: rustwork db migrate");
+    Ok(migration)
+}
 
-    Ok(())
+/// Associe chaque `references(table)` au modèle `src/models/<singulier>.rs` s'il existe
+async fn resolve_parents(schema: &mut ModelSchema, models_dir: &Path) {
+    let own_table = schema.table_name.clone();
+    let own = ParentModel {
+        module: schema.snake_name.clone(),
+        struct_name: schema.struct_name.clone(),
+        uuid_pk: schema.uuid_pk,
+    };
+
+    for field in &mut schema.fields {
+        let Some(table) = field.references() else {
+            continue;
+        };
+
+        if table == own_table {
+            field.parent = Some(own.clone());
+            continue;
+        }
+
+        let module = singularize(table);
+        let Ok(content) = fs::read_to_string(models_dir.join(format!("{}.rs", module))).await
+        else {
+            continue;
+        };
+        // `pub type <Struct> = Model;` garde la casse choisie à la génération (`APIKey`)
+        let struct_name = content
+            .lines()
+            .find_map(|line| line.strip_prefix("pub type ")?.strip_suffix(" = Model;"))
+            .map(str::to_string)
+            .unwrap_or_else(|| to_pascal_case(&module));
+        field.parent = Some(ParentModel {
+            struct_name,
+            uuid_pk: content.contains("pub id: uuid::Uuid"),
+            module,
+        });
+    }
 }
 
-async fn create_migration(
-    _name: &str,
-    _snake_name: &str,
-    table_name: &str,
-    context: &TemplateContext,
-) -> Result<()> {
-    // Vérifier que le crate migration existe
-    let migration_dir = Path::new("migration");
-    if !migration_dir.exists() {
-        anyhow::bail!(
-            "Migration directory not found. This project may have been created with an older version of Rustwork.\n\
-            Please create a 'migration' directory with the proper structure."
-        );
+/// Dialecte SQL du service, lu dans `config/default.toml` (SQLite par défaut)
+pub(super) async fn detect_dialect(config_path: &Path) -> DbConnection {
+    match fs::read_to_string(config_path).await {
+        Ok(content) => db_connection_from_config(&content),
+        Err(_) => DbConnection::Sqlite,
     }
+}
 
-    let timestamp = Utc::now().format("%Y%m%d%H%M%S");
-    let migration_name = format!("m{}_create_{}", timestamp, table_name);
-    let migration_path = migration_dir
-        .join("src")
-        .join(format!("{}.rs", migration_name));
+/// Écrit `migrations/<timestamp>_create_<table>.{up,down}.sql` et retourne leur préfixe
+async fn create_migration(
+    schema: &ModelSchema,
+    dialect: &DbConnection,
+    migrations_dir: &Path,
+) -> Result<String> {
+    let migration_name = format!(
+        "{}_create_{}",
+        next_migration_version(migrations_dir).await,
+        schema.table_name
+    );
 
     let env = create_micro_env();
-    let template = env.get_template("migration.rs")?;
-    let content = template.render(context)?;
+    let mut context = TemplateContext::new();
+    context.insert(
+        "struct_name".to_string(),
+        serde_json::json!(schema.struct_name),
+    );
+    context.insert(
+        "table_name".to_string(),
+        serde_json::json!(schema.table_name),
+    );
+    context.insert("dialect".to_string(), serde_json::json!(dialect));
+
+    for (suffix, template, sql) in [
+        (
+            "up",
+            "migration_create_table_up.sql",
+            schema.create_table_sql(dialect),
+        ),
+        (
+            "down",
+            "migration_create_table_down.sql",
+            schema.drop_table_sql(),
+        ),
+    ] {
+        context.insert("sql".to_string(), serde_json::json!(sql));
+        let content = env.get_template(template)?.render(&context)?;
+        let path = migrations_dir.join(format!("{}.{}.sql", migration_name, suffix));
+        output::create(&path, &content)?;
+    }
 
-    ensure_parent_dir(&migration_path).await?;
-    fs::write(&migration_path, content).await?;
-    println!("  Created: {}", migration_path.display());
+    Ok(migration_name)
+}
 
-    // Mettre à jour migration/src/lib.rs
-    update_migration_lib(&migration_name).await?;
+/// Version sqlx (préfixe numérique) : horodatage, incrémenté si déjà pris
+pub(super) async fn next_migration_version(migrations_dir: &Path) -> u64 {
+    let now: u64 = Utc::now()
+        .format("%Y%m%d%H%M%S")
+        .to_string()
+        .parse()
+        .unwrap_or_default();
+
+    let mut latest = 0;
+    if let Ok(mut entries) = fs::read_dir(migrations_dir).await {
+        while let Ok(Some(entry)) = entries.next_entry().await {
+            let version = entry
+                .file_name()
+                .to_str()
+                .and_then(|name| name.split('_').next())
+                .and_then(|prefix| prefix.parse::<u64>().ok());
+            latest = latest.max(version.unwrap_or_default());
+        }
+    }
 
-    Ok(())
+    now.max(latest + 1)
 }
 
-async fn update_migration_lib(migration_name: &str) -> Result<()> {
-    let lib_path = Path::new("migration/src/lib.rs");
-
-    if !lib_path.exists() {
-        anyhow::bail!("migration/src/lib.rs not found");
+/// Ajoute les crates et features sqlx nécessaires aux types du modèle
+async fn update_cargo_toml(schema: &ModelSchema, cargo_toml_path: &Path) -> Result<()> {
+    if !cargo_toml_path.exists() {
+        return Ok(());
     }
 
-    let content = fs::read_to_string(lib_path).await?;
-
-    // Vérifier si la migration est déjà référencée
-    if content.contains(&format!("mod {};", migration_name)) {
-        return Ok(()); // Déjà référencée
+    let mut deps = vec![(
+        "chrono",
+        r#"chrono = { version = "0.4", features = ["serde"] }"#.to_string(),
+    )];
+    let mut features = vec!["chrono"];
+    if schema.uses_uuid() {
+        deps.push((
+            "uuid",
+            r#"uuid = { version = "1", features = ["v4", "serde"] }"#.to_string(),
+        ));
+        features.push("uuid");
+    }
+    if schema.uses_json() {
+        deps.push(("serde_json", r#"serde_json = "1.0""#.to_string()));
+        features.push("json");
     }
 
-    // Trouver où insérer le mod
-    let mod_line = format!("mod {};\n", migration_name);
+    let mut added = super::common::add_dependencies(cargo_toml_path, &deps).await?;
+    match super::common::add_dependency_features(cargo_toml_path, "sqlx", &features).await {
+        Ok(sqlx_features) => added.extend(
+            sqlx_features
+                .into_iter()
+                .map(|feature| format!("sqlx/{}", feature)),
+        ),
+        Err(e) => println!("⚠️  Could not enable sqlx features {:?}: {}", features, e),
+    }
 
-    // Insérer après "pub use sea_orm_migration::prelude::*;" et avant "pub struct Migrator;"
-    let new_content = if let Some(pos) = content.find("pub struct Migrator;") {
-        let (before, after) = content.split_at(pos);
+    if !added.is_empty() {
+        println!("  Added dependencies: {}", added.join(", "));
+    }
 
-        // Ajouter le mod avant "pub struct Migrator;"
-        let before_with_mod = if before.ends_with('\n') {
-            format!("{}{}\n", before, mod_line.trim_end())
-        } else {
-            format!("{}\n{}\n", before, mod_line.trim_end())
-        };
+    Ok(())
+}
 
-        format!("{}{}", before_with_mod, after)
-    } else {
-        // Fallback: ajouter à la fin de la section des mods
-        content.replace(
-            "pub use sea_orm_migration::prelude::*;",
-            &format!("pub use sea_orm_migration::prelude::*;\n\n{}", mod_line),
-        )
-    };
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use tempfile::TempDir;
 
-    // Maintenant, ajouter la migration à la liste dans migrations()
-    let final_content = add_migration_to_vector(&new_content, migration_name)?;
+    fn specs(list: &[&str]) -> Vec<String> {
+        list.iter().map(|s| s.to_string()).collect()
+    }
 
-    fs::write(lib_path, final_content).await?;
-    println!("  Updated: migration/src/lib.rs");
+    #[tokio::test]
+    async fn test_detect_dialect() {
+        let dir = TempDir::new().unwrap();
+        let path = dir.path().join("default.toml");
 
-    Ok(())
-}
+        assert_eq!(detect_dialect(&path).await, DbConnection::Sqlite);
 
-fn add_migration_to_vector(content: &str, migration_name: &str) -> Result<String> {
-    // Trouver le vecteur dans migrations()
-    let migration_box = format!("            Box::new({}::Migration),\n", migration_name);
+        fs::write(&path, "[database]\nurl = \"postgres://localhost/app\"\n")
+            .await
+            .unwrap();
+        assert_eq!(detect_dialect(&path).await, DbConnection::Postgres);
 
-    // Chercher la dernière Box::new avant le ]
-    if let Some(pos) = content.rfind("Box::new(") {
-        // Trouver la fin de cette ligne
-        if let Some(end_pos) = content[pos..].find("),") {
-            let insert_pos = pos + end_pos + 2; // Après "),\n"
+        fs::write(&path, "[database]\nconnection = \"mysql\"\n")
+            .await
+            .unwrap();
+        assert_eq!(detect_dialect(&path).await, DbConnection::Mysql);
+    }
 
-            // Vérifier si on a déjà un saut de ligne
-            let (before, after) = content.split_at(insert_pos);
+    #[tokio::test]
+    async fn test_resolve_parents() {
+        let dir = TempDir::new().unwrap();
+        fs::write(
+            dir.path().join("team.rs"),
+            "pub struct Model {\n    pub id: uuid::Uuid,\n}\n",
+        )
+        .await
+        .unwrap();
+
+        let mut schema = ModelSchema::parse(
+            "Member",
+            &specs(&[
+                "team_id:references(teams)",
+                "mentor_id:references(members)?",
+                "user_id:references(users)",
+            ]),
+            false,
+            false,
+        )
+        .unwrap();
+        resolve_parents(&mut schema, dir.path()).await;
+
+        let team = schema.fields[0].parent.as_ref().unwrap();
+        assert_eq!(team.struct_name, "Team");
+        assert!(team.uuid_pk);
+        assert_eq!(
+            schema.fields[1].parent.as_ref().unwrap().struct_name,
+            "Member"
+        );
+        assert_eq!(schema.unresolved_references().count(), 1);
+    }
 
-            return Ok(format!("{}{}{}", before, migration_box, after));
-        }
+    #[tokio::test]
+    async fn test_render_model_and_service() {
+        let mut schema = ModelSchema::parse(
+            "Post",
+            &specs(&["title:string", "body:text?", "author_id:references(users)"]),
+            false,
+            true,
+        )
+        .unwrap();
+        schema.fields[2].parent = Some(ParentModel {
+            module: "user".to_string(),
+            struct_name: "User".to_string(),
+            uuid_pk: false,
+        });
+        let context: TemplateContext = serde_json::from_value(schema.template_context()).unwrap();
+        let env = create_micro_env();
+
+        let model = env
+            .get_template("model.rs")
+            .unwrap()
+            .render(&context)
+            .unwrap();
+        syn::parse_file(&model).unwrap();
+        assert!(model.contains("pub body: Option<String>,"));
+        assert!(model.contains("pub deleted_at: Option<chrono::DateTime<chrono::Utc>>,"));
+        assert!(model.contains("pub async fn author("));
+        assert!(model.contains("Relation::belongs_to::<super::user::UserFactory>(\"author_id\")"));
+        assert!(model.contains("\"title\": format!(\"title {}\", seq),"));
+        assert!(!model.contains("\"author_id\":"));
+        assert!(!model.contains("PostInput"));
+
+        let service = env
+            .get_template("service.rs")
+            .unwrap()
+            .render(&context)
+            .unwrap();
+        syn::parse_file(&service).unwrap();
+        assert!(service.contains("pub async fn list_posts("));
+        assert!(service.contains("WHERE deleted_at IS NULL"));
     }
 
-    // Fallback: remplacer le dernier ] avant }
-    Ok(content.replace(
-        "        ]\n    }",
-        &format!("{}        ]\n    }}", migration_box.trim_end()),
-    ))
+    #[tokio::test]
+    async fn test_create_migration() {
+        let dir = TempDir::new().unwrap();
+        let schema = ModelSchema::parse("Post", &specs(&["title:string"]), false, false).unwrap();
+
+        let name = create_migration(&schema, &DbConnection::Sqlite, dir.path())
+            .await
+            .unwrap();
+        assert!(name.ends_with("_create_posts"));
+
+        let up = fs::read_to_string(dir.path().join(format!("{}.up.sql", name)))
+            .await
+            .unwrap();
+        assert!(up.contains("CREATE TABLE IF NOT EXISTS posts ("));
+        let down = fs::read_to_string(dir.path().join(format!("{}.down.sql", name)))
+            .await
+            .unwrap();
+        assert!(down.contains("DROP TABLE IF EXISTS posts;"));
+
+        // Deux modèles générés dans la même seconde : versions distinctes
+        let comments = ModelSchema::parse("Comment", &[], false, false).unwrap();
+        let next = create_migration(&comments, &DbConnection::Sqlite, dir.path())
+            .await
+            .unwrap();
+        assert_ne!(next.split('_').next(), name.split('_').next());
+    }
 }
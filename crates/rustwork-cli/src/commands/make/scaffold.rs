@@ -0,0 +1,133 @@
+use anyhow::Result;
+use std::path::Path;
+
+use super::model::{prepare_schema, write_model, ModelOptions};
+use crate::commands::utils::is_rustwork_project;
+use crate::templates::{create_micro_env, output, TemplateContext};
+
+/// Génère une ressource CRUD complète : modèle, migration, service, contrôleur, routes et tests
+pub async fn execute(name: &str, options: &ModelOptions) -> Result<()> {
+    if !is_rustwork_project() {
+        anyhow::bail!("Not in a Rustwork project. Run this command from a project created with 'rustwork new'");
+    }
+
+    let schema = prepare_schema(name, options).await?;
+
+    // Les tests générés créent les parents via leurs factories
+    if let Some(field) = schema.unresolved_references().next() {
+        anyhow::bail!(
+            "Field '{}' references '{}' but no matching model exists in src/models/. Create the parent model first",
+            field.name,
+            field.references().unwrap_or_default()
+        );
+    }
+    if let Some(field) = schema
+        .fields
+        .iter()
+        .find(|f| !f.nullable && f.references() == Some(schema.table_name.as_str()))
+    {
+        anyhow::bail!(
+            "Self-reference '{}' must be nullable (use '{}:references({})?')",
+            field.name,
+            field.name,
+            schema.table_name
+        );
+    }
+
+    println!("📝 Generating scaffold: {}", name);
+
+    let migration = write_model(&schema, true).await?;
+
+    // Create controller file
+    let mut context: TemplateContext = serde_json::from_value(schema.template_context())?;
+    context.insert("crud".to_string(), serde_json::json!(true));
+    let content = create_micro_env()
+        .get_template("scaffold_controller.rs")?
+        .render(&context)?;
+
+    let controller_path = Path::new("src/controllers").join(format!("{}.rs", schema.snake_name));
+    output::create(&controller_path, &content)?;
+
+    // Update controllers/mod.rs
+    super::common::update_mod_file("src/controllers/mod.rs", &schema.snake_name).await?;
+
+    // Update routes.rs
+    let routes = super::common::resource_routes(&schema.snake_name, &schema.table_name);
+    if !super::common::update_routes_file("src/routes.rs", "create_routes", &routes).await? {
+        println!("  Routes already exist in src/routes.rs");
+    }
+
+    // Update manifest
+    super::common::update_manifest("models", name).await?;
+    super::common::update_manifest("controllers", name).await?;
+
+    println!("✅ Scaffold '{}' created successfully!", name);
+    println!("\nRoutes registered in src/routes.rs:");
+    println!("  GET    /api/{}", schema.table_name);
+    println!("  POST   /api/{}", schema.table_name);
+    println!("  GET    /api/{}/{{id}}", schema.table_name);
+    println!("  PUT    /api/{}/{{id}}", schema.table_name);
+    println!("  DELETE /api/{}/{{id}}", schema.table_name);
+
+    println!("\nNext steps:");
+    println!(
+        "  1. Review the migration in migrations/{}.up.sql",
+        migration
+    );
+    println!("  2. Run the generated tests: cargo test");
+
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::super::schema::{ModelSchema, ParentModel};
+    use super::*;
+
+    #[test]
+    fn test_render_crud_templates() {
+        let mut schema = ModelSchema::parse(
+            "Post",
+            &[
+                "title:string".to_string(),
+                "summary:string?".to_string(),
+                "author_id:references(users)".to_string(),
+            ],
+            false,
+            true,
+        )
+        .unwrap();
+        schema.fields[2].parent = Some(ParentModel {
+            module: "user".to_string(),
+            struct_name: "User".to_string(),
+            uuid_pk: false,
+        });
+        let mut context: TemplateContext =
+            serde_json::from_value(schema.template_context()).unwrap();
+        context.insert("crud".to_string(), serde_json::json!(true));
+        let env = create_micro_env();
+        let render = |name: &str| {
+            let content = env.get_template(name).unwrap().render(&context).unwrap();
+            syn::parse_file(&content).unwrap();
+            content
+        };
+
+        let model = render("model.rs");
+        assert!(model.contains("pub struct PostInput {"));
+        assert!(model.contains("errors.push(\"title is required\");"));
+        assert!(model.contains("errors.push(\"summary must be at most 255 characters\");"));
+        assert!(!model.contains("\"summary is required\""));
+
+        let service = render("service.rs");
+        assert!(service.contains(
+            "\"INSERT INTO posts (title, summary, author_id) VALUES ($1, $2, $3) RETURNING *\""
+        ));
+        assert!(service.contains("get_post(db, result.last_insert_id() as i64).await"));
+        assert!(service.contains("pub async fn delete_post(db: &DatabaseConnection, id: i64)"));
+
+        let controller = render("scaffold_controller.rs");
+        assert!(controller.contains("use crate::models::user::UserFactory;"));
+        assert!(controller.contains("async fn test_create_rejects_blank_title()"));
+        assert!(controller.contains("Path(id): Path<i64>,"));
+    }
+}
@@ -0,0 +1,718 @@
+use anyhow::Result;
+use rustwork::DbConnection;
+use serde::Serialize;
+
+/// Colonnes gérées par le générateur (interdites dans la liste de champs)
+const RESERVED_COLUMNS: &[&str] = &["id", "created_at", "updated_at", "deleted_at"];
+
+/// Type d'un champ du DSL `name:type`
+#[derive(Debug, Clone, PartialEq)]
+pub(crate) enum FieldType {
+    String,
+    Text,
+    Integer,
+    BigInteger,
+    Float,
+    Boolean,
+    Date,
+    DateTime,
+    Uuid,
+    Json,
+    /// Clé étrangère vers `table.id`
+    References(String),
+}
+
+impl FieldType {
+    fn parse(ty: &str) -> Result<Self> {
+        if let Some(table) = ty
+            .strip_prefix("references(")
+            .and_then(|rest| rest.strip_suffix(')'))
+        {
+            check_identifier(table, "table")?;
+            return Ok(Self::References(table.to_string()));
+        }
+
+        Ok(match ty {
+            "string" => Self::String,
+            "text" => Self::Text,
+            "int" | "integer" => Self::Integer,
+            "bigint" => Self::BigInteger,
+            "float" | "double" => Self::Float,
+            "bool" | "boolean" => Self::Boolean,
+            "date" => Self::Date,
+            "datetime" | "timestamp" => Self::DateTime,
+            "uuid" => Self::Uuid,
+            "json" => Self::Json,
+            other => anyhow::bail!(
+                "Unknown field type '{}'. Supported: string, text, integer, bigint, float, bool, \
+                 date, datetime, uuid, json, references(<table>)",
+                other
+            ),
+        })
+    }
+}
+
+/// Modèle parent d'une clé étrangère, trouvé dans `src/models/`
+#[derive(Debug, Clone, PartialEq)]
+pub(crate) struct ParentModel {
+    pub module: String,
+    pub struct_name: String,
+    pub uuid_pk: bool,
+}
+
+/// Champ déclaré en ligne de commande : `name:type[?][:unique][:index]`
+#[derive(Debug, Clone, PartialEq)]
+pub(crate) struct Field {
+    pub name: String,
+    pub ty: FieldType,
+    pub nullable: bool,
+    pub unique: bool,
+    pub index: bool,
+    pub parent: Option<ParentModel>,
+}
+
+impl Field {
+    pub fn parse(spec: &str) -> Result<Self> {
+        let mut parts = spec.split(':');
+        let name = parts.next().unwrap_or_default();
+        let Some(ty) = parts.next() else {
+            anyhow::bail!("Invalid field '{}': expected name:type", spec);
+        };
+        check_identifier(name, "field")?;
+        if RESERVED_COLUMNS.contains(&name) {
+            anyhow::bail!(
+                "Field '{}' is generated automatically (see --uuid-pk and --soft-deletes)",
+                name
+            );
+        }
+
+        let (ty, mut nullable) = match ty.strip_suffix('?') {
+            Some(ty) => (ty, true),
+            None => (ty, false),
+        };
+        let mut field = Self {
+            name: name.to_string(),
+            ty: FieldType::parse(ty)?,
+            nullable: false,
+            unique: false,
+            index: false,
+            parent: None,
+        };
+
+        for modifier in parts {
+            match modifier {
+                "nullable" => nullable = true,
+                "unique" => field.unique = true,
+                "index" => field.index = true,
+                other => anyhow::bail!(
+                    "Unknown modifier '{}' on field '{}' (expected nullable, unique or index)",
+                    other,
+                    name
+                ),
+            }
+        }
+        field.nullable = nullable;
+
+        Ok(field)
+    }
+
+    /// Table référencée (`references(users)`)
+    pub fn references(&self) -> Option<&str> {
+        match &self.ty {
+            FieldType::References(table) => Some(table),
+            _ => None,
+        }
+    }
+
+    /// Nom de la relation `belongs_to` : `author_id` → `author`
+    pub fn relation_name(&self) -> String {
+        self.name
+            .strip_suffix("_id")
+            .unwrap_or(&self.name)
+            .to_string()
+    }
+
+    pub fn rust_type(&self) -> String {
+        let ty = match &self.ty {
+            FieldType::String | FieldType::Text => "String",
+            FieldType::Integer => "i32",
+            FieldType::BigInteger => "i64",
+            FieldType::Float => "f64",
+            FieldType::Boolean => "bool",
+            FieldType::Date => "chrono::NaiveDate",
+            FieldType::DateTime => "chrono::DateTime<chrono::Utc>",
+            FieldType::Uuid => "uuid::Uuid",
+            FieldType::Json => "sqlx::types::Json<serde_json::Value>",
+            FieldType::References(_) if self.parent_uuid_pk() => "uuid::Uuid",
+            FieldType::References(_) => "i64",
+        };
+
+        if self.nullable {
+            format!("Option<{}>", ty)
+        } else {
+            ty.to_string()
+        }
+    }
+
+    pub fn sql_type(&self, dialect: &DbConnection) -> &'static str {
+        match (&self.ty, dialect) {
+            (FieldType::String, _) => "VARCHAR(255)",
+            (FieldType::Text, _) => "TEXT",
+            (FieldType::Integer, _) => "INTEGER",
+            (FieldType::BigInteger, DbConnection::Sqlite) => "INTEGER",
+            (FieldType::BigInteger, _) => "BIGINT",
+            (FieldType::Float, DbConnection::Sqlite) => "REAL",
+            (FieldType::Float, DbConnection::Postgres) => "DOUBLE PRECISION",
+            (FieldType::Float, DbConnection::Mysql) => "DOUBLE",
+            (FieldType::Boolean, _) => "BOOLEAN",
+            (FieldType::Date, _) => "DATE",
+            (FieldType::DateTime, DbConnection::Postgres) => "TIMESTAMPTZ",
+            (FieldType::DateTime, _) => "TIMESTAMP",
+            (FieldType::Json, DbConnection::Sqlite) => "TEXT",
+            (FieldType::Json, DbConnection::Postgres) => "JSONB",
+            (FieldType::Json, DbConnection::Mysql) => "JSON",
+            (FieldType::Uuid, _) => uuid_sql_type(dialect),
+            (FieldType::References(_), _) if self.parent_uuid_pk() => uuid_sql_type(dialect),
+            (FieldType::References(_), DbConnection::Sqlite) => "INTEGER",
+            (FieldType::References(_), _) => "BIGINT",
+        }
+    }
+
+    /// Valeur par défaut de la factory (expression Rust), `None` pour les clés étrangères
+    pub fn fake_value(&self) -> Option<String> {
+        if self.nullable {
+            return Some("serde_json::Value::Null".to_string());
+        }
+
+        Some(match &self.ty {
+            FieldType::String | FieldType::Text => format!("format!(\"{} {{}}\", seq)", self.name),
+            FieldType::Integer | FieldType::BigInteger => "seq".to_string(),
+            FieldType::Float => "seq as f64".to_string(),
+            FieldType::Boolean => "true".to_string(),
+            FieldType::Date => "chrono::Utc::now().date_naive()".to_string(),
+            FieldType::DateTime => "chrono::Utc::now()".to_string(),
+            FieldType::Uuid => "uuid::Uuid::new_v4()".to_string(),
+            FieldType::Json => "serde_json::json!({})".to_string(),
+            FieldType::References(_) => return None,
+        })
+    }
+
+    fn is_text(&self) -> bool {
+        matches!(self.ty, FieldType::String | FieldType::Text)
+    }
+
+    fn parent_uuid_pk(&self) -> bool {
+        self.parent.as_ref().is_some_and(|parent| parent.uuid_pk)
+    }
+}
+
+/// Modèle décrit par `rustwork make model <Name> [fields...]`
+#[derive(Debug, Clone)]
+pub(crate) struct ModelSchema {
+    pub struct_name: String,
+    pub snake_name: String,
+    pub table_name: String,
+    pub fields: Vec<Field>,
+    pub uuid_pk: bool,
+    pub soft_deletes: bool,
+}
+
+/// Champ tel qu'exposé aux templates
+#[derive(Debug, Serialize)]
+struct TemplateField {
+    name: String,
+    rust_type: String,
+    fake_value: Option<String>,
+    nullable: bool,
+    /// Chaîne à valider (`required` si non nullable)
+    is_text: bool,
+    max_length: Option<usize>,
+}
+
+/// Relation `belongs_to` exposée aux templates
+#[derive(Debug, Serialize)]
+struct TemplateRelation {
+    name: String,
+    column: String,
+    table: String,
+    module: String,
+    struct_name: String,
+    nullable: bool,
+    model: String,
+    /// Absente pour une auto-référence (la factory se créerait elle-même)
+    factory: Option<String>,
+}
+
+/// Requêtes d'écriture d'un dialecte (service CRUD de `make scaffold`)
+#[derive(Debug, Serialize)]
+struct CrudSql {
+    insert: String,
+    update: String,
+    delete: String,
+}
+
+impl ModelSchema {
+    /// Sans champ, le modèle garde la colonne `name` historique
+    pub fn parse(
+        struct_name: &str,
+        specs: &[String],
+        uuid_pk: bool,
+        soft_deletes: bool,
+    ) -> Result<Self> {
+        let snake_name = crate::commands::utils::to_snake_case(struct_name);
+        let table_name = crate::inflection::pluralize(&snake_name);
+
+        let fields = if specs.is_empty() {
+            vec![Field::parse("name:string")?]
+        } else {
+            specs
+                .iter()
+                .map(|spec| Field::parse(spec))
+                .collect::<Result<Vec<_>>>()?
+        };
+
+        for (i, field) in fields.iter().enumerate() {
+            if fields[..i].iter().any(|f| f.name == field.name) {
+                anyhow::bail!("Field '{}' is declared twice", field.name);
+            }
+        }
+
+        Ok(Self {
+            struct_name: struct_name.to_string(),
+            snake_name,
+            table_name,
+            fields,
+            uuid_pk,
+            soft_deletes,
+        })
+    }
+
+    pub fn uses_uuid(&self) -> bool {
+        self.uuid_pk
+            || self
+                .fields
+                .iter()
+                .any(|f| f.ty == FieldType::Uuid || f.parent_uuid_pk())
+    }
+
+    pub fn uses_json(&self) -> bool {
+        self.fields.iter().any(|f| f.ty == FieldType::Json)
+    }
+
+    /// Clés étrangères dont le modèle parent est introuvable
+    pub fn unresolved_references(&self) -> impl Iterator<Item = &Field> {
+        self.fields
+            .iter()
+            .filter(|f| f.references().is_some() && f.parent.is_none())
+    }
+
+    /// Colonnes dans l'ordre du `CREATE TABLE`
+    pub fn columns(&self) -> Vec<String> {
+        let mut columns = vec!["id".to_string()];
+        columns.extend(self.fields.iter().map(|f| f.name.clone()));
+        columns.push("created_at".to_string());
+        columns.push("updated_at".to_string());
+        if self.soft_deletes {
+            columns.push("deleted_at".to_string());
+        }
+        columns
+    }
+
+    /// Contexte des templates `model.rs` et `service.rs`
+    pub fn template_context(&self) -> serde_json::Value {
+        let fields: Vec<TemplateField> = self
+            .fields
+            .iter()
+            .map(|f| TemplateField {
+                name: f.name.clone(),
+                rust_type: f.rust_type(),
+                fake_value: f.fake_value(),
+                nullable: f.nullable,
+                is_text: f.is_text(),
+                max_length: (f.ty == FieldType::String).then_some(255),
+            })
+            .collect();
+
+        let relations: Vec<TemplateRelation> = self
+            .fields
+            .iter()
+            .filter_map(|f| {
+                let parent = f.parent.as_ref()?;
+                Some(TemplateRelation {
+                    name: f.relation_name(),
+                    column: f.name.clone(),
+                    table: f.references()?.to_string(),
+                    module: parent.module.clone(),
+                    struct_name: parent.struct_name.clone(),
+                    nullable: f.nullable,
+                    model: format!("super::{}::{}", parent.module, parent.struct_name),
+                    factory: (parent.module != self.snake_name).then(|| {
+                        format!("super::{}::{}Factory", parent.module, parent.struct_name)
+                    }),
+                })
+            })
+            .collect();
+
+        let uses_seq = fields
+            .iter()
+            .any(|f| f.fake_value.as_deref().is_some_and(|v| v.contains("seq")));
+
+        serde_json::json!({
+            "struct_name": self.struct_name,
+            "snake_name": self.snake_name,
+            "table_name": self.table_name,
+            "id_type": if self.uuid_pk { "uuid::Uuid" } else { "i64" },
+            "uuid_pk": self.uuid_pk,
+            "soft_deletes": self.soft_deletes,
+            "columns": self.columns(),
+            "fields": fields,
+            "relations": relations,
+            "uses_seq": uses_seq,
+            "validates": fields
+                .iter()
+                .any(|f| f.is_text && (!f.nullable || f.max_length.is_some())),
+            "update_field": self
+                .fields
+                .iter()
+                .find(|f| f.is_text() && !f.nullable)
+                .map(|f| f.name.clone()),
+            "sql": {
+                "sqlite": self.crud_sql(&DbConnection::Sqlite),
+                "postgres": self.crud_sql(&DbConnection::Postgres),
+                "mysql": self.crud_sql(&DbConnection::Mysql),
+            },
+        })
+    }
+
+    /// `CREATE TABLE` + index pour le dialecte du service
+    pub fn create_table_sql(&self, dialect: &DbConnection) -> String {
+        let timestamp = match dialect {
+            DbConnection::Postgres => "TIMESTAMPTZ",
+            _ => "TIMESTAMP",
+        };
+
+        let mut lines = vec![match (self.uuid_pk, dialect) {
+            (true, DbConnection::Postgres) => {
+                "id UUID PRIMARY KEY DEFAULT gen_random_uuid()".to_string()
+            }
+            (true, _) => format!("id {} PRIMARY KEY", uuid_sql_type(dialect)),
+            (false, DbConnection::Sqlite) => "id INTEGER PRIMARY KEY AUTOINCREMENT".to_string(),
+            (false, DbConnection::Postgres) => "id BIGSERIAL PRIMARY KEY".to_string(),
+            (false, DbConnection::Mysql) => "id BIGINT AUTO_INCREMENT PRIMARY KEY".to_string(),
+        }];
+
+        for field in &self.fields {
+            let mut line = format!("{} {}", field.name, field.sql_type(dialect));
+            if !field.nullable {
+                line.push_str(" NOT NULL");
+            }
+            lines.push(line);
+        }
+
+        lines.push(format!(
+            "created_at {} NOT NULL DEFAULT CURRENT_TIMESTAMP",
+            timestamp
+        ));
+        lines.push(format!(
+            "updated_at {} NOT NULL DEFAULT CURRENT_TIMESTAMP",
+            timestamp
+        ));
+        if self.soft_deletes {
+            lines.push(format!("deleted_at {} NULL", timestamp));
+        }
+
+        for field in &self.fields {
+            if let Some(table) = field.references() {
+                lines.push(format!(
+                    "CONSTRAINT fk_{}_{} FOREIGN KEY ({}) REFERENCES {} (id) ON DELETE CASCADE",
+                    self.table_name, field.name, field.name, table
+                ));
+            }
+        }
+
+        let mut sql = format!(
+            "CREATE TABLE IF NOT EXISTS {} (\n    {}\n);\n",
+            self.table_name,
+            lines.join(",\n    ")
+        );
+
+        // MySQL ne supporte pas `CREATE INDEX IF NOT EXISTS`
+        let if_not_exists = match dialect {
+            DbConnection::Mysql => "",
+            _ => "IF NOT EXISTS ",
+        };
+        let mut indexes: Vec<(&str, bool)> = self
+            .fields
+            .iter()
+            .filter(|f| f.unique || f.index || f.references().is_some())
+            .map(|f| (f.name.as_str(), f.unique))
+            .collect();
+        if self.soft_deletes {
+            indexes.push(("deleted_at", false));
+        }
+        if !indexes.is_empty() {
+            sql.push('\n');
+        }
+        for (column, unique) in indexes {
+            sql.push_str(&format!(
+                "CREATE {}INDEX {}{}_{}_{} ON {} ({});\n",
+                if unique { "UNIQUE " } else { "" },
+                if_not_exists,
+                if unique { "uniq" } else { "idx" },
+                self.table_name,
+                column,
+                self.table_name,
+                column
+            ));
+        }
+
+        sql
+    }
+
+    /// `INSERT` (avec `RETURNING *` hors MySQL), `UPDATE` et `DELETE` par id
+    fn crud_sql(&self, dialect: &DbConnection) -> CrudSql {
+        let placeholder = |i: usize| match dialect {
+            DbConnection::Postgres => format!("${}", i),
+            _ => "?".to_string(),
+        };
+
+        let mut insert_columns: Vec<&str> = Vec::new();
+        if self.uuid_pk {
+            insert_columns.push("id");
+        }
+        insert_columns.extend(self.fields.iter().map(|f| f.name.as_str()));
+        let values: Vec<String> = (1..=insert_columns.len()).map(placeholder).collect();
+        let mut insert = format!(
+            "INSERT INTO {} ({}) VALUES ({})",
+            self.table_name,
+            insert_columns.join(", "),
+            values.join(", ")
+        );
+        if *dialect != DbConnection::Mysql {
+            insert.push_str(" RETURNING *");
+        }
+
+        let live = if self.soft_deletes {
+            " AND deleted_at IS NULL"
+        } else {
+            ""
+        };
+        let assignments: Vec<String> = self
+            .fields
+            .iter()
+            .enumerate()
+            .map(|(i, f)| format!("{} = {}", f.name, placeholder(i + 1)))
+            .collect();
+        let update = format!(
+            "UPDATE {} SET {}, updated_at = CURRENT_TIMESTAMP WHERE id = {}{}",
+            self.table_name,
+            assignments.join(", "),
+            placeholder(self.fields.len() + 1),
+            live
+        );
+
+        let delete = if self.soft_deletes {
+            format!(
+                "UPDATE {} SET deleted_at = CURRENT_TIMESTAMP WHERE id = {}{}",
+                self.table_name,
+                placeholder(1),
+                live
+            )
+        } else {
+            format!(
+                "DELETE FROM {} WHERE id = {}",
+                self.table_name,
+                placeholder(1)
+            )
+        };
+
+        CrudSql {
+            insert,
+            update,
+            delete,
+        }
+    }
+
+    pub fn drop_table_sql(&self) -> String {
+        format!("DROP TABLE IF EXISTS {};\n", self.table_name)
+    }
+}
+
+fn uuid_sql_type(dialect: &DbConnection) -> &'static str {
+    match dialect {
+        DbConnection::Sqlite => "BLOB",
+        DbConnection::Postgres => "UUID",
+        DbConnection::Mysql => "BINARY(16)",
+    }
+}
+
+/// Identifiants interpolés dans le code et le SQL : `snake_case` uniquement
+fn check_identifier(name: &str, kind: &str) -> Result<()> {
+    let valid = name
+        .chars()
+        .next()
+        .is_some_and(|c| c.is_ascii_lowercase() || c == '_')
+        && name
+            .chars()
+            .all(|c| c.is_ascii_lowercase() || c.is_ascii_digit() || c == '_');
+    if !valid {
+        anyhow::bail!("Invalid {} name '{}': use snake_case", kind, name);
+    }
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn specs(list: &[&str]) -> Vec<String> {
+        list.iter().map(|s| s.to_string()).collect()
+    }
+
+    #[test]
+    fn test_parse_field() {
+        let field = Field::parse("title:string:unique").unwrap();
+        assert_eq!(field.ty, FieldType::String);
+        assert!(field.unique && !field.nullable);
+
+        let field = Field::parse("summary:text?:index").unwrap();
+        assert!(field.nullable && field.index);
+        assert_eq!(field.rust_type(), "Option<String>");
+
+        let field = Field::parse("author_id:references(users)").unwrap();
+        assert_eq!(field.references(), Some("users"));
+        assert_eq!(field.relation_name(), "author");
+        assert_eq!(field.rust_type(), "i64");
+    }
+
+    #[test]
+    fn test_parse_field_errors() {
+        assert!(Field::parse("title").is_err());
+        assert!(Field::parse("title:varchar").is_err());
+        assert!(Field::parse("Title:string").is_err());
+        assert!(Field::parse("id:integer").is_err());
+        assert!(Field::parse("title:string:primary").is_err());
+        assert!(Field::parse("user_id:references(Users)").is_err());
+        assert!(ModelSchema::parse("Post", &specs(&["a:int", "a:bool"]), false, false).is_err());
+    }
+
+    #[test]
+    fn test_table_name_inflection() {
+        let table = |name: &str| {
+            ModelSchema::parse(name, &[], false, false)
+                .unwrap()
+                .table_name
+        };
+        assert_eq!(table("Category"), "categories");
+        assert_eq!(table("Person"), "people");
+        assert_eq!(table("APIKey"), "api_keys");
+        assert_eq!(table("Address"), "addresses");
+    }
+
+    #[test]
+    fn test_default_schema_keeps_name_column() {
+        let schema = ModelSchema::parse("BlogPost", &[], false, false).unwrap();
+        assert_eq!(schema.table_name, "blog_posts");
+        assert_eq!(
+            schema.columns(),
+            vec!["id", "name", "created_at", "updated_at"]
+        );
+    }
+
+    #[test]
+    fn test_create_table_sql_sqlite() {
+        let schema = ModelSchema::parse(
+            "Post",
+            &specs(&[
+                "title:string",
+                "slug:string:unique",
+                "published:bool",
+                "author_id:references(users)",
+            ]),
+            false,
+            true,
+        )
+        .unwrap();
+        let sql = schema.create_table_sql(&DbConnection::Sqlite);
+
+        assert!(sql.contains("id INTEGER PRIMARY KEY AUTOINCREMENT"));
+        assert!(sql.contains("title VARCHAR(255) NOT NULL"));
+        assert!(sql.contains("author_id INTEGER NOT NULL"));
+        assert!(sql.contains("deleted_at TIMESTAMP NULL"));
+        assert!(sql.contains(
+            "CONSTRAINT fk_posts_author_id FOREIGN KEY (author_id) REFERENCES users (id) ON DELETE CASCADE"
+        ));
+        assert!(sql.contains("CREATE UNIQUE INDEX IF NOT EXISTS uniq_posts_slug ON posts (slug);"));
+        assert!(
+            sql.contains("CREATE INDEX IF NOT EXISTS idx_posts_author_id ON posts (author_id);")
+        );
+        assert!(
+            sql.contains("CREATE INDEX IF NOT EXISTS idx_posts_deleted_at ON posts (deleted_at);")
+        );
+    }
+
+    #[test]
+    fn test_create_table_sql_dialects() {
+        let schema = ModelSchema::parse(
+            "Event",
+            &specs(&["payload:json", "starts_at:datetime"]),
+            true,
+            false,
+        )
+        .unwrap();
+
+        let pg = schema.create_table_sql(&DbConnection::Postgres);
+        assert!(pg.contains("id UUID PRIMARY KEY DEFAULT gen_random_uuid()"));
+        assert!(pg.contains("payload JSONB NOT NULL"));
+        assert!(pg.contains("starts_at TIMESTAMPTZ NOT NULL"));
+
+        let mysql = schema.create_table_sql(&DbConnection::Mysql);
+        assert!(mysql.contains("id BINARY(16) PRIMARY KEY"));
+        assert!(mysql.contains("payload JSON NOT NULL"));
+
+        assert!(schema.uses_uuid() && schema.uses_json());
+    }
+
+    #[test]
+    fn test_crud_sql() {
+        let schema = ModelSchema::parse(
+            "Post",
+            &specs(&["title:string", "views:integer"]),
+            false,
+            true,
+        )
+        .unwrap();
+
+        let pg = schema.crud_sql(&DbConnection::Postgres);
+        assert_eq!(
+            pg.insert,
+            "INSERT INTO posts (title, views) VALUES ($1, $2) RETURNING *"
+        );
+        assert_eq!(
+            pg.update,
+            "UPDATE posts SET title = $1, views = $2, updated_at = CURRENT_TIMESTAMP \
+             WHERE id = $3 AND deleted_at IS NULL"
+        );
+        assert_eq!(
+            pg.delete,
+            "UPDATE posts SET deleted_at = CURRENT_TIMESTAMP WHERE id = $1 AND deleted_at IS NULL"
+        );
+
+        let uuid = ModelSchema::parse("Team", &specs(&["label:string"]), true, false).unwrap();
+        let mysql = uuid.crud_sql(&DbConnection::Mysql);
+        assert_eq!(mysql.insert, "INSERT INTO teams (id, label) VALUES (?, ?)");
+        assert_eq!(mysql.delete, "DELETE FROM teams WHERE id = ?");
+    }
+
+    #[test]
+    fn test_references_uuid_parent() {
+        let mut field = Field::parse("team_id:references(teams)").unwrap();
+        field.parent = Some(ParentModel {
+            module: "team".to_string(),
+            struct_name: "Team".to_string(),
+            uuid_pk: true,
+        });
+        assert_eq!(field.rust_type(), "uuid::Uuid");
+        assert_eq!(field.sql_type(&DbConnection::Postgres), "UUID");
+    }
+}
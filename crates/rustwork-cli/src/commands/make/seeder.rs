@@ -0,0 +1,138 @@
+use anyhow::Result;
+use std::path::Path;
+
+use crate::commands::db::seeders::write_seeders_mod;
+use crate::commands::utils::{is_rustwork_project, to_snake_case};
+use crate::templates::{create_micro_env, output, TemplateContext};
+
+/// Ligne de `main.rs` après laquelle le mode seed est branché
+const DB_CONNECTED_LINE: &str = "tracing::info!(\"Database connected\");";
+
+const SEED_HOOK: &str = "
+    // `rustwork db seed` runs the service binary in seed mode
+    if let Some(seed) = rustwork::seeding::SeedCommand::from_args() {
+        seeders::registry().run(&db, &seed).await?;
+        return Ok(());
+    }
+";
+
+/// Génère un seeder dans `src/seeders/`
+pub async fn execute(name: &str, profiles: &[String]) -> Result<()> {
+    if !is_rustwork_project() {
+        anyhow::bail!("Not in a Rustwork project. Run this command from a project created with 'rustwork new'");
+    }
+
+    let struct_name = if name.ends_with("Seeder") {
+        name.to_string()
+    } else {
+        format!("{}Seeder", name)
+    };
+    let snake_name = to_snake_case(&struct_name);
+
+    println!("🌱 Generating seeder: {}", struct_name);
+
+    let seeder_path = Path::new("src/seeders").join(format!("{}.rs", snake_name));
+
+    let mut context = TemplateContext::new();
+    context.insert("struct_name".to_string(), serde_json::json!(struct_name));
+    context.insert("profiles".to_string(), serde_json::json!(profiles));
+
+    let env = create_micro_env();
+    let template = env.get_template("seeder.rs")?;
+    let content = template.render(&context)?;
+
+    output::create(&seeder_path, &content)?;
+
+    write_seeders_mod(Path::new("src/seeders")).await?;
+
+    // Brancher le mode seed dans main.rs (services créés avant `db seed`)
+    let main_path = Path::new("src/main.rs");
+    let main_rs = output::read_to_string(main_path)?;
+    match add_seed_hook(&main_rs) {
+        Some(updated) => {
+            output::update(main_path, &updated)?;
+        }
+        None => {
+            println!("\n⚠️  Could not update src/main.rs automatically. Add:");
+            println!("  mod seeders;");
+            println!("and, once the database is connected:{}", SEED_HOOK);
+        }
+    }
+
+    println!("✅ Seeder '{}' created successfully!", struct_name);
+    println!("\nNext steps:");
+    println!("  1. Insert your data in {}", seeder_path.display());
+    println!("  2. Run: rustwork db seed --class {}", struct_name);
+
+    Ok(())
+}
+
+/// Ajoute `mod seeders;` et le mode seed à `main.rs` ; `None` si la connexion DB est introuvable
+fn add_seed_hook(main_rs: &str) -> Option<String> {
+    let mut content = main_rs.to_string();
+
+    if !content.contains("SeedCommand") {
+        let pos = content.find(DB_CONNECTED_LINE)? + DB_CONNECTED_LINE.len();
+        let pos = content[pos..]
+            .find('\n')
+            .map_or(content.len(), |i| pos + i + 1);
+        content.insert_str(pos, SEED_HOOK);
+    }
+
+    if !content.lines().any(|l| l.trim() == "mod seeders;") {
+        // Après la dernière déclaration `mod x;` de tête
+        let mut pos = 0;
+        let mut offset = 0;
+        for line in content.lines() {
+            offset += line.len() + 1;
+            if line.starts_with("mod ") && line.trim_end().ends_with(';') {
+                pos = offset;
+            }
+        }
+        content.insert_str(pos.min(content.len()), "mod seeders;\n");
+    }
+
+    Some(content)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const MAIN_RS: &str = r#"use rustwork::{AppConfig, AppState, connect_db};
+
+mod app;
+mod middlewares;
+
+#[tokio::main]
+async fn main() -> anyhow::Result<()> {
+    let config = AppConfig::load()?;
+
+    // Initialize database
+    let db = connect_db(&config.database).await?;
+    tracing::info!("Database connected");
+
+    let state = AppState::new(db, config.clone());
+    Ok(())
+}
+"#;
+
+    #[test]
+    fn test_add_seed_hook() {
+        let updated = add_seed_hook(MAIN_RS).unwrap();
+
+        assert!(updated.contains("mod middlewares;\nmod seeders;\n"));
+        assert!(updated
+            .contains("tracing::info!(\"Database connected\");\n\n    // `rustwork db seed`"));
+        assert!(updated.find("SeedCommand") < updated.find("AppState::new"));
+        syn::parse_file(&updated).unwrap();
+
+        // Idempotent
+        assert_eq!(add_seed_hook(&updated).unwrap(), updated);
+    }
+
+    #[test]
+    fn test_add_seed_hook_without_database() {
+        assert!(add_seed_hook("mod app;\n\nfn main() {}\n").is_none());
+    }
+}
@@ -0,0 +1,443 @@
+use anyhow::Result;
+use proc_macro2::LineColumn;
+use syn::{Expr, ExprCall, ExprMethodCall, Item, Lit, Stmt};
+
+use crate::templates::output::unified_diff;
+
+/// Appel ajouté à une chaîne `Router::new()` (`.route(...)` ou `.nest(...)`)
+#[derive(Debug, Clone)]
+pub(crate) struct RouterCall {
+    method: &'static str,
+    path: String,
+    target: String,
+}
+
+impl RouterCall {
+    /// `.route("<path>", <method_router>)`
+    pub(crate) fn route(path: &str, method_router: impl Into<String>) -> Self {
+        Self {
+            method: "route",
+            path: path.to_string(),
+            target: method_router.into(),
+        }
+    }
+
+    /// `.nest("<path>", <router>)`
+    #[allow(dead_code)]
+    pub(crate) fn nest(path: &str, router: impl Into<String>) -> Self {
+        Self {
+            method: "nest",
+            path: path.to_string(),
+            target: router.into(),
+        }
+    }
+
+    /// Rendu sur une ligne, ou sur plusieurs au-delà de 100 colonnes
+    fn render(&self, indent: &str) -> String {
+        let inline = format!(".{}({:?}, {})", self.method, self.path, self.target);
+        if indent.len() + inline.len() <= 100 {
+            return inline;
+        }
+
+        let mut target = self.target.clone();
+        if indent.len() + 4 + target.len() + 1 > 100 {
+            // Un appel chaîné par ligne : `get(a)\n    .post(b)`
+            target = split_chain(&target).join(&format!("\n{}        ", indent));
+        }
+
+        format!(
+            ".{}(\n{indent}    {:?},\n{indent}    {},\n{indent})",
+            self.method,
+            self.path,
+            target,
+            indent = indent
+        )
+    }
+}
+
+/// Découpe `a(..).b(..).c(..)` en `["a(..)", ".b(..)", ".c(..)"]` (hors parenthèses)
+fn split_chain(expr: &str) -> Vec<&str> {
+    let mut parts = Vec::new();
+    let mut depth = 0usize;
+    let mut start = 0;
+    let mut previous = None;
+    for (i, c) in expr.char_indices() {
+        match c {
+            '(' | '[' | '{' => depth += 1,
+            ')' | ']' | '}' => depth = depth.saturating_sub(1),
+            '.' if depth == 0 && previous == Some(')') => {
+                parts.push(&expr[start..i]);
+                start = i;
+            }
+            _ => {}
+        }
+        previous = Some(c);
+    }
+    parts.push(&expr[start..]);
+    parts
+}
+
+/// Ajoute `pub mod <module>;` après la dernière déclaration de module
+///
+/// Retourne `None` si le module est déjà déclaré.
+pub(crate) fn add_mod_declaration(source: &str, module: &str) -> Result<Option<String>> {
+    let declaration = format!("pub mod {};", module);
+    let file = parse(source, &declaration)?;
+
+    let mods: Vec<&syn::ItemMod> = file
+        .items
+        .iter()
+        .filter_map(|item| match item {
+            Item::Mod(item) => Some(item),
+            _ => None,
+        })
+        .collect();
+    if mods.iter().any(|item| item.ident == module) {
+        return Ok(None);
+    }
+
+    let updated = match mods.last() {
+        Some(last) => {
+            let end = match &last.semi {
+                Some(semi) => semi.span.end(),
+                None => last.content.as_ref().map_or_else(
+                    || last.ident.span().end(),
+                    |(brace, _)| brace.span.close().end(),
+                ),
+            };
+            splice(source, offset(source, end), &format!("\n{}", declaration))
+        }
+        None if source.is_empty() || source.ends_with('\n') => {
+            format!("{}{}\n", source, declaration)
+        }
+        None => format!("{}\n{}\n", source, declaration),
+    };
+
+    check(source, &updated)?;
+    Ok(Some(updated))
+}
+
+/// Ajoute des appels à la chaîne `Router::new()...` retournée par `fn <fn_name>`
+///
+/// Les chemins déjà enregistrés sont ignorés ; retourne `None` s'il n'y a rien à ajouter.
+/// Les appels sont insérés après le dernier `.route`/`.nest`/`.merge` (donc avant
+/// `.layer`/`.with_state`), en reprenant l'indentation de la chaîne.
+pub(crate) fn add_router_calls(
+    source: &str,
+    fn_name: &str,
+    calls: &[RouterCall],
+) -> Result<Option<String>> {
+    let wanted: String = calls
+        .iter()
+        .map(|call| format!("        {}\n", call.render("        ")))
+        .collect();
+    let file = parse(source, &wanted)?;
+
+    let chain = file
+        .items
+        .iter()
+        .find_map(|item| match item {
+            Item::Fn(item) if item.sig.ident == fn_name => item.block.stmts.last(),
+            _ => None,
+        })
+        .and_then(|stmt| match stmt {
+            Stmt::Expr(expr, None) => router_chain(expr),
+            _ => None,
+        });
+    let Some((root, methods)) = chain else {
+        anyhow::bail!(
+            "no `fn {}` ending with a `Router::new()` chain\n{}",
+            fn_name,
+            added_lines(&wanted)
+        );
+    };
+
+    let registered: Vec<String> = methods
+        .iter()
+        .filter(|call| is_registration(call))
+        .filter_map(|call| match call.args.first() {
+            Some(Expr::Lit(lit)) => match &lit.lit {
+                Lit::Str(path) => Some(path.value()),
+                _ => None,
+            },
+            _ => None,
+        })
+        .collect();
+    let missing: Vec<&RouterCall> = calls
+        .iter()
+        .filter(|call| !registered.contains(&call.path))
+        .collect();
+    if missing.is_empty() {
+        return Ok(None);
+    }
+
+    // Chaîne multiligne : indentation du premier `.method(` placé en début de ligne
+    let indent = methods.iter().find_map(|call| {
+        let dot = call.dot_token.span.start();
+        let line = source.lines().nth(dot.line - 1)?;
+        let indent: String = line.chars().take_while(|c| c.is_whitespace()).collect();
+        (indent.chars().count() == dot.column).then_some(indent)
+    });
+
+    let anchor = methods
+        .iter()
+        .rev()
+        .find(|call| is_registration(call) || call.method == "merge")
+        .map_or(root.paren_token.span.close().end(), |call| {
+            call.paren_token.span.close().end()
+        });
+
+    let mut at = offset(source, anchor);
+    if indent.is_some() {
+        // Le commentaire de fin de ligne reste attaché à l'appel précédent
+        let rest = source[at..].split('\n').next().unwrap_or_default();
+        if rest.trim().is_empty() || rest.trim_start().starts_with("//") {
+            at += rest.len();
+        }
+    }
+
+    let insertion: String = missing
+        .iter()
+        .map(|call| match &indent {
+            Some(indent) => format!("\n{}{}", indent, call.render(indent)),
+            None => call.render(""),
+        })
+        .collect();
+    let updated = splice(source, at, &insertion);
+
+    check(source, &updated)?;
+    Ok(Some(updated))
+}
+
+fn parse(source: &str, wanted: &str) -> Result<syn::File> {
+    syn::parse_file(source).map_err(|e| {
+        let at = e.span().start();
+        anyhow::anyhow!(
+            "the file does not parse ({} at line {}, column {})\n{}",
+            e,
+            at.line,
+            at.column + 1,
+            added_lines(wanted)
+        )
+    })
+}
+
+/// Vérifie que le résultat reste du Rust valide avant d'écrire
+fn check(before: &str, after: &str) -> Result<()> {
+    syn::parse_file(after).map(|_| ()).map_err(|e| {
+        anyhow::anyhow!(
+            "the edited file would not parse ({})\n{}",
+            e,
+            unified_diff(before, after)
+        )
+    })
+}
+
+/// Lignes à ajouter à la main quand l'édition est refusée
+fn added_lines(wanted: &str) -> String {
+    let lines: String = wanted
+        .trim_start_matches('\n')
+        .lines()
+        .map(|line| format!("+{}\n", line))
+        .collect();
+    format!("Apply this change manually:\n{}", lines)
+}
+
+/// Décompose `Router::new().a(..).b(..)` en (racine, appels de l'intérieur vers l'extérieur)
+fn router_chain(expr: &Expr) -> Option<(&ExprCall, Vec<&ExprMethodCall>)> {
+    let mut methods = Vec::new();
+    let mut current = expr;
+    while let Expr::MethodCall(call) = current {
+        methods.push(call);
+        current = &call.receiver;
+    }
+    methods.reverse();
+
+    let Expr::Call(root) = current else {
+        return None;
+    };
+    let Expr::Path(func) = &*root.func else {
+        return None;
+    };
+    let segments: Vec<String> = func
+        .path
+        .segments
+        .iter()
+        .map(|segment| segment.ident.to_string())
+        .collect();
+    segments
+        .ends_with(&["Router".to_string(), "new".to_string()])
+        .then_some((root, methods))
+}
+
+fn is_registration(call: &ExprMethodCall) -> bool {
+    matches!(
+        call.method.to_string().as_str(),
+        "route" | "nest" | "route_service" | "nest_service"
+    )
+}
+
+/// Position `LineColumn` (ligne 1-based, colonne en caractères) → octet
+fn offset(source: &str, position: LineColumn) -> usize {
+    let line_start: usize = source
+        .split_inclusive('\n')
+        .take(position.line - 1)
+        .map(str::len)
+        .sum();
+    line_start
+        + source[line_start..]
+            .chars()
+            .take(position.column)
+            .map(char::len_utf8)
+            .sum::<usize>()
+}
+
+fn splice(source: &str, at: usize, text: &str) -> String {
+    let mut updated = String::with_capacity(source.len() + text.len());
+    updated.push_str(&source[..at]);
+    updated.push_str(text);
+    updated.push_str(&source[at..]);
+    updated
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const ROUTES: &str = r#"use axum::{routing::get, Router};
+use rustwork::AppState;
+
+use crate::controllers;
+
+pub fn create_routes() -> Router<AppState> {
+    // Public endpoints
+    Router::new()
+        .route("/health", get(controllers::health::health_check)) // liveness
+        .layer(tower_http::trace::TraceLayer::new_for_http())
+}
+"#;
+
+    #[test]
+    fn test_add_mod_declaration_after_last_mod() {
+        let source = "//! Models\n\npub mod user;\n#[cfg(test)]\nmod fixtures;\n\n// Add your modules here\npub use user::User;\n";
+
+        let updated = add_mod_declaration(source, "post").unwrap().unwrap();
+
+        assert_eq!(
+            updated,
+            "//! Models\n\npub mod user;\n#[cfg(test)]\nmod fixtures;\npub mod post;\n\n// Add your modules here\npub use user::User;\n"
+        );
+        assert!(add_mod_declaration(&updated, "post").unwrap().is_none());
+    }
+
+    #[test]
+    fn test_add_mod_declaration_without_mods() {
+        assert_eq!(
+            add_mod_declaration("// Add your modules here\n", "post")
+                .unwrap()
+                .unwrap(),
+            "// Add your modules here\npub mod post;\n"
+        );
+        assert_eq!(
+            add_mod_declaration("", "post").unwrap().unwrap(),
+            "pub mod post;\n"
+        );
+    }
+
+    #[test]
+    fn test_add_router_calls_preserves_formatting() {
+        let calls = [
+            RouterCall::route("/api/posts", "get(controllers::post::index)"),
+            RouterCall::nest("/api/admin", "crate::admin::routes()"),
+        ];
+
+        let updated = add_router_calls(ROUTES, "create_routes", &calls)
+            .unwrap()
+            .unwrap();
+
+        assert_eq!(
+            updated,
+            ROUTES.replace(
+                "// liveness\n",
+                "// liveness\n        \
+                 .route(\"/api/posts\", get(controllers::post::index))\n        \
+                 .nest(\"/api/admin\", crate::admin::routes())\n"
+            )
+        );
+        assert!(add_router_calls(&updated, "create_routes", &calls)
+            .unwrap()
+            .is_none());
+    }
+
+    #[test]
+    fn test_add_router_calls_single_line_chain() {
+        let source = "fn routes() -> Router {\n    Router::new().with_state(state)\n}\n";
+
+        let updated = add_router_calls(source, "routes", &[RouterCall::route("/a", "get(a)")])
+            .unwrap()
+            .unwrap();
+
+        assert_eq!(
+            updated,
+            "fn routes() -> Router {\n    Router::new().route(\"/a\", get(a)).with_state(state)\n}\n"
+        );
+    }
+
+    #[test]
+    fn test_add_router_calls_wraps_long_calls() {
+        let handler = "axum::routing::get(crate::controllers::blog_post::index).post(crate::controllers::blog_post::create)";
+
+        let updated = add_router_calls(
+            ROUTES,
+            "create_routes",
+            &[RouterCall::route("/api/blog_posts", handler)],
+        )
+        .unwrap()
+        .unwrap();
+
+        assert!(updated.contains(
+            "// liveness\n        .route(\n            \"/api/blog_posts\",\n            \
+             axum::routing::get(crate::controllers::blog_post::index)\n                \
+             .post(crate::controllers::blog_post::create),\n        )\n        .layer"
+        ));
+    }
+
+    #[test]
+    fn test_split_chain() {
+        assert_eq!(
+            split_chain("get(a::b).post(c.d()).delete(e)"),
+            vec!["get(a::b)", ".post(c.d())", ".delete(e)"]
+        );
+        assert_eq!(
+            split_chain("crate::admin::routes()"),
+            vec!["crate::admin::routes()"]
+        );
+    }
+
+    #[test]
+    fn test_add_router_calls_refuses_unknown_shape() {
+        let source =
+            "fn create_routes() -> Router {\n    let router = Router::new();\n    router\n}\n";
+
+        let error = add_router_calls(
+            source,
+            "create_routes",
+            &[RouterCall::route("/a", "get(a)")],
+        )
+        .unwrap_err()
+        .to_string();
+
+        assert!(error.contains("no `fn create_routes` ending with a `Router::new()` chain"));
+        assert!(error.contains("+        .route(\"/a\", get(a))"));
+    }
+
+    #[test]
+    fn test_refuses_unparsable_file() {
+        let error = add_mod_declaration("pub mod user\n", "post")
+            .unwrap_err()
+            .to_string();
+
+        assert!(error.contains("does not parse"));
+        assert!(error.contains("+pub mod post;"));
+    }
+}
@@ -0,0 +1,153 @@
+use anyhow::Result;
+use std::path::{Path, PathBuf};
+
+use crate::commands::utils::{
+    detect_rustwork_services, is_valid_rustwork_service, RustworkService,
+};
+use crate::mcp::common::workspace_root::WorkspaceRoot;
+
+/// Se place dans le service ciblé par un générateur
+///
+/// Les générateurs écrivent dans des chemins relatifs (`src/controllers`, `migrations`…) :
+/// le dossier courant devient la racine du service.
+pub fn enter_service(service: Option<&str>, project: Option<&str>) -> Result<()> {
+    let current_dir = std::env::current_dir()?;
+
+    if let Some(service_path) = resolve_service(&current_dir, service, project)? {
+        if service_path != current_dir.canonicalize()? {
+            println!(
+                "📦 Service: {}",
+                service_path
+                    .file_name()
+                    .map(|n| n.to_string_lossy().to_string())
+                    .unwrap_or_default()
+            );
+            std::env::set_current_dir(&service_path)?;
+        }
+    }
+
+    Ok(())
+}
+
+/// Dossier du service ciblé
+///
+/// Avec `--service`, le service est cherché dans le workspace ; sinon c'est le service
+/// qui contient `current_dir`. `None` hors de tout workspace.
+fn resolve_service(
+    current_dir: &Path,
+    service: Option<&str>,
+    project: Option<&str>,
+) -> Result<Option<PathBuf>> {
+    let current_dir = current_dir.canonicalize()?;
+
+    let Some(name) = service else {
+        if let Some(service_path) = current_dir
+            .ancestors()
+            .find(|dir| is_valid_rustwork_service(dir))
+        {
+            return Ok(Some(service_path.to_path_buf()));
+        }
+
+        // Racine du workspace : impossible de deviner le service
+        if let Ok(workspace_root) = WorkspaceRoot::detect(&current_dir) {
+            let services = detect_rustwork_services(workspace_root.path())?;
+            if !services.is_empty() {
+                anyhow::bail!(
+                    "Run this command from a service directory or pass --service <name>. Available services: {}",
+                    service_names(&services)
+                );
+            }
+        }
+        return Ok(None);
+    };
+
+    let workspace_root = WorkspaceRoot::detect_with_explicit(&current_dir, project.map(Path::new))?;
+    let services = detect_rustwork_services(workspace_root.path())?;
+
+    services
+        .iter()
+        .find(|s| s.name == name)
+        .map(|s| Some(s.path.clone()))
+        .ok_or_else(|| {
+            anyhow::anyhow!(
+                "Service '{}' not found. Available services: {}",
+                name,
+                service_names(&services)
+            )
+        })
+}
+
+fn service_names(services: &[RustworkService]) -> String {
+    services
+        .iter()
+        .map(|s| s.name.as_str())
+        .collect::<Vec<_>>()
+        .join(", ")
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::fs;
+    use tempfile::TempDir;
+
+    fn workspace() -> TempDir {
+        let dir = TempDir::new().unwrap();
+        fs::write(
+            dir.path().join("Cargo.toml"),
+            "[workspace]\nmembers = [\"Backend/services/*\"]\n",
+        )
+        .unwrap();
+        for service in ["users", "billing"] {
+            let path = dir.path().join("Backend/services").join(service);
+            fs::create_dir_all(path.join("src/controllers")).unwrap();
+            fs::create_dir_all(path.join(".rustwork")).unwrap();
+            fs::write(path.join(".rustwork/manifest.json"), "{}").unwrap();
+            fs::write(path.join("Cargo.toml"), "[package]\n").unwrap();
+            fs::write(path.join("src/main.rs"), "fn main() {}\n").unwrap();
+        }
+        dir
+    }
+
+    #[test]
+    fn test_resolve_named_service_from_workspace_root() {
+        let dir = workspace();
+        let users = dir
+            .path()
+            .join("Backend/services/users")
+            .canonicalize()
+            .unwrap();
+
+        let resolved = resolve_service(dir.path(), Some("users"), None).unwrap();
+        assert_eq!(resolved, Some(users.clone()));
+
+        let from_other_service = dir.path().join("Backend/services/billing/src");
+        let resolved = resolve_service(&from_other_service, Some("users"), None).unwrap();
+        assert_eq!(resolved, Some(users));
+    }
+
+    #[test]
+    fn test_resolve_enclosing_service() {
+        let dir = workspace();
+        let billing = dir.path().join("Backend/services/billing");
+
+        let resolved = resolve_service(&billing.join("src/controllers"), None, None).unwrap();
+        assert_eq!(resolved, Some(billing.canonicalize().unwrap()));
+    }
+
+    #[test]
+    fn test_resolve_requires_service_at_workspace_root() {
+        let dir = workspace();
+
+        let err = resolve_service(dir.path(), None, None)
+            .unwrap_err()
+            .to_string();
+        assert!(err.contains("pass --service <name>"));
+
+        let err = resolve_service(dir.path(), Some("orders"), None)
+            .unwrap_err()
+            .to_string();
+        assert!(err.contains("Service 'orders' not found"));
+        assert!(err.contains("billing") && err.contains("users"));
+    }
+}
@@ -1,12 +1,19 @@
 pub mod add_service;
 pub mod conventions;
+pub mod db;
+pub mod deploy;
 pub mod dev;
+pub mod doctor;
 pub mod grpc_build;
 pub mod make;
 pub mod new;
+pub mod openapi;
+pub mod ports;
+pub mod service;
+pub mod templates;
 
 // Utilities
 pub mod utils;
 
 // Re-exports pour compatibilité
-pub use make::{make_controller, make_model};
+pub use make::{make_client, make_controller, make_job, make_model, make_scaffold, make_seeder};
@@ -1,8 +1,10 @@
 use anyhow::Result;
 
+use super::starter::{Starter, StarterOptions};
+
 /// Point d'entrée de la commande new
 ///
-/// Usage: rustwork new auth,user,session
+/// Usage: rustwork new auth,user,session [--template <starter>] [--var key=value]
 ///
 /// Crée un workspace micro-services avec la structure:
 /// ```text
@@ -18,7 +20,11 @@ use anyhow::Result;
 /// │   │   └── shared/
 /// │   └── README.md
 /// ```
-pub async fn execute(services: Vec<String>, create_shared: bool) -> Result<()> {
+pub async fn execute(
+    services: Vec<String>,
+    create_shared: bool,
+    starter: StarterOptions,
+) -> Result<()> {
     // Filtrer les services vides et valider
     let services: Vec<String> = services
         .into_iter()
@@ -67,11 +73,15 @@ pub async fn execute(services: Vec<String>, create_shared: bool) -> Result<()> {
         anyhow::bail!("Duplicate service names detected. Each service must have a unique name.");
     }
 
-    super::microservices::create_microservices_workspace(services, create_shared).await
+    // Charger le starter avant de créer quoi que ce soit
+    let starter = Starter::load(&starter)?;
+
+    super::microservices::create_microservices_workspace(services, create_shared, starter.as_ref())
+        .await
 }
 
 /// Vérifie si un nom de service est valide
-fn is_valid_service_name(name: &str) -> bool {
+pub(crate) fn is_valid_service_name(name: &str) -> bool {
     if name.is_empty() {
         return false;
     }
@@ -1,8 +1,9 @@
 use anyhow::Result;
 use std::path::Path;
-use tokio::fs;
 
-use crate::templates::{create_micro_env, TemplateContext};
+use super::starter::Starter;
+use crate::commands::ports::{PortRegistry, ServicePorts};
+use crate::templates::{create_micro_env, output, TemplateContext};
 
 /// Crée un workspace micro-services Rustwork
 ///
@@ -22,6 +23,7 @@ use crate::templates::{create_micro_env, TemplateContext};
 pub async fn create_microservices_workspace(
     services: Vec<String>,
     create_shared: bool,
+    starter: Option<&Starter>,
 ) -> Result<()> {
     let root_path = Path::new(".");
 
@@ -39,10 +41,13 @@ pub async fn create_microservices_workspace(
     if create_shared {
         println!("   Shared library: enabled");
     }
+    if let Some(starter) = starter {
+        println!("   Starter: {}", starter.label());
+    }
 
     // Create .vscode at workspace root
     let vscode_dir = root_path.join(".vscode");
-    fs::create_dir_all(&vscode_dir).await?;
+    output::create_dir_all(&vscode_dir)?;
 
     let absolute_root_path = root_path
         .canonicalize()
@@ -87,18 +92,28 @@ pub async fn create_microservices_workspace(
     .await?;
 
     // Create Backend directory structure
-    fs::create_dir_all(&backend_path).await?;
+    output::create_dir_all(&backend_path)?;
 
     let services_dir = backend_path.join("services");
-    fs::create_dir_all(&services_dir).await?;
-
-    // Create each service with unique port
-    for (index, service_name) in services.iter().enumerate() {
-        println!("   📦 Creating service: {}", service_name);
+    output::create_dir_all(&services_dir)?;
+
+    // Create each service with a unique port from the registry (ranges kept if it already exists)
+    let mut registry = PortRegistry::load(root_path, &[])?;
+    for service_name in &services {
+        let ports = registry.allocate(service_name)?;
+        println!(
+            "   📦 Creating service: {} (http {})",
+            service_name, ports.http
+        );
         let service_path = services_dir.join(service_name);
-        let service_port = 3001 + index as u16;
-        create_service_in_project(&service_path, service_name, service_port, &env).await?;
+        create_service_in_project(&service_path, service_name, ports, &env).await?;
+        if let Some(starter) = starter {
+            starter
+                .apply(&service_path, service_name, ports, &env)
+                .await?;
+        }
     }
+    registry.save(root_path)?;
 
     // Always create shared library inside services/
     if create_shared {
@@ -128,7 +143,7 @@ members = [
             .collect::<Vec<_>>()
             .join("\n")
     );
-    fs::write(backend_path.join("Cargo.toml"), backend_cargo_toml).await?;
+    output::create(&backend_path.join("Cargo.toml"), &backend_cargo_toml)?;
 
     // Create Backend README
     let backend_readme = format!(
@@ -187,11 +202,11 @@ rustwork add-service <name>
         },
     );
 
-    fs::write(backend_path.join("README.md"), backend_readme).await?;
+    output::create(&backend_path.join("README.md"), &backend_readme)?;
 
     // Create root .gitignore
-    fs::write(
-        root_path.join(".gitignore"),
+    output::create(
+        &root_path.join(".gitignore"),
         r#"# Rust
 /target
 **/target
@@ -200,6 +215,7 @@ Cargo.lock
 # Environment
 .env
 **/.env
+deploy/*.env
 
 # SQLite Database
 **/data/*.db
@@ -211,15 +227,15 @@ Cargo.lock
 *.swp
 *.swo
 
-# Rustwork
-.rustwork/
-**/.rustwork/
+# Rustwork (the port registry is shared)
+.rustwork/*
+!.rustwork/ports.json
+*/**/.rustwork/
 
 # Do NOT ignore .vscode - it contains MCP config
 !.vscode/
 "#,
-    )
-    .await?;
+    )?;
 
     // Create root README
     let root_readme = format!(
@@ -286,7 +302,7 @@ Built with [Rustwork](https://github.com/rustwork) 🦀
             .join("\n"),
     );
 
-    fs::write(root_path.join("README.md"), root_readme).await?;
+    output::create(&root_path.join("README.md"), &root_readme)?;
 
     println!();
     println!("✅ Rustwork workspace created successfully!");
@@ -319,7 +335,7 @@ async fn create_shared_library(
     _workspace_name: &str,
     env: &minijinja::Environment<'_>,
 ) -> Result<()> {
-    fs::create_dir_all(shared_path).await?;
+    output::create_dir_all(shared_path)?;
 
     let mut context = TemplateContext::new();
     context.insert("project_name".to_string(), serde_json::json!("shared"));
@@ -333,38 +349,35 @@ async fn create_shared_library(
     .await?;
 
     let src_dir = shared_path.join("src");
-    fs::create_dir_all(&src_dir).await?;
+    output::create_dir_all(&src_dir)?;
 
     super::utils::create_file(&src_dir.join("lib.rs"), env, "shared_lib.rs", &context).await?;
 
     // Create modules
-    fs::create_dir_all(&src_dir.join("types")).await?;
-    fs::write(
-        src_dir.join("types").join("mod.rs"),
+    output::create_dir_all(&src_dir.join("types"))?;
+    output::create(
+        &src_dir.join("types").join("mod.rs"),
         "//! Shared types across services\n\n// Add your shared types here\n",
-    )
-    .await?;
+    )?;
 
-    fs::create_dir_all(&src_dir.join("utils")).await?;
-    fs::write(
-        src_dir.join("utils").join("mod.rs"),
+    output::create_dir_all(&src_dir.join("utils"))?;
+    output::create(
+        &src_dir.join("utils").join("mod.rs"),
         "//! Shared utilities across services\n\n// Add your shared utilities here\n",
-    )
-    .await?;
+    )?;
 
     // Create .rustwork manifest for shared lib
     let rustwork_dir = shared_path.join(".rustwork");
-    fs::create_dir_all(&rustwork_dir).await?;
+    output::create_dir_all(&rustwork_dir)?;
     let manifest = serde_json::json!({
         "version": "0.1.0",
         "type": "shared_library",
         "exports": []
     });
-    fs::write(
-        rustwork_dir.join("manifest.json"),
-        serde_json::to_string_pretty(&manifest)?,
-    )
-    .await?;
+    output::create(
+        &rustwork_dir.join("manifest.json"),
+        &serde_json::to_string_pretty(&manifest)?,
+    )?;
 
     Ok(())
 }
@@ -373,7 +386,7 @@ async fn create_shared_library(
 pub async fn create_service_in_project(
     service_path: &Path,
     service_name: &str,
-    service_port: u16,
+    ports: ServicePorts,
     env: &minijinja::Environment<'_>,
 ) -> Result<()> {
     if service_path.exists() {
@@ -381,12 +394,12 @@ pub async fn create_service_in_project(
     }
 
     // Create service directory
-    fs::create_dir_all(service_path).await?;
+    output::create_dir_all(service_path)?;
 
-    // Setup template context with port
+    // Setup template context with ports
     let mut context = TemplateContext::new();
     context.insert("project_name".to_string(), serde_json::json!(service_name));
-    context.insert("service_port".to_string(), serde_json::json!(service_port));
+    context.insert("service_port".to_string(), serde_json::json!(ports.http));
 
     // Create Cargo.toml
     super::utils::create_file(
@@ -399,7 +412,7 @@ pub async fn create_service_in_project(
 
     // Create src directory structure
     let src_dir = service_path.join("src");
-    fs::create_dir_all(&src_dir).await?;
+    output::create_dir_all(&src_dir)?;
 
     super::utils::create_file(&src_dir.join("main.rs"), env, "main.rs", &context).await?;
     super::utils::create_file(&src_dir.join("app.rs"), env, "app.rs", &context).await?;
@@ -409,8 +422,8 @@ pub async fn create_service_in_project(
     // Create subdirectories
     for dir in &["controllers", "services", "models", "middlewares"] {
         let dir_path = src_dir.join(dir);
-        fs::create_dir_all(&dir_path).await?;
-        fs::write(dir_path.join("mod.rs"), "// Add your modules here\n").await?;
+        output::create_dir_all(&dir_path)?;
+        output::create(&dir_path.join("mod.rs"), "// Add your modules here\n")?;
     }
 
     // Create health controller
@@ -424,11 +437,19 @@ pub async fn create_service_in_project(
     .await?;
 
     // Update controllers/mod.rs
-    fs::write(controllers_dir.join("mod.rs"), "pub mod health;\n").await?;
+    output::update(&controllers_dir.join("mod.rs"), "pub mod health;\n")?;
+
+    // Create seeders (registry regenerated by `rustwork make seeder` / `rustwork db seed`)
+    let seeders_dir = src_dir.join("seeders");
+    output::create_dir_all(&seeders_dir)?;
+    output::create(
+        &seeders_dir.join("mod.rs"),
+        &crate::commands::db::seeders::render_seeders_mod(&[]),
+    )?;
 
     // Create config directory
     let config_dir = service_path.join("config");
-    fs::create_dir_all(&config_dir).await?;
+    output::create_dir_all(&config_dir)?;
 
     super::utils::create_file(
         &config_dir.join("default.toml"),
@@ -439,6 +460,7 @@ pub async fn create_service_in_project(
     .await?;
 
     super::utils::create_file(&config_dir.join("dev.toml"), env, "dev.toml", &context).await?;
+    super::utils::create_file(&config_dir.join("test.toml"), env, "test.toml", &context).await?;
 
     // Create .env.example
     super::utils::create_file(
@@ -454,7 +476,7 @@ pub async fn create_service_in_project(
 
     // Create migrations directory (SQL files only)
     let migrations_dir = service_path.join("migrations");
-    fs::create_dir_all(&migrations_dir).await?;
+    output::create_dir_all(&migrations_dir)?;
 
     // Create README for migrations
     super::utils::create_file(
@@ -484,12 +506,12 @@ pub async fn create_service_in_project(
 
     // Create data directory for SQLite
     let data_dir = service_path.join("data");
-    fs::create_dir_all(&data_dir).await?;
-    fs::write(data_dir.join(".gitkeep"), "").await?;
+    output::create_dir_all(&data_dir)?;
+    output::create(&data_dir.join(".gitkeep"), "")?;
 
     // Create .rustwork directory for metadata
     let rustwork_dir = service_path.join(".rustwork");
-    fs::create_dir_all(&rustwork_dir).await?;
+    output::create_dir_all(&rustwork_dir)?;
     let manifest = serde_json::json!({
         "version": "0.1.0",
         "type": "service",
@@ -497,11 +519,10 @@ pub async fn create_service_in_project(
         "models": [],
         "controllers": ["health"],
     });
-    fs::write(
-        rustwork_dir.join("manifest.json"),
-        serde_json::to_string_pretty(&manifest)?,
-    )
-    .await?;
+    output::create(
+        &rustwork_dir.join("manifest.json"),
+        &serde_json::to_string_pretty(&manifest)?,
+    )?;
 
     // Create service README
     super::utils::create_file(&service_path.join("README.md"), env, "readme.md", &context).await?;
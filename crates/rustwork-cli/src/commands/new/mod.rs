@@ -1,7 +1,10 @@
 /// Commande de création de workspace micro-services
 mod execute;
 mod microservices;
+mod starter;
 mod utils;
 
 pub use execute::execute;
+pub(crate) use execute::is_valid_service_name;
 pub use microservices::create_service_in_project;
+pub use starter::{Starter, StarterOptions};
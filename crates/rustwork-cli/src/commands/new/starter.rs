@@ -0,0 +1,426 @@
+//! Starters de `rustwork new --template <starter>`
+//!
+//! Un starter est un manifeste `rustwork-starter.toml` et un dossier `files/` dont les
+//! fichiers (chemins et contenus rendus avec minijinja) remplacent ou complètent le
+//! squelette de chaque service. Il peut être intégré, local ou cloné depuis git.
+
+use anyhow::{Context, Result};
+use serde::Deserialize;
+use std::io::{BufRead, IsTerminal, Write};
+use std::path::{Path, PathBuf};
+use std::process::Command;
+
+use crate::commands::ports::ServicePorts;
+use crate::templates::micro::starters::STARTERS;
+use crate::templates::overrides::template_files;
+use crate::templates::{output, TemplateContext};
+
+/// Manifeste d'un starter
+pub const MANIFEST_FILE: &str = "rustwork-starter.toml";
+
+/// Dossier des fichiers d'un starter
+pub const FILES_DIR: &str = "files";
+
+/// Variables fournies par `rustwork new` à tous les templates
+const RESERVED_VARIABLES: &[&str] = &["project_name", "service_port"];
+
+/// Options `--template` / `--var` de `rustwork new` et `rustwork add-service`
+#[derive(Debug, Default, Clone)]
+pub struct StarterOptions {
+    pub template: Option<String>,
+    pub vars: Vec<(String, String)>,
+}
+
+#[derive(Debug, Deserialize)]
+pub struct StarterManifest {
+    pub name: String,
+    #[serde(default)]
+    pub description: String,
+    #[serde(default)]
+    pub variables: Vec<StarterVariable>,
+    /// Dépendances ajoutées au Cargo.toml du service (syntaxe Cargo)
+    #[serde(default)]
+    pub dependencies: toml::Table,
+}
+
+#[derive(Debug, Deserialize)]
+pub struct StarterVariable {
+    pub name: String,
+    #[serde(default)]
+    pub description: String,
+    pub default: Option<String>,
+}
+
+/// Starter chargé, variables résolues
+#[derive(Debug)]
+pub struct Starter {
+    pub manifest: StarterManifest,
+    files: Vec<(String, String)>,
+    values: TemplateContext,
+}
+
+impl Starter {
+    /// Charge le starter demandé et résout ses variables (`None` sans `--template`)
+    pub fn load(options: &StarterOptions) -> Result<Option<Self>> {
+        let Some(spec) = options.template.as_deref() else {
+            if !options.vars.is_empty() {
+                anyhow::bail!("--var requires --template");
+            }
+            return Ok(None);
+        };
+
+        let (manifest, files) = load_source(spec)?;
+        let manifest: StarterManifest = toml::from_str(&manifest)
+            .with_context(|| format!("Invalid {} in starter '{}'", MANIFEST_FILE, spec))?;
+        let values = resolve_variables(&manifest, &options.vars, prompt)?;
+
+        Ok(Some(Self {
+            manifest,
+            files,
+            values,
+        }))
+    }
+
+    /// Nom et description, pour l'affichage
+    pub fn label(&self) -> String {
+        if self.manifest.description.is_empty() {
+            self.manifest.name.clone()
+        } else {
+            format!("{} ({})", self.manifest.name, self.manifest.description)
+        }
+    }
+
+    /// Applique le starter à un service fraîchement créé
+    pub async fn apply(
+        &self,
+        service_path: &Path,
+        service_name: &str,
+        ports: ServicePorts,
+        env: &minijinja::Environment<'_>,
+    ) -> Result<()> {
+        let mut context = self.values.clone();
+        context.insert("project_name".to_string(), serde_json::json!(service_name));
+        context.insert("service_port".to_string(), serde_json::json!(ports.http));
+
+        for (path, source) in &self.files {
+            let path = env
+                .render_str(path, &context)
+                .with_context(|| format!("Failed to render starter path: {}", path))?;
+            let mut content = env
+                .render_str(source, &context)
+                .with_context(|| format!("Failed to render starter file: {}", path))?;
+            // minijinja retire le saut de ligne final
+            if source.ends_with('\n') && !content.ends_with('\n') {
+                content.push('\n');
+            }
+
+            let target = service_path.join(&path);
+            crate::commands::utils::ensure_parent_dir(&target).await?;
+            output::update(&target, &content)?;
+        }
+
+        let deps: Vec<(&str, String)> = self
+            .manifest
+            .dependencies
+            .iter()
+            .map(|(name, value)| (name.as_str(), format!("{} = {}", name, value)))
+            .collect();
+        if !deps.is_empty() {
+            crate::commands::make::common::add_dependencies(
+                &service_path.join("Cargo.toml"),
+                &deps,
+            )
+            .await?;
+        }
+
+        Ok(())
+    }
+}
+
+/// Manifeste et fichiers d'un starter : nom intégré, dépôt git ou dossier local
+fn load_source(spec: &str) -> Result<(String, Vec<(String, String)>)> {
+    if let Some(builtin) = STARTERS.iter().find(|s| s.name == spec) {
+        let files = builtin
+            .files
+            .iter()
+            .map(|(path, source)| (path.to_string(), source.to_string()))
+            .collect();
+        return Ok((builtin.manifest.to_string(), files));
+    }
+
+    if is_git_url(spec) {
+        let checkout = clone(spec)?;
+        let result = load_dir(&checkout);
+        let _ = std::fs::remove_dir_all(&checkout);
+        return result;
+    }
+
+    let dir = Path::new(spec);
+    if dir.is_dir() {
+        return load_dir(dir);
+    }
+
+    anyhow::bail!(
+        "Unknown starter '{}'. Use a built-in starter ({}), a local directory or a git repository URL",
+        spec,
+        STARTERS
+            .iter()
+            .map(|s| s.name)
+            .collect::<Vec<_>>()
+            .join(", ")
+    )
+}
+
+/// Charge `rustwork-starter.toml` et `files/**` d'un dossier
+fn load_dir(dir: &Path) -> Result<(String, Vec<(String, String)>)> {
+    let manifest_path = dir.join(MANIFEST_FILE);
+    let manifest = std::fs::read_to_string(&manifest_path)
+        .with_context(|| format!("Starter manifest not found: {}", manifest_path.display()))?;
+
+    let files_dir = dir.join(FILES_DIR);
+    let mut files = Vec::new();
+    for path in template_files(&files_dir) {
+        let relative = path
+            .strip_prefix(&files_dir)?
+            .components()
+            .map(|c| c.as_os_str().to_string_lossy())
+            .collect::<Vec<_>>()
+            .join("/");
+        let source = std::fs::read_to_string(&path)
+            .with_context(|| format!("Failed to read starter file: {}", path.display()))?;
+        files.push((relative, source));
+    }
+
+    Ok((manifest, files))
+}
+
+fn is_git_url(spec: &str) -> bool {
+    ["https://", "http://", "ssh://", "git://", "git@", "file://"]
+        .iter()
+        .any(|prefix| spec.starts_with(prefix))
+        || spec
+            .split('#')
+            .next()
+            .is_some_and(|url| url.ends_with(".git"))
+}
+
+/// Clone un dépôt (`url#ref` pour une branche ou un tag) dans un dossier temporaire
+fn clone(spec: &str) -> Result<PathBuf> {
+    let (url, reference) = match spec.split_once('#') {
+        Some((url, reference)) => (url, Some(reference)),
+        None => (spec, None),
+    };
+
+    let checkout = std::env::temp_dir().join(format!("rustwork-starter-{}", std::process::id()));
+    let _ = std::fs::remove_dir_all(&checkout);
+
+    println!("📥 Cloning starter {}", url);
+    let mut command = Command::new("git");
+    command.args(["clone", "--quiet", "--depth", "1"]);
+    if let Some(reference) = reference {
+        command.args(["--branch", reference]);
+    }
+    let status = command
+        .arg(url)
+        .arg(&checkout)
+        .status()
+        .context("Failed to run git. Is git installed?")?;
+
+    if !status.success() {
+        anyhow::bail!("git clone {} failed", url);
+    }
+
+    Ok(checkout)
+}
+
+/// Valeurs des variables : `--var`, puis saisie interactive, puis valeur par défaut
+fn resolve_variables(
+    manifest: &StarterManifest,
+    vars: &[(String, String)],
+    mut ask: impl FnMut(&StarterVariable) -> Result<Option<String>>,
+) -> Result<TemplateContext> {
+    for variable in &manifest.variables {
+        if RESERVED_VARIABLES.contains(&variable.name.as_str()) {
+            anyhow::bail!(
+                "Starter variable '{}' is reserved (provided by rustwork)",
+                variable.name
+            );
+        }
+    }
+
+    for (key, _) in vars {
+        if !manifest.variables.iter().any(|v| &v.name == key) {
+            anyhow::bail!(
+                "Unknown variable '{}' for starter '{}'. Available variables: {}",
+                key,
+                manifest.name,
+                manifest
+                    .variables
+                    .iter()
+                    .map(|v| v.name.as_str())
+                    .collect::<Vec<_>>()
+                    .join(", ")
+            );
+        }
+    }
+
+    let mut values = TemplateContext::new();
+    for variable in &manifest.variables {
+        let value = match vars.iter().rev().find(|(key, _)| key == &variable.name) {
+            Some((_, value)) => value.clone(),
+            None => match ask(variable)? {
+                Some(value) => value,
+                None => variable.default.clone().with_context(|| {
+                    format!(
+                        "Missing value for starter variable '{}'. Pass --var {}=<value>",
+                        variable.name, variable.name
+                    )
+                })?,
+            },
+        };
+        values.insert(variable.name.clone(), serde_json::json!(value));
+    }
+
+    Ok(values)
+}
+
+/// Demande une valeur sur le terminal (`None` hors terminal ou réponse vide)
+fn prompt(variable: &StarterVariable) -> Result<Option<String>> {
+    if !std::io::stdin().is_terminal() {
+        return Ok(None);
+    }
+
+    let mut stdout = std::io::stdout();
+    if variable.description.is_empty() {
+        write!(stdout, "❓ {}", variable.name)?;
+    } else {
+        write!(stdout, "❓ {} ({})", variable.name, variable.description)?;
+    }
+    if let Some(default) = &variable.default {
+        write!(stdout, " [{}]", default)?;
+    }
+    write!(stdout, ": ")?;
+    stdout.flush()?;
+
+    let mut line = String::new();
+    std::io::stdin().lock().read_line(&mut line)?;
+    let line = line.trim();
+
+    Ok((!line.is_empty()).then(|| line.to_string()))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use tempfile::TempDir;
+
+    const MANIFEST: &str = r#"
+name = "queue"
+description = "Queue consumer"
+
+[[variables]]
+name = "queue_name"
+default = "jobs"
+
+[[variables]]
+name = "broker_url"
+
+[dependencies]
+lapin = "2"
+serde_json = { version = "1", features = ["raw_value"] }
+"#;
+
+    fn no_prompt(_: &StarterVariable) -> Result<Option<String>> {
+        Ok(None)
+    }
+
+    #[test]
+    fn test_builtin_manifests_parse() {
+        for starter in STARTERS {
+            let manifest: StarterManifest = toml::from_str(starter.manifest).unwrap();
+            assert_eq!(manifest.name, starter.name);
+
+            let vars: Vec<(String, String)> = Vec::new();
+            let values = resolve_variables(&manifest, &vars, no_prompt).unwrap();
+            let env = crate::templates::create_micro_env();
+            for (path, source) in starter.files {
+                env.render_str(path, &values).unwrap();
+                env.render_str(source, &values).unwrap();
+            }
+        }
+    }
+
+    #[test]
+    fn test_resolve_variables() {
+        let manifest: StarterManifest = toml::from_str(MANIFEST).unwrap();
+
+        let vars = vec![("broker_url".to_string(), "amqp://localhost".to_string())];
+        let values = resolve_variables(&manifest, &vars, no_prompt).unwrap();
+        assert_eq!(values["queue_name"], "jobs");
+        assert_eq!(values["broker_url"], "amqp://localhost");
+
+        let values =
+            resolve_variables(&manifest, &vars, |v| Ok(Some(format!("{}!", v.name)))).unwrap();
+        assert_eq!(values["queue_name"], "queue_name!");
+        assert_eq!(values["broker_url"], "amqp://localhost");
+
+        let err = resolve_variables(&manifest, &[], no_prompt).unwrap_err();
+        assert!(err.to_string().contains("--var broker_url=<value>"));
+
+        let unknown = vec![("queue".to_string(), "x".to_string())];
+        let err = resolve_variables(&manifest, &unknown, no_prompt).unwrap_err();
+        assert!(err.to_string().contains("Unknown variable 'queue'"));
+    }
+
+    #[tokio::test]
+    async fn test_apply_local_starter() {
+        let starter_dir = TempDir::new().unwrap();
+        std::fs::write(starter_dir.path().join(MANIFEST_FILE), MANIFEST).unwrap();
+        let consumer = starter_dir
+            .path()
+            .join("files/src/{{ queue_name }}_consumer.rs");
+        std::fs::create_dir_all(consumer.parent().unwrap()).unwrap();
+        std::fs::write(&consumer, "// {{ project_name }} reads {{ queue_name }}\n").unwrap();
+
+        let options = StarterOptions {
+            template: Some(starter_dir.path().to_string_lossy().to_string()),
+            vars: vec![("broker_url".to_string(), "amqp://localhost".to_string())],
+        };
+        let starter = Starter::load(&options).unwrap().unwrap();
+
+        let service = TempDir::new().unwrap();
+        std::fs::write(
+            service.path().join("Cargo.toml"),
+            "[package]\nname = \"orders\"\n\n[dependencies]\nserde_json = \"1.0\"\n",
+        )
+        .unwrap();
+
+        let env = crate::templates::create_micro_env();
+        starter
+            .apply(
+                service.path(),
+                "orders",
+                ServicePorts { http: 3001 },
+                &env,
+            )
+            .await
+            .unwrap();
+
+        assert_eq!(
+            std::fs::read_to_string(service.path().join("src/jobs_consumer.rs")).unwrap(),
+            "// orders reads jobs\n"
+        );
+        let cargo = std::fs::read_to_string(service.path().join("Cargo.toml")).unwrap();
+        assert!(cargo.contains("lapin = \"2\"\n"));
+        assert!(cargo.contains("serde_json = \"1.0\"\n"));
+    }
+
+    #[test]
+    fn test_git_url_detection() {
+        assert!(is_git_url("https://github.com/acme/starter"));
+        assert!(is_git_url("git@github.com:acme/starter.git"));
+        assert!(is_git_url("../starters/queue.git#v2"));
+        assert!(!is_git_url("./starters/queue"));
+        assert!(!is_git_url("worker"));
+    }
+}
@@ -1,8 +1,7 @@
 use anyhow::{Context, Result};
 use std::path::Path;
-use tokio::fs;
 
-use crate::templates::TemplateContext;
+use crate::templates::{output, TemplateContext};
 
 /// Crée un fichier à partir d'un template
 pub(crate) async fn create_file(
@@ -21,9 +20,7 @@ pub(crate) async fn create_file(
         .render(context)
         .with_context(|| format!("Failed to render template: {}", template_name))?;
 
-    fs::write(path, content)
-        .await
-        .with_context(|| format!("Failed to write file: {}", path.display()))?;
+    output::create(path, &content)?;
 
     Ok(())
 }
@@ -0,0 +1,119 @@
+use anyhow::{Context, Result};
+use std::path::{Path, PathBuf};
+
+use super::generator::{build_document, DocumentInfo};
+use crate::commands::utils::{detect_rustwork_services, read_service_port, service_source_prefix};
+use crate::mcp::common::indexer::{scan_project, SourceFile};
+use crate::mcp::common::routes::{scan_routes, RouteInfo};
+use crate::mcp::common::workspace_root::WorkspaceRoot;
+
+/// Point d'entrée de la commande `rustwork openapi`
+///
+/// Génère un document OpenAPI 3.1 par service (`<service>/openapi.json`)
+/// à partir des routes axum, des signatures de handlers et des DTOs indexés.
+pub async fn execute(
+    project: Option<String>,
+    service: Option<String>,
+    output: Option<String>,
+) -> Result<()> {
+    let current_dir = std::env::current_dir()?;
+    let workspace_root = if let Some(ref path) = project {
+        let explicit = PathBuf::from(path);
+        WorkspaceRoot::detect_with_explicit(&current_dir, Some(&explicit))?
+    } else {
+        WorkspaceRoot::detect(&current_dir)?
+    };
+    let root = workspace_root.path();
+
+    let mut services = detect_rustwork_services(root)?;
+    if let Some(ref name) = service {
+        services.retain(|s| &s.name == name);
+        if services.is_empty() {
+            anyhow::bail!("Service '{}' not found in workspace", name);
+        }
+    }
+
+    if services.is_empty() {
+        anyhow::bail!("No Rustwork services found in {}", root.display());
+    }
+
+    if output.is_some() && services.len() > 1 {
+        anyhow::bail!("--output requires --service when the workspace has several services");
+    }
+
+    println!("📘 Generating OpenAPI documents...");
+
+    let registry = scan_routes(root).await.context("Failed to scan routes")?;
+    let index = scan_project(root)
+        .await
+        .context("Failed to index sources")?;
+
+    for service in &services {
+        let prefix = service_source_prefix(root, &service.path);
+
+        let routes: Vec<&RouteInfo> = registry
+            .routes
+            .iter()
+            .filter(|r| r.file.starts_with(&prefix))
+            .collect();
+        let mut files: Vec<&SourceFile> = index
+            .files
+            .values()
+            .filter(|f| f.relative_path.starts_with(&prefix))
+            .collect();
+        files.sort_by(|a, b| a.relative_path.cmp(&b.relative_path));
+
+        let info = DocumentInfo {
+            title: service.name.clone(),
+            version: read_package_version(&service.path).unwrap_or_else(|| "0.1.0".to_string()),
+            server_url: read_service_port(&service.path)
+                .map(|port| format!("http://localhost:{}", port)),
+        };
+
+        let document = build_document(&info, &routes, &files);
+        let operations: usize = document["paths"]
+            .as_object()
+            .map(|paths| {
+                paths
+                    .values()
+                    .filter_map(|p| p.as_object())
+                    .map(|p| p.len())
+                    .sum()
+            })
+            .unwrap_or(0);
+
+        let output_path = output
+            .as_ref()
+            .map(PathBuf::from)
+            .unwrap_or_else(|| service.path.join("openapi.json"));
+        crate::templates::output::update(
+            &output_path,
+            &format!("{}\n", serde_json::to_string_pretty(&document)?),
+        )?;
+
+        println!(
+            "   ✅ {} ({} operations) → {}",
+            service.name,
+            operations,
+            output_path.display()
+        );
+    }
+
+    println!();
+    println!("💡 Serve it from a service with:");
+    println!("   .merge(rustwork::openapi_router(\"openapi.json\"))");
+    println!("   → GET /openapi.json and GET /docs (Swagger UI)");
+
+    Ok(())
+}
+
+/// Version du package depuis le Cargo.toml du service
+fn read_package_version(service_path: &Path) -> Option<String> {
+    let content = std::fs::read_to_string(service_path.join("Cargo.toml")).ok()?;
+    let manifest: toml::Value = toml::from_str(&content).ok()?;
+    manifest
+        .get("package")?
+        .get("version")?
+        .as_str()
+        .map(str::to_string)
+}
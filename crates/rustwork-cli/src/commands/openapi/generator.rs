@@ -0,0 +1,555 @@
+use super::schema::{
+    error_response_schema, option_inner, parse_type, type_segment, SchemaRegistry,
+};
+use crate::mcp::common::indexer::{FunctionInfo, SourceFile};
+use crate::mcp::common::routes::{HttpMethod, RouteInfo};
+use serde_json::{json, Map, Value};
+use std::collections::{BTreeMap, HashSet};
+use std::path::Path;
+
+/// Métadonnées du document (section `info` et `servers`)
+pub(crate) struct DocumentInfo {
+    pub title: String,
+    pub version: String,
+    pub server_url: Option<String>,
+}
+
+/// Construit un document OpenAPI 3.1 à partir des routes et du code indexé d'un service
+pub(crate) fn build_document(
+    info: &DocumentInfo,
+    routes: &[&RouteInfo],
+    files: &[&SourceFile],
+) -> Value {
+    let mut registry = SchemaRegistry::new(files);
+    let mut paths: BTreeMap<String, Map<String, Value>> = BTreeMap::new();
+    let mut operation_ids = HashSet::new();
+
+    for route in routes {
+        let path = to_openapi_path(&route.path);
+        let handler = find_handler(files, route);
+
+        // operationId unique dans le document (même handler monté sur plusieurs routes)
+        let mut id = operation_id(route, handler);
+        if !operation_ids.insert(id.clone()) {
+            id = format!("{}_{}", id, method_key(&route.method));
+            operation_ids.insert(id.clone());
+        }
+        let operation = build_operation(route, &path, &id, handler, &mut registry);
+
+        paths
+            .entry(path)
+            .or_default()
+            .insert(method_key(&route.method).to_string(), operation);
+    }
+
+    let mut schemas = registry.into_components();
+    schemas.insert("ErrorResponse".to_string(), error_response_schema());
+
+    let mut document = json!({
+        "openapi": "3.1.0",
+        "info": {
+            "title": info.title,
+            "version": info.version,
+        },
+        "paths": paths,
+        "components": { "schemas": schemas },
+    });
+
+    if let Some(url) = &info.server_url {
+        document["servers"] = json!([{ "url": url }]);
+    }
+
+    document
+}
+
+/// Convertit un chemin axum (`/users/:id`, `/users/{id}`, `/files/*path`) au format OpenAPI
+pub(crate) fn to_openapi_path(path: &str) -> String {
+    path.split('/')
+        .map(|segment| {
+            if let Some(name) = segment
+                .strip_prefix(':')
+                .or_else(|| segment.strip_prefix('*'))
+            {
+                format!("{{{}}}", name)
+            } else if let Some(name) = segment.strip_prefix("{*") {
+                format!("{{{}", name)
+            } else {
+                segment.to_string()
+            }
+        })
+        .collect::<Vec<_>>()
+        .join("/")
+}
+
+/// Noms des paramètres de chemin (`/users/{id}` -> `["id"]`)
+fn path_param_names(path: &str) -> Vec<String> {
+    path.split('/')
+        .filter_map(|segment| {
+            segment
+                .strip_prefix('{')
+                .and_then(|s| s.strip_suffix('}'))
+                .map(str::to_string)
+        })
+        .collect()
+}
+
+fn method_key(method: &HttpMethod) -> &'static str {
+    match method {
+        HttpMethod::GET => "get",
+        HttpMethod::POST => "post",
+        HttpMethod::PUT => "put",
+        HttpMethod::PATCH => "patch",
+        HttpMethod::DELETE => "delete",
+        HttpMethod::HEAD => "head",
+        HttpMethod::OPTIONS => "options",
+    }
+}
+
+/// Modules du chemin d'un handler tel qu'écrit dans le router
+/// (`crate::controllers::users::index` -> `["controllers", "users"]`)
+fn handler_modules(route: &RouteInfo) -> Vec<&str> {
+    let path = route.handler_function.as_deref().unwrap_or(&route.handler);
+    let mut segments: Vec<&str> = path
+        .split("::")
+        .skip_while(|s| matches!(*s, "crate" | "self" | "super"))
+        .collect();
+    segments.pop();
+    segments
+}
+
+/// Modules d'un fichier indexé (`services::users::src::controllers::users::mod` -> `[..., "users"]`)
+fn file_modules(file: &SourceFile) -> Vec<&str> {
+    file.module_path
+        .split("::")
+        .filter(|s| !matches!(*s, "" | "mod" | "lib" | "main"))
+        .collect()
+}
+
+/// Retrouve la fonction handler dans l'index
+///
+/// Le chemin du router (`controllers::users::index`) désigne le fichier ; un handler
+/// importé sans module (`get(index)`) est cherché par nom, en priorité dans les controllers.
+pub(crate) fn find_handler<'a>(
+    files: &[&'a SourceFile],
+    route: &RouteInfo,
+) -> Option<(&'a SourceFile, &'a FunctionInfo)> {
+    let modules = handler_modules(route);
+    let mut candidates: Vec<(&SourceFile, &FunctionInfo)> = files
+        .iter()
+        .filter(|file| file_modules(file).ends_with(&modules))
+        .flat_map(|file| {
+            file.functions
+                .iter()
+                .filter(|f| f.name == route.handler)
+                .map(move |f| (*file, f))
+        })
+        .collect();
+
+    candidates.sort_by_key(|(file, _)| {
+        (
+            !file.relative_path.contains("controllers"),
+            file.relative_path.clone(),
+        )
+    });
+
+    candidates.into_iter().next()
+}
+
+/// Identifiant d'opération qualifié par le module du handler (`users_index`)
+pub(crate) fn operation_id(
+    route: &RouteInfo,
+    handler: Option<(&SourceFile, &FunctionInfo)>,
+) -> String {
+    let module = match handler {
+        Some((file, _)) => file_modules(file).last().map(|m| m.to_string()),
+        None => handler_modules(route).last().map(|m| m.to_string()),
+    };
+    match module {
+        Some(module) if module != "src" => format!("{}_{}", module, route.handler),
+        _ => route.handler.clone(),
+    }
+}
+
+fn build_operation(
+    route: &RouteInfo,
+    path: &str,
+    operation_id: &str,
+    handler: Option<(&SourceFile, &FunctionInfo)>,
+    registry: &mut SchemaRegistry,
+) -> Value {
+    let mut operation = Map::new();
+    operation.insert("operationId".to_string(), json!(operation_id));
+
+    let Some((file, function)) = handler else {
+        operation.insert(
+            "responses".to_string(),
+            json!({ "200": { "description": "Successful response" } }),
+        );
+        return Value::Object(operation);
+    };
+
+    if let Some(tag) = Path::new(&file.relative_path)
+        .file_stem()
+        .and_then(|s| s.to_str())
+        .filter(|s| *s != "mod")
+    {
+        operation.insert("tags".to_string(), json!([tag]));
+    }
+
+    // Extracteurs
+    let path_names = path_param_names(path);
+    let mut path_params: BTreeMap<String, Value> = BTreeMap::new();
+    let mut parameters = Vec::new();
+
+    for param in &function.parameters {
+        let Some(ty) = parse_type(&param.type_name) else {
+            continue;
+        };
+        let Some((extractor, args)) = type_segment(&ty) else {
+            continue;
+        };
+        let Some(inner) = args.first().copied() else {
+            continue;
+        };
+
+        match extractor.as_str() {
+            "Path" => collect_path_params(inner, &path_names, &mut path_params, registry),
+            "Query" => parameters.extend(query_params(inner, registry)),
+            "Json" => {
+                operation.insert(
+                    "requestBody".to_string(),
+                    request_body("application/json", registry.schema_for(inner)),
+                );
+            }
+            "Form" => {
+                operation.insert(
+                    "requestBody".to_string(),
+                    request_body(
+                        "application/x-www-form-urlencoded",
+                        registry.schema_for(inner),
+                    ),
+                );
+            }
+            _ => {}
+        }
+    }
+
+    let mut all_params: Vec<Value> = path_names
+        .iter()
+        .map(|name| {
+            let schema = path_params
+                .remove(name)
+                .unwrap_or_else(|| json!({ "type": "string" }));
+            json!({ "name": name, "in": "path", "required": true, "schema": schema })
+        })
+        .collect();
+    all_params.extend(parameters);
+
+    if !all_params.is_empty() {
+        operation.insert("parameters".to_string(), json!(all_params));
+    }
+
+    // Réponses
+    let return_type = function.return_type.as_deref().and_then(parse_type);
+    let body = return_type
+        .as_ref()
+        .and_then(|ty| response_body(ty, registry));
+    let fallible = return_type.as_ref().is_some_and(is_result);
+
+    let status = if function.calls.iter().any(|c| c == "created") {
+        "201"
+    } else if body.is_none() && route.method == HttpMethod::DELETE {
+        "204"
+    } else {
+        "200"
+    };
+
+    let mut success = json!({ "description": "Successful response" });
+    if let Some(schema) = body {
+        success["content"] = json!({ "application/json": { "schema": schema } });
+    }
+
+    let mut responses = Map::new();
+    responses.insert(status.to_string(), success);
+    if fallible {
+        responses.insert(
+            "default".to_string(),
+            json!({
+                "description": "Error response",
+                "content": {
+                    "application/json": {
+                        "schema": { "$ref": "#/components/schemas/ErrorResponse" }
+                    }
+                }
+            }),
+        );
+    }
+    operation.insert("responses".to_string(), Value::Object(responses));
+
+    Value::Object(operation)
+}
+
+/// `Path<T>` : tuple positionnel, struct nommée ou type scalaire
+fn collect_path_params(
+    inner: &syn::Type,
+    names: &[String],
+    params: &mut BTreeMap<String, Value>,
+    registry: &mut SchemaRegistry,
+) {
+    if let syn::Type::Tuple(tuple) = inner {
+        for (name, ty) in names.iter().zip(tuple.elems.iter()) {
+            params.insert(name.clone(), registry.schema_for(ty));
+        }
+        return;
+    }
+
+    if let Some(info) = type_segment(inner).and_then(|(name, _)| registry.struct_info(&name)) {
+        for field in &info.fields {
+            if let Some(ty) = parse_type(&field.type_name) {
+                params.insert(field.name.clone(), registry.schema_for(&ty));
+            }
+        }
+        return;
+    }
+
+    if let Some(name) = names.first() {
+        params.insert(name.clone(), registry.schema_for(inner));
+    }
+}
+
+/// `Query<T>` : un paramètre par champ de la struct
+fn query_params(inner: &syn::Type, registry: &mut SchemaRegistry) -> Vec<Value> {
+    let Some((name, _)) = type_segment(inner) else {
+        return Vec::new();
+    };
+
+    if name == "Paginator" {
+        return vec![
+            json!({ "name": "page", "in": "query", "required": false, "schema": { "type": "integer", "minimum": 1, "default": 1 } }),
+            json!({ "name": "per_page", "in": "query", "required": false, "schema": { "type": "integer", "minimum": 1, "maximum": 100, "default": 20 } }),
+        ];
+    }
+
+    let Some(info) = registry.struct_info(&name) else {
+        return Vec::new();
+    };
+
+    info.fields
+        .iter()
+        .filter_map(|field| {
+            let ty = parse_type(&field.type_name)?;
+            let required = option_inner(&ty).is_none();
+            let schema = registry.schema_for(option_inner(&ty).unwrap_or(&ty));
+            Some(json!({
+                "name": field.name,
+                "in": "query",
+                "required": required,
+                "schema": schema,
+            }))
+        })
+        .collect()
+}
+
+fn request_body(content_type: &str, schema: Value) -> Value {
+    json!({
+        "required": true,
+        "content": { content_type: { "schema": schema } }
+    })
+}
+
+/// Schéma du corps de réponse (`Json<T>`, `ApiResponse<T>`, `Result`, tuples avec `StatusCode`)
+fn response_body(ty: &syn::Type, registry: &mut SchemaRegistry) -> Option<Value> {
+    if let syn::Type::Tuple(tuple) = ty {
+        return tuple
+            .elems
+            .iter()
+            .rev()
+            .find_map(|elem| response_body(elem, registry));
+    }
+
+    let (name, args) = type_segment(ty)?;
+    match (name.as_str(), args.as_slice()) {
+        ("Result" | "AppResult", [ok, ..]) => response_body(ok, registry),
+        ("Json", [inner]) => Some(registry.schema_for(inner)),
+        _ => None,
+    }
+}
+
+fn is_result(ty: &syn::Type) -> bool {
+    matches!(type_segment(ty), Some((name, _)) if name == "Result" || name == "AppResult")
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::mcp::common::indexer::scan_project;
+    use crate::mcp::common::routes::scan_routes;
+
+    const ROUTES_RS: &str = r#"
+use axum::{routing::get, Router};
+use rustwork::AppState;
+
+use crate::controllers;
+
+pub fn create_routes() -> Router<AppState> {
+    Router::new()
+        .route("/api/users", get(controllers::users::index).post(controllers::users::create))
+        .route("/api/users/:id", get(controllers::users::show))
+        .route("/api/posts", get(controllers::posts::index))
+}
+"#;
+
+    const POSTS_RS: &str = r#"
+use axum::Json;
+use rustwork::{ok, ApiResponse, AppResult};
+
+use crate::models::post::Post;
+
+pub async fn index() -> AppResult<Json<ApiResponse<Vec<Post>>>> {
+    Ok(ok(vec![]))
+}
+"#;
+
+    const USERS_RS: &str = r#"
+use axum::{extract::{Path, Query, State}, http::StatusCode, Json};
+use rustwork::{created, ok, ApiResponse, AppResult, AppState, Paginator};
+
+use crate::models::user::{CreateUser, User};
+
+pub async fn index(
+    State(state): State<AppState>,
+    Query(paginator): Query<Paginator>,
+) -> AppResult<(StatusCode, Json<ApiResponse<Vec<User>>>)> {
+    Ok(ok(vec![]))
+}
+
+pub async fn show(Path(id): Path<i64>) -> AppResult<Json<ApiResponse<User>>> {
+    todo!()
+}
+
+pub async fn create(
+    State(state): State<AppState>,
+    Json(payload): Json<CreateUser>,
+) -> AppResult<(StatusCode, Json<ApiResponse<User>>)> {
+    Ok(created(todo!()))
+}
+"#;
+
+    const USER_RS: &str = r#"
+pub struct User {
+    pub id: i64,
+    pub email: String,
+    pub nickname: Option<String>,
+}
+
+pub struct CreateUser {
+    pub email: String,
+}
+"#;
+
+    const POST_RS: &str = r#"
+pub struct Post {
+    pub id: i64,
+    pub title: String,
+}
+"#;
+
+    async fn build_fixture_document() -> Value {
+        let dir = tempfile::tempdir().unwrap();
+        let src = dir.path().join("src");
+        std::fs::create_dir_all(src.join("controllers")).unwrap();
+        std::fs::create_dir_all(src.join("models")).unwrap();
+        std::fs::write(src.join("routes.rs"), ROUTES_RS).unwrap();
+        std::fs::write(src.join("controllers/users.rs"), USERS_RS).unwrap();
+        std::fs::write(src.join("controllers/posts.rs"), POSTS_RS).unwrap();
+        std::fs::write(src.join("models/user.rs"), USER_RS).unwrap();
+        std::fs::write(src.join("models/post.rs"), POST_RS).unwrap();
+
+        let registry = scan_routes(dir.path()).await.unwrap();
+        let index = scan_project(dir.path()).await.unwrap();
+
+        let routes: Vec<&RouteInfo> = registry.routes.iter().collect();
+        let files: Vec<&SourceFile> = index.files.values().collect();
+        let info = DocumentInfo {
+            title: "users".to_string(),
+            version: "0.1.0".to_string(),
+            server_url: Some("http://localhost:3001".to_string()),
+        };
+
+        build_document(&info, &routes, &files)
+    }
+
+    #[test]
+    fn test_to_openapi_path() {
+        assert_eq!(to_openapi_path("/users/:id"), "/users/{id}");
+        assert_eq!(to_openapi_path("/users/{id}"), "/users/{id}");
+        assert_eq!(to_openapi_path("/files/*path"), "/files/{path}");
+        assert_eq!(to_openapi_path("/files/{*path}"), "/files/{path}");
+        assert_eq!(to_openapi_path("/health"), "/health");
+    }
+
+    #[tokio::test]
+    async fn test_document_paths_and_operations() {
+        let doc = build_fixture_document().await;
+
+        assert_eq!(doc["openapi"], "3.1.0");
+        assert_eq!(doc["servers"][0]["url"], "http://localhost:3001");
+
+        let list = &doc["paths"]["/api/users"]["get"];
+        assert_eq!(list["operationId"], "users_index");
+        assert_eq!(list["tags"], json!(["users"]));
+        assert_eq!(list["parameters"][0]["name"], "page");
+        assert_eq!(
+            list["responses"]["200"]["content"]["application/json"]["schema"]["properties"]["data"]
+                ["items"]["$ref"],
+            "#/components/schemas/User"
+        );
+        assert!(list["responses"]["default"].is_object());
+    }
+
+    #[tokio::test]
+    async fn test_document_homonymous_handlers() {
+        let doc = build_fixture_document().await;
+
+        let users = &doc["paths"]["/api/users"]["get"];
+        let posts = &doc["paths"]["/api/posts"]["get"];
+        assert_eq!(users["operationId"], "users_index");
+        assert_eq!(posts["operationId"], "posts_index");
+        assert_eq!(posts["tags"], json!(["posts"]));
+        assert!(posts.get("parameters").is_none());
+        assert_eq!(
+            posts["responses"]["200"]["content"]["application/json"]["schema"]["properties"]
+                ["data"]["items"]["$ref"],
+            "#/components/schemas/Post"
+        );
+    }
+
+    #[tokio::test]
+    async fn test_document_extractors() {
+        let doc = build_fixture_document().await;
+
+        let show = &doc["paths"]["/api/users/{id}"]["get"];
+        assert_eq!(show["parameters"][0]["in"], "path");
+        assert_eq!(show["parameters"][0]["schema"]["format"], "int64");
+
+        let create = &doc["paths"]["/api/users"]["post"];
+        assert_eq!(
+            create["requestBody"]["content"]["application/json"]["schema"]["$ref"],
+            "#/components/schemas/CreateUser"
+        );
+        assert!(create["responses"]["201"].is_object());
+    }
+
+    #[tokio::test]
+    async fn test_document_components() {
+        let doc = build_fixture_document().await;
+        let schemas = &doc["components"]["schemas"];
+
+        assert_eq!(schemas["User"]["required"], json!(["id", "email"]));
+        assert_eq!(
+            schemas["User"]["properties"]["nickname"]["type"],
+            json!(["string", "null"])
+        );
+        assert!(schemas["ErrorResponse"].is_object());
+    }
+}
@@ -0,0 +1,8 @@
+/// Commande de génération de documents OpenAPI
+mod execute;
+mod generator;
+mod schema;
+
+pub use execute::execute;
+pub(crate) use generator::{find_handler, operation_id, to_openapi_path};
+pub(crate) use schema::{parse_type, type_segment};
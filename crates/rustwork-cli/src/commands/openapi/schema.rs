@@ -0,0 +1,287 @@
+use crate::mcp::common::indexer::{SourceFile, StructInfo};
+use serde_json::{json, Value};
+use std::collections::{BTreeMap, HashMap};
+
+/// Registre des schémas `components/schemas`, alimenté par les structs du `CodeIndex`
+pub(crate) struct SchemaRegistry<'a> {
+    structs: HashMap<&'a str, &'a StructInfo>,
+    components: BTreeMap<String, Value>,
+}
+
+impl<'a> SchemaRegistry<'a> {
+    /// Indexe les structs des fichiers fournis (la première définition rencontrée gagne)
+    pub fn new(files: &[&'a SourceFile]) -> Self {
+        let mut structs = HashMap::new();
+        for file in files {
+            for info in &file.structs {
+                structs.entry(info.name.as_str()).or_insert(info);
+            }
+        }
+
+        Self {
+            structs,
+            components: BTreeMap::new(),
+        }
+    }
+
+    /// Struct indexée correspondant à un nom de type
+    pub fn struct_info(&self, name: &str) -> Option<&'a StructInfo> {
+        self.structs.get(name).copied()
+    }
+
+    /// Schéma JSON (OpenAPI 3.1) d'un type Rust
+    pub fn schema_for(&mut self, ty: &syn::Type) -> Value {
+        match ty {
+            syn::Type::Reference(reference) => self.schema_for(&reference.elem),
+            syn::Type::Paren(paren) => self.schema_for(&paren.elem),
+            syn::Type::Group(group) => self.schema_for(&group.elem),
+            syn::Type::Slice(slice) => {
+                json!({ "type": "array", "items": self.schema_for(&slice.elem) })
+            }
+            syn::Type::Array(array) => {
+                json!({ "type": "array", "items": self.schema_for(&array.elem) })
+            }
+            syn::Type::Tuple(tuple) if tuple.elems.is_empty() => json!({ "type": "null" }),
+            syn::Type::Tuple(tuple) => {
+                let items: Vec<Value> = tuple.elems.iter().map(|t| self.schema_for(t)).collect();
+                json!({ "type": "array", "prefixItems": items })
+            }
+            syn::Type::Path(_) => self.schema_for_path(ty),
+            _ => json!({}),
+        }
+    }
+
+    fn schema_for_path(&mut self, ty: &syn::Type) -> Value {
+        let Some((name, args)) = type_segment(ty) else {
+            return json!({});
+        };
+
+        match (name.as_str(), args.as_slice()) {
+            ("String" | "str" | "char", _) => json!({ "type": "string" }),
+            ("i8" | "i16" | "i32" | "u8" | "u16" | "u32", _) => {
+                json!({ "type": "integer", "format": "int32" })
+            }
+            ("i64" | "u64" | "i128" | "u128" | "isize" | "usize", _) => {
+                json!({ "type": "integer", "format": "int64" })
+            }
+            ("f32", _) => json!({ "type": "number", "format": "float" }),
+            ("f64", _) => json!({ "type": "number", "format": "double" }),
+            ("Decimal", _) => json!({ "type": "string", "format": "decimal" }),
+            ("bool", _) => json!({ "type": "boolean" }),
+            ("Uuid", _) => json!({ "type": "string", "format": "uuid" }),
+            ("DateTime" | "NaiveDateTime", _) => json!({ "type": "string", "format": "date-time" }),
+            ("NaiveDate", _) => json!({ "type": "string", "format": "date" }),
+            ("NaiveTime", _) => json!({ "type": "string", "format": "time" }),
+            ("Value", _) => json!({}),
+            ("Option", [inner]) => nullable(self.schema_for(inner)),
+            ("Vec" | "VecDeque" | "HashSet" | "BTreeSet", [inner]) => {
+                json!({ "type": "array", "items": self.schema_for(inner) })
+            }
+            ("HashMap" | "BTreeMap", [_, value]) => {
+                json!({ "type": "object", "additionalProperties": self.schema_for(value) })
+            }
+            ("Box" | "Arc" | "Rc" | "Cow" | "Json", [.., inner]) => self.schema_for(inner),
+            ("ApiResponse", [inner]) => {
+                let data = self.schema_for(inner);
+                api_response_schema(data)
+            }
+            ("Paginator", _) => {
+                self.components
+                    .entry("Paginator".to_string())
+                    .or_insert_with(paginator_schema);
+                component_ref("Paginator")
+            }
+            (other, _) => self.struct_schema(other),
+        }
+    }
+
+    /// Référence vers une struct indexée (enregistrée dans `components/schemas`)
+    pub fn struct_schema(&mut self, name: &str) -> Value {
+        if self.components.contains_key(name) {
+            return component_ref(name);
+        }
+
+        let Some(info) = self.struct_info(name) else {
+            return json!({ "type": "object" });
+        };
+
+        // Placeholder pour couper les récursions (struct qui se référence elle-même)
+        self.components.insert(name.to_string(), json!({}));
+
+        let mut properties = serde_json::Map::new();
+        let mut required = Vec::new();
+        for field in &info.fields {
+            let Some(field_ty) = parse_type(&field.type_name) else {
+                properties.insert(field.name.clone(), json!({}));
+                continue;
+            };
+            if option_inner(&field_ty).is_none() {
+                required.push(json!(field.name));
+            }
+            properties.insert(field.name.clone(), self.schema_for(&field_ty));
+        }
+
+        let mut schema = json!({ "type": "object", "properties": properties });
+        if !required.is_empty() {
+            schema["required"] = json!(required);
+        }
+        self.components.insert(name.to_string(), schema);
+
+        component_ref(name)
+    }
+
+    /// Consomme le registre et retourne les schémas collectés
+    pub fn into_components(self) -> BTreeMap<String, Value> {
+        self.components
+    }
+}
+
+/// Parse une chaîne de type issue de l'index (`Json < CreateUser >`)
+pub(crate) fn parse_type(type_name: &str) -> Option<syn::Type> {
+    syn::parse_str(type_name).ok()
+}
+
+/// Dernier segment d'un type chemin et ses arguments génériques
+pub(crate) fn type_segment(ty: &syn::Type) -> Option<(String, Vec<&syn::Type>)> {
+    let syn::Type::Path(type_path) = ty else {
+        return None;
+    };
+    let segment = type_path.path.segments.last()?;
+
+    let args = match &segment.arguments {
+        syn::PathArguments::AngleBracketed(generics) => generics
+            .args
+            .iter()
+            .filter_map(|arg| match arg {
+                syn::GenericArgument::Type(t) => Some(t),
+                _ => None,
+            })
+            .collect(),
+        _ => Vec::new(),
+    };
+
+    Some((segment.ident.to_string(), args))
+}
+
+/// Type interne d'un `Option<T>`
+pub(crate) fn option_inner(ty: &syn::Type) -> Option<&syn::Type> {
+    match type_segment(ty) {
+        Some((name, args)) if name == "Option" && args.len() == 1 => Some(args[0]),
+        _ => None,
+    }
+}
+
+/// Rend un schéma nullable (`type: [T, "null"]` en OpenAPI 3.1)
+fn nullable(mut schema: Value) -> Value {
+    match schema
+        .get("type")
+        .and_then(|t| t.as_str())
+        .map(str::to_string)
+    {
+        Some(ty) => {
+            schema["type"] = json!([ty, "null"]);
+            schema
+        }
+        None => json!({ "anyOf": [schema, { "type": "null" }] }),
+    }
+}
+
+fn component_ref(name: &str) -> Value {
+    json!({ "$ref": format!("#/components/schemas/{}", name) })
+}
+
+/// Enveloppe `ApiResponse<T>` du crate rustwork
+pub(crate) fn api_response_schema(data: Value) -> Value {
+    json!({
+        "type": "object",
+        "properties": {
+            "success": { "type": "boolean" },
+            "data": nullable(data),
+            "message": { "type": ["string", "null"] },
+            "error": { "type": "string" },
+        },
+        "required": ["success"],
+    })
+}
+
+/// Corps d'erreur produit par `AppError::into_response`
+pub(crate) fn error_response_schema() -> Value {
+    json!({
+        "type": "object",
+        "properties": {
+            "success": { "type": "boolean", "const": false },
+            "error": { "type": "string" },
+            "status": { "type": "integer", "format": "int32" },
+        },
+        "required": ["success", "error", "status"],
+    })
+}
+
+/// `rustwork::Paginator`
+fn paginator_schema() -> Value {
+    json!({
+        "type": "object",
+        "properties": {
+            "page": { "type": "integer", "format": "int64", "minimum": 1, "default": 1 },
+            "per_page": { "type": "integer", "format": "int64", "minimum": 1, "maximum": 100, "default": 20 },
+        },
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn schema(type_name: &str) -> Value {
+        let mut registry = SchemaRegistry::new(&[]);
+        registry.schema_for(&parse_type(type_name).unwrap())
+    }
+
+    #[test]
+    fn test_primitive_schemas() {
+        assert_eq!(schema("String"), json!({ "type": "string" }));
+        assert_eq!(schema("& str"), json!({ "type": "string" }));
+        assert_eq!(schema("i64")["format"], "int64");
+        assert_eq!(schema("bool"), json!({ "type": "boolean" }));
+        assert_eq!(schema("uuid :: Uuid")["format"], "uuid");
+        assert_eq!(
+            schema("chrono :: DateTime < chrono :: Utc >")["format"],
+            "date-time"
+        );
+    }
+
+    #[test]
+    fn test_option_is_nullable() {
+        assert_eq!(
+            schema("Option < String >"),
+            json!({ "type": ["string", "null"] })
+        );
+    }
+
+    #[test]
+    fn test_collections() {
+        assert_eq!(
+            schema("Vec < i32 >"),
+            json!({ "type": "array", "items": { "type": "integer", "format": "int32" } })
+        );
+        assert_eq!(
+            schema("HashMap < String , bool >")["additionalProperties"],
+            json!({ "type": "boolean" })
+        );
+    }
+
+    #[test]
+    fn test_api_response_envelope() {
+        let value = schema("ApiResponse < String >");
+        assert_eq!(
+            value["properties"]["data"]["type"],
+            json!(["string", "null"])
+        );
+        assert_eq!(value["required"], json!(["success"]));
+    }
+
+    #[test]
+    fn test_unknown_type_is_object() {
+        assert_eq!(schema("SomethingElse"), json!({ "type": "object" }));
+    }
+}
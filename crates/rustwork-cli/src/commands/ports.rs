@@ -0,0 +1,253 @@
+//! Registre des ports du workspace (`.rustwork/ports.json`)
+//!
+//! `rustwork new` et `rustwork add-service` y attribuent à chaque service un port HTTP
+//! unique, pris dans une plage configurable :
+//!
+//! ```json
+//! {
+//!   "ranges": { "http": { "start": 3001, "end": 3999 } },
+//!   "services": { "auth": { "http": 3001 } }
+//! }
+//! ```
+//!
+//! Les services générés n'ouvrent pas de serveur gRPC : aucun port gRPC n'est réservé.
+
+use anyhow::{Context, Result};
+use serde::{Deserialize, Serialize};
+use std::collections::{BTreeMap, BTreeSet};
+use std::fmt;
+use std::path::{Path, PathBuf};
+
+use super::utils::{read_service_port, RustworkService};
+use crate::templates::output;
+
+/// Chemin du registre, relatif à la racine du workspace
+pub const REGISTRY_FILE: &str = ".rustwork/ports.json";
+
+/// Plage de ports, bornes incluses
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+pub struct PortRange {
+    pub start: u16,
+    pub end: u16,
+}
+
+impl PortRange {
+    pub fn contains(&self, port: u16) -> bool {
+        (self.start..=self.end).contains(&port)
+    }
+
+    /// Premier port libre après le plus haut port utilisé de la plage,
+    /// sinon le premier trou (les ports d'un service supprimé sont réutilisés en dernier)
+    pub fn next_free(&self, used: &BTreeSet<u16>) -> Option<u16> {
+        let highest = used.range(self.start..=self.end).next_back();
+        let after = highest.map_or(self.start, |p| p.saturating_add(1));
+        (after..=self.end)
+            .chain(self.start..after)
+            .find(|port| self.contains(*port) && !used.contains(port))
+    }
+}
+
+impl fmt::Display for PortRange {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}-{}", self.start, self.end)
+    }
+}
+
+/// Plages d'allocation
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+pub struct PortRanges {
+    pub http: PortRange,
+}
+
+impl Default for PortRanges {
+    fn default() -> Self {
+        Self {
+            http: PortRange {
+                start: 3001,
+                end: 3999,
+            },
+        }
+    }
+}
+
+/// Ports attribués à un service
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+pub struct ServicePorts {
+    pub http: u16,
+}
+
+/// Contenu de `.rustwork/ports.json`
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+pub struct PortRegistry {
+    #[serde(default)]
+    pub ranges: PortRanges,
+    #[serde(default)]
+    pub services: BTreeMap<String, ServicePorts>,
+}
+
+impl PortRegistry {
+    pub fn path(workspace_root: &Path) -> PathBuf {
+        workspace_root.join(REGISTRY_FILE)
+    }
+
+    /// Lit le registre (vide s'il n'existe pas) et y ajoute les services non
+    /// enregistrés, avec les ports de leur `config/default.toml`
+    pub fn load(workspace_root: &Path, services: &[RustworkService]) -> Result<Self> {
+        let path = Self::path(workspace_root);
+        let mut registry = match std::fs::read_to_string(&path) {
+            Ok(content) => Self::parse(&content)
+                .with_context(|| format!("Invalid port registry {}", path.display()))?,
+            Err(_) => Self::default(),
+        };
+
+        for service in services {
+            if registry.services.contains_key(&service.name) {
+                continue;
+            }
+            let Some(http) = read_service_port(&service.path).and_then(|p| u16::try_from(p).ok())
+            else {
+                continue;
+            };
+            registry
+                .services
+                .insert(service.name.clone(), ServicePorts { http });
+        }
+
+        Ok(registry)
+    }
+
+    pub fn parse(content: &str) -> Result<Self> {
+        Ok(serde_json::from_str(content)?)
+    }
+
+    pub fn to_json(&self) -> String {
+        serde_json::to_string_pretty(self).unwrap_or_default() + "\n"
+    }
+
+    pub fn save(&self, workspace_root: &Path) -> Result<()> {
+        output::update(&Self::path(workspace_root), &self.to_json())?;
+        Ok(())
+    }
+
+    /// Tous les ports réservés
+    pub fn used_ports(&self) -> BTreeSet<u16> {
+        self.services.values().map(|ports| ports.http).collect()
+    }
+
+    /// Attribue au service un port HTTP libre (ou rend le sien)
+    pub fn allocate(&mut self, service: &str) -> Result<ServicePorts> {
+        if let Some(ports) = self.services.get(service) {
+            return Ok(*ports);
+        }
+
+        let range = self.ranges.http;
+        let http = range.next_free(&self.used_ports()).ok_or_else(|| {
+            anyhow::anyhow!(
+                "No free HTTP port left in {} (widen the range in {})",
+                range,
+                REGISTRY_FILE
+            )
+        })?;
+
+        let ports = ServicePorts { http };
+        self.services.insert(service.to_string(), ports);
+        Ok(ports)
+    }
+}
+
+/// Applique `edit` au contenu d'un registre (contenu inchangé s'il est invalide)
+pub fn edit_registry(content: &str, edit: impl FnOnce(&mut PortRegistry)) -> String {
+    match PortRegistry::parse(content) {
+        Ok(mut registry) => {
+            edit(&mut registry);
+            registry.to_json()
+        }
+        Err(_) => content.to_string(),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn service(dir: &Path, name: &str, config: &str) -> RustworkService {
+        let path = dir.join("Backend/services").join(name);
+        std::fs::create_dir_all(path.join("config")).unwrap();
+        std::fs::write(path.join("config/default.toml"), config).unwrap();
+        RustworkService {
+            name: name.to_string(),
+            path,
+        }
+    }
+
+    #[test]
+    fn test_next_free() {
+        let range = PortRange {
+            start: 3001,
+            end: 3004,
+        };
+        assert_eq!(range.next_free(&BTreeSet::new()), Some(3001));
+        assert_eq!(range.next_free(&[3001, 3002].into()), Some(3003));
+        // Après le plus haut, même si un port plus bas s'est libéré
+        assert_eq!(range.next_free(&[3002, 8080].into()), Some(3003));
+        assert_eq!(range.next_free(&[3002, 3004].into()), Some(3001));
+        assert_eq!(range.next_free(&[3001, 3002, 3003, 3004].into()), None);
+    }
+
+    #[test]
+    fn test_allocate() {
+        let mut registry = PortRegistry::default();
+        let auth = registry.allocate("auth").unwrap();
+        let user = registry.allocate("user").unwrap();
+        assert_eq!(auth, ServicePorts { http: 3001 });
+        assert_eq!(user, ServicePorts { http: 3002 });
+        assert_eq!(registry.allocate("auth").unwrap(), auth);
+
+        registry.ranges.http.end = 3002;
+        let err = registry.allocate("billing").unwrap_err();
+        assert!(err
+            .to_string()
+            .contains("No free HTTP port left in 3001-3002"));
+    }
+
+    #[test]
+    fn test_load_backfills_existing_services() {
+        let dir = tempfile::tempdir().unwrap();
+        let auth = service(dir.path(), "auth", "[server]\nport = 3001\n");
+        let user = service(dir.path(), "user", "[server]\nport = 3005\n");
+
+        let mut registry = PortRegistry::load(dir.path(), &[auth, user]).unwrap();
+        assert_eq!(registry.services["user"], ServicePorts { http: 3005 });
+
+        assert_eq!(registry.allocate("user").unwrap().http, 3005);
+        assert_eq!(registry.allocate("billing").unwrap().http, 3006);
+
+        registry.save(dir.path()).unwrap();
+        let saved = PortRegistry::load(dir.path(), &[]).unwrap();
+        assert_eq!(saved, registry);
+    }
+
+    #[test]
+    fn test_parse_ignores_legacy_grpc_ports() {
+        let registry = PortRegistry::parse(
+            r#"{"ranges":{"http":{"start":4001,"end":4999},"grpc":{"start":50051,"end":50999}},"services":{"auth":{"http":4001,"grpc":50051}}}"#,
+        )
+        .unwrap();
+        assert_eq!(registry.ranges.http.start, 4001);
+        assert_eq!(registry.services["auth"], ServicePorts { http: 4001 });
+        assert!(!registry.to_json().contains("grpc"));
+    }
+
+    #[test]
+    fn test_edit_registry() {
+        let mut registry = PortRegistry::default();
+        registry.allocate("auth").unwrap();
+        let content = registry.to_json();
+
+        let edited = edit_registry(&content, |r| {
+            r.services.remove("auth");
+        });
+        assert!(PortRegistry::parse(&edited).unwrap().services.is_empty());
+        assert_eq!(edit_registry("not json", |_| {}), "not json");
+    }
+}
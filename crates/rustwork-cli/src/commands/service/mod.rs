@@ -0,0 +1,242 @@
+/// Commandes `rustwork remove-service` et `rustwork rename-service`
+mod plan;
+pub(crate) mod refs;
+mod remove;
+mod rename;
+
+pub use remove::execute as remove_service;
+pub use rename::execute as rename_service;
+
+use anyhow::Result;
+use std::path::{Path, PathBuf};
+
+use crate::commands::new::is_valid_service_name;
+use crate::commands::utils::{detect_rustwork_services, to_pascal_case, RustworkService};
+use crate::mcp::common::workspace_root::WorkspaceRoot;
+
+/// Workspace et service visés par une commande
+struct ServiceWorkspace {
+    root: PathBuf,
+    services_dir: PathBuf,
+    cargo_dir: PathBuf,
+    services: Vec<RustworkService>,
+    service: RustworkService,
+}
+
+impl ServiceWorkspace {
+    fn load(name: &str, project: Option<&str>) -> Result<Self> {
+        Self::load_from(&std::env::current_dir()?, name, project)
+    }
+
+    fn load_from(start: &Path, name: &str, project: Option<&str>) -> Result<Self> {
+        let workspace_root = WorkspaceRoot::detect_with_explicit(start, project.map(Path::new))?;
+        let services = detect_rustwork_services(workspace_root.path())?;
+
+        let service = services
+            .iter()
+            .find(|s| s.name == name)
+            .cloned()
+            .ok_or_else(|| {
+                anyhow::anyhow!(
+                    "Service '{}' not found. Available services: {}",
+                    name,
+                    services
+                        .iter()
+                        .map(|s| s.name.as_str())
+                        .collect::<Vec<_>>()
+                        .join(", ")
+                )
+            })?;
+
+        Ok(Self {
+            root: workspace_root.path().to_path_buf(),
+            services_dir: workspace_root.services_dir(),
+            cargo_dir: workspace_root.cargo_workspace_dir(),
+            services,
+            service,
+        })
+    }
+
+    /// Entrée `members` du Cargo.toml de workspace pour un service
+    fn member(&self, name: &str) -> String {
+        let path = self.services_dir.join(name);
+        path.strip_prefix(&self.cargo_dir)
+            .unwrap_or(&path)
+            .components()
+            .map(|c| c.as_os_str().to_string_lossy())
+            .collect::<Vec<_>>()
+            .join("/")
+    }
+
+    /// Autres services du workspace (hors service visé)
+    fn others(&self) -> impl Iterator<Item = &RustworkService> {
+        self.services
+            .iter()
+            .filter(move |s| s.path != self.service.path)
+    }
+
+    fn shared_dir(&self) -> Option<PathBuf> {
+        Some(self.services_dir.join("shared")).filter(|dir| dir.is_dir())
+    }
+
+    /// README du workspace (racine et Backend/)
+    fn readmes(&self) -> Vec<PathBuf> {
+        let mut readmes = vec![self.root.join("README.md")];
+        if let Some(backend) = self.services_dir.parent().filter(|dir| *dir != self.root) {
+            readmes.push(backend.join("README.md"));
+        }
+        readmes
+    }
+
+    /// Fichiers de configuration VS Code du workspace
+    fn vscode_files(&self) -> Vec<PathBuf> {
+        files_with_extension(&self.root.join(".vscode"), "json")
+    }
+
+    fn display(&self, path: &Path) -> String {
+        path.strip_prefix(&self.root)
+            .unwrap_or(path)
+            .display()
+            .to_string()
+    }
+}
+
+/// Nom du client généré par `rustwork make client <service>`
+fn client_name(service: &str) -> String {
+    format!("{}Client", to_pascal_case(service))
+}
+
+/// Fichiers d'extension donnée, récursivement (hors `target/`)
+fn files_with_extension(dir: &Path, extension: &str) -> Vec<PathBuf> {
+    let mut files = Vec::new();
+    let Ok(entries) = std::fs::read_dir(dir) else {
+        return files;
+    };
+
+    let mut paths: Vec<PathBuf> = entries.flatten().map(|e| e.path()).collect();
+    paths.sort();
+    for path in paths {
+        if path.is_dir() {
+            if path.file_name().is_some_and(|n| n != "target") {
+                files.extend(files_with_extension(&path, extension));
+            }
+        } else if path.extension().is_some_and(|e| e == extension) {
+            files.push(path);
+        }
+    }
+
+    files
+}
+
+/// Valide un nom de service (mêmes règles que `rustwork new`)
+fn validate_service_name(name: &str) -> Result<()> {
+    if name == "shared" {
+        anyhow::bail!("'shared' is a reserved name for the shared library");
+    }
+    if !is_valid_service_name(name) {
+        anyhow::bail!(
+            "Invalid service name: '{}'\n\
+             Service names must be lowercase alphanumeric with underscores only.",
+            name
+        );
+    }
+
+    Ok(())
+}
+
+#[cfg(test)]
+pub(crate) mod test_support {
+    use std::fs;
+    use std::path::Path;
+
+    /// Workspace `Backend/services/{users,billing,shared}` avec un client `users` utilisé par billing
+    pub fn workspace(root: &Path) {
+        fs::write(root.join("README.md"), "# demo\n\n## Services\n\n- `Backend/services/users` - users service\n- `Backend/services/billing` - billing service\n").unwrap();
+        fs::create_dir_all(root.join(".vscode")).unwrap();
+        fs::write(
+            root.join(".vscode/settings.json"),
+            "{\n  \"rust-analyzer.linkedProjects\": [\"Backend/services/users/Cargo.toml\"]\n}\n",
+        )
+        .unwrap();
+        fs::create_dir_all(root.join("Backend")).unwrap();
+        fs::write(
+            root.join("Backend/Cargo.toml"),
+            "[workspace]\nresolver = \"2\"\nmembers = [\n    \"services/users\",\n    \"services/billing\",\n    \"services/shared\",\n]\n",
+        )
+        .unwrap();
+        fs::write(
+            root.join("Backend/README.md"),
+            "# Backend\n\n## Services\n\n- **users** - users service\n- **billing** - billing service\n\n## Structure\n\n```\nBackend/\n└── services/\n    ├── users/\n    ├── billing/\n```\n",
+        )
+        .unwrap();
+
+        for service in ["users", "billing"] {
+            let path = root.join("Backend/services").join(service);
+            fs::create_dir_all(path.join("src/controllers")).unwrap();
+            fs::create_dir_all(path.join(".rustwork")).unwrap();
+            fs::create_dir_all(path.join("config")).unwrap();
+            fs::create_dir_all(path.join("grpc")).unwrap();
+            fs::write(path.join(".rustwork/manifest.json"), "{}").unwrap();
+            let dependency = if service == "billing" {
+                "users = { path = \"../users\" }\n"
+            } else {
+                ""
+            };
+            fs::write(
+                path.join("Cargo.toml"),
+                format!("[package]\nname = \"{}\"\nversion = \"0.1.0\"\n\n[dependencies]\nshared = {{ path = \"../shared\" }}\n{}", service, dependency),
+            )
+            .unwrap();
+            fs::write(
+                path.join("src/main.rs"),
+                format!(
+                    "fn main() {{\n    tracing::info!(\"Starting {} service...\");\n}}\n",
+                    service
+                ),
+            )
+            .unwrap();
+            fs::write(
+                path.join(".env.example"),
+                format!("# RUST_LOG=info,{}=debug\n", service),
+            )
+            .unwrap();
+        }
+        fs::write(
+            root.join("Backend/services/users/grpc/users.rwk"),
+            "service Users {\n  rpc GetUser(GetUserRequest) returns (User)\n}\n",
+        )
+        .unwrap();
+        fs::write(
+            root.join("Backend/services/billing/grpc/users.rwk"),
+            "service Users {\n  rpc GetUser(GetUserRequest) returns (User)\n}\n",
+        )
+        .unwrap();
+        fs::write(
+            root.join("Backend/services/billing/src/controllers/invoices.rs"),
+            "use shared::clients::users::UsersClient;\n\npub fn client() -> UsersClient {\n    UsersClient::new(\"http://localhost:3001\")\n}\n",
+        )
+        .unwrap();
+
+        fs::create_dir_all(root.join(".rustwork")).unwrap();
+        fs::write(
+            root.join(".rustwork/ports.json"),
+            "{\n  \"services\": {\n    \"billing\": { \"http\": 3002, \"grpc\": 50052 },\n    \"users\": { \"http\": 3001, \"grpc\": 50051 }\n  }\n}\n",
+        )
+        .unwrap();
+
+        let shared = root.join("Backend/services/shared");
+        fs::create_dir_all(shared.join("src/clients")).unwrap();
+        fs::write(shared.join("Cargo.toml"), "[package]\nname = \"shared\"\n").unwrap();
+        fs::write(shared.join("src/lib.rs"), "pub mod clients;\n").unwrap();
+        fs::write(
+            shared.join("src/clients/mod.rs"),
+            "//! Typed HTTP clients generated by `rustwork make client`\n\nmod support;\n\npub mod users;\n",
+        )
+        .unwrap();
+        fs::write(
+            shared.join("src/clients/users.rs"),
+            "//! HTTP client for the `users` service\n\npub struct UsersClient;\n",
+        )
+        .unwrap();
+    }
+}
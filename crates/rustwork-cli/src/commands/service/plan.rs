@@ -0,0 +1,139 @@
+use anyhow::{Context, Result};
+use std::io::{BufRead, IsTerminal, Write};
+use std::path::{Path, PathBuf};
+
+use crate::templates::output;
+
+/// Modifications calculées avant toute écriture, affichées puis appliquées
+#[derive(Debug, Default)]
+pub struct Plan {
+    /// Fichiers modifiés : (chemin, avant, après)
+    edits: Vec<(PathBuf, String, String)>,
+    /// Fichiers ou dossiers déplacés : (avant, après)
+    moves: Vec<(PathBuf, PathBuf)>,
+    /// Fichiers ou dossiers supprimés
+    removals: Vec<PathBuf>,
+    /// Références à corriger à la main
+    warnings: Vec<String>,
+}
+
+impl Plan {
+    /// Remplace le contenu d'un fichier (ignoré s'il est inchangé ou absent)
+    pub fn edit(&mut self, path: &Path, edit: impl FnOnce(&str) -> String) -> Result<()> {
+        let (original, before) = match self.edits.iter().position(|(p, _, _)| p == path) {
+            Some(index) => {
+                let (_, original, after) = self.edits.remove(index);
+                (original, after)
+            }
+            None if path.is_file() => {
+                let content = std::fs::read_to_string(path)
+                    .with_context(|| format!("Failed to read {}", path.display()))?;
+                (content.clone(), content)
+            }
+            None => return Ok(()),
+        };
+
+        let after = edit(&before);
+        if after != original {
+            self.edits.push((path.to_path_buf(), original, after));
+        }
+
+        Ok(())
+    }
+
+    pub fn rename(&mut self, from: &Path, to: &Path) {
+        self.moves.push((from.to_path_buf(), to.to_path_buf()));
+    }
+
+    pub fn remove(&mut self, path: &Path) {
+        self.removals.push(path.to_path_buf());
+    }
+
+    pub fn warn(&mut self, message: String) {
+        self.warnings.push(message);
+    }
+
+    #[cfg(test)]
+    pub fn warnings(&self) -> &[String] {
+        &self.warnings
+    }
+
+    /// Liste chaque fichier touché, avec son diff
+    pub fn preview(&self, root: &Path) {
+        let display = |path: &Path| {
+            path.strip_prefix(root)
+                .unwrap_or(path)
+                .display()
+                .to_string()
+        };
+
+        println!("📋 Affected files:");
+        for (path, before, after) in &self.edits {
+            println!("  ✏️  {}", display(path));
+            for line in output::unified_diff(before, after).lines() {
+                println!("      {}", line);
+            }
+        }
+        for (from, to) in &self.moves {
+            println!("  📁 {} → {}", display(from), display(to));
+        }
+        for path in &self.removals {
+            println!("  🗑️  {}", display(path));
+        }
+
+        if !self.warnings.is_empty() {
+            println!();
+            println!("⚠️  Update these references manually:");
+            for warning in &self.warnings {
+                println!("  - {}", warning);
+            }
+        }
+        println!();
+    }
+
+    /// Demande confirmation (sauf `--yes`) ; hors terminal, `--yes` est obligatoire
+    pub fn confirm(&self, yes: bool) -> Result<bool> {
+        if yes {
+            return Ok(true);
+        }
+        if !std::io::stdin().is_terminal() {
+            anyhow::bail!("Refusing to modify the workspace without confirmation. Pass --yes");
+        }
+
+        print!("Apply these changes? [y/N] ");
+        std::io::stdout().flush()?;
+        let mut answer = String::new();
+        std::io::stdin().lock().read_line(&mut answer)?;
+
+        Ok(matches!(answer.trim(), "y" | "Y" | "yes"))
+    }
+
+    /// Applique les modifications, puis les déplacements et les suppressions
+    pub fn apply(&self) -> Result<()> {
+        for (path, _, after) in &self.edits {
+            output::update(path, after)?;
+        }
+
+        for (from, to) in &self.moves {
+            if let Some(parent) = to.parent() {
+                std::fs::create_dir_all(parent)?;
+            }
+            std::fs::rename(from, to).with_context(|| {
+                format!("Failed to move {} to {}", from.display(), to.display())
+            })?;
+            println!("  Moved: {} → {}", from.display(), to.display());
+        }
+
+        for path in &self.removals {
+            if path.is_dir() {
+                std::fs::remove_dir_all(path)
+            } else {
+                std::fs::remove_file(path)
+            }
+            .with_context(|| format!("Failed to remove {}", path.display()))?;
+            println!("  Removed: {}", path.display());
+        }
+
+        Ok(())
+    }
+}
@@ -0,0 +1,321 @@
+//! Réécritures textuelles des références à un service
+
+use crate::commands::utils::to_pascal_case;
+
+/// Remplace ou retire `"services/<name>"` dans `members` d'un Cargo.toml de workspace
+///
+/// Avec `new_member: None`, l'entrée est retirée (ligne entière si elle est seule).
+pub fn rewrite_member(content: &str, member: &str, new_member: Option<&str>) -> String {
+    let quoted = format!("\"{}\"", member);
+    let mut lines = Vec::new();
+
+    for line in content.lines() {
+        if !line.contains(&quoted) {
+            lines.push(line.to_string());
+            continue;
+        }
+
+        match new_member {
+            Some(new_member) => lines.push(line.replace(&quoted, &format!("\"{}\"", new_member))),
+            None => {
+                let trimmed = line.trim().trim_end_matches(',');
+                if trimmed == quoted {
+                    continue;
+                }
+                let line = line
+                    .replace(&format!("{}, ", quoted), "")
+                    .replace(&format!(", {}", quoted), "")
+                    .replace(&format!("{},", quoted), "")
+                    .replace(&quoted, "");
+                lines.push(line);
+            }
+        }
+    }
+
+    lines.join("\n") + "\n"
+}
+
+/// Ajoute `"services/<name>"` à la fin de `members` (sans doublon)
+pub fn add_member(content: &str, member: &str) -> String {
+    let quoted = format!("\"{}\"", member);
+    if content.contains(&quoted) {
+        return content.to_string();
+    }
+
+    let mut lines: Vec<String> = content.lines().map(|s| s.to_string()).collect();
+    let Some(start) = lines
+        .iter()
+        .position(|l| l.trim_start().starts_with("members"))
+    else {
+        return content.to_string();
+    };
+
+    // `members = ["a", "b"]` sur une ligne
+    if lines[start].trim_end().ends_with(']') {
+        let line = lines[start].trim_end();
+        let open = line.find('[').unwrap_or(0);
+        let inner = line[open + 1..line.len() - 1].trim().trim_end_matches(',');
+        lines[start] = if inner.is_empty() {
+            format!("{}[{}]", &line[..open], quoted)
+        } else {
+            format!("{}[{}, {}]", &line[..open], inner, quoted)
+        };
+    } else if let Some(end) = lines[start..]
+        .iter()
+        .position(|l| l.trim_start().starts_with(']'))
+    {
+        lines.insert(start + end, format!("    {},", quoted));
+    }
+
+    lines.join("\n") + "\n"
+}
+
+/// Remplace un identifiant entier (bornes : tout sauf `[A-Za-z0-9_]`)
+pub fn replace_word(content: &str, from: &str, to: &str) -> String {
+    if from.is_empty() {
+        return content.to_string();
+    }
+
+    let is_word = |c: char| c.is_ascii_alphanumeric() || c == '_';
+    let mut result = String::with_capacity(content.len());
+    let mut rest = content;
+
+    while let Some(index) = rest.find(from) {
+        let before = rest[..index].chars().next_back();
+        let after = rest[index + from.len()..].chars().next();
+        result.push_str(&rest[..index]);
+        if before.is_some_and(is_word) || after.is_some_and(is_word) {
+            result.push_str(from);
+        } else {
+            result.push_str(to);
+        }
+        rest = &rest[index + from.len()..];
+    }
+    result.push_str(rest);
+
+    result
+}
+
+/// Vrai si `content` contient `word` comme identifiant entier
+pub fn contains_word(content: &str, word: &str) -> bool {
+    replace_word(content, word, "\u{0}") != content
+}
+
+/// Lignes (numérotées à partir de 1) contenant `word` comme identifiant entier
+pub fn word_lines(content: &str, word: &str) -> Vec<usize> {
+    content
+        .lines()
+        .enumerate()
+        .filter(|(_, line)| contains_word(line, word))
+        .map(|(i, _)| i + 1)
+        .collect()
+}
+
+/// Retire les lignes contenant un des motifs
+pub fn remove_lines(content: &str, patterns: &[String]) -> String {
+    let kept: Vec<&str> = content
+        .lines()
+        .filter(|line| !patterns.iter().any(|p| line.contains(p.as_str())))
+        .collect();
+
+    kept.join("\n") + "\n"
+}
+
+/// Retire d'un fichier JSON les chaînes qui mentionnent `word`
+///
+/// Éléments de tableau et entrées `"clé": "valeur"` seuls sur leur ligne (la virgule de
+/// l'élément devenu dernier est retirée), ou éléments d'un tableau de chaînes sur une ligne.
+pub fn remove_json_refs(content: &str, word: &str) -> String {
+    let mut lines: Vec<String> = Vec::new();
+
+    for line in content.lines() {
+        if !contains_word(line, word) {
+            lines.push(line.to_string());
+            continue;
+        }
+
+        if is_json_string_entry(line.trim().trim_end_matches(',')) {
+            if !line.trim_end().ends_with(',') {
+                if let Some(previous) = lines.last_mut() {
+                    if let Some(stripped) = previous.trim_end().strip_suffix(',') {
+                        *previous = stripped.to_string();
+                    }
+                }
+            }
+            continue;
+        }
+
+        lines.push(remove_inline_json_refs(line, word));
+    }
+
+    lines.join("\n") + "\n"
+}
+
+/// `"valeur"` ou `"clé": "valeur"`
+fn is_json_string_entry(entry: &str) -> bool {
+    let value = match entry.split_once("\": ") {
+        Some((key, value)) => {
+            if serde_json::from_str::<String>(&format!("{}\"", key)).is_err() {
+                return false;
+            }
+            value
+        }
+        None => entry,
+    };
+    serde_json::from_str::<String>(value).is_ok()
+}
+
+/// `["a", "b"]` sur une ligne : retire les éléments qui mentionnent `word`
+fn remove_inline_json_refs(line: &str, word: &str) -> String {
+    let (Some(open), Some(close)) = (line.find('['), line.rfind(']')) else {
+        return line.to_string();
+    };
+    if close < open {
+        return line.to_string();
+    }
+    let Ok(items) = serde_json::from_str::<Vec<String>>(&line[open..=close]) else {
+        return line.to_string();
+    };
+
+    let kept: Vec<String> = items
+        .iter()
+        .filter(|item| !contains_word(item, word))
+        .map(|item| serde_json::Value::from(item.as_str()).to_string())
+        .collect();
+    format!(
+        "{}[{}]{}",
+        &line[..open],
+        kept.join(", "),
+        &line[close + 1..]
+    )
+}
+
+/// Renomme la déclaration `service <Name>` d'un contrat `.rwk` (`Users` ou `UsersService`)
+pub fn rename_contract_service(content: &str, name: &str, new_name: &str) -> String {
+    let (service, new_service) = (to_pascal_case(name), to_pascal_case(new_name));
+    let mut lines = Vec::new();
+
+    for line in content.lines() {
+        if line.trim_start().starts_with("service ") {
+            let line = replace_word(line, &service, &new_service);
+            lines.push(replace_word(
+                &line,
+                &format!("{}Service", service),
+                &format!("{}Service", new_service),
+            ));
+        } else {
+            lines.push(line.to_string());
+        }
+    }
+
+    lines.join("\n") + "\n"
+}
+
+/// Retire les `use` d'un module `clients::<name>` de la librairie shared
+pub fn remove_client_imports(content: &str, name: &str) -> String {
+    let (module, path) = (
+        format!("clients::{};", name),
+        format!("clients::{}::", name),
+    );
+    let kept: Vec<&str> = content
+        .lines()
+        .filter(|line| {
+            let trimmed = line.trim_start();
+            let import = trimmed.starts_with("use ") || trimmed.starts_with("pub use ");
+            !(import && (trimmed.contains(&module) || trimmed.contains(&path)))
+        })
+        .collect();
+
+    kept.join("\n") + "\n"
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const WORKSPACE: &str = "[workspace]\nresolver = \"2\"\nmembers = [\n    \"services/users\",\n    \"services/billing\",\n    \"services/shared\",\n]\n";
+
+    #[test]
+    fn test_rewrite_member() {
+        let renamed = rewrite_member(WORKSPACE, "services/users", Some("services/accounts"));
+        assert!(renamed.contains("    \"services/accounts\",\n    \"services/billing\","));
+
+        let removed = rewrite_member(WORKSPACE, "services/users", None);
+        assert_eq!(
+            removed,
+            "[workspace]\nresolver = \"2\"\nmembers = [\n    \"services/billing\",\n    \"services/shared\",\n]\n"
+        );
+
+        let inline = "[workspace]\nmembers = [\"services/users\", \"services/billing\"]\n";
+        assert_eq!(
+            rewrite_member(inline, "services/users", None),
+            "[workspace]\nmembers = [\"services/billing\"]\n"
+        );
+    }
+
+    #[test]
+    fn test_add_member() {
+        let added = add_member(WORKSPACE, "services/orders");
+        assert!(added.contains("    \"services/shared\",\n    \"services/orders\",\n]\n"));
+        assert!(added.starts_with("[workspace]\n"));
+        assert_eq!(add_member(&added, "services/orders"), added);
+
+        let inline = "[workspace]\nmembers = [\"services/users\"]\n";
+        assert_eq!(
+            add_member(inline, "services/orders"),
+            "[workspace]\nmembers = [\"services/users\", \"services/orders\"]\n"
+        );
+    }
+
+    #[test]
+    fn test_replace_word() {
+        let source =
+            "use shared::clients::users::UsersClient;\nlet users_count = users::count();\n";
+        assert_eq!(
+            replace_word(source, "users", "accounts"),
+            "use shared::clients::accounts::UsersClient;\nlet users_count = accounts::count();\n"
+        );
+        assert_eq!(word_lines(source, "UsersClient"), vec![1]);
+        assert!(!contains_word(source, "user"));
+    }
+
+    #[test]
+    fn test_remove_json_refs() {
+        let settings = "{\n  \"rust-analyzer.linkedProjects\": [\n    \"Backend/services/billing/Cargo.toml\",\n    \"Backend/services/users/Cargo.toml\"\n  ],\n  \"users.path\": \"Backend/services/users\",\n  \"files.exclude\": { \"**/target\": true }\n}\n";
+        assert_eq!(
+            remove_json_refs(settings, "services/users"),
+            "{\n  \"rust-analyzer.linkedProjects\": [\n    \"Backend/services/billing/Cargo.toml\"\n  ],\n  \"files.exclude\": { \"**/target\": true }\n}\n"
+        );
+
+        let inline = "{\n  \"linked\": [\"services/users/Cargo.toml\", \"services/users_api/Cargo.toml\"]\n}\n";
+        assert_eq!(
+            remove_json_refs(inline, "services/users"),
+            "{\n  \"linked\": [\"services/users_api/Cargo.toml\"]\n}\n"
+        );
+    }
+
+    #[test]
+    fn test_rename_contract_service() {
+        assert_eq!(
+            rename_contract_service(
+                "service UsersService\n\nrpc GetUser (Req) returns (Users)\n",
+                "users",
+                "accounts"
+            ),
+            "service AccountsService\n\nrpc GetUser (Req) returns (Users)\n"
+        );
+        assert_eq!(
+            rename_contract_service("service Users {\n}\n", "users", "accounts"),
+            "service Accounts {\n}\n"
+        );
+    }
+
+    #[test]
+    fn test_remove_client_imports() {
+        let source = "use shared::clients::users::UsersClient;\nuse shared::clients::users_api;\n\nfn f() {}\n";
+        assert_eq!(
+            remove_client_imports(source, "users"),
+            "use shared::clients::users_api;\n\nfn f() {}\n"
+        );
+    }
+}
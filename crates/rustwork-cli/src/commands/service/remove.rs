@@ -0,0 +1,192 @@
+use anyhow::Result;
+
+use super::plan::Plan;
+use super::refs::{
+    remove_client_imports, remove_json_refs, remove_lines, rewrite_member, word_lines,
+};
+use super::{client_name, files_with_extension, ServiceWorkspace};
+use crate::commands::ports::{edit_registry, PortRegistry};
+use crate::templates::output;
+
+/// Commande `rustwork remove-service <name>`
+///
+/// Retire le service du workspace Cargo, des README, de la configuration VS Code,
+/// des autres services (dépendance, contrat gRPC, imports du client) et de la
+/// librairie shared, après avoir affiché chaque fichier touché.
+pub async fn execute(name: &str, project: Option<&str>, yes: bool) -> Result<()> {
+    let workspace = ServiceWorkspace::load(name, project)?;
+
+    println!("🗑️  Removing service '{}'", name);
+    println!("   Workspace: {}", workspace.root.display());
+    println!();
+
+    let plan = plan_removal(&workspace)?;
+    plan.preview(&workspace.root);
+
+    if output::is_dry_run() {
+        return Ok(());
+    }
+    if !plan.confirm(yes)? {
+        println!("❌ Aborted.");
+        return Ok(());
+    }
+
+    plan.apply()?;
+
+    println!();
+    println!("✅ Service '{}' removed.", name);
+    Ok(())
+}
+
+fn plan_removal(workspace: &ServiceWorkspace) -> Result<Plan> {
+    let name = workspace.service.name.as_str();
+    let client = client_name(name);
+    let mut plan = Plan::default();
+
+    // Workspace Cargo
+    let member = workspace.member(name);
+    plan.edit(&workspace.cargo_dir.join("Cargo.toml"), |content| {
+        rewrite_member(content, &member, None)
+    })?;
+
+    // README : lignes de liste et d'arborescence du service
+    let patterns = vec![
+        format!("services/{}`", name),
+        format!("services/{}/", name),
+        format!("**{}**", name),
+        format!("── {}/", name),
+    ];
+    for readme in workspace.readmes() {
+        plan.edit(&readme, |content| remove_lines(content, &patterns))?;
+    }
+
+    // Configuration VS Code : entrées qui désignent le service
+    let path_ref = format!("services/{}", name);
+    for file in workspace.vscode_files() {
+        let content = std::fs::read_to_string(&file)?;
+        let updated = remove_json_refs(&content, &path_ref);
+        for line in word_lines(&updated, &path_ref) {
+            plan.warn(format!(
+                "{}:{} points to the removed service",
+                workspace.display(&file),
+                line
+            ));
+        }
+        plan.edit(&file, |_| updated)?;
+    }
+
+    // Autres services : dépendance Cargo, contrats gRPC, clients shared
+    let dependency = format!("\"../{}\"", name);
+    for other in workspace.others() {
+        plan.edit(&other.path.join("Cargo.toml"), |content| {
+            remove_lines(content, std::slice::from_ref(&dependency))
+        })?;
+
+        let contract = other.path.join("grpc").join(format!("{}.rwk", name));
+        if contract.is_file() {
+            plan.remove(&contract);
+        }
+
+        for file in files_with_extension(&other.path.join("src"), "rs") {
+            let content = std::fs::read_to_string(&file)?;
+            let updated = remove_client_imports(&content, name);
+            let mut lines = word_lines(&updated, &client);
+            lines.extend(word_lines(&updated, &format!("clients::{}", name)));
+            lines.sort();
+            lines.dedup();
+            for line in lines {
+                plan.warn(format!(
+                    "{}:{} uses the shared client {}",
+                    workspace.display(&file),
+                    line,
+                    client
+                ));
+            }
+            plan.edit(&file, |_| updated)?;
+        }
+    }
+
+    if let Some(shared) = workspace.shared_dir() {
+        let client_file = shared.join("src/clients").join(format!("{}.rs", name));
+        if client_file.is_file() {
+            let declaration = format!("pub mod {};", name);
+            plan.edit(&shared.join("src/clients/mod.rs"), |content| {
+                remove_lines(content, &[declaration])
+            })?;
+            plan.remove(&client_file);
+        }
+    }
+
+    // Registre des ports : libère ceux du service
+    plan.edit(&PortRegistry::path(&workspace.root), |content| {
+        edit_registry(content, |registry| {
+            registry.services.remove(name);
+        })
+    })?;
+
+    plan.remove(&workspace.service.path);
+
+    Ok(plan)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::commands::service::test_support;
+    use tempfile::TempDir;
+
+    #[test]
+    fn test_plan_removal() {
+        let dir = TempDir::new().unwrap();
+        test_support::workspace(dir.path());
+
+        let workspace = ServiceWorkspace::load_from(dir.path(), "users", None).unwrap();
+        let plan = plan_removal(&workspace).unwrap();
+        plan.apply().unwrap();
+
+        let backend = dir.path().join("Backend");
+        assert!(!backend.join("services/users").exists());
+        assert!(backend.join("services/billing").exists());
+        assert!(!backend
+            .join("services/shared/src/clients/users.rs")
+            .exists());
+
+        let members = std::fs::read_to_string(backend.join("Cargo.toml")).unwrap();
+        assert!(!members.contains("services/users"));
+        assert!(members.contains("    \"services/billing\",\n    \"services/shared\","));
+
+        let readme = std::fs::read_to_string(backend.join("README.md")).unwrap();
+        assert!(!readme.contains("users"));
+        assert!(readme.contains("- **billing** - billing service"));
+        assert!(readme.contains("    ├── billing/"));
+
+        let clients =
+            std::fs::read_to_string(backend.join("services/shared/src/clients/mod.rs")).unwrap();
+        assert!(!clients.contains("pub mod users;"));
+        assert!(clients.contains("mod support;"));
+
+        let ports = std::fs::read_to_string(dir.path().join(".rustwork/ports.json")).unwrap();
+        assert!(!ports.contains("\"users\""));
+        assert!(ports.contains("\"billing\""));
+
+        // Contrat gRPC, dépendance, import du client et config VS Code retirés
+        assert!(!backend.join("services/billing/grpc/users.rwk").exists());
+        let billing = std::fs::read_to_string(backend.join("services/billing/Cargo.toml")).unwrap();
+        assert!(!billing.contains("../users"));
+        assert!(billing.contains("shared = { path = \"../shared\" }"));
+        let invoices =
+            std::fs::read_to_string(backend.join("services/billing/src/controllers/invoices.rs"))
+                .unwrap();
+        assert!(!invoices.contains("use shared::clients::users"));
+        let settings = std::fs::read_to_string(dir.path().join(".vscode/settings.json")).unwrap();
+        assert_eq!(settings, "{\n  \"rust-analyzer.linkedProjects\": []\n}\n");
+
+        // Les appels restants au client sont signalés
+        let warnings = plan.warnings().join("\n");
+        assert_eq!(
+            warnings,
+            "Backend/services/billing/src/controllers/invoices.rs:2 uses the shared client UsersClient\n\
+             Backend/services/billing/src/controllers/invoices.rs:3 uses the shared client UsersClient"
+        );
+    }
+}
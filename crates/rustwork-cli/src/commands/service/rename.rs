@@ -0,0 +1,277 @@
+use anyhow::Result;
+
+use super::plan::Plan;
+use super::refs::{contains_word, rename_contract_service, replace_word, rewrite_member};
+use super::{client_name, files_with_extension, validate_service_name, ServiceWorkspace};
+use crate::commands::ports::{edit_registry, PortRegistry};
+use crate::templates::output;
+
+/// Chaînes générées à partir du nom du service dans ses propres fichiers
+const SERVICE_NAME_PATTERNS: &[&str] = &[
+    "name = \"{}\"",
+    "\"{}=debug",
+    ",{}=debug",
+    "Starting {} service",
+    "{} service listening",
+    "service: \"{}\".to_string()",
+    "# {}\n",
+];
+
+/// Fichiers du service générés avec son nom
+const SERVICE_NAME_FILES: &[&str] = &[
+    "Cargo.toml",
+    "src/main.rs",
+    "src/controllers/health.rs",
+    ".env.example",
+    "README.md",
+];
+
+/// Commande `rustwork rename-service <name> <new-name>`
+///
+/// Renomme le dossier du service et met à jour chaque référence du workspace,
+/// après avoir affiché chaque fichier touché.
+pub async fn execute(name: &str, new_name: &str, project: Option<&str>, yes: bool) -> Result<()> {
+    let new_name = new_name.trim().to_lowercase();
+    validate_service_name(&new_name)?;
+
+    let workspace = ServiceWorkspace::load(name, project)?;
+    if workspace.services_dir.join(&new_name).exists() {
+        anyhow::bail!("Service '{}' already exists in this workspace", new_name);
+    }
+
+    println!("✏️  Renaming service '{}' to '{}'", name, new_name);
+    println!("   Workspace: {}", workspace.root.display());
+    println!();
+
+    let plan = plan_rename(&workspace, &new_name)?;
+    plan.preview(&workspace.root);
+
+    if output::is_dry_run() {
+        return Ok(());
+    }
+    if !plan.confirm(yes)? {
+        println!("❌ Aborted.");
+        return Ok(());
+    }
+
+    plan.apply()?;
+
+    println!();
+    println!("✅ Service '{}' renamed to '{}'.", name, new_name);
+    println!();
+    println!("🚀 Next steps:");
+    println!("   rustwork grpc build   # if the service exposes gRPC contracts");
+    println!("   cargo build           # from the Cargo workspace");
+
+    Ok(())
+}
+
+fn plan_rename(workspace: &ServiceWorkspace, new_name: &str) -> Result<Plan> {
+    let name = workspace.service.name.as_str();
+    let (client, new_client) = (client_name(name), client_name(new_name));
+    let (path_ref, new_path_ref) = (
+        format!("services/{}", name),
+        format!("services/{}", new_name),
+    );
+    let mut plan = Plan::default();
+
+    // Workspace Cargo
+    let (member, new_member) = (workspace.member(name), workspace.member(new_name));
+    plan.edit(&workspace.cargo_dir.join("Cargo.toml"), |content| {
+        rewrite_member(content, &member, Some(&new_member))
+    })?;
+
+    // README : lignes qui désignent le service
+    let markers = [
+        format!("{}`", path_ref),
+        format!("{}/", path_ref),
+        format!("**{}**", name),
+        format!("── {}/", name),
+    ];
+    for readme in workspace.readmes() {
+        plan.edit(&readme, |content| {
+            map_lines(content, |line| {
+                if markers.iter().any(|m| line.contains(m.as_str())) {
+                    replace_word(line, name, new_name)
+                } else {
+                    line.to_string()
+                }
+            })
+        })?;
+    }
+
+    // Configuration VS Code
+    for file in workspace.vscode_files() {
+        plan.edit(&file, |content| {
+            replace_word(content, &path_ref, &new_path_ref)
+        })?;
+    }
+
+    // Autres services : dépendance Cargo, contrats gRPC, clients shared
+    let (dependency, new_dependency) = (format!("\"../{}\"", name), format!("\"../{}\"", new_name));
+    for other in workspace.others() {
+        plan.edit(&other.path.join("Cargo.toml"), |content| {
+            map_lines(content, |line| {
+                if line.contains(&dependency) {
+                    replace_word(line, name, new_name).replace(&dependency, &new_dependency)
+                } else {
+                    line.to_string()
+                }
+            })
+        })?;
+
+        for file in files_with_extension(&other.path.join("src"), "rs") {
+            let content = std::fs::read_to_string(&file)?;
+            if contains_word(&content, &client) || content.contains(&format!("clients::{}", name)) {
+                plan.edit(&file, |content| rename_client_refs(content, name, new_name))?;
+            }
+        }
+    }
+
+    for service in &workspace.services {
+        let contract = service.path.join("grpc").join(format!("{}.rwk", name));
+        if contract.is_file() {
+            plan.edit(&contract, |content| {
+                rename_contract_service(content, name, new_name)
+            })?;
+            plan.rename(
+                &contract,
+                &contract.with_file_name(format!("{}.rwk", new_name)),
+            );
+        }
+    }
+
+    if let Some(shared) = workspace.shared_dir() {
+        let clients_dir = shared.join("src/clients");
+        let client_file = clients_dir.join(format!("{}.rs", name));
+        if client_file.is_file() {
+            plan.edit(&clients_dir.join("mod.rs"), |content| {
+                content.replace(
+                    &format!("pub mod {};", name),
+                    &format!("pub mod {};", new_name),
+                )
+            })?;
+            plan.edit(&client_file, |content| {
+                replace_word(content, &client, &new_client)
+                    .replace(&format!("`{}`", name), &format!("`{}`", new_name))
+                    .replace(
+                        &format!("make client {}", name),
+                        &format!("make client {}", new_name),
+                    )
+            })?;
+            plan.rename(&client_file, &clients_dir.join(format!("{}.rs", new_name)));
+        }
+    }
+
+    // Registre des ports : le service garde les siens
+    plan.edit(&PortRegistry::path(&workspace.root), |content| {
+        edit_registry(content, |registry| {
+            if let Some(ports) = registry.services.remove(name) {
+                registry.services.insert(new_name.to_string(), ports);
+            }
+        })
+    })?;
+
+    // Le service lui-même : nom du package, logs, health check, README
+    for file in SERVICE_NAME_FILES {
+        plan.edit(&workspace.service.path.join(file), |content| {
+            SERVICE_NAME_PATTERNS
+                .iter()
+                .fold(content.to_string(), |content, pattern| {
+                    content.replace(
+                        &pattern.replace("{}", name),
+                        &pattern.replace("{}", new_name),
+                    )
+                })
+        })?;
+    }
+    plan.rename(
+        &workspace.service.path,
+        &workspace.services_dir.join(new_name),
+    );
+
+    Ok(plan)
+}
+
+/// `clients::<name>` et `<Name>Client` dans le code d'un autre service
+fn rename_client_refs(content: &str, name: &str, new_name: &str) -> String {
+    replace_word(content, &client_name(name), &client_name(new_name)).replace(
+        &format!("clients::{}::", name),
+        &format!("clients::{}::", new_name),
+    )
+}
+
+fn map_lines(content: &str, f: impl Fn(&str) -> String) -> String {
+    let mut result: String = content.lines().map(|line| f(line) + "\n").collect();
+    if !content.ends_with('\n') {
+        result.pop();
+    }
+    result
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::commands::service::test_support;
+    use tempfile::TempDir;
+
+    #[test]
+    fn test_plan_rename() {
+        let dir = TempDir::new().unwrap();
+        test_support::workspace(dir.path());
+
+        let workspace = ServiceWorkspace::load_from(dir.path(), "users", None).unwrap();
+        let plan = plan_rename(&workspace, "accounts").unwrap();
+        plan.apply().unwrap();
+
+        let backend = dir.path().join("Backend");
+        let read = |path: &str| std::fs::read_to_string(backend.join(path)).unwrap();
+        assert!(!backend.join("services/users").exists());
+
+        assert!(
+            read("Cargo.toml").contains("    \"services/accounts\",\n    \"services/billing\",")
+        );
+        assert!(read("README.md").contains("- **accounts** - accounts service"));
+        assert!(read("README.md").contains("    ├── accounts/"));
+        assert!(std::fs::read_to_string(dir.path().join("README.md"))
+            .unwrap()
+            .contains("- `Backend/services/accounts` - accounts service"));
+        assert!(
+            std::fs::read_to_string(dir.path().join(".vscode/settings.json"))
+                .unwrap()
+                .contains("Backend/services/accounts/Cargo.toml")
+        );
+
+        // Service renommé
+        assert!(read("services/accounts/Cargo.toml").contains("name = \"accounts\""));
+        assert!(read("services/accounts/Cargo.toml").contains("path = \"../shared\""));
+        assert!(read("services/accounts/src/main.rs").contains("Starting accounts service"));
+        assert!(read("services/accounts/.env.example").contains("info,accounts=debug"));
+        assert_eq!(
+            read("services/accounts/grpc/accounts.rwk"),
+            "service Accounts {\n  rpc GetUser(GetUserRequest) returns (User)\n}\n"
+        );
+
+        // Références des autres services et du client shared
+        assert!(read("services/billing/grpc/accounts.rwk").starts_with("service Accounts {\n"));
+        assert!(!backend.join("services/billing/grpc/users.rwk").exists());
+        assert!(
+            read("services/billing/Cargo.toml").contains("accounts = { path = \"../accounts\" }")
+        );
+        assert_eq!(
+            read("services/billing/src/controllers/invoices.rs"),
+            "use shared::clients::accounts::AccountsClient;\n\npub fn client() -> AccountsClient {\n    AccountsClient::new(\"http://localhost:3001\")\n}\n"
+        );
+        assert!(read("services/shared/src/clients/mod.rs").contains("pub mod accounts;\n"));
+        assert_eq!(
+            read("services/shared/src/clients/accounts.rs"),
+            "//! HTTP client for the `accounts` service\n\npub struct AccountsClient;\n"
+        );
+
+        // Ports conservés sous le nouveau nom
+        let ports = std::fs::read_to_string(dir.path().join(".rustwork/ports.json")).unwrap();
+        let registry = crate::commands::ports::PortRegistry::parse(&ports).unwrap();
+        assert_eq!(registry.services["accounts"].http, 3001);
+        assert!(!registry.services.contains_key("users"));
+    }
+}
@@ -0,0 +1,82 @@
+use anyhow::{Context, Result};
+use std::path::{Path, PathBuf};
+
+use crate::mcp::common::workspace_root::WorkspaceRoot;
+use crate::templates::micro::TEMPLATES;
+use crate::templates::output;
+use crate::templates::overrides::{override_dirs, user_templates_dir, TEMPLATES_DIR};
+
+/// Commande `rustwork templates list`
+pub fn list() -> Result<()> {
+    let current_dir = std::env::current_dir()?;
+    let dirs = override_dirs(&current_dir);
+
+    println!("📄 Templates:");
+    for (name, _) in TEMPLATES {
+        match dirs.iter().rev().find(|dir| dir.join(name).is_file()) {
+            Some(dir) => println!("  {} (overridden in {})", name, dir.display()),
+            None => println!("  {}", name),
+        }
+    }
+
+    Ok(())
+}
+
+/// Commande `rustwork templates eject <name>...`
+///
+/// Copie les templates intégrés dans `.rustwork/templates/` du workspace
+/// (ou dans le dossier utilisateur avec `--user`) pour les modifier.
+pub fn eject(names: &[String], user: bool, project: Option<String>) -> Result<()> {
+    let templates = names
+        .iter()
+        .map(|name| builtin(name))
+        .collect::<Result<Vec<_>>>()?;
+
+    let target = if user {
+        user_templates_dir()
+            .context("Cannot determine the user config directory (HOME is not set)")?
+    } else {
+        let current_dir = std::env::current_dir()?;
+        let explicit = project.map(PathBuf::from);
+        WorkspaceRoot::detect_with_explicit(&current_dir, explicit.as_deref())?
+            .path()
+            .join(TEMPLATES_DIR)
+    };
+
+    println!("📤 Ejecting templates to {}", target.display());
+    for (name, source) in templates {
+        output::create(&target.join(name), source)?;
+    }
+
+    println!("✅ Generators now use these files instead of the built-in templates.");
+    Ok(())
+}
+
+/// Template intégré par nom (`controller.rs`, ou `controller` sans extension)
+fn builtin(name: &str) -> Result<(&'static str, &'static str)> {
+    TEMPLATES
+        .iter()
+        .find(|(builtin, _)| {
+            *builtin == name || Path::new(builtin).file_stem() == Some(name.as_ref())
+        })
+        .copied()
+        .with_context(|| {
+            format!(
+                "Unknown template '{}'. Run 'rustwork templates list' to see the available templates",
+                name
+            )
+        })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_builtin_lookup() {
+        let (name, source) = builtin("controller").unwrap();
+        assert_eq!(name, "controller.rs");
+        assert!(source.contains("AppResult"));
+        assert!(builtin("unknown.rs").is_err());
+    }
+}
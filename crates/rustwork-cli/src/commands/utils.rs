@@ -1,3 +1,4 @@
+use rustwork::DbConnection;
 use std::path::{Path, PathBuf};
 
 /// Représente un service Rustwork détecté
@@ -8,7 +9,7 @@ pub struct RustworkService {
 }
 
 /// Vérifie si un dossier est un service Rustwork valide
-fn is_valid_rustwork_service(path: &Path) -> bool {
+pub(crate) fn is_valid_rustwork_service(path: &Path) -> bool {
     path.join(".rustwork/manifest.json").exists()
         && path.join("Cargo.toml").exists()
         && path.join("src/main.rs").exists()
@@ -39,18 +40,16 @@ pub fn detect_rustwork_services(workspace_root: &Path) -> anyhow::Result<Vec<Rus
     }
 
     // If still empty, try scanning directly (for backward compatibility)
-    if services.is_empty() {
-        if is_valid_rustwork_service(workspace_root) {
-            let name = workspace_root
-                .file_name()
-                .and_then(|n| n.to_str())
-                .unwrap_or("service")
-                .to_string();
-            services.push(RustworkService {
-                name,
-                path: workspace_root.to_path_buf(),
-            });
-        }
+    if services.is_empty() && is_valid_rustwork_service(workspace_root) {
+        let name = workspace_root
+            .file_name()
+            .and_then(|n| n.to_str())
+            .unwrap_or("service")
+            .to_string();
+        services.push(RustworkService {
+            name,
+            path: workspace_root.to_path_buf(),
+        });
     }
 
     Ok(services)
@@ -85,19 +84,66 @@ fn scan_services_directory(
     Ok(())
 }
 
-/// Convertit PascalCase en snake_case
-pub fn to_snake_case(s: &str) -> String {
-    let mut result = String::new();
-    let chars = s.chars();
+/// Port HTTP d'un service (`[server] port` de `config/default.toml`)
+pub fn read_service_port(service_path: &Path) -> Option<i64> {
+    let content = std::fs::read_to_string(service_path.join("config/default.toml")).ok()?;
+    let config: toml::Value = toml::from_str(&content).ok()?;
+    config.get("server")?.get("port")?.as_integer()
+}
 
-    for c in chars {
-        if c.is_uppercase() && !result.is_empty() {
-            result.push('_');
-        }
-        result.push(c.to_lowercase().next().unwrap());
+/// Base de données d'un service (`[database]` de `config/default.toml`, SQLite par défaut)
+pub fn read_service_db_connection(service_path: &Path) -> DbConnection {
+    std::fs::read_to_string(service_path.join("config/default.toml"))
+        .map(|content| db_connection_from_config(&content))
+        .unwrap_or(DbConnection::Sqlite)
+}
+
+/// Schéma de `database.url`, sinon `database.connection` (SQLite par défaut)
+pub fn db_connection_from_config(content: &str) -> DbConnection {
+    let Ok(config) = content.parse::<toml::Table>() else {
+        return DbConnection::Sqlite;
+    };
+    let database = config.get("database");
+
+    let from_url = database
+        .and_then(|db| db.get("url"))
+        .and_then(|url| url.as_str())
+        .and_then(|url| url.split(':').next())
+        .and_then(|scheme| scheme.parse().ok());
+    let from_connection = database
+        .and_then(|db| db.get("connection"))
+        .and_then(|connection| connection.as_str())
+        .and_then(|connection| connection.parse().ok());
+
+    from_url.or(from_connection).unwrap_or(DbConnection::Sqlite)
+}
+
+/// Préfixe (relatif au workspace) des chemins de fichiers d'un service
+///
+/// Utilisé pour filtrer les résultats de `scan_routes` / `scan_project`,
+/// dont les chemins sont relatifs à la racine du workspace.
+pub fn service_source_prefix(workspace_root: &Path, service_path: &Path) -> String {
+    let relative = service_path
+        .strip_prefix(workspace_root)
+        .unwrap_or(service_path)
+        .to_string_lossy()
+        .to_string();
+
+    if relative.is_empty() {
+        relative
+    } else {
+        format!("{}/", relative)
     }
+}
+
+/// Convertit PascalCase en snake_case (voir [`crate::inflection`])
+pub fn to_snake_case(s: &str) -> String {
+    crate::inflection::to_snake_case(s)
+}
 
-    result
+/// Convertit snake_case en PascalCase (voir [`crate::inflection`])
+pub fn to_pascal_case(s: &str) -> String {
+    crate::inflection::to_pascal_case(s)
 }
 
 /// Vérifie si on est dans un projet Rustwork (présence de Cargo.toml avec rustwork)
@@ -114,6 +160,9 @@ pub fn is_rustwork_project() -> bool {
 
 /// Crée un fichier si le dossier parent n'existe pas
 pub async fn ensure_parent_dir(path: &Path) -> anyhow::Result<()> {
+    if crate::templates::output::is_dry_run() {
+        return Ok(());
+    }
     if let Some(parent) = path.parent() {
         tokio::fs::create_dir_all(parent).await?;
     }
@@ -128,7 +177,7 @@ mod tests {
     fn test_to_snake_case_simple() {
         assert_eq!(to_snake_case("HelloWorld"), "hello_world");
         assert_eq!(to_snake_case("User"), "user");
-        assert_eq!(to_snake_case("APIKey"), "a_p_i_key");
+        assert_eq!(to_snake_case("APIKey"), "api_key");
     }
 
     #[test]
@@ -139,8 +188,8 @@ mod tests {
 
     #[test]
     fn test_to_snake_case_consecutive_capitals() {
-        assert_eq!(to_snake_case("HTTPRequest"), "h_t_t_p_request");
-        assert_eq!(to_snake_case("XMLParser"), "x_m_l_parser");
+        assert_eq!(to_snake_case("HTTPRequest"), "http_request");
+        assert_eq!(to_snake_case("XMLParser"), "xml_parser");
     }
 
     #[test]
@@ -149,6 +198,12 @@ mod tests {
         assert_eq!(to_snake_case("X"), "x");
     }
 
+    #[test]
+    fn test_to_pascal_case() {
+        assert_eq!(to_pascal_case("blog_post"), "BlogPost");
+        assert_eq!(to_pascal_case("user"), "User");
+    }
+
     #[test]
     fn test_to_snake_case_empty() {
         assert_eq!(to_snake_case(""), "");
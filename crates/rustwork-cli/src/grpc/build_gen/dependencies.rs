@@ -1,6 +1,8 @@
 use std::fs;
 use std::path::Path;
 
+use crate::templates::output;
+
 /// Vérifie les dépendances requises dans Cargo.toml
 #[allow(dead_code)]
 pub fn check_dependencies(project_root: &Path) -> Result<(), String> {
@@ -52,7 +54,7 @@ pub fn add_grpc_dependencies(project_root: &Path) -> Result<(), String> {
         return Err("Cargo.toml introuvable".to_string());
     }
 
-    let content = fs::read_to_string(&cargo_toml_path)
+    let content = output::read_to_string(&cargo_toml_path)
         .map_err(|e| format!("Erreur lecture Cargo.toml: {}", e))?;
 
     let mut lines: Vec<String> = content.lines().map(|s| s.to_string()).collect();
@@ -129,9 +131,8 @@ pub fn add_grpc_dependencies(project_root: &Path) -> Result<(), String> {
 
     if modified {
         let new_content = lines.join("\n") + "\n";
-        fs::write(&cargo_toml_path, new_content)
+        output::update(&cargo_toml_path, &new_content)
             .map_err(|e| format!("Erreur écriture Cargo.toml: {}", e))?;
-        println!("✓ Dépendances gRPC ajoutées au Cargo.toml");
     }
 
     Ok(())
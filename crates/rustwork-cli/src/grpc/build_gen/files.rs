@@ -1,4 +1,5 @@
 use super::content::{generate_build_rs_content, generate_service_build_rs_content};
+use crate::templates::output;
 use std::fs;
 use std::path::Path;
 
@@ -10,8 +11,8 @@ pub fn generate_service_build_rs(
     let build_rs_path = service_path.join("build.rs");
 
     // Vérifier si build.rs existe déjà
-    if build_rs_path.exists() {
-        let content = fs::read_to_string(&build_rs_path)?;
+    if output::exists(&build_rs_path) {
+        let content = output::read_to_string(&build_rs_path)?;
 
         // Si c'est un build.rs géré par Rustwork, on peut le mettre à jour
         if !content.contains("Généré automatiquement par Rustwork") {
@@ -25,8 +26,11 @@ pub fn generate_service_build_rs(
     }
 
     // Créer/mettre à jour build.rs
-    fs::write(&build_rs_path, generate_service_build_rs_content(proto_dir))?;
-    println!("  ✓ build.rs généré");
+    output::update(
+        &build_rs_path,
+        &generate_service_build_rs_content(proto_dir),
+    )
+    .map_err(|e| std::io::Error::other(e.to_string()))?;
 
     Ok(())
 }
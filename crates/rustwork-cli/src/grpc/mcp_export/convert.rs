@@ -1,5 +1,6 @@
 use super::types::{FieldExport, GrpcContractExport, MessageExport, RpcExport};
 use crate::grpc::ast::Contract;
+use crate::inflection::to_snake_case;
 use std::path::Path;
 
 /// Convertit un contrat en export
@@ -43,25 +44,3 @@ pub(crate) fn contract_to_export(contract: &Contract, file_path: &Path) -> GrpcC
         file_path: file_path.display().to_string(),
     }
 }
-
-/// Convertit un nom PascalCase en snake_case
-#[allow(dead_code)]
-fn to_snake_case(s: &str) -> String {
-    let mut result = String::new();
-    let mut prev_is_lower = false;
-
-    for (i, c) in s.chars().enumerate() {
-        if c.is_uppercase() {
-            if i > 0 && prev_is_lower {
-                result.push('_');
-            }
-            result.push(c.to_ascii_lowercase());
-            prev_is_lower = false;
-        } else {
-            result.push(c);
-            prev_is_lower = c.is_lowercase();
-        }
-    }
-
-    result
-}
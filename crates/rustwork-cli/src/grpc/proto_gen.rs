@@ -1,5 +1,6 @@
 /// Générateur de fichiers .proto à partir de l'AST
 use super::ast::{Contract, Field, Message, Rpc, Service};
+use crate::inflection::to_snake_case;
 use std::fmt::Write;
 
 /// Génère un fichier .proto complet à partir d'un contrat
@@ -88,26 +89,6 @@ fn generate_field(
     Ok(())
 }
 
-/// Convertit un nom PascalCase ou camelCase en snake_case
-fn to_snake_case(s: &str) -> String {
-    let mut result = String::new();
-    let chars: Vec<char> = s.chars().collect();
-
-    for (i, &c) in chars.iter().enumerate() {
-        if c.is_uppercase() {
-            // Ajouter underscore sauf pour le premier caractère
-            if i > 0 {
-                result.push('_');
-            }
-            result.push(c.to_lowercase().next().unwrap());
-        } else {
-            result.push(c);
-        }
-    }
-
-    result
-}
-
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -117,7 +98,7 @@ mod tests {
     fn test_to_snake_case() {
         assert_eq!(to_snake_case("UserService"), "user_service");
         assert_eq!(to_snake_case("GetUser"), "get_user");
-        assert_eq!(to_snake_case("HTTPRequest"), "h_t_t_p_request");
+        assert_eq!(to_snake_case("HTTPRequest"), "http_request");
     }
 
     #[test]
@@ -1,26 +1,6 @@
+pub(crate) use crate::inflection::to_snake_case;
 use std::fmt::Write;
 
-/// Convertit un nom PascalCase en snake_case
-pub(crate) fn to_snake_case(s: &str) -> String {
-    let mut result = String::new();
-    let mut prev_is_lower = false;
-
-    for (i, c) in s.chars().enumerate() {
-        if c.is_uppercase() {
-            if i > 0 && prev_is_lower {
-                result.push('_');
-            }
-            result.push(c.to_ascii_lowercase());
-            prev_is_lower = false;
-        } else {
-            result.push(c);
-            prev_is_lower = c.is_lowercase();
-        }
-    }
-
-    result
-}
-
 /// Génère le fichier mod.rs pour le module grpc
 pub fn generate_grpc_mod(service_names: &[String]) -> Result<String, std::fmt::Error> {
     let mut output = String::new();
@@ -0,0 +1,482 @@
+//! Inflexion des noms générés (pluriel/singulier anglais, snake/camel/Pascal/kebab case)
+//!
+//! Les règles intégrées couvrent les pluriels irréguliers et les indénombrables courants.
+//! Chaque projet peut les compléter avec `.rustwork/inflections.json` :
+//!
+//! ```json
+//! {
+//!   "irregular": { "cactus": "cacti" },
+//!   "uncountable": ["equipment"],
+//!   "acronyms": ["API", "HTTP"]
+//! }
+//! ```
+//!
+//! Le fichier est cherché dans le dossier courant et ses parents (service puis workspace) ;
+//! le plus proche l'emporte.
+
+use serde::Deserialize;
+use std::collections::HashMap;
+use std::path::Path;
+use std::sync::OnceLock;
+
+const IRREGULAR: &[(&str, &str)] = &[
+    ("person", "people"),
+    ("man", "men"),
+    ("woman", "women"),
+    ("child", "children"),
+    ("tooth", "teeth"),
+    ("foot", "feet"),
+    ("mouse", "mice"),
+    ("goose", "geese"),
+    ("ox", "oxen"),
+    ("leaf", "leaves"),
+    ("movie", "movies"),
+    ("cookie", "cookies"),
+    ("criterion", "criteria"),
+];
+
+const UNCOUNTABLE: &[&str] = &[
+    "data",
+    "metadata",
+    "equipment",
+    "information",
+    "feedback",
+    "software",
+    "money",
+    "rice",
+    "news",
+    "series",
+    "species",
+    "sheep",
+    "fish",
+    "deer",
+];
+
+/// Règles d'inflexion : intégrées + `.rustwork/inflections.json`
+#[derive(Debug, Clone)]
+pub struct Inflector {
+    irregular: Vec<(String, String)>,
+    uncountable: Vec<String>,
+    acronyms: Vec<String>,
+}
+
+/// Contenu de `.rustwork/inflections.json`
+#[derive(Debug, Default, Deserialize)]
+#[serde(default)]
+struct Overrides {
+    irregular: HashMap<String, String>,
+    uncountable: Vec<String>,
+    acronyms: Vec<String>,
+}
+
+impl Default for Inflector {
+    fn default() -> Self {
+        Self {
+            irregular: IRREGULAR
+                .iter()
+                .map(|(s, p)| (s.to_string(), p.to_string()))
+                .collect(),
+            uncountable: UNCOUNTABLE.iter().map(|w| w.to_string()).collect(),
+            acronyms: Vec::new(),
+        }
+    }
+}
+
+impl Inflector {
+    /// Règles intégrées complétées par les `.rustwork/inflections.json` de `start` et de ses parents
+    pub fn load(start: &Path) -> Self {
+        let mut inflector = Self::default();
+
+        let mut files: Vec<_> = start
+            .ancestors()
+            .map(|dir| dir.join(".rustwork/inflections.json"))
+            .filter(|path| path.is_file())
+            .collect();
+        files.reverse();
+
+        for path in files {
+            let overrides = std::fs::read_to_string(&path)
+                .ok()
+                .and_then(|content| serde_json::from_str::<Overrides>(&content).ok());
+            match overrides {
+                Some(overrides) => inflector.apply(overrides),
+                None => eprintln!("⚠️  Ignoring invalid {}", path.display()),
+            }
+        }
+
+        inflector
+    }
+
+    fn apply(&mut self, overrides: Overrides) {
+        for (singular, plural) in overrides.irregular {
+            let (singular, plural) = (singular.to_lowercase(), plural.to_lowercase());
+            self.irregular
+                .retain(|(s, p)| *s != singular && *p != plural);
+            self.uncountable.retain(|w| *w != singular && *w != plural);
+            self.irregular.insert(0, (singular, plural));
+        }
+        self.uncountable
+            .extend(overrides.uncountable.iter().map(|w| w.to_lowercase()));
+        self.acronyms
+            .extend(overrides.acronyms.iter().map(|a| a.to_uppercase()));
+    }
+
+    /// Pluriel du dernier mot : `blog_post` → `blog_posts`, `Category` → `Categories`
+    pub fn pluralize(&self, word: &str) -> String {
+        self.inflect_last_word(word, |w| self.plural_word(w))
+    }
+
+    /// Singulier du dernier mot : `blog_posts` → `blog_post`, `people` → `person`
+    pub fn singularize(&self, word: &str) -> String {
+        self.inflect_last_word(word, |w| self.singular_word(w))
+    }
+
+    /// `blog_post` → `BlogPost` ; les acronymes déclarés restent en capitales
+    pub fn to_pascal_case(&self, s: &str) -> String {
+        words(s).iter().map(|w| self.capitalize(w)).collect()
+    }
+
+    /// `blog_post` → `blogPost`
+    pub fn to_camel_case(&self, s: &str) -> String {
+        words(s)
+            .iter()
+            .enumerate()
+            .map(|(i, w)| {
+                if i == 0 {
+                    w.to_lowercase()
+                } else {
+                    self.capitalize(w)
+                }
+            })
+            .collect()
+    }
+
+    fn capitalize(&self, word: &str) -> String {
+        let upper = word.to_uppercase();
+        if self.acronyms.contains(&upper) {
+            return upper;
+        }
+        let lower = word.to_lowercase();
+        let mut chars = lower.chars();
+        match chars.next() {
+            Some(first) => first.to_uppercase().chain(chars).collect(),
+            None => String::new(),
+        }
+    }
+
+    /// Applique `inflect` au dernier mot en gardant le séparateur et la casse de l'original
+    fn inflect_last_word(&self, word: &str, inflect: impl Fn(&str) -> String) -> String {
+        let start = last_word_start(word);
+        let (head, last) = word.split_at(start);
+        if last.is_empty() {
+            return word.to_string();
+        }
+
+        let inflected = inflect(&last.to_lowercase());
+        let inflected = if last.chars().all(|c| !c.is_lowercase()) && last.len() > 1 {
+            inflected.to_uppercase()
+        } else if last.starts_with(char::is_uppercase) {
+            self.capitalize(&inflected)
+        } else {
+            inflected
+        };
+
+        format!("{}{}", head, inflected)
+    }
+
+    fn plural_word(&self, word: &str) -> String {
+        if self.uncountable.iter().any(|w| w == word) {
+            return word.to_string();
+        }
+        if let Some((_, plural)) = self.irregular.iter().find(|(s, p)| s == word || p == word) {
+            return plural.clone();
+        }
+
+        if word.ends_with("quiz") {
+            return format!("{}zes", word);
+        }
+        for (suffix, replacement) in [
+            ("matrix", "matrices"),
+            ("vertex", "vertices"),
+            ("index", "indices"),
+        ] {
+            if let Some(stem) = word.strip_suffix(suffix) {
+                return format!("{}{}", stem, replacement);
+            }
+        }
+        if let Some(stem) = word.strip_suffix("sis") {
+            return format!("{}ses", stem);
+        }
+        if ["s", "x", "z", "ch", "sh"]
+            .iter()
+            .any(|s| word.ends_with(s))
+        {
+            return format!("{}es", word);
+        }
+        if let Some(stem) = word.strip_suffix('y') {
+            if stem.ends_with("qu") || stem.ends_with(|c: char| !"aeiou".contains(c)) {
+                return format!("{}ies", stem);
+            }
+        }
+        if let Some(stem) = word.strip_suffix("fe") {
+            if ["kni", "wi", "li"].iter().any(|s| stem.ends_with(s)) {
+                return format!("{}ves", stem);
+            }
+        }
+        if let Some(stem) = word.strip_suffix("lf") {
+            if !["go", "gu"].iter().any(|s| stem.ends_with(s)) {
+                return format!("{}lves", stem);
+            }
+        }
+        if ["tomato", "potato", "hero", "echo"]
+            .iter()
+            .any(|w| word.ends_with(w))
+        {
+            return format!("{}es", word);
+        }
+
+        format!("{}s", word)
+    }
+
+    fn singular_word(&self, word: &str) -> String {
+        if self.uncountable.iter().any(|w| w == word) {
+            return word.to_string();
+        }
+        if let Some((singular, _)) = self.irregular.iter().find(|(s, p)| p == word || s == word) {
+            return singular.clone();
+        }
+
+        if let Some(stem) = word.strip_suffix("zzes") {
+            return format!("{}z", stem);
+        }
+        for (suffix, replacement) in [
+            ("matrices", "matrix"),
+            ("vertices", "vertex"),
+            ("indices", "index"),
+        ] {
+            if let Some(stem) = word.strip_suffix(suffix) {
+                return format!("{}{}", stem, replacement);
+            }
+        }
+        for stem in [
+            "analy", "ba", "diagno", "parenthe", "progno", "synop", "the",
+        ] {
+            if word.ends_with(&format!("{}ses", stem)) {
+                return format!("{}is", &word[..word.len() - 2]);
+            }
+        }
+        for stem in [
+            "alias", "status", "bus", "campus", "virus", "census", "bonus",
+        ] {
+            if word.ends_with(&format!("{}es", stem)) {
+                return word[..word.len() - 2].to_string();
+            }
+        }
+        if ["ss", "us", "is"].iter().any(|s| word.ends_with(s)) {
+            return word.to_string();
+        }
+        if let Some(stem) = word.strip_suffix("ies") {
+            if !stem.is_empty() {
+                return format!("{}y", stem);
+            }
+        }
+        if ["xes", "ches", "shes", "sses", "zes"]
+            .iter()
+            .any(|s| word.ends_with(s))
+        {
+            return word[..word.len() - 2].to_string();
+        }
+        if let Some(stem) = word.strip_suffix("ves") {
+            if ["hal", "shel", "wol", "sel", "cal", "el"]
+                .iter()
+                .any(|s| stem.ends_with(s))
+            {
+                return format!("{}f", stem);
+            }
+            if ["kni", "wi", "li"].iter().any(|s| stem.ends_with(s)) {
+                return format!("{}fe", stem);
+            }
+        }
+        if ["tomatoes", "potatoes", "heroes", "echoes"]
+            .iter()
+            .any(|w| word.ends_with(w))
+        {
+            return word[..word.len() - 2].to_string();
+        }
+        if let Some(stem) = word.strip_suffix('s') {
+            return stem.to_string();
+        }
+
+        word.to_string()
+    }
+}
+
+/// Règles du projet courant (chargées au premier appel)
+pub fn inflector() -> &'static Inflector {
+    static INFLECTOR: OnceLock<Inflector> = OnceLock::new();
+    INFLECTOR.get_or_init(|| {
+        std::env::current_dir()
+            .map(|dir| Inflector::load(&dir))
+            .unwrap_or_default()
+    })
+}
+
+pub fn pluralize(word: &str) -> String {
+    inflector().pluralize(word)
+}
+
+pub fn singularize(word: &str) -> String {
+    inflector().singularize(word)
+}
+
+/// `HTTPRequest` / `blogPost` / `blog-post` → `http_request` / `blog_post`
+pub fn to_snake_case(s: &str) -> String {
+    words(s)
+        .iter()
+        .map(|w| w.to_lowercase())
+        .collect::<Vec<_>>()
+        .join("_")
+}
+
+/// `BlogPost` → `blog-post`
+pub fn to_kebab_case(s: &str) -> String {
+    words(s)
+        .iter()
+        .map(|w| w.to_lowercase())
+        .collect::<Vec<_>>()
+        .join("-")
+}
+
+pub fn to_pascal_case(s: &str) -> String {
+    inflector().to_pascal_case(s)
+}
+
+pub fn to_camel_case(s: &str) -> String {
+    inflector().to_camel_case(s)
+}
+
+/// Découpe un identifiant en mots : séparateurs `_`, `-`, espace et changements de casse
+///
+/// Une suite de capitales est un acronyme : `HTTPRequest` → `HTTP`, `Request`.
+fn words(s: &str) -> Vec<&str> {
+    let mut words = Vec::new();
+    for part in s.split(['_', '-', ' ']).filter(|p| !p.is_empty()) {
+        let chars: Vec<(usize, char)> = part.char_indices().collect();
+        let mut start = 0;
+        for k in 1..chars.len() {
+            let (i, c) = chars[k];
+            let prev = chars[k - 1].1;
+            let next_is_lower = chars.get(k + 1).is_some_and(|(_, n)| n.is_lowercase());
+            let boundary = c.is_uppercase()
+                && (prev.is_lowercase()
+                    || prev.is_ascii_digit()
+                    || (prev.is_uppercase() && next_is_lower));
+            if boundary {
+                words.push(&part[start..i]);
+                start = i;
+            }
+        }
+        words.push(&part[start..]);
+    }
+    words
+}
+
+/// Début du dernier mot d'un identifiant (après le dernier séparateur ou changement de casse)
+fn last_word_start(s: &str) -> usize {
+    let last = words(s).last().copied().unwrap_or_default();
+    s.len() - last.len()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use tempfile::TempDir;
+
+    #[test]
+    fn test_pluralize() {
+        let inflector = Inflector::default();
+        for (singular, plural) in [
+            ("user", "users"),
+            ("category", "categories"),
+            ("day", "days"),
+            ("person", "people"),
+            ("child", "children"),
+            ("status", "statuses"),
+            ("address", "addresses"),
+            ("box", "boxes"),
+            ("match", "matches"),
+            ("knife", "knives"),
+            ("half", "halves"),
+            ("golf", "golfs"),
+            ("safe", "safes"),
+            ("valve", "valves"),
+            ("hero", "heroes"),
+            ("photo", "photos"),
+            ("analysis", "analyses"),
+            ("index", "indices"),
+            ("quiz", "quizzes"),
+            ("movie", "movies"),
+            ("equipment", "equipment"),
+            ("blog_post", "blog_posts"),
+            ("order_item", "order_items"),
+        ] {
+            assert_eq!(
+                inflector.pluralize(singular),
+                plural,
+                "pluralize({})",
+                singular
+            );
+            assert_eq!(
+                inflector.singularize(plural),
+                singular,
+                "singularize({})",
+                plural
+            );
+        }
+    }
+
+    #[test]
+    fn test_inflection_keeps_case_of_last_word() {
+        let inflector = Inflector::default();
+        assert_eq!(inflector.pluralize("Category"), "Categories");
+        assert_eq!(inflector.pluralize("BlogPerson"), "BlogPeople");
+        assert_eq!(inflector.singularize("SalesPeople"), "SalesPerson");
+        assert_eq!(inflector.singularize("house"), "house");
+        assert_eq!(inflector.singularize("houses"), "house");
+    }
+
+    #[test]
+    fn test_case_conversions() {
+        assert_eq!(to_snake_case("HTTPRequest"), "http_request");
+        assert_eq!(to_snake_case("XMLHttpRequest"), "xml_http_request");
+        assert_eq!(to_snake_case("userID"), "user_id");
+        assert_eq!(to_snake_case("Oauth2Token"), "oauth2_token");
+        assert_eq!(to_snake_case("auth-service"), "auth_service");
+        assert_eq!(to_kebab_case("BlogPost"), "blog-post");
+
+        let inflector = Inflector::default();
+        assert_eq!(inflector.to_pascal_case("http_request"), "HttpRequest");
+        assert_eq!(inflector.to_pascal_case("HTTPRequest"), "HttpRequest");
+        assert_eq!(inflector.to_camel_case("blog_post"), "blogPost");
+    }
+
+    #[test]
+    fn test_project_overrides() {
+        let dir = TempDir::new().unwrap();
+        std::fs::create_dir_all(dir.path().join(".rustwork")).unwrap();
+        std::fs::write(
+            dir.path().join(".rustwork/inflections.json"),
+            r#"{"irregular": {"cactus": "cacti"}, "uncountable": ["staff"], "acronyms": ["HTTP"]}"#,
+        )
+        .unwrap();
+        let service = dir.path().join("Backend/services/users");
+        std::fs::create_dir_all(&service).unwrap();
+
+        let inflector = Inflector::load(&service);
+        assert_eq!(inflector.pluralize("cactus"), "cacti");
+        assert_eq!(inflector.singularize("cacti"), "cactus");
+        assert_eq!(inflector.pluralize("staff"), "staff");
+        assert_eq!(inflector.to_pascal_case("http_request"), "HTTPRequest");
+        assert_eq!(inflector.to_camel_case("http_request"), "httpRequest");
+    }
+}
@@ -3,5 +3,6 @@
 
 pub mod commands;
 pub mod grpc;
+pub mod inflection;
 pub mod mcp;
 pub mod templates;
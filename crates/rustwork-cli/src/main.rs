@@ -1,8 +1,10 @@
 use anyhow::Result;
 use clap::{Parser, Subcommand};
+use templates::output::{ConflictPolicy, OutputOptions};
 
 mod commands;
 mod grpc;
+mod inflection;
 mod mcp;
 mod templates;
 
@@ -13,6 +15,15 @@ mod templates;
 struct Cli {
     #[command(subcommand)]
     command: Commands,
+    /// Print the files that would be created or modified, with diffs, without writing anything
+    #[arg(long, global = true)]
+    dry_run: bool,
+    /// Overwrite existing files (same as --on-conflict overwrite)
+    #[arg(long, global = true)]
+    force: bool,
+    /// What to do when a generated file already exists
+    #[arg(long, global = true, value_enum, default_value_t = ConflictPolicy::Skip)]
+    on_conflict: ConflictPolicy,
 }
 
 #[derive(Subcommand)]
@@ -31,6 +42,8 @@ enum Commands {
         /// Skip creating the shared library
         #[arg(long)]
         no_shared: bool,
+        #[command(flatten)]
+        starter: StarterArgs,
     },
     /// Add a new service to an existing Rustwork workspace
     AddService {
@@ -39,6 +52,32 @@ enum Commands {
         /// Path to the workspace root (default: auto-detect from current directory)
         #[arg(long)]
         project: Option<String>,
+        #[command(flatten)]
+        starter: StarterArgs,
+    },
+    /// Remove a service and its references from the workspace
+    RemoveService {
+        /// Name of the service to remove
+        name: String,
+        /// Path to the workspace root (default: auto-detect from current directory)
+        #[arg(long)]
+        project: Option<String>,
+        /// Apply the changes without asking for confirmation
+        #[arg(long, short = 'y')]
+        yes: bool,
+    },
+    /// Rename a service and update its references in the workspace
+    RenameService {
+        /// Current name of the service
+        name: String,
+        /// New name of the service
+        new_name: String,
+        /// Path to the workspace root (default: auto-detect from current directory)
+        #[arg(long)]
+        project: Option<String>,
+        /// Apply the changes without asking for confirmation
+        #[arg(long, short = 'y')]
+        yes: bool,
     },
     /// Generate code from templates
     Make {
@@ -53,6 +92,30 @@ enum Commands {
         /// Explicit path to the workspace root (optional)
         #[arg(long)]
         path: Option<String>,
+        /// Only start these services (comma-separated, e.g. auth,user)
+        #[arg(long, value_delimiter = ',', conflicts_with = "except")]
+        only: Vec<String>,
+        /// Start every service except these (comma-separated)
+        #[arg(long, value_delimiter = ',')]
+        except: Vec<String>,
+        /// Serve every service behind one port: /<service>/... (default port: 8080)
+        #[arg(long, value_name = "PORT", num_args = 0..=1, default_missing_value = "8080")]
+        gateway: Option<u16>,
+        /// Move conflicting services to free ports instead of refusing to start
+        #[arg(long)]
+        remap_ports: bool,
+        /// Print the service status table every N seconds (0 to disable)
+        #[arg(long, value_name = "SECS", default_value_t = 30)]
+        status_interval: u64,
+        /// Hide service logs below this level (trace, debug, info, warn, error)
+        #[arg(long, value_name = "LEVEL")]
+        log_level: Option<commands::dev::LogLevel>,
+        /// Only show log lines containing this text (case-insensitive, request ids included)
+        #[arg(long, value_name = "TEXT")]
+        grep: Option<String>,
+        /// Append every service log line to this file as JSON Lines
+        #[arg(long, value_name = "FILE")]
+        log_file: Option<std::path::PathBuf>,
     },
     /// Start MCP (Model Context Protocol) server for IDE integration
     Mcp {
@@ -74,11 +137,67 @@ enum Commands {
         #[command(subcommand)]
         action: GrpcAction,
     },
+    /// Generate OpenAPI 3.1 documents from routes, handlers and DTOs
+    Openapi {
+        /// Only generate the document for this service
+        #[arg(long)]
+        service: Option<String>,
+        /// Output file (default: <service>/openapi.json)
+        #[arg(long, short)]
+        output: Option<String>,
+        /// Path to the workspace root (default: auto-detect from current directory)
+        #[arg(long)]
+        project: Option<String>,
+    },
+    /// Deployment artifacts
+    Deploy {
+        #[command(subcommand)]
+        action: DeployAction,
+    },
+    /// Check the toolchain, workspace structure, dependencies and databases
+    Doctor {
+        /// Path to the workspace root (default: auto-detect from current directory)
+        #[arg(long)]
+        project: Option<String>,
+        /// Print the report as JSON (for CI)
+        #[arg(long)]
+        json: bool,
+    },
+    /// Database commands
+    Db {
+        #[command(subcommand)]
+        command: DbCommand,
+    },
     /// Manage Rustwork conventions
     Conventions {
         #[command(subcommand)]
         action: ConventionsAction,
     },
+    /// Customize the templates used by the generators
+    Templates {
+        #[command(subcommand)]
+        action: TemplatesAction,
+    },
+}
+
+#[derive(Subcommand)]
+enum TemplatesAction {
+    /// List the built-in templates and their overrides
+    List,
+    /// Copy built-in templates to .rustwork/templates/ for editing
+    ///
+    /// Usage: rustwork templates eject controller.rs model.rs
+    Eject {
+        /// Template names (see `rustwork templates list`)
+        #[arg(required = true)]
+        names: Vec<String>,
+        /// Eject to the user-level directory (~/.config/rustwork/templates) instead of the workspace
+        #[arg(long)]
+        user: bool,
+        /// Path to the workspace (default: auto-detected)
+        #[arg(long, conflicts_with = "user")]
+        project: Option<String>,
+    },
 }
 
 #[derive(Subcommand)]
@@ -91,6 +210,25 @@ enum ConventionsAction {
     },
 }
 
+#[derive(Subcommand)]
+enum DbCommand {
+    /// Run the seeders of each service (src/seeders/)
+    Seed {
+        /// Only run this seeder (and its dependencies)
+        #[arg(long)]
+        class: Option<String>,
+        /// Only seed this service
+        #[arg(long)]
+        service: Option<String>,
+        /// Profile used to load the config and filter seeders (default: APP_ENV or dev)
+        #[arg(long)]
+        profile: Option<String>,
+        /// Path to the workspace root (default: auto-detect from current directory)
+        #[arg(long)]
+        project: Option<String>,
+    },
+}
+
 #[derive(Subcommand)]
 enum GrpcAction {
     /// Build gRPC services from .rwk files
@@ -101,45 +239,285 @@ enum GrpcAction {
     },
 }
 
+#[derive(Subcommand)]
+enum DeployAction {
+    /// Generate a Dockerfile per service, .dockerignore and docker-compose.yml
+    Generate {
+        /// Path to the workspace root (default: auto-detect from current directory)
+        #[arg(long)]
+        project: Option<String>,
+    },
+    /// Generate Kubernetes manifests with a kustomize overlay per profile
+    K8s {
+        /// Path to the workspace root (default: auto-detect from current directory)
+        #[arg(long)]
+        project: Option<String>,
+        /// Also generate a HorizontalPodAutoscaler per service
+        #[arg(long)]
+        hpa: bool,
+    },
+}
+
 #[derive(Subcommand)]
 enum Generator {
     /// Generate a new controller
     Controller {
         /// Name of the controller (PascalCase)
         name: String,
+        #[command(flatten)]
+        target: ServiceTarget,
     },
-    /// Generate a new model
+    /// Generate a new model, its service and SQL migration
+    ///
+    /// Usage: rustwork make model Post title:string body:text? author_id:references(users)
     Model {
         /// Name of the model (PascalCase)
         name: String,
+        /// Fields as name:type[?][:unique][:index]
+        /// Types: string, text, integer, bigint, float, bool, date, datetime, uuid, json, references(<table>)
+        fields: Vec<String>,
+        /// Add a nullable deleted_at column
+        #[arg(long)]
+        soft_deletes: bool,
+        /// Use a UUID primary key instead of an auto-increment integer
+        #[arg(long)]
+        uuid_pk: bool,
+        #[command(flatten)]
+        target: ServiceTarget,
+    },
+    /// Generate a CRUD resource: model, migration, service, controller, routes and tests
+    ///
+    /// Usage: rustwork make scaffold Post title:string body:text published:bool
+    Scaffold {
+        /// Name of the resource (PascalCase)
+        name: String,
+        /// Fields as name:type[?][:unique][:index] (same DSL as `make model`)
+        fields: Vec<String>,
+        /// Add a nullable deleted_at column (DELETE becomes a soft delete)
+        #[arg(long)]
+        soft_deletes: bool,
+        /// Use a UUID primary key instead of an auto-increment integer
+        #[arg(long)]
+        uuid_pk: bool,
+        #[command(flatten)]
+        target: ServiceTarget,
+    },
+    /// Generate a typed HTTP client for a service into shared/
+    Client {
+        /// Name of the target service
+        service: String,
+        /// Path to the workspace (default: auto-detected)
+        #[arg(long)]
+        project: Option<String>,
+    },
+    /// Generate a new database seeder in src/seeders/
+    Seeder {
+        /// Name of the seeder (PascalCase, "Seeder" suffix added if missing)
+        name: String,
+        /// Only run the seeder for these profiles (comma-separated, e.g. dev,test)
+        #[arg(long, value_delimiter = ',')]
+        profile: Vec<String>,
+        #[command(flatten)]
+        target: ServiceTarget,
+    },
+    /// Generate a background job in src/jobs/ and start the service's job worker
+    Job {
+        /// Name of the job (PascalCase, e.g. SendWelcomeEmail)
+        name: String,
+        /// Queue of the job (default: "default")
+        #[arg(long)]
+        queue: Option<String>,
+        /// Attempts before the job is moved to the dead-letter queue
+        #[arg(long, default_value_t = 3)]
+        max_attempts: u32,
+        #[command(flatten)]
+        target: ServiceTarget,
     },
 }
 
+/// Service ciblé par un générateur
+#[derive(clap::Args)]
+struct ServiceTarget {
+    /// Target service (default: the service containing the current directory)
+    #[arg(long)]
+    service: Option<String>,
+    /// Path to the workspace (default: auto-detected)
+    #[arg(long, requires = "service")]
+    project: Option<String>,
+}
+
+impl ServiceTarget {
+    fn enter(&self) -> Result<()> {
+        commands::make::enter_service(self.service.as_deref(), self.project.as_deref())
+    }
+}
+
+/// Starter appliqué aux services créés
+#[derive(clap::Args)]
+struct StarterArgs {
+    /// Starter kit: api-gateway, worker, auth-service, a local directory or a git repository URL
+    #[arg(long)]
+    template: Option<String>,
+    /// Starter variable (repeatable), e.g. --var upstream_url=http://127.0.0.1:3001
+    #[arg(long = "var", value_name = "KEY=VALUE", value_parser = parse_var, requires = "template")]
+    vars: Vec<(String, String)>,
+}
+
+impl From<StarterArgs> for commands::new::StarterOptions {
+    fn from(args: StarterArgs) -> Self {
+        Self {
+            template: args.template,
+            vars: args.vars,
+        }
+    }
+}
+
+fn parse_var(value: &str) -> Result<(String, String), String> {
+    match value.split_once('=') {
+        Some((key, value)) if !key.trim().is_empty() => {
+            Ok((key.trim().to_string(), value.to_string()))
+        }
+        _ => Err(format!("expected KEY=VALUE, got '{}'", value)),
+    }
+}
+
 #[tokio::main]
 async fn main() -> Result<()> {
     let cli = Cli::parse();
 
+    templates::output::configure(OutputOptions {
+        dry_run: cli.dry_run,
+        on_conflict: if cli.force {
+            ConflictPolicy::Overwrite
+        } else {
+            cli.on_conflict
+        },
+    });
+    if cli.dry_run
+        && matches!(
+            cli.command,
+            Commands::Dev { .. }
+                | Commands::Mcp { .. }
+                | Commands::Db { .. }
+                | Commands::Doctor { .. }
+        )
+    {
+        anyhow::bail!("--dry-run is only supported by commands that generate files");
+    }
+
     match cli.command {
         Commands::New {
             services,
             no_shared,
+            starter,
+        } => {
+            commands::new::execute(services, !no_shared, starter.into()).await?;
+        }
+        Commands::AddService {
+            name,
+            project,
+            starter,
         } => {
-            commands::new::execute(services, !no_shared).await?;
+            commands::add_service::execute(&name, project.as_deref(), starter.into()).await?;
+        }
+        Commands::RemoveService { name, project, yes } => {
+            commands::service::remove_service(&name, project.as_deref(), yes).await?;
         }
-        Commands::AddService { name, project } => {
-            commands::add_service::execute(&name, project.as_deref()).await?;
+        Commands::RenameService {
+            name,
+            new_name,
+            project,
+            yes,
+        } => {
+            commands::service::rename_service(&name, &new_name, project.as_deref(), yes).await?;
         }
         Commands::Make { generator } => match generator {
-            Generator::Controller { name } => {
+            Generator::Controller { name, target } => {
+                target.enter()?;
                 commands::make_controller(&name).await?;
             }
-            Generator::Model { name } => {
-                commands::make_model(&name).await?;
+            Generator::Model {
+                name,
+                fields,
+                soft_deletes,
+                uuid_pk,
+                target,
+            } => {
+                target.enter()?;
+                let options = commands::make::ModelOptions {
+                    fields,
+                    soft_deletes,
+                    uuid_pk,
+                };
+                commands::make_model(&name, &options).await?;
+            }
+            Generator::Scaffold {
+                name,
+                fields,
+                soft_deletes,
+                uuid_pk,
+                target,
+            } => {
+                target.enter()?;
+                let options = commands::make::ModelOptions {
+                    fields,
+                    soft_deletes,
+                    uuid_pk,
+                };
+                commands::make_scaffold(&name, &options).await?;
+            }
+            Generator::Client { service, project } => {
+                commands::make_client(&service, project.as_deref()).await?;
+            }
+            Generator::Seeder {
+                name,
+                profile,
+                target,
+            } => {
+                target.enter()?;
+                commands::make_seeder(&name, &profile).await?;
+            }
+            Generator::Job {
+                name,
+                queue,
+                max_attempts,
+                target,
+            } => {
+                target.enter()?;
+                let options = commands::make::JobOptions {
+                    queue,
+                    max_attempts,
+                };
+                commands::make_job(&name, &options).await?;
             }
         },
-        Commands::Dev { mcp, path } => {
+        Commands::Dev {
+            mcp,
+            path,
+            only,
+            except,
+            gateway,
+            remap_ports,
+            status_interval,
+            log_level,
+            grep,
+            log_file,
+        } => {
             let explicit_path = path.as_deref().map(std::path::Path::new);
-            commands::dev::execute(mcp, explicit_path).await?;
+            let options = commands::dev::DevOptions {
+                mcp,
+                only,
+                except,
+                gateway,
+                remap_ports,
+                status_interval,
+                logs: commands::dev::LogOptions {
+                    level: log_level,
+                    grep,
+                    file: log_file,
+                },
+            };
+            commands::dev::execute(explicit_path, &options).await?;
         }
         Commands::Mcp {
             stdio,
@@ -163,12 +541,58 @@ async fn main() -> Result<()> {
                 commands::grpc_build::execute(project).await?;
             }
         },
+        Commands::Deploy { action } => match action {
+            DeployAction::Generate { project } => {
+                commands::deploy::generate(project).await?;
+            }
+            DeployAction::K8s { project, hpa } => {
+                commands::deploy::k8s(project, hpa).await?;
+            }
+        },
+        Commands::Openapi {
+            service,
+            output,
+            project,
+        } => {
+            commands::openapi::execute(project, service, output).await?;
+        }
+        Commands::Doctor { project, json } => {
+            commands::doctor::execute(project, json).await?;
+        }
+        Commands::Db { command } => match command {
+            DbCommand::Seed {
+                class,
+                service,
+                profile,
+                project,
+            } => {
+                commands::db::seed(commands::db::SeedOptions {
+                    class,
+                    service,
+                    profile,
+                    project,
+                })
+                .await?;
+            }
+        },
         Commands::Conventions { action } => match action {
             ConventionsAction::Init { project } => {
                 let project_path = project.map(std::path::PathBuf::from);
                 commands::conventions::conventions_init(project_path)?;
             }
         },
+        Commands::Templates { action } => match action {
+            TemplatesAction::List => commands::templates::list()?,
+            TemplatesAction::Eject {
+                names,
+                user,
+                project,
+            } => commands::templates::eject(&names, user, project)?,
+        },
+    }
+
+    if cli.dry_run {
+        println!("\n🔍 Dry run: no files were written.");
     }
 
     Ok(())
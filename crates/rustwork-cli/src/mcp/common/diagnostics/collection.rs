@@ -10,6 +10,12 @@ pub struct DiagnosticCollection {
     pub last_build_success: bool,
 }
 
+impl Default for DiagnosticCollection {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
 impl DiagnosticCollection {
     pub fn new() -> Self {
         Self {
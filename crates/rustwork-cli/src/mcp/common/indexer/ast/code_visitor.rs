@@ -29,6 +29,17 @@ impl CodeVisitor {
         quote::quote!(#ty).to_string()
     }
 
+    /// Nom d'un paramètre, y compris les extracteurs déstructurés (`Path(id): Path<i32>`)
+    fn extract_param_name(pat: &syn::Pat) -> String {
+        match pat {
+            syn::Pat::Ident(pat_ident) => pat_ident.ident.to_string(),
+            syn::Pat::TupleStruct(tuple_struct) if tuple_struct.elems.len() == 1 => {
+                Self::extract_param_name(&tuple_struct.elems[0])
+            }
+            _ => quote::quote!(#pat).to_string(),
+        }
+    }
+
     fn extract_return_type(output: &syn::ReturnType) -> Option<String> {
         match output {
             syn::ReturnType::Default => None,
@@ -74,12 +85,10 @@ impl<'ast> Visit<'ast> for CodeVisitor {
             .iter()
             .filter_map(|arg| {
                 if let syn::FnArg::Typed(pat_type) = arg {
-                    if let syn::Pat::Ident(pat_ident) = &*pat_type.pat {
-                        return Some(Parameter {
-                            name: pat_ident.ident.to_string(),
-                            type_name: Self::extract_type_string(&pat_type.ty),
-                        });
-                    }
+                    return Some(Parameter {
+                        name: Self::extract_param_name(&pat_type.pat),
+                        type_name: Self::extract_type_string(&pat_type.ty),
+                    });
                 }
                 None
             })
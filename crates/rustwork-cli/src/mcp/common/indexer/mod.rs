@@ -3,7 +3,7 @@ mod scan;
 mod types;
 
 pub use scan::scan_project;
-pub use types::{CodeIndex, IndexState, SourceFile};
+pub use types::{CodeIndex, FunctionInfo, IndexState, SourceFile, StructInfo};
 
 #[cfg(test)]
 mod tests {
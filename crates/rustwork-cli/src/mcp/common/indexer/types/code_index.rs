@@ -12,6 +12,12 @@ pub struct CodeIndex {
     pub last_scan: u64,
 }
 
+impl Default for CodeIndex {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
 impl CodeIndex {
     pub fn new() -> Self {
         Self {
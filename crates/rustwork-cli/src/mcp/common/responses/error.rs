@@ -49,7 +49,7 @@ impl McpError {
             code: "FUNCTION_NOT_FOUND".to_string(),
             message: format!("Function not found: {}", function),
             cause: Some("Function does not exist in indexed codebase".to_string()),
-            suggestion: Some("Request the tool `rustwork_get_diagnostics` to check the current cargo-watch and build status before retrying".to_string()),
+            suggestion: Some("Request the tool `rustwork_get_diagnostics` to check the current build status before retrying".to_string()),
         }
     }
 
@@ -58,7 +58,7 @@ impl McpError {
             code: "ROUTE_NOT_FOUND".to_string(),
             message: format!("Route not found: {} {}", method, path),
             cause: Some("Route does not exist in indexed routes".to_string()),
-            suggestion: Some("Request the tool `rustwork_get_diagnostics` to verify cargo-watch state and route scanning before retrying".to_string()),
+            suggestion: Some("Request the tool `rustwork_get_diagnostics` to verify the build state and route scanning before retrying".to_string()),
         }
     }
 
@@ -3,7 +3,7 @@ mod types;
 mod visitor;
 
 pub use scanner::scan_routes;
-pub use types::{HttpMethod, RouteRegistry};
+pub use types::{HttpMethod, RouteInfo, RouteRegistry};
 
 #[cfg(test)]
 mod tests {
@@ -37,6 +37,30 @@ mod tests {
         assert_eq!(visitor.routes[1].handler, "list_users");
     }
 
+    #[test]
+    fn test_parse_chained_method_router() {
+        let code = r#"
+            pub fn create_routes() -> Router<AppState> {
+                Router::new()
+                    .route("/api/users", get(users::index).post(users::create))
+            }
+        "#;
+
+        let syntax_tree: File = syn::parse_str(code).unwrap();
+        let mut visitor = RouteVisitor::new("routes.rs".to_string());
+        visitor.visit_file(&syntax_tree);
+
+        assert_eq!(visitor.routes.len(), 2);
+        assert_eq!(visitor.routes[0].method, HttpMethod::GET);
+        assert_eq!(visitor.routes[0].handler, "index");
+        assert_eq!(
+            visitor.routes[0].handler_function.as_deref(),
+            Some("users::index")
+        );
+        assert_eq!(visitor.routes[1].method, HttpMethod::POST);
+        assert_eq!(visitor.routes[1].handler, "create");
+    }
+
     #[test]
     fn test_route_registry() {
         let mut registry = RouteRegistry::new();
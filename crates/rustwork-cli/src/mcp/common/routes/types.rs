@@ -32,6 +32,12 @@ pub struct RouteRegistry {
     pub handler_to_routes: HashMap<String, Vec<usize>>, // handler -> route indices
 }
 
+impl Default for RouteRegistry {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
 impl RouteRegistry {
     pub fn new() -> Self {
         Self {
@@ -42,8 +42,8 @@ impl RouteVisitor {
                     let path = lit_str.value();
 
                     // Extract handler (second argument if present)
-                    let handler = if let Some(handler_arg) = method_call.args.iter().nth(1) {
-                        self.extract_handler_name(handler_arg)
+                    let handler_path = if let Some(handler_arg) = method_call.args.iter().nth(1) {
+                        self.extract_handler_path(handler_arg)
                     } else {
                         "unknown".to_string()
                     };
@@ -53,8 +53,8 @@ impl RouteVisitor {
                     self.routes.push(RouteInfo {
                         method,
                         path,
-                        handler: handler.clone(),
-                        handler_function: Some(handler),
+                        handler: Self::handler_name(&handler_path),
+                        handler_function: Some(handler_path),
                         file: self.current_file.clone(),
                         line,
                     });
@@ -70,31 +70,20 @@ impl RouteVisitor {
                 {
                     let path = lit_str.value();
 
-                    // Second arg should be method_routing::get(handler) or similar
-                    if let Some(syn::Expr::Call(call_expr)) = method_call.args.iter().nth(1) {
-                        if let syn::Expr::Path(path_expr) = &*call_expr.func {
-                            if let Some(segment) = path_expr.path.segments.last() {
-                                let method_str = segment.ident.to_string();
-                                if let Some(method) = self.parse_http_method(&method_str) {
-                                    let handler = if let Some(handler_arg) = call_expr.args.first()
-                                    {
-                                        self.extract_handler_name(handler_arg)
-                                    } else {
-                                        "unknown".to_string()
-                                    };
-
-                                    let line = 0; // proc_macro2::Span doesn't provide easy line access
-
-                                    self.routes.push(RouteInfo {
-                                        method,
-                                        path,
-                                        handler: handler.clone(),
-                                        handler_function: Some(handler),
-                                        file: self.current_file.clone(),
-                                        line,
-                                    });
-                                }
-                            }
+                    // Second arg should be method_routing::get(handler) or similar,
+                    // possibly chained: get(index).post(create)
+                    if let Some(method_router) = method_call.args.iter().nth(1) {
+                        for (method, handler_path) in self.extract_method_router(method_router) {
+                            let line = 0; // proc_macro2::Span doesn't provide easy line access
+
+                            self.routes.push(RouteInfo {
+                                method,
+                                path: path.clone(),
+                                handler: Self::handler_name(&handler_path),
+                                handler_function: Some(handler_path),
+                                file: self.current_file.clone(),
+                                line,
+                            });
                         }
                     }
                 }
@@ -102,6 +91,40 @@ impl RouteVisitor {
         }
     }
 
+    /// Extract (method, handler path) pairs from a method router expression
+    fn extract_method_router(&self, expr: &syn::Expr) -> Vec<(HttpMethod, String)> {
+        match expr {
+            syn::Expr::Call(call_expr) => {
+                if let syn::Expr::Path(path_expr) = &*call_expr.func {
+                    if let Some(segment) = path_expr.path.segments.last() {
+                        if let Some(method) = self.parse_http_method(&segment.ident.to_string()) {
+                            let handler = call_expr
+                                .args
+                                .first()
+                                .map(|arg| self.extract_handler_path(arg))
+                                .unwrap_or_else(|| "unknown".to_string());
+                            return vec![(method, handler)];
+                        }
+                    }
+                }
+                Vec::new()
+            }
+            syn::Expr::MethodCall(chained) => {
+                let mut pairs = self.extract_method_router(&chained.receiver);
+                if let Some(method) = self.parse_http_method(&chained.method.to_string()) {
+                    let handler = chained
+                        .args
+                        .first()
+                        .map(|arg| self.extract_handler_path(arg))
+                        .unwrap_or_else(|| "unknown".to_string());
+                    pairs.push((method, handler));
+                }
+                pairs
+            }
+            _ => Vec::new(),
+        }
+    }
+
     fn parse_http_method(&self, s: &str) -> Option<HttpMethod> {
         match s {
             "get" => Some(HttpMethod::GET),
@@ -115,17 +138,28 @@ impl RouteVisitor {
         }
     }
 
-    fn extract_handler_name(&self, expr: &syn::Expr) -> String {
+    /// Handler path as written in the router (`controllers::users::index`)
+    fn extract_handler_path(&self, expr: &syn::Expr) -> String {
         match expr {
-            syn::Expr::Path(path_expr) => path_expr
+            syn::Expr::Path(path_expr) if !path_expr.path.segments.is_empty() => path_expr
                 .path
                 .segments
-                .last()
+                .iter()
                 .map(|s| s.ident.to_string())
-                .unwrap_or_else(|| "unknown".to_string()),
+                .collect::<Vec<_>>()
+                .join("::"),
             _ => "unknown".to_string(),
         }
     }
+
+    /// Function name of a handler path (`controllers::users::index` -> `index`)
+    fn handler_name(handler_path: &str) -> String {
+        handler_path
+            .rsplit("::")
+            .next()
+            .unwrap_or(handler_path)
+            .to_string()
+    }
 }
 
 impl<'ast> Visit<'ast> for RouteVisitor {
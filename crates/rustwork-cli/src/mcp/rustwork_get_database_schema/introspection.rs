@@ -58,7 +58,7 @@ async fn read_env_database_config(service_path: &Path) -> Result<DatabaseConfig,
 
             if line.starts_with("DATABASE_URL=") || line.starts_with("DB_URL=") {
                 // Utiliser splitn pour ne split qu'une seule fois sur le premier '='
-                let url = line.splitn(2, '=').nth(1).unwrap_or("").trim();
+                let url = line.split_once('=').map(|(_, v)| v).unwrap_or("").trim();
                 let url = url.trim_matches('"').trim_matches('\'');
 
                 if !url.is_empty() {
@@ -293,11 +293,9 @@ fn extract_toml_data(
                 } else {
                     // Store non-sensitive settings
                     match val {
-                        toml::Value::String(s) => {
-                            // Only store if not a secret-like value
-                            if !is_sensitive_value(s) {
-                                settings.insert(full_key.clone(), s.clone());
-                            }
+                        // Only store if not a secret-like value
+                        toml::Value::String(s) if !is_sensitive_value(s) => {
+                            settings.insert(full_key.clone(), s.clone());
                         }
                         toml::Value::Integer(i) => {
                             settings.insert(full_key.clone(), i.to_string());
@@ -6,6 +6,9 @@ use std::path::PathBuf;
 mod model_types;
 mod parser;
 
+pub use model_types::RustModel;
+pub use parser::parse_service_models;
+
 /// rustwork.getModels - Get all Rust models/DTOs in the project
 ///
 /// Parses Rust source code to identify structs used as models or DTOs.
@@ -153,6 +156,26 @@ fn discover_services(workspace_root: &std::path::Path) -> Result<Vec<PathBuf>, R
 mod tests {
     use super::*;
 
+    #[tokio::test]
+    async fn test_parse_optional_fields() {
+        let dir = tempfile::tempdir().unwrap();
+        std::fs::create_dir_all(dir.path().join("src/models")).unwrap();
+        std::fs::write(
+            dir.path().join("src/models/user.rs"),
+            "pub struct User { pub id: i64, pub bio: Option<String> }",
+        )
+        .unwrap();
+
+        let models = parser::parse_service_models(dir.path(), "users")
+            .await
+            .unwrap();
+        let bio = &models[0].fields[1];
+        assert_eq!(bio.rust_type, "String");
+        assert!(bio.nullable);
+        assert_eq!(models[0].fields[0].rust_type, "i64");
+        assert!(!models[0].fields[0].nullable);
+    }
+
     #[test]
     fn test_discover_services_validation() {
         let temp_dir = std::env::temp_dir();
@@ -258,20 +258,20 @@ fn extract_fields(fields: &syn::Fields) -> Vec<ModelField> {
 
 /// Extract type information (and check if Option<T>)
 fn extract_type_info(ty: &Type) -> (String, bool) {
-    let type_string = quote::quote!(#ty).to_string();
-
-    // Check if it's Option<T>
-    if type_string.starts_with("Option") {
-        // Extract inner type
-        let inner = type_string
-            .trim_start_matches("Option")
-            .trim_start_matches('<')
-            .trim_end_matches('>')
-            .trim();
-        (inner.to_string(), true)
-    } else {
-        (type_string, false)
+    // Check if it's Option<T> and extract inner type
+    if let Type::Path(type_path) = ty {
+        if let Some(segment) = type_path.path.segments.last() {
+            if segment.ident == "Option" {
+                if let syn::PathArguments::AngleBracketed(args) = &segment.arguments {
+                    if let Some(syn::GenericArgument::Type(inner)) = args.args.first() {
+                        return (quote::quote!(#inner).to_string(), true);
+                    }
+                }
+            }
+        }
     }
+
+    (quote::quote!(#ty).to_string(), false)
 }
 
 /// Extract field-level attributes (serde, validation, etc.)
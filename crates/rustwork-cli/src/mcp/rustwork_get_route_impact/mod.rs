@@ -77,8 +77,7 @@ pub async fn p1_get_route_impact(
     };
 
     // Step 3: Resolve handler to concrete function
-    let handler_name = route.handler_function.as_ref().unwrap_or(&route.handler);
-    let handler_resolution = resolve_handler(&code_index, handler_name);
+    let handler_resolution = resolve_handler(&code_index, &route.handler);
 
     // Step 4: Get called functions (only if handler resolved)
     let called_functions = match &handler_resolution {
@@ -1,11 +1,14 @@
 mod analyzer;
-mod types;
+pub mod types;
 
 use crate::mcp::common::protocol::RpcError;
 use crate::mcp::common::state::LiveProjectState;
+use crate::mcp::common::workspace_root::WorkspaceRoot;
 use crate::mcp::rustwork_grpc_list_definitions;
 use analyzer::CallGraphAnalyzer;
 use serde_json::{json, Value};
+use std::sync::Arc;
+use types::CallGraph;
 
 /// rustwork_grpc_get_call_graph - Cartographie inter-services gRPC
 ///
@@ -63,6 +66,14 @@ pub async fn rustwork_grpc_get_call_graph(
     }))
 }
 
+/// Graphe d'appels gRPC du workspace, hors serveur MCP (ordre de démarrage de `rustwork dev`)
+pub fn workspace_call_graph(workspace_root: Arc<WorkspaceRoot>) -> Result<CallGraph, String> {
+    let (definitions, dependencies) =
+        rustwork_grpc_list_definitions::analyze_workspace(workspace_root)?;
+
+    Ok(CallGraphAnalyzer::new(definitions, dependencies).build_graph())
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -5,10 +5,13 @@ pub mod types;
 use crate::mcp::common::path_normalization::NormalizedPath;
 use crate::mcp::common::protocol::RpcError;
 use crate::mcp::common::state::LiveProjectState;
+use crate::mcp::common::workspace_root::WorkspaceRoot;
 use analyzer::DependencyAnalyzer;
 use scanner::RwkScanner;
 use serde_json::{json, Value};
-use types::{FieldDefinition, GrpcDefinition, MessageDefinition, RpcDefinition};
+use std::path::{Path, PathBuf};
+use std::sync::Arc;
+use types::{FieldDefinition, GrpcDefinition, MessageDefinition, RpcDefinition, ServiceDependency};
 
 /// rustwork_grpc_list_definitions - Cartographie complète des définitions gRPC
 ///
@@ -45,9 +48,39 @@ pub async fn rustwork_grpc_list_definitions(
     }
 
     // Parser chaque fichier .rwk
+    let definitions = parse_definitions(&rwk_files, state.workspace_root.path());
+
+    // Analyser les dépendances
+    let dependencies = DependencyAnalyzer::analyze(&definitions);
+
+    Ok(json!({
+        "confidence": "high",
+        "context": {
+            "workspace": state.workspace_root.path().display().to_string(),
+            "scanned_files": rwk_files.len(),
+            "valid_definitions": definitions.len(),
+        },
+        "definitions": definitions,
+        "dependencies": dependencies,
+    }))
+}
+
+/// Définitions et dépendances gRPC du workspace, hors serveur MCP (`rustwork dev`)
+pub fn analyze_workspace(
+    workspace_root: Arc<WorkspaceRoot>,
+) -> Result<(Vec<GrpcDefinition>, Vec<ServiceDependency>), String> {
+    let rwk_files = RwkScanner::new(workspace_root.clone()).scan_rwk_files()?;
+    let definitions = parse_definitions(&rwk_files, workspace_root.path());
+    let dependencies = DependencyAnalyzer::analyze(&definitions);
+
+    Ok((definitions, dependencies))
+}
+
+/// Parse les fichiers .rwk (fichiers illisibles ou invalides ignorés)
+fn parse_definitions(rwk_files: &[PathBuf], workspace_root: &Path) -> Vec<GrpcDefinition> {
     let mut definitions = Vec::new();
 
-    for rwk_path in &rwk_files {
+    for rwk_path in rwk_files {
         // Lire le contenu
         let source = match std::fs::read_to_string(rwk_path) {
             Ok(s) => s,
@@ -69,8 +102,7 @@ pub async fn rustwork_grpc_list_definitions(
         };
 
         // Convertir en définition
-        let normalized_path = match NormalizedPath::from_path(rwk_path, state.workspace_root.path())
-        {
+        let normalized_path = match NormalizedPath::from_path(rwk_path, workspace_root) {
             Ok(path) => path.as_str().to_string(),
             Err(_) => rwk_path.display().to_string(), // Fallback
         };
@@ -110,19 +142,7 @@ pub async fn rustwork_grpc_list_definitions(
         });
     }
 
-    // Analyser les dépendances
-    let dependencies = DependencyAnalyzer::analyze(&definitions);
-
-    Ok(json!({
-        "confidence": "high",
-        "context": {
-            "workspace": state.workspace_root.path().display().to_string(),
-            "scanned_files": rwk_files.len(),
-            "valid_definitions": definitions.len(),
-        },
-        "definitions": definitions,
-        "dependencies": dependencies,
-    }))
+    definitions
 }
 
 #[cfg(test)]
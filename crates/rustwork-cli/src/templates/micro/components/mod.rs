@@ -5,10 +5,10 @@ use crate::errors::AppResult;
 use crate::models::{{ name }}::{{ name | capitalize }};
 use crate::services::{{ name }}_service;
 
-pub async fn list_{{ name }}s(
+pub async fn list_{{ name | plural }}(
     State(state): State<AppState>,
 ) -> AppResult<Json<Vec<{{ name | capitalize }}}>> {
-    let items = {{ name }}_service::list_{{ name }}s(&state.db).await?;
+    let items = {{ name }}_service::list_{{ name | plural }}(&state.db).await?;
     Ok(Json(items))
 }
 
@@ -25,26 +25,687 @@ pub const MODEL_RS: &str = r#"use serde::{Deserialize, Serialize};
 
 #[derive(Debug, Clone, Serialize, Deserialize, sqlx::FromRow)]
 pub struct Model {
-    pub id: i32,
-    pub name: String,
+    pub id: {{ id_type }},
+{%- for field in fields %}
+    pub {{ field.name }}: {{ field.rust_type }},
+{%- endfor %}
     pub created_at: chrono::DateTime<chrono::Utc>,
+    pub updated_at: chrono::DateTime<chrono::Utc>,
+{%- if soft_deletes %}
+    pub deleted_at: Option<chrono::DateTime<chrono::Utc>>,
+{%- endif %}
 }
 
-pub type {{ name | capitalize }} = Model;
+pub type {{ struct_name }} = Model;
+
+impl Model {
+    pub const TABLE: &'static str = "{{ table_name }}";
+{%- if soft_deletes %}
+
+    pub fn is_deleted(&self) -> bool {
+        self.deleted_at.is_some()
+    }
+{%- endif %}
+{%- for relation in relations %}
+
+    /// `{{ relation.column }}` → `{{ relation.table }}.id`
+    pub async fn {{ relation.name }}(
+        &self,
+        db: &rustwork::DatabaseConnection,
+    ) -> rustwork::AppResult<Option<{{ relation.model }}>> {
+        use rustwork::DatabaseConnection;
+
+        let parent = match db {
+            DatabaseConnection::Sqlite(pool) => {
+                sqlx::query_as("SELECT * FROM {{ relation.table }} WHERE id = ?")
+                    .bind(self.{{ relation.column }})
+                    .fetch_optional(pool)
+                    .await?
+            }
+            DatabaseConnection::Postgres(pool) => {
+                sqlx::query_as("SELECT * FROM {{ relation.table }} WHERE id = $1")
+                    .bind(self.{{ relation.column }})
+                    .fetch_optional(pool)
+                    .await?
+            }
+            DatabaseConnection::Mysql(pool) => {
+                sqlx::query_as("SELECT * FROM {{ relation.table }} WHERE id = ?")
+                    .bind(self.{{ relation.column }})
+                    .fetch_optional(pool)
+                    .await?
+            }
+        };
+
+        Ok(parent)
+    }
+{%- endfor %}
+}
+{%- if crud %}
+
+/// Create/update payload of `{{ struct_name }}`
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct {{ struct_name }}Input {
+{%- for field in fields %}
+    pub {{ field.name }}: {{ field.rust_type }},
+{%- endfor %}
+}
+
+impl {{ struct_name }}Input {
+    /// Reject the payload with a 422 before it reaches the database
+    pub fn validate(&self) -> rustwork::AppResult<()> {
+{%- if validates %}
+        let mut errors = Vec::new();
+{%- for field in fields %}{% if field.is_text %}{% if not field.nullable %}
+        if self.{{ field.name }}.trim().is_empty() {
+            errors.push("{{ field.name }} is required");
+        }
+{%- if field.max_length %}
+        if self.{{ field.name }}.chars().count() > {{ field.max_length }} {
+            errors.push("{{ field.name }} must be at most {{ field.max_length }} characters");
+        }
+{%- endif %}{% elif field.max_length %}
+        if self
+            .{{ field.name }}
+            .as_deref()
+            .is_some_and(|value| value.chars().count() > {{ field.max_length }})
+        {
+            errors.push("{{ field.name }} must be at most {{ field.max_length }} characters");
+        }
+{%- endif %}{% endif %}{% endfor %}
+
+        if errors.is_empty() {
+            Ok(())
+        } else {
+            Err(rustwork::AppError::Validation(errors.join(", ")))
+        }
+{%- else %}
+        Ok(())
+{%- endif %}
+    }
+}
+{%- endif %}
+
+/// Test factory: `{{ struct_name }}Factory::builder().create(app.db()).await?`
+#[cfg(test)]
+pub struct {{ struct_name }}Factory;
+
+#[cfg(test)]
+impl rustwork::testing::Factory for {{ struct_name }}Factory {
+    type Model = Model;
+
+    fn table() -> &'static str {
+        "{{ table_name }}"
+    }
+
+    fn definition({% if not uses_seq %}_{% endif %}seq: u64) -> serde_json::Value {
+        serde_json::json!({
+{%- if uuid_pk %}
+            "id": uuid::Uuid::new_v4(),
+{%- endif %}
+{%- for field in fields %}{% if field.fake_value %}
+            "{{ field.name }}": {{ field.fake_value }},
+{%- endif %}{% endfor %}
+            "created_at": chrono::Utc::now(),
+            "updated_at": chrono::Utc::now(),
+        })
+    }
+{%- if relations | selectattr("factory") | list %}
+
+    fn relations() -> Vec<rustwork::testing::Relation> {
+        vec![
+{%- for relation in relations %}{% if relation.factory %}
+            rustwork::testing::Relation::belongs_to::<{{ relation.factory }}>("{{ relation.column }}"),
+{%- endif %}{% endfor %}
+        ]
+    }
+{%- endif %}
+}
 "#;
 
-pub const SERVICE_RS: &str = r#"use anyhow::Result;
-use rustwork::DatabaseConnection;
+pub const SERVICE_RS: &str = r#"use rustwork::{AppError, AppResult, DatabaseConnection, Paginator};
 
-use crate::models::{{ name }}::{{ name | capitalize }};
+use crate::models::{{ snake_name }}::{{ struct_name }};
+{%- if crud %}
+use crate::models::{{ snake_name }}::{{ struct_name }}Input;
+{%- endif %}
+
+const SELECT_{{ table_name | upper }}: &str = "SELECT * FROM {{ table_name }}{% if soft_deletes %} WHERE deleted_at IS NULL{% endif %}";
 
-pub async fn list_{{ name }}s(db: &DatabaseConnection) -> Result<Vec<{{ name | capitalize }}>> {
-    // TODO: Implement list logic with sqlx
-    Ok(vec![])
+/// List {{ table_name }}, most recent first
+pub async fn list_{{ table_name }}(
+    db: &DatabaseConnection,
+    paginator: &Paginator,
+) -> AppResult<Vec<{{ struct_name }}>> {
+    let sql = format!(
+        "{} ORDER BY {% if uuid_pk %}created_at{% else %}id{% endif %} DESC LIMIT {} OFFSET {}",
+        SELECT_{{ table_name | upper }},
+        paginator.limit(),
+        paginator.offset()
+    );
+
+    let items = match db {
+        DatabaseConnection::Sqlite(pool) => sqlx::query_as(&sql).fetch_all(pool).await?,
+        DatabaseConnection::Postgres(pool) => sqlx::query_as(&sql).fetch_all(pool).await?,
+        DatabaseConnection::Mysql(pool) => sqlx::query_as(&sql).fetch_all(pool).await?,
+    };
+
+    Ok(items)
 }
 
-pub async fn get_{{ name }}_by_id(db: &DatabaseConnection, id: i32) -> Result<{{ name | capitalize }}> {
-    // TODO: Implement get logic
-    unimplemented!()
+/// Find a {{ snake_name | replace("_", " ") }} by id
+pub async fn get_{{ snake_name }}(db: &DatabaseConnection, id: {{ id_type }}) -> AppResult<{{ struct_name }}> {
+    let filter = "{% if soft_deletes %}AND{% else %}WHERE{% endif %} id =";
+
+    let item = match db {
+        DatabaseConnection::Sqlite(pool) => {
+            sqlx::query_as(&format!("{} {} ?", SELECT_{{ table_name | upper }}, filter))
+                .bind(id)
+                .fetch_optional(pool)
+                .await?
+        }
+        DatabaseConnection::Postgres(pool) => {
+            sqlx::query_as(&format!("{} {} $1", SELECT_{{ table_name | upper }}, filter))
+                .bind(id)
+                .fetch_optional(pool)
+                .await?
+        }
+        DatabaseConnection::Mysql(pool) => {
+            sqlx::query_as(&format!("{} {} ?", SELECT_{{ table_name | upper }}, filter))
+                .bind(id)
+                .fetch_optional(pool)
+                .await?
+        }
+    };
+
+    item.ok_or_else(|| AppError::NotFound(format!("{{ struct_name }} {} not found", id)))
+}
+{%- if crud %}
+
+/// Insert a {{ snake_name | replace("_", " ") }} and return it
+pub async fn create_{{ snake_name }}(
+    db: &DatabaseConnection,
+    input: &{{ struct_name }}Input,
+) -> AppResult<{{ struct_name }}> {
+{%- if uuid_pk %}
+    let id = uuid::Uuid::new_v4();
+{% endif %}
+    match db {
+{%- for backend in ["Sqlite", "Postgres"] %}
+        DatabaseConnection::{{ backend }}(pool) => {
+            let item = sqlx::query_as(
+                "{{ sql[backend | lower].insert }}",
+            )
+{%- if uuid_pk %}
+            .bind(id)
+{%- endif %}
+{%- for field in fields %}
+            .bind(&input.{{ field.name }})
+{%- endfor %}
+            .fetch_one(pool)
+            .await?;
+
+            Ok(item)
+        }
+{%- endfor %}
+        DatabaseConnection::Mysql(pool) => {
+            {% if not uuid_pk %}let result = {% endif %}sqlx::query(
+                "{{ sql.mysql.insert }}",
+            )
+{%- if uuid_pk %}
+            .bind(id)
+{%- endif %}
+{%- for field in fields %}
+            .bind(&input.{{ field.name }})
+{%- endfor %}
+            .execute(pool)
+            .await?;
+
+            get_{{ snake_name }}(db, {% if uuid_pk %}id{% else %}result.last_insert_id() as i64{% endif %}).await
+        }
+    }
+}
+
+/// Replace the attributes of a {{ snake_name | replace("_", " ") }}
+pub async fn update_{{ snake_name }}(
+    db: &DatabaseConnection,
+    id: {{ id_type }},
+    input: &{{ struct_name }}Input,
+) -> AppResult<{{ struct_name }}> {
+    // MySQL does not count unchanged rows as affected: check existence first
+    get_{{ snake_name }}(db, id).await?;
+
+    match db {
+{%- for backend in ["Sqlite", "Postgres", "Mysql"] %}
+        DatabaseConnection::{{ backend }}(pool) => {
+            sqlx::query(
+                "{{ sql[backend | lower].update }}",
+            )
+{%- for field in fields %}
+            .bind(&input.{{ field.name }})
+{%- endfor %}
+            .bind(id)
+            .execute(pool)
+            .await?;
+        }
+{%- endfor %}
+    }
+
+    get_{{ snake_name }}(db, id).await
+}
+
+/// {% if soft_deletes %}Soft delete{% else %}Delete{% endif %} a {{ snake_name | replace("_", " ") }}
+pub async fn delete_{{ snake_name }}(db: &DatabaseConnection, id: {{ id_type }}) -> AppResult<()> {
+    let result = match db {
+{%- for backend in ["Sqlite", "Postgres", "Mysql"] %}
+        DatabaseConnection::{{ backend }}(pool) => {
+            sqlx::query("{{ sql[backend | lower].delete }}")
+                .bind(id)
+                .execute(pool)
+                .await?
+                .rows_affected()
+        }
+{%- endfor %}
+    };
+
+    if result == 0 {
+        return Err(AppError::NotFound(format!("{{ struct_name }} {} not found", id)));
+    }
+
+    Ok(())
+}
+{%- endif %}
+"#;
+
+pub const CLIENT_RS: &str = r#"//! HTTP client for the `{{ service_name }}` service
+//!
+//! Generated by `rustwork make client {{ service_name }}`.
+//! Do not edit by hand: re-run the command after changing the service routes or DTOs.
+
+#![allow(dead_code)]
+
+use rustwork::AppError;
+use serde::{Deserialize, Serialize};
+
+use super::support;
+{% for model in models %}
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct {{ model.name }} {
+{%- for field in model.fields %}
+{%- for attribute in field.attributes %}
+    {{ attribute }}
+{%- endfor %}
+    pub {{ field.name }}: {{ field.rust_type }},
+{%- endfor %}
+}
+{% endfor %}
+/// Typed client for the `{{ service_name }}` service
+#[derive(Debug, Clone)]
+pub struct {{ client_name }} {
+    base_url: String,
+    http: reqwest::Client,
+}
+
+impl {{ client_name }} {
+    /// Create a client targeting `base_url` (e.g. `http://localhost:{{ service_port }}`)
+    pub fn new(base_url: impl Into<String>) -> Self {
+        Self::with_client(base_url, reqwest::Client::new())
+    }
+
+    /// Create a client reusing an existing `reqwest::Client`
+    pub fn with_client(base_url: impl Into<String>, http: reqwest::Client) -> Self {
+        Self {
+            base_url: base_url.into().trim_end_matches('/').to_string(),
+            http,
+        }
+    }
+{% for endpoint in endpoints %}
+    /// `{{ endpoint.method }} {{ endpoint.path }}`
+    pub async fn {{ endpoint.fn_name }}(&self{% for arg in endpoint.args %}, {{ arg.name }}: {{ arg.ty }}{% endfor %}) -> Result<{{ endpoint.response_type }}, AppError> {
+        let url = format!("{}{{ endpoint.path_format }}", self.base_url{% for name in endpoint.path_args %}, {{ name }}{% endfor %});
+        let request = self.http.{{ endpoint.http_method }}(url){% if endpoint.query %}.query({{ endpoint.query }}){% endif %}{% if endpoint.body %}.json({{ endpoint.body }}){% endif %};
+        support::{{ endpoint.sender }}(request).await
+    }
+{% endfor -%}
+}
+"#;
+
+pub const CLIENT_SUPPORT_RS: &str = r#"//! Shared helpers for the generated HTTP clients
+//!
+//! Generated by `rustwork make client`. Do not edit by hand.
+
+use rustwork::{ApiResponse, AppError};
+use serde::{de::DeserializeOwned, Deserialize};
+
+/// Error body produced by `AppError::into_response`
+#[derive(Debug, Deserialize)]
+struct ErrorBody {
+    error: Option<String>,
+}
+
+/// Send a request and unwrap an `ApiResponse<T>` envelope
+pub(super) async fn send_api<T: DeserializeOwned>(
+    request: reqwest::RequestBuilder,
+) -> Result<T, AppError> {
+    let response = checked(request).await?;
+    let envelope: ApiResponse<T> = response
+        .json()
+        .await
+        .map_err(|e| AppError::InternalError(format!("Invalid response body: {}", e)))?;
+
+    if !envelope.success {
+        return Err(AppError::InternalError(
+            envelope.error.unwrap_or_else(|| "Request failed".to_string()),
+        ));
+    }
+
+    envelope
+        .data
+        .ok_or_else(|| AppError::InternalError("Missing data in response".to_string()))
+}
+
+/// Send a request and decode a plain JSON body
+pub(super) async fn send_json<T: DeserializeOwned>(
+    request: reqwest::RequestBuilder,
+) -> Result<T, AppError> {
+    checked(request)
+        .await?
+        .json()
+        .await
+        .map_err(|e| AppError::InternalError(format!("Invalid response body: {}", e)))
+}
+
+/// Send a request and ignore the response body
+pub(super) async fn send_empty(request: reqwest::RequestBuilder) -> Result<(), AppError> {
+    checked(request).await.map(|_| ())
+}
+
+/// Send a request and map non-2xx responses to `AppError`
+async fn checked(request: reqwest::RequestBuilder) -> Result<reqwest::Response, AppError> {
+    let response = request
+        .send()
+        .await
+        .map_err(|e| AppError::InternalError(format!("Service unreachable: {}", e)))?;
+
+    let status = response.status();
+    if status.is_success() {
+        return Ok(response);
+    }
+
+    let message = response
+        .json::<ErrorBody>()
+        .await
+        .ok()
+        .and_then(|body| body.error)
+        .unwrap_or_else(|| status.to_string());
+
+    Err(map_status(status.as_u16(), message))
+}
+
+/// Map an HTTP status to the matching `AppError` variant
+fn map_status(status: u16, message: String) -> AppError {
+    match status {
+        400 => AppError::BadRequest(message),
+        401 | 403 => AppError::Forbidden(message),
+        404 => AppError::NotFound(message),
+        409 => AppError::Conflict(message),
+        422 => AppError::Validation(message),
+        501 => AppError::NotImplemented(message),
+        _ => AppError::InternalError(message),
+    }
+}
+"#;
+
+pub const SEEDER_RS: &str = r#"use rustwork::{seeding::BoxFuture, AppResult, DatabaseConnection, Seeder};
+
+/// Run with `rustwork db seed` or `rustwork db seed --class {{ struct_name }}`
+#[derive(Default)]
+pub struct {{ struct_name }};
+
+impl Seeder for {{ struct_name }} {
+    /// Seeders that must run first, e.g. `vec!["RolesSeeder"]`
+    fn dependencies(&self) -> Vec<&'static str> {
+        vec![]
+    }
+{% if profiles %}
+    /// Only run for these profiles (`APP_ENV`)
+    fn profiles(&self) -> Vec<&'static str> {
+        vec![{% for profile in profiles %}"{{ profile }}"{% if not loop.last %}, {% endif %}{% endfor %}]
+    }
+{% endif %}
+    fn run<'a>(&'a self, db: &'a DatabaseConnection) -> BoxFuture<'a, AppResult<()>> {
+        Box::pin(async move {
+            // TODO: insert your data, e.g.
+            // if let DatabaseConnection::Sqlite(pool) = db {
+            //     sqlx::query("INSERT INTO my_table (name) VALUES (?)")
+            //         .bind("example")
+            //         .execute(pool)
+            //         .await?;
+            // }
+            let _ = db;
+            Ok(())
+        })
+    }
+}
+"#;
+
+pub const JOB_RS: &str = r#"use rustwork::{jobs::BoxFuture, AppResult, AppState, Job};
+use serde::{Deserialize, Serialize};
+
+/// Dispatch with `rustwork::JobQueue::new(state.db.clone()).push(&{{ struct_name }} { .. }).await?`
+#[derive(Debug, Serialize, Deserialize)]
+pub struct {{ struct_name }} {
+    // Job arguments, stored as JSON until a worker runs the job
+}
+
+impl Job for {{ struct_name }} {
+{% if queue %}    fn queue(&self) -> &'static str {
+        "{{ queue }}"
+    }
+
+{% endif %}    /// Attempts before the job is moved to the dead-letter queue
+    fn max_attempts(&self) -> u32 {
+        {{ max_attempts }}
+    }
+
+    fn handle<'a>(&'a self, state: &'a AppState) -> BoxFuture<'a, AppResult<()>> {
+        Box::pin(async move {
+            // TODO: do the work; returning an error retries the job with backoff
+            let _ = state;
+            Ok(())
+        })
+    }
+}
+"#;
+
+pub const SCAFFOLD_CONTROLLER_RS: &str = r#"use axum::{
+    extract::{Path, Query, State},
+    http::StatusCode,
+    Json,
+};
+use rustwork::{ApiResponse, AppResult, AppState, Paginator};
+
+use crate::models::{{ snake_name }}::{{ struct_name }};
+use crate::models::{{ snake_name }}::{{ struct_name }}Input;
+use crate::services::{{ snake_name }}_service;
+
+type ApiResult<T> = AppResult<(StatusCode, Json<ApiResponse<T>>)>;
+
+/// `GET /api/{{ table_name }}?page=1&per_page=20`
+pub async fn index(
+    State(state): State<AppState>,
+    Query(paginator): Query<Paginator>,
+) -> ApiResult<Vec<{{ struct_name }}>> {
+    let items = {{ snake_name }}_service::list_{{ table_name }}(&state.db, &paginator).await?;
+    Ok(rustwork::ok(items))
+}
+
+/// `GET /api/{{ table_name }}/{id}`
+pub async fn show(
+    State(state): State<AppState>,
+    Path(id): Path<{{ id_type }}>,
+) -> ApiResult<{{ struct_name }}> {
+    let item = {{ snake_name }}_service::get_{{ snake_name }}(&state.db, id).await?;
+    Ok(rustwork::ok(item))
+}
+
+/// `POST /api/{{ table_name }}`
+pub async fn create(
+    State(state): State<AppState>,
+    Json(input): Json<{{ struct_name }}Input>,
+) -> ApiResult<{{ struct_name }}> {
+    input.validate()?;
+    let item = {{ snake_name }}_service::create_{{ snake_name }}(&state.db, &input).await?;
+    Ok(rustwork::created(item))
+}
+
+/// `PUT /api/{{ table_name }}/{id}`
+pub async fn update(
+    State(state): State<AppState>,
+    Path(id): Path<{{ id_type }}>,
+    Json(input): Json<{{ struct_name }}Input>,
+) -> ApiResult<{{ struct_name }}> {
+    input.validate()?;
+    let item = {{ snake_name }}_service::update_{{ snake_name }}(&state.db, id, &input).await?;
+    Ok(rustwork::ok(item))
+}
+
+/// `DELETE /api/{{ table_name }}/{id}`
+pub async fn delete(
+    State(state): State<AppState>,
+    Path(id): Path<{{ id_type }}>,
+) -> AppResult<StatusCode> {
+    {{ snake_name }}_service::delete_{{ snake_name }}(&state.db, id).await?;
+    Ok(StatusCode::NO_CONTENT)
+}
+{%- set parents = relations | selectattr("factory") | rejectattr("nullable") | list %}
+
+#[cfg(test)]
+mod tests {
+    use axum::http::StatusCode;
+    use rustwork::testing::{Factory, TestApp};
+    use serde_json::Value;
+
+    use crate::app::build_app_router;
+    use crate::models::{{ snake_name }}::{{ struct_name }};
+    use crate::models::{{ snake_name }}::{{ struct_name }}Factory;
+{%- for relation in parents %}
+    use crate::models::{{ relation.module }}::{{ relation.struct_name }}Factory;
+{%- endfor %}
+
+    /// Valid payload: factory attributes{% if parents %} and freshly created parents{% endif %}
+    async fn payload({% if not parents %}_{% endif %}app: &TestApp) -> Value {
+        let {% if parents %}mut {% endif %}attributes = {{ struct_name }}Factory::builder().attributes().unwrap();
+{%- for relation in parents %}
+        let {{ relation.name }} = {{ relation.struct_name }}Factory::builder()
+            .create(app.db())
+            .await
+            .unwrap();
+        attributes.insert(
+            "{{ relation.column }}".to_string(),
+            serde_json::json!({{ relation.name }}.id),
+        );
+{%- endfor %}
+        Value::Object(attributes)
+    }
+
+    async fn create(app: &TestApp) -> {{ struct_name }} {
+        let body = payload(app).await;
+        let response = app.post("/api/{{ table_name }}").json(&body).send().await;
+        response.assert_status(StatusCode::CREATED);
+        response.data()
+    }
+
+    #[tokio::test]
+    async fn test_create_{{ snake_name }}() {
+        let app = TestApp::new(build_app_router).await.unwrap();
+
+        let item = create(&app).await;
+
+        app.get(&format!("/api/{{ table_name }}/{}", item.id))
+            .send()
+            .await
+            .assert_ok();
+    }
+{%- if update_field %}
+
+    #[tokio::test]
+    async fn test_create_rejects_blank_{{ update_field }}() {
+        let app = TestApp::new(build_app_router).await.unwrap();
+        let mut body = payload(&app).await;
+        body["{{ update_field }}"] = serde_json::json!("  ");
+
+        app.post("/api/{{ table_name }}")
+            .json(&body)
+            .send()
+            .await
+            .assert_status(StatusCode::UNPROCESSABLE_ENTITY);
+    }
+{%- endif %}
+
+    #[tokio::test]
+    async fn test_index_paginates_{{ table_name }}() {
+        let app = TestApp::new(build_app_router).await.unwrap();
+        for _ in 0..3 {
+            create(&app).await;
+        }
+
+        let items: Vec<{{ struct_name }}> = app
+            .get("/api/{{ table_name }}?page=1&per_page=2")
+            .send()
+            .await
+            .assert_ok()
+            .data();
+
+        assert_eq!(items.len(), 2);
+    }
+
+    #[tokio::test]
+    async fn test_show_missing_{{ snake_name }}_returns_404() {
+        let app = TestApp::new(build_app_router).await.unwrap();
+
+        app.get(&format!("/api/{{ table_name }}/{}", {% if uuid_pk %}uuid::Uuid::new_v4(){% else %}999_999{% endif %}))
+            .send()
+            .await
+            .assert_status(StatusCode::NOT_FOUND);
+    }
+
+    #[tokio::test]
+    async fn test_update_{{ snake_name }}() {
+        let app = TestApp::new(build_app_router).await.unwrap();
+        let item = create(&app).await;
+        let {% if update_field %}mut {% endif %}body = payload(&app).await;
+{%- if update_field %}
+        body["{{ update_field }}"] = serde_json::json!("Updated");
+{%- endif %}
+
+        let updated: {{ struct_name }} = app
+            .put(&format!("/api/{{ table_name }}/{}", item.id))
+            .json(&body)
+            .send()
+            .await
+            .assert_ok()
+            .data();
+
+        assert_eq!(updated.id, item.id);
+{%- if update_field %}
+        assert_eq!(updated.{{ update_field }}, "Updated");
+{%- endif %}
+    }
+
+    #[tokio::test]
+    async fn test_delete_{{ snake_name }}() {
+        let app = TestApp::new(build_app_router).await.unwrap();
+        let item = create(&app).await;
+        let uri = format!("/api/{{ table_name }}/{}", item.id);
+
+        app.delete(&uri)
+            .send()
+            .await
+            .assert_status(StatusCode::NO_CONTENT);
+        app.get(&uri)
+            .send()
+            .await
+            .assert_status(StatusCode::NOT_FOUND);
+    }
 }
 "#;
@@ -0,0 +1,322 @@
+pub const DOCKERFILE: &str = r#"# syntax=docker/dockerfile:1
+# Generated by `rustwork deploy generate` for the {{ service }} service.
+# Build context: the Cargo workspace ({{ context }})
+#
+#   docker build -f {{ dockerfile }} {{ context }}
+
+# Dependencies are compiled in their own layer (cargo-chef): it is only rebuilt
+# when a Cargo.toml or Cargo.lock of the workspace changes.
+FROM lukemathwalker/cargo-chef:latest-rust-1 AS chef
+WORKDIR /app
+
+FROM chef AS planner
+COPY . .
+RUN cargo chef prepare --recipe-path recipe.json --bin {{ service }}
+
+FROM chef AS builder
+COPY --from=planner /app/recipe.json recipe.json
+RUN cargo chef cook --release --recipe-path recipe.json --bin {{ service }}
+# Workspace sources{% if has_shared %}, including the shared crate{% endif %}
+COPY . .
+RUN cargo build --release --bin {{ service }}
+
+FROM debian:bookworm-slim AS runtime
+RUN apt-get update \
+    && apt-get install -y --no-install-recommends ca-certificates libssl3 curl \
+    && rm -rf /var/lib/apt/lists/*
+WORKDIR /app
+COPY --from=builder /app/target/release/{{ service }} /usr/local/bin/{{ service }}
+COPY {{ service_dir }}/config ./config
+RUN mkdir -p data
+
+ENV APP_ENV=prod \
+    APP__SERVER__HOST=0.0.0.0 \
+    APP__SERVER__PORT={{ http_port }}
+EXPOSE {{ http_port }}
+
+HEALTHCHECK --interval=10s --timeout=3s --start-period=10s --retries=5 \
+    CMD curl -fsS http://127.0.0.1:{{ http_port }}{{ health_path }} || exit 1
+
+CMD ["{{ service }}"]
+"#;
+
+pub const DOCKERIGNORE: &str = r#"# Generated by `rustwork deploy generate`
+**/target
+**/data
+**/.env
+**/.rustwork/dev*
+**/*.swp
+.git
+"#;
+
+pub const DOCKER_COMPOSE_YML: &str = r#"# Generated by `rustwork deploy generate`
+# Regenerate with `rustwork deploy generate --force` after adding a service.
+# Per-service settings and secrets: deploy/<service>.env
+#
+#   docker compose up --build
+
+services:
+{%- for service in services %}
+{%- if not loop.first %}
+{% endif %}
+  {{ service.name }}:
+    build:
+      context: {{ context }}
+      dockerfile: {{ service.dockerfile }}
+    env_file: {{ service.env_file }}
+    environment:
+      APP__SERVER__PORT: "{{ service.http_port }}"
+    ports:
+      - "{{ service.http_port }}:{{ service.http_port }}"
+{%- if service.depends_on %}
+    depends_on:
+{%- for dependency in service.depends_on %}
+      {{ dependency }}:
+        condition: service_healthy
+{%- endfor %}
+{%- endif %}
+{%- if not service.database %}
+    volumes:
+      - {{ service.name }}-data:/app/data
+{%- endif %}
+    healthcheck:
+      test: ["CMD", "curl", "-fsS", "http://127.0.0.1:{{ service.http_port }}{{ service.health_path }}"]
+      interval: 10s
+      timeout: 3s
+      retries: 5
+      start_period: 10s
+    restart: unless-stopped
+{%- if service.database %}
+
+  {{ service.name }}-db:
+    image: {{ service.database.image }}
+    env_file: {{ service.database.env_file }}
+    volumes:
+      - {{ service.name }}-db:{{ service.database.data_dir }}
+    healthcheck:
+      test: ["CMD-SHELL", "{{ service.database.healthcheck }}"]
+      interval: 5s
+      timeout: 3s
+      retries: 10
+    restart: unless-stopped
+{%- endif %}
+{%- endfor %}
+
+volumes:
+{%- for volume in volumes %}
+  {{ volume }}:
+{%- endfor %}
+"#;
+
+pub const DEPLOY_ENV: &str = r#"# Environment of the {{ service }} container (docker-compose.yml)
+# Generated once by `rustwork deploy generate`: edit it, it is not overwritten.
+APP_ENV=prod
+RUST_LOG=info,{{ service }}=info
+{%- if database %}
+
+# {{ database.image }} container ({{ service }}-db, credentials in {{ service }}-db.env)
+DB_URL={{ database.url }}
+{%- endif %}
+"#;
+
+pub const DEPLOY_DB_ENV: &str = r#"# Environment of the {{ service }}-db container ({{ image }}, docker-compose.yml)
+# Generated once with {{ service }}.env by `rustwork deploy generate`: keep the passwords in sync.
+{%- for variable in env %}
+{{ variable.name }}={{ variable.value }}
+{%- endfor %}
+"#;
+
+pub const K8S_DEPLOYMENT: &str = r#"# Generated by `rustwork deploy k8s` for the {{ service }} service.
+apiVersion: apps/v1
+kind: Deployment
+metadata:
+  name: {{ name }}
+  labels:
+    app.kubernetes.io/name: {{ name }}
+spec:
+  replicas: 1
+  selector:
+    matchLabels:
+      app.kubernetes.io/name: {{ name }}
+  template:
+    metadata:
+      labels:
+        app.kubernetes.io/name: {{ name }}
+    spec:
+      containers:
+        - name: {{ name }}
+          image: {{ service }}
+          ports:
+            - name: http
+              containerPort: {{ http_port }}
+          env:
+            - name: APP__SERVER__HOST
+              value: "0.0.0.0"
+            - name: APP__SERVER__PORT
+              value: "{{ http_port }}"
+          # APP_ENV comes from the overlay of the profile
+          envFrom:
+            - configMapRef:
+                name: rustwork-env
+            - secretRef:
+                name: {{ name }}-secrets
+          volumeMounts:
+            - name: config
+              mountPath: /app/config
+              readOnly: true
+{%- if sqlite %}
+            - name: data
+              mountPath: /app/data
+{%- endif %}
+          livenessProbe:
+            httpGet:
+              path: {{ health_path }}
+              port: http
+            initialDelaySeconds: 10
+            periodSeconds: 10
+            failureThreshold: 3
+          readinessProbe:
+            httpGet:
+              path: {{ health_path }}
+              port: http
+            periodSeconds: 5
+            failureThreshold: 2
+          resources:
+            requests:
+              cpu: 100m
+              memory: 128Mi
+            limits:
+              memory: 512Mi
+      volumes:
+        - name: config
+          configMap:
+            name: {{ name }}-config
+{%- if sqlite %}
+        # SQLite database: lost when the pod is deleted, use a PersistentVolumeClaim to keep it
+        - name: data
+          emptyDir: {}
+{%- endif %}
+"#;
+
+pub const K8S_SERVICE: &str = r#"# Generated by `rustwork deploy k8s` for the {{ service }} service.
+apiVersion: v1
+kind: Service
+metadata:
+  name: {{ name }}
+  labels:
+    app.kubernetes.io/name: {{ name }}
+spec:
+  selector:
+    app.kubernetes.io/name: {{ name }}
+  ports:
+    - name: http
+      port: {{ http_port }}
+      targetPort: http
+"#;
+
+pub const K8S_CONFIGMAP: &str = r#"# Generated by `rustwork deploy k8s` from {{ service_dir }}/config/*.toml
+# Mounted on /app/config: the file of each profile overrides default.toml.
+apiVersion: v1
+kind: ConfigMap
+metadata:
+  name: {{ name }}-config
+  labels:
+    app.kubernetes.io/name: {{ name }}
+data:{% if not files %} {}{% endif %}
+{%- for file in files %}
+  {{ file.name }}: |
+{{ file.content }}
+{%- endfor %}
+"#;
+
+pub const K8S_SECRET: &str = r#"# Secret template of the {{ service }} service, loaded as environment variables.
+# Generated once by `rustwork deploy k8s`: replace the placeholders, or manage the
+# secret outside of git (sealed-secrets, external-secrets...).
+apiVersion: v1
+kind: Secret
+metadata:
+  name: {{ name }}-secrets
+  labels:
+    app.kubernetes.io/name: {{ name }}
+type: Opaque
+{%- if database %}
+# No database is deployed: point DB_HOST and DB_URL at your {{ database.connection }} server.
+stringData:
+  DB_CONNECTION: {{ database.connection }}
+  DB_HOST: {{ database.host }}
+  DB_PORT: "{{ database.port }}"
+  DB_DATABASE: {{ database.name }}
+  DB_USERNAME: {{ database.name }}
+  DB_PASSWORD: "{{ database.password }}"
+  DB_URL: "{{ database.url }}"
+{%- else %}
+stringData: {}
+{%- endif %}
+"#;
+
+pub const K8S_HPA: &str = r#"# Generated by `rustwork deploy k8s --hpa` for the {{ service }} service.
+apiVersion: autoscaling/v2
+kind: HorizontalPodAutoscaler
+metadata:
+  name: {{ name }}
+  labels:
+    app.kubernetes.io/name: {{ name }}
+spec:
+  scaleTargetRef:
+    apiVersion: apps/v1
+    kind: Deployment
+    name: {{ name }}
+  minReplicas: 1
+  maxReplicas: 5
+  metrics:
+    - type: Resource
+      resource:
+        name: cpu
+        target:
+          type: Utilization
+          averageUtilization: 80
+"#;
+
+pub const K8S_BASE_KUSTOMIZATION: &str = r#"# Generated by `rustwork deploy k8s`
+# Regenerate with `rustwork deploy k8s --force` after adding a service.
+apiVersion: kustomize.config.k8s.io/v1beta1
+kind: Kustomization
+labels:
+  - pairs:
+      app.kubernetes.io/part-of: {{ project }}
+resources:
+{%- for service in services %}
+  - {{ service.name }}/deployment.yaml
+  - {{ service.name }}/service.yaml
+  - {{ service.name }}/configmap.yaml
+  - {{ service.name }}/secret.yaml
+{%- if hpa %}
+  - {{ service.name }}/hpa.yaml
+{%- endif %}
+{%- endfor %}
+configMapGenerator:
+  - name: rustwork-env
+    literals:
+      - APP_ENV=prod
+      - RUST_LOG=info
+# kustomize edit set image <service>=<registry>/<service>:<tag>
+images:
+{%- for service in services %}
+  - name: {{ service.name }}
+    newTag: latest
+{%- endfor %}
+"#;
+
+pub const K8S_OVERLAY_KUSTOMIZATION: &str = r#"# Generated by `rustwork deploy k8s`: {{ profile }} profile (APP_ENV={{ profile }})
+#
+#   kubectl apply -k {{ path }}
+apiVersion: kustomize.config.k8s.io/v1beta1
+kind: Kustomization
+resources:
+  - ../../base
+configMapGenerator:
+  - name: rustwork-env
+    behavior: merge
+    literals:
+      - APP_ENV={{ profile }}
+"#;
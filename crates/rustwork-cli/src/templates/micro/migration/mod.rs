@@ -20,6 +20,16 @@ pub const MIGRATION_DOWN_SQL: &str = r#"-- Migration DOWN: {{ migration_name }}
 -- Add your rollback SQL here
 "#;
 
+pub const MIGRATION_CREATE_TABLE_UP_SQL: &str = r#"-- Migration UP: create_{{ table_name }}
+-- Generated by `rustwork make model {{ struct_name }}` ({{ dialect }})
+
+{{ sql }}"#;
+
+pub const MIGRATION_CREATE_TABLE_DOWN_SQL: &str = r#"-- Migration DOWN: create_{{ table_name }}
+-- Generated by `rustwork make model {{ struct_name }}` ({{ dialect }})
+
+{{ sql }}"#;
+
 pub const MIGRATION_README: &str = r#"# Migrations SQL
 
 Ce dossier contient les migrations de base de données au format SQL pur, compatible avec sqlx.
@@ -1,64 +1,104 @@
 pub mod components;
+pub mod deploy;
 pub mod migration;
 pub mod project;
+pub mod starters;
 pub mod vscode;
 
 use minijinja::Environment;
 
-/// Crée un environnement de templates pour l'architecture microservices
-///
-/// Rustwork est 100% microservices - pas de support monolithe.
-pub fn create_micro_env() -> Environment<'static> {
-    let mut env = Environment::new();
+use crate::inflection;
 
+/// Templates intégrés au binaire : (nom, source)
+///
+/// Le nom sert aussi de chemin dans `.rustwork/templates/` pour les surcharger.
+pub const TEMPLATES: &[(&str, &str)] = &[
     // Project templates
-    env.add_template("main.rs", project::MAIN_RS).unwrap();
-    env.add_template("app.rs", project::APP_RS).unwrap();
-    env.add_template("routes.rs", project::ROUTES_RS).unwrap();
-    env.add_template("errors.rs", project::ERRORS_RS).unwrap();
-    env.add_template("health.rs", project::HEALTH_RS).unwrap();
-    env.add_template("default.toml", project::DEFAULT_TOML)
-        .unwrap();
-    env.add_template("dev.toml", project::DEV_TOML).unwrap();
-    env.add_template(".env.example", project::ENV_EXAMPLE)
-        .unwrap();
-    env.add_template("Cargo.toml", project::CARGO_TOML).unwrap();
-    env.add_template("gitignore", project::GITIGNORE).unwrap();
-    env.add_template("readme.md", project::README_MD).unwrap();
-
+    ("main.rs", project::MAIN_RS),
+    ("app.rs", project::APP_RS),
+    ("routes.rs", project::ROUTES_RS),
+    ("errors.rs", project::ERRORS_RS),
+    ("health.rs", project::HEALTH_RS),
+    ("default.toml", project::DEFAULT_TOML),
+    ("dev.toml", project::DEV_TOML),
+    ("test.toml", project::TEST_TOML),
+    (".env.example", project::ENV_EXAMPLE),
+    ("Cargo.toml", project::CARGO_TOML),
+    ("gitignore", project::GITIGNORE),
+    ("readme.md", project::README_MD),
     // Shared library templates
-    env.add_template("shared_cargo.toml", project::SHARED_CARGO_TOML)
-        .unwrap();
-    env.add_template("shared_lib.rs", project::SHARED_LIB_RS)
-        .unwrap();
-
+    ("shared_cargo.toml", project::SHARED_CARGO_TOML),
+    ("shared_lib.rs", project::SHARED_LIB_RS),
     // Component templates
-    env.add_template("controller.rs", components::CONTROLLER_RS)
-        .unwrap();
-    env.add_template("model.rs", components::MODEL_RS).unwrap();
-    env.add_template("service.rs", components::SERVICE_RS)
-        .unwrap();
-
+    ("controller.rs", components::CONTROLLER_RS),
+    ("model.rs", components::MODEL_RS),
+    ("service.rs", components::SERVICE_RS),
+    ("client.rs", components::CLIENT_RS),
+    ("client_support.rs", components::CLIENT_SUPPORT_RS),
+    ("seeder.rs", components::SEEDER_RS),
+    ("job.rs", components::JOB_RS),
+    ("scaffold_controller.rs", components::SCAFFOLD_CONTROLLER_RS),
     // Migration templates (SQL files)
-    env.add_template("migration_up.sql", migration::MIGRATION_UP_SQL)
-        .unwrap();
-    env.add_template("migration_down.sql", migration::MIGRATION_DOWN_SQL)
-        .unwrap();
-    env.add_template("migration_readme.md", migration::MIGRATION_README)
-        .unwrap();
-    env.add_template("migration_initial_up.sql", migration::MIGRATION_INITIAL_UP)
-        .unwrap();
-    env.add_template(
+    ("migration_up.sql", migration::MIGRATION_UP_SQL),
+    ("migration_down.sql", migration::MIGRATION_DOWN_SQL),
+    (
+        "migration_create_table_up.sql",
+        migration::MIGRATION_CREATE_TABLE_UP_SQL,
+    ),
+    (
+        "migration_create_table_down.sql",
+        migration::MIGRATION_CREATE_TABLE_DOWN_SQL,
+    ),
+    ("migration_readme.md", migration::MIGRATION_README),
+    ("migration_initial_up.sql", migration::MIGRATION_INITIAL_UP),
+    (
         "migration_initial_down.sql",
         migration::MIGRATION_INITIAL_DOWN,
-    )
-    .unwrap();
-
+    ),
     // VSCode templates for workspace
-    env.add_template("vscode_mcp.json", vscode::VSCODE_MCP_JSON)
-        .unwrap();
-    env.add_template("vscode_settings.json", vscode::VSCODE_SETTINGS_JSON)
-        .unwrap();
+    ("vscode_mcp.json", vscode::VSCODE_MCP_JSON),
+    ("vscode_settings.json", vscode::VSCODE_SETTINGS_JSON),
+    // Deployment templates (rustwork deploy generate)
+    ("Dockerfile", deploy::DOCKERFILE),
+    ("dockerignore", deploy::DOCKERIGNORE),
+    ("docker-compose.yml", deploy::DOCKER_COMPOSE_YML),
+    ("deploy.env", deploy::DEPLOY_ENV),
+    ("deploy-db.env", deploy::DEPLOY_DB_ENV),
+    // Kubernetes templates (rustwork deploy k8s)
+    ("k8s/deployment.yaml", deploy::K8S_DEPLOYMENT),
+    ("k8s/service.yaml", deploy::K8S_SERVICE),
+    ("k8s/configmap.yaml", deploy::K8S_CONFIGMAP),
+    ("k8s/secret.yaml", deploy::K8S_SECRET),
+    ("k8s/hpa.yaml", deploy::K8S_HPA),
+    ("k8s/kustomization.yaml", deploy::K8S_BASE_KUSTOMIZATION),
+    ("k8s/overlay.yaml", deploy::K8S_OVERLAY_KUSTOMIZATION),
+];
+
+/// Crée un environnement de templates pour l'architecture microservices
+///
+/// Rustwork est 100% microservices - pas de support monolithe.
+/// Les templates de `.rustwork/templates/` (workspace) et du dossier utilisateur
+/// remplacent les templates intégrés de même nom.
+pub fn create_micro_env() -> Environment<'static> {
+    let mut env = Environment::new();
+
+    // Inflection filters: `{{ name | plural }}`, `{{ name | pascal }}`…
+    env.add_filter("plural", |s: String| inflection::pluralize(&s));
+    env.add_filter("singular", |s: String| inflection::singularize(&s));
+    env.add_filter("snake", |s: String| inflection::to_snake_case(&s));
+    env.add_filter("kebab", |s: String| inflection::to_kebab_case(&s));
+    env.add_filter("pascal", |s: String| inflection::to_pascal_case(&s));
+    env.add_filter("camel", |s: String| inflection::to_camel_case(&s));
+
+    for (name, source) in TEMPLATES {
+        env.add_template(name, source).unwrap();
+    }
+
+    if let Ok(current_dir) = std::env::current_dir() {
+        for dir in super::overrides::override_dirs(&current_dir) {
+            super::overrides::load_overrides(&mut env, &dir);
+        }
+    }
 
     env
 }
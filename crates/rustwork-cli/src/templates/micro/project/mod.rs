@@ -1,5 +1,4 @@
 pub const MAIN_RS: &str = r#"use rustwork::{AppConfig, AppState, connect_db};
-use tracing_subscriber::{layer::SubscriberExt, util::SubscriberInitExt};
 
 mod app;
 mod routes;
@@ -8,15 +7,12 @@ mod controllers;
 mod services;
 mod models;
 mod middlewares;
+mod seeders;
 
 #[tokio::main]
 async fn main() -> anyhow::Result<()> {
-    // Initialize tracing
-    tracing_subscriber::registry()
-        .with(tracing_subscriber::EnvFilter::try_from_default_env()
-            .unwrap_or_else(|_| "{{ project_name }}=debug,rustwork=debug,tower_http=debug".into()))
-        .with(tracing_subscriber::fmt::layer())
-        .init();
+    // Initialize tracing (RUST_LOG overrides the filter, JSON under `rustwork dev`)
+    rustwork::init_tracing("{{ project_name }}=debug,rustwork=debug,tower_http=debug");
 
     tracing::info!("Starting {{ project_name }} service...");
 
@@ -28,6 +24,12 @@ async fn main() -> anyhow::Result<()> {
     let db = connect_db(&config.database).await?;
     tracing::info!("Database connected");
 
+    // `rustwork db seed` runs the service binary in seed mode
+    if let Some(seed) = rustwork::seeding::SeedCommand::from_args() {
+        seeders::registry().run(&db, &seed).await?;
+        return Ok(());
+    }
+
     // Create application state
     let state = AppState::new(db, config.clone());
 
@@ -45,7 +47,7 @@ async fn main() -> anyhow::Result<()> {
 }
 "#;
 
-pub const APP_RS: &str = r#"use axum::Router;
+pub const APP_RS: &str = r#"use axum::{middleware, Router};
 use rustwork::AppState;
 
 use crate::routes;
@@ -53,6 +55,8 @@ use crate::routes;
 pub fn build_app_router(state: AppState) -> Router {
     Router::new()
         .merge(routes::create_routes())
+        // Reuses the caller's x-request-id so logs can be correlated across services
+        .layer(middleware::from_fn(rustwork::middleware::request_id_middleware))
         .with_state(state)
 }
 "#;
@@ -132,6 +136,16 @@ url = "sqlite://data/dev.db?mode=rwc"
 max_connections = 10
 "#;
 
+pub const TEST_TOML: &str = r#"[server]
+host = "127.0.0.1"
+port = 0
+
+# Used by rustwork::testing::TestApp (isolated in-memory SQLite database)
+[database]
+url = "sqlite::memory:"
+max_connections = 1
+"#;
+
 pub const ENV_EXAMPLE: &str = r#"# Database URL (override config)
 # DATABASE_URL=sqlite://data/db.sqlite?mode=rwc
 
@@ -151,13 +165,18 @@ edition = "2021"
 [dependencies]
 rustwork = { git = "https://github.com/entcorporg/rustwork.git", branch = "main" }
 shared = { path = "../shared" }
-axum = "0.7"
+axum = "0.8"
 tokio = { version = "1.40", features = ["full"] }
 tracing = "0.1"
 tracing-subscriber = { version = "0.3", features = ["env-filter"] }
 anyhow = "1.0"
 serde = { version = "1.0", features = ["derive"] }
-sqlx = { version = "0.8", features = ["runtime-tokio-native-tls", "sqlite", "postgres", "mysql", "macros"] }
+sqlx = { version = "0.8", features = ["runtime-tokio-native-tls", "sqlite", "postgres", "mysql", "macros", "chrono"] }
+chrono = { version = "0.4", features = ["serde"] }
+
+[dev-dependencies]
+rustwork = { git = "https://github.com/entcorporg/rustwork.git", branch = "main", features = ["testing"] }
+serde_json = "1.0"
 "#;
 
 pub const GITIGNORE: &str = r#"# Rust
@@ -193,7 +212,7 @@ cargo run
 ## Development
 
 ```bash
-cargo watch -x run
+rustwork dev
 ```
 
 ## Testing
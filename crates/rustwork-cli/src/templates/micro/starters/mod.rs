@@ -0,0 +1,603 @@
+//! Starters intégrés de `rustwork new --template <nom>`
+//!
+//! Chaque starter est un manifeste `rustwork-starter.toml` et des fichiers rendus
+//! par-dessus le squelette du service (chemins relatifs à la racine du service).
+
+/// Starter intégré : (nom, manifeste, fichiers)
+pub struct BuiltinStarter {
+    pub name: &'static str,
+    pub manifest: &'static str,
+    pub files: &'static [(&'static str, &'static str)],
+}
+
+pub const STARTERS: &[BuiltinStarter] = &[
+    BuiltinStarter {
+        name: "api-gateway",
+        manifest: API_GATEWAY_MANIFEST,
+        files: &[
+            ("src/routes.rs", API_GATEWAY_ROUTES_RS),
+            ("src/controllers/mod.rs", API_GATEWAY_CONTROLLERS_MOD_RS),
+            ("src/controllers/proxy.rs", API_GATEWAY_PROXY_RS),
+        ],
+    },
+    BuiltinStarter {
+        name: "worker",
+        manifest: WORKER_MANIFEST,
+        files: &[
+            ("src/main.rs", WORKER_MAIN_RS),
+            ("src/worker.rs", WORKER_RS),
+        ],
+    },
+    BuiltinStarter {
+        name: "auth-service",
+        manifest: AUTH_SERVICE_MANIFEST,
+        files: &[
+            ("src/routes.rs", AUTH_SERVICE_ROUTES_RS),
+            ("src/controllers/mod.rs", AUTH_SERVICE_CONTROLLERS_MOD_RS),
+            ("src/controllers/auth.rs", AUTH_SERVICE_AUTH_RS),
+            (
+                "migrations/20240101000002_create_users.up.sql",
+                AUTH_SERVICE_USERS_UP_SQL,
+            ),
+            (
+                "migrations/20240101000002_create_users.down.sql",
+                AUTH_SERVICE_USERS_DOWN_SQL,
+            ),
+        ],
+    },
+];
+
+// ---------------------------------------------------------------------------
+// api-gateway
+// ---------------------------------------------------------------------------
+
+pub const API_GATEWAY_MANIFEST: &str = r#"name = "api-gateway"
+description = "Reverse proxy forwarding a path prefix to an upstream service"
+
+[[variables]]
+name = "upstream_url"
+description = "Upstream base URL (overridable at runtime with UPSTREAM_URL)"
+default = "http://127.0.0.1:3001"
+
+[[variables]]
+name = "prefix"
+description = "Path prefix forwarded to the upstream"
+default = "/api"
+
+[dependencies]
+reqwest = "0.12"
+"#;
+
+pub const API_GATEWAY_ROUTES_RS: &str = r#"use axum::{
+    routing::{any, get},
+    Router,
+};
+use rustwork::AppState;
+
+use crate::controllers;
+
+pub fn create_routes() -> Router<AppState> {
+    Router::new()
+        .route("/health", get(controllers::health::health_check))
+        .route("{{ prefix }}/{*path}", any(controllers::proxy::forward))
+}
+"#;
+
+pub const API_GATEWAY_CONTROLLERS_MOD_RS: &str = r#"pub mod health;
+pub mod proxy;
+"#;
+
+pub const API_GATEWAY_PROXY_RS: &str = r#"use axum::{
+    body::Body,
+    extract::{Path, Request},
+    http::{header, StatusCode},
+    response::{IntoResponse, Response},
+};
+use std::sync::LazyLock;
+
+/// Largest request body forwarded upstream
+const MAX_BODY_BYTES: usize = 10 * 1024 * 1024;
+
+static CLIENT: LazyLock<reqwest::Client> = LazyLock::new(reqwest::Client::new);
+
+static UPSTREAM_URL: LazyLock<String> = LazyLock::new(|| {
+    std::env::var("UPSTREAM_URL").unwrap_or_else(|_| "{{ upstream_url }}".to_string())
+});
+
+/// Forward `{{ prefix }}/*path` to the upstream service, keeping method, query, headers and body
+pub async fn forward(Path(path): Path<String>, request: Request) -> Response {
+    let (parts, body) = request.into_parts();
+
+    let mut url = format!("{}/{}", UPSTREAM_URL.trim_end_matches('/'), path);
+    if let Some(query) = parts.uri.query() {
+        url.push('?');
+        url.push_str(query);
+    }
+
+    let Ok(body) = axum::body::to_bytes(body, MAX_BODY_BYTES).await else {
+        return StatusCode::PAYLOAD_TOO_LARGE.into_response();
+    };
+
+    let mut headers = parts.headers;
+    headers.remove(header::HOST);
+
+    let upstream = CLIENT
+        .request(parts.method, &url)
+        .headers(headers)
+        .body(body)
+        .send()
+        .await;
+
+    let response = match upstream {
+        Ok(response) => response,
+        Err(e) => {
+            tracing::warn!("Upstream request to {} failed: {}", url, e);
+            return StatusCode::BAD_GATEWAY.into_response();
+        }
+    };
+
+    let status = response.status();
+    let mut headers = response.headers().clone();
+    headers.remove(header::TRANSFER_ENCODING);
+    headers.remove(header::CONNECTION);
+
+    match response.bytes().await {
+        Ok(bytes) => (status, headers, Body::from(bytes)).into_response(),
+        Err(e) => {
+            tracing::warn!("Failed to read upstream response from {}: {}", url, e);
+            StatusCode::BAD_GATEWAY.into_response()
+        }
+    }
+}
+"#;
+
+// ---------------------------------------------------------------------------
+// worker
+// ---------------------------------------------------------------------------
+
+pub const WORKER_MANIFEST: &str = r#"name = "worker"
+description = "Background worker running a periodic task next to the HTTP server"
+
+[[variables]]
+name = "interval_secs"
+description = "Seconds between two runs (overridable at runtime with WORKER_INTERVAL_SECS)"
+default = "60"
+"#;
+
+pub const WORKER_MAIN_RS: &str = r#"use rustwork::{AppConfig, AppState, connect_db};
+
+mod app;
+mod routes;
+mod errors;
+mod controllers;
+mod services;
+mod models;
+mod middlewares;
+mod seeders;
+mod worker;
+
+#[tokio::main]
+async fn main() -> anyhow::Result<()> {
+    // Initialize tracing (RUST_LOG overrides the filter, JSON under `rustwork dev`)
+    rustwork::init_tracing("{{ project_name }}=debug,rustwork=debug,tower_http=debug");
+
+    tracing::info!("Starting {{ project_name }} service...");
+
+    // Load configuration
+    let config = AppConfig::load()?;
+    tracing::info!("Configuration loaded");
+
+    // Initialize database
+    let db = connect_db(&config.database).await?;
+    tracing::info!("Database connected");
+
+    // `rustwork db seed` runs the service binary in seed mode
+    if let Some(seed) = rustwork::seeding::SeedCommand::from_args() {
+        seeders::registry().run(&db, &seed).await?;
+        return Ok(());
+    }
+
+    // Create application state
+    let state = AppState::new(db, config.clone());
+
+    // Start the background worker
+    tokio::spawn(worker::run(state.clone()));
+
+    // Build router with custom routes
+    let app = app::build_app_router(state);
+
+    // Start server
+    let addr = format!("{}:{}", config.server.host, config.server.port);
+    tracing::info!("{{ project_name }} service listening on {}", addr);
+
+    let listener = tokio::net::TcpListener::bind(&addr).await?;
+    axum::serve(listener, app).await?;
+
+    Ok(())
+}
+"#;
+
+pub const WORKER_RS: &str = r#"use rustwork::AppState;
+use std::time::Duration;
+use tokio::time::MissedTickBehavior;
+
+/// Seconds between two runs, unless WORKER_INTERVAL_SECS is set
+const DEFAULT_INTERVAL_SECS: u64 = {{ interval_secs }};
+
+/// Run `tick` forever at a fixed interval; a failed run is logged and retried on the next tick
+pub async fn run(state: AppState) {
+    let secs = std::env::var("WORKER_INTERVAL_SECS")
+        .ok()
+        .and_then(|value| value.parse().ok())
+        .unwrap_or(DEFAULT_INTERVAL_SECS);
+    tracing::info!("Worker started (every {}s)", secs);
+
+    let mut interval = tokio::time::interval(Duration::from_secs(secs));
+    interval.set_missed_tick_behavior(MissedTickBehavior::Skip);
+
+    loop {
+        interval.tick().await;
+        if let Err(e) = tick(&state).await {
+            tracing::error!("Worker run failed: {:#}", e);
+        }
+    }
+}
+
+/// One unit of work: replace with your job logic
+async fn tick(_state: &AppState) -> anyhow::Result<()> {
+    tracing::debug!("Worker tick");
+    Ok(())
+}
+"#;
+
+// ---------------------------------------------------------------------------
+// auth-service
+// ---------------------------------------------------------------------------
+
+pub const AUTH_SERVICE_MANIFEST: &str = r#"name = "auth-service"
+description = "User registration and login with Argon2 password hashes and JWT access tokens"
+
+[[variables]]
+name = "token_ttl_minutes"
+description = "Access token lifetime in minutes"
+default = "60"
+
+[dependencies]
+argon2 = "0.5"
+jsonwebtoken = "9"
+"#;
+
+pub const AUTH_SERVICE_ROUTES_RS: &str = r#"use axum::{
+    routing::{get, post},
+    Router,
+};
+use rustwork::AppState;
+
+use crate::controllers;
+
+pub fn create_routes() -> Router<AppState> {
+    Router::new()
+        .route("/health", get(controllers::health::health_check))
+        .route("/auth/register", post(controllers::auth::register))
+        .route("/auth/login", post(controllers::auth::login))
+        .route("/auth/me", get(controllers::auth::me))
+}
+"#;
+
+pub const AUTH_SERVICE_CONTROLLERS_MOD_RS: &str = r#"pub mod auth;
+pub mod health;
+"#;
+
+pub const AUTH_SERVICE_AUTH_RS: &str = r#"use argon2::{
+    password_hash::{rand_core::OsRng, PasswordHash, PasswordHasher, PasswordVerifier, SaltString},
+    Argon2,
+};
+use axum::{
+    extract::State,
+    http::{header, HeaderMap, StatusCode},
+    response::{IntoResponse, Response},
+    Json,
+};
+use jsonwebtoken::{DecodingKey, EncodingKey, Header, Validation};
+use rustwork::{ApiResponse, AppError, AppState, DatabaseConnection};
+use serde::{Deserialize, Serialize};
+
+/// Access token lifetime
+const TOKEN_TTL_MINUTES: i64 = {{ token_ttl_minutes }};
+
+/// Signing key used by debug builds when JWT_SECRET is not set
+const DEV_JWT_SECRET: &str = "{{ project_name }}-dev-secret";
+
+#[derive(Debug, Serialize, Deserialize, sqlx::FromRow)]
+pub struct User {
+    pub id: i64,
+    pub email: String,
+    #[serde(skip)]
+    pub password_hash: String,
+}
+
+#[derive(Debug, Deserialize)]
+pub struct Credentials {
+    pub email: String,
+    pub password: String,
+}
+
+#[derive(Debug, Serialize, Deserialize)]
+pub struct TokenResponse {
+    pub access_token: String,
+    pub token_type: String,
+    pub expires_in: i64,
+}
+
+#[derive(Debug, Serialize, Deserialize)]
+struct Claims {
+    sub: String,
+    exp: i64,
+}
+
+/// Authentication failure: 401 for bad credentials or tokens, `AppError` otherwise
+pub enum AuthError {
+    Unauthorized(&'static str),
+    App(AppError),
+}
+
+impl From<AppError> for AuthError {
+    fn from(err: AppError) -> Self {
+        AuthError::App(err)
+    }
+}
+
+impl From<sqlx::Error> for AuthError {
+    fn from(err: sqlx::Error) -> Self {
+        AuthError::App(err.into())
+    }
+}
+
+impl IntoResponse for AuthError {
+    fn into_response(self) -> Response {
+        match self {
+            AuthError::Unauthorized(message) => {
+                rustwork::error::<()>(StatusCode::UNAUTHORIZED, message.to_string()).into_response()
+            }
+            AuthError::App(err) => err.into_response(),
+        }
+    }
+}
+
+type AuthResult<T> = Result<(StatusCode, Json<ApiResponse<T>>), AuthError>;
+
+/// `POST /auth/register`
+pub async fn register(
+    State(state): State<AppState>,
+    Json(input): Json<Credentials>,
+) -> AuthResult<User> {
+    let email = input.email.trim().to_lowercase();
+    if !email.contains('@') {
+        return Err(AppError::Validation("A valid email is required".to_string()).into());
+    }
+    if input.password.len() < 8 {
+        return Err(AppError::Validation("Password must be at least 8 characters".to_string()).into());
+    }
+    if find_user_by_email(&state.db, &email).await?.is_some() {
+        return Err(AppError::Conflict(format!("{} is already registered", email)).into());
+    }
+
+    let salt = SaltString::generate(&mut OsRng);
+    let password_hash = Argon2::default()
+        .hash_password(input.password.as_bytes(), &salt)
+        .map_err(|e| AppError::InternalError(e.to_string()))?
+        .to_string();
+
+    match &state.db {
+        DatabaseConnection::Sqlite(pool) => {
+            sqlx::query("INSERT INTO users (email, password_hash) VALUES (?, ?)")
+                .bind(&email)
+                .bind(&password_hash)
+                .execute(pool)
+                .await?;
+        }
+        DatabaseConnection::Postgres(pool) => {
+            sqlx::query("INSERT INTO users (email, password_hash) VALUES ($1, $2)")
+                .bind(&email)
+                .bind(&password_hash)
+                .execute(pool)
+                .await?;
+        }
+        DatabaseConnection::Mysql(pool) => {
+            sqlx::query("INSERT INTO users (email, password_hash) VALUES (?, ?)")
+                .bind(&email)
+                .bind(&password_hash)
+                .execute(pool)
+                .await?;
+        }
+    }
+
+    let user = find_user_by_email(&state.db, &email)
+        .await?
+        .ok_or_else(|| AppError::InternalError("User not found after insert".to_string()))?;
+    Ok(rustwork::created(user))
+}
+
+/// `POST /auth/login`
+pub async fn login(
+    State(state): State<AppState>,
+    Json(input): Json<Credentials>,
+) -> AuthResult<TokenResponse> {
+    let email = input.email.trim().to_lowercase();
+    let user = find_user_by_email(&state.db, &email)
+        .await?
+        .ok_or(AuthError::Unauthorized("Invalid email or password"))?;
+
+    let hash = PasswordHash::new(&user.password_hash)
+        .map_err(|e| AppError::InternalError(e.to_string()))?;
+    Argon2::default()
+        .verify_password(input.password.as_bytes(), &hash)
+        .map_err(|_| AuthError::Unauthorized("Invalid email or password"))?;
+
+    let claims = Claims {
+        sub: user.id.to_string(),
+        exp: chrono::Utc::now().timestamp() + TOKEN_TTL_MINUTES * 60,
+    };
+    let access_token = jsonwebtoken::encode(
+        &Header::default(),
+        &claims,
+        &EncodingKey::from_secret(jwt_secret()?.as_bytes()),
+    )
+    .map_err(|e| AppError::InternalError(e.to_string()))?;
+
+    Ok(rustwork::ok(TokenResponse {
+        access_token,
+        token_type: "Bearer".to_string(),
+        expires_in: TOKEN_TTL_MINUTES * 60,
+    }))
+}
+
+/// `GET /auth/me` with `Authorization: Bearer <token>`
+pub async fn me(State(state): State<AppState>, headers: HeaderMap) -> AuthResult<User> {
+    let token = headers
+        .get(header::AUTHORIZATION)
+        .and_then(|value| value.to_str().ok())
+        .and_then(|value| value.strip_prefix("Bearer "))
+        .ok_or(AuthError::Unauthorized("Missing bearer token"))?;
+
+    let claims = jsonwebtoken::decode::<Claims>(
+        token,
+        &DecodingKey::from_secret(jwt_secret()?.as_bytes()),
+        &Validation::default(),
+    )
+    .map_err(|_| AuthError::Unauthorized("Invalid or expired token"))?
+    .claims;
+    let id: i64 = claims
+        .sub
+        .parse()
+        .map_err(|_| AuthError::Unauthorized("Invalid or expired token"))?;
+
+    let user = match &state.db {
+        DatabaseConnection::Sqlite(pool) => {
+            sqlx::query_as("SELECT id, email, password_hash FROM users WHERE id = ?")
+                .bind(id)
+                .fetch_optional(pool)
+                .await?
+        }
+        DatabaseConnection::Postgres(pool) => {
+            sqlx::query_as("SELECT id, email, password_hash FROM users WHERE id = $1")
+                .bind(id)
+                .fetch_optional(pool)
+                .await?
+        }
+        DatabaseConnection::Mysql(pool) => {
+            sqlx::query_as("SELECT id, email, password_hash FROM users WHERE id = ?")
+                .bind(id)
+                .fetch_optional(pool)
+                .await?
+        }
+    };
+
+    let user: User = user.ok_or(AuthError::Unauthorized("Unknown user"))?;
+    Ok(rustwork::ok(user))
+}
+
+async fn find_user_by_email(db: &DatabaseConnection, email: &str) -> Result<Option<User>, AppError> {
+    let user = match db {
+        DatabaseConnection::Sqlite(pool) => {
+            sqlx::query_as("SELECT id, email, password_hash FROM users WHERE email = ?")
+                .bind(email)
+                .fetch_optional(pool)
+                .await?
+        }
+        DatabaseConnection::Postgres(pool) => {
+            sqlx::query_as("SELECT id, email, password_hash FROM users WHERE email = $1")
+                .bind(email)
+                .fetch_optional(pool)
+                .await?
+        }
+        DatabaseConnection::Mysql(pool) => {
+            sqlx::query_as("SELECT id, email, password_hash FROM users WHERE email = ?")
+                .bind(email)
+                .fetch_optional(pool)
+                .await?
+        }
+    };
+
+    Ok(user)
+}
+
+/// JWT signing key from JWT_SECRET (required in release builds)
+fn jwt_secret() -> Result<String, AppError> {
+    match std::env::var("JWT_SECRET") {
+        Ok(secret) if !secret.is_empty() => Ok(secret),
+        _ if cfg!(debug_assertions) => Ok(DEV_JWT_SECRET.to_string()),
+        _ => Err(AppError::InternalError("JWT_SECRET is not set".to_string())),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use axum::http::StatusCode;
+    use rustwork::testing::TestApp;
+    use serde_json::json;
+
+    use super::{TokenResponse, User};
+    use crate::app::build_app_router;
+
+    #[tokio::test]
+    async fn test_register_login_me() {
+        let app = TestApp::new(build_app_router).await.unwrap();
+        let credentials = json!({ "email": "ada@example.com", "password": "correct horse" });
+
+        app.post("/auth/register")
+            .json(&credentials)
+            .send()
+            .await
+            .assert_status(StatusCode::CREATED);
+
+        let response = app.post("/auth/login").json(&credentials).send().await;
+        response.assert_ok();
+        let token: TokenResponse = response.data();
+
+        let response = app.get("/auth/me").bearer_auth(&token.access_token).send().await;
+        response.assert_ok();
+        let user: User = response.data();
+        assert_eq!(user.email, "ada@example.com");
+    }
+
+    #[tokio::test]
+    async fn test_login_rejects_wrong_password() {
+        let app = TestApp::new(build_app_router).await.unwrap();
+
+        app.post("/auth/register")
+            .json(&json!({ "email": "ada@example.com", "password": "correct horse" }))
+            .send()
+            .await
+            .assert_status(StatusCode::CREATED);
+
+        app.post("/auth/login")
+            .json(&json!({ "email": "ada@example.com", "password": "wrong horse" }))
+            .send()
+            .await
+            .assert_status(StatusCode::UNAUTHORIZED);
+
+        app.get("/auth/me")
+            .send()
+            .await
+            .assert_status(StatusCode::UNAUTHORIZED);
+    }
+}
+"#;
+
+pub const AUTH_SERVICE_USERS_UP_SQL: &str = r#"-- Migration UP: create_users
+-- Generated by `rustwork new --template auth-service` (sqlite)
+
+CREATE TABLE users (
+    id INTEGER PRIMARY KEY AUTOINCREMENT,
+    email VARCHAR(255) NOT NULL UNIQUE,
+    password_hash VARCHAR(255) NOT NULL,
+    created_at TIMESTAMP NOT NULL DEFAULT CURRENT_TIMESTAMP
+);
+"#;
+
+pub const AUTH_SERVICE_USERS_DOWN_SQL: &str = r#"-- Migration DOWN: create_users
+-- Generated by `rustwork new --template auth-service` (sqlite)
+
+DROP TABLE IF EXISTS users;
+"#;
@@ -5,6 +5,8 @@
 // Architecture-specific template module
 pub mod common;
 pub mod micro;
+pub mod output;
+pub mod overrides;
 
 // Legacy modules (kept for backwards compatibility during migration)
 pub mod components;
@@ -57,6 +59,33 @@ mod tests {
         assert!(env.get_template("shared_cargo.toml").is_ok());
     }
 
+    #[test]
+    fn test_micro_env_inflection_filters() {
+        let env = create_micro_env();
+        let render = |source: &str| {
+            env.render_str(source, serde_json::json!({ "name": "Category" }))
+                .unwrap()
+        };
+        assert_eq!(render("{{ name | snake | plural }}"), "categories");
+        assert_eq!(render("{{ 'people' | singular | pascal }}"), "Person");
+        assert_eq!(
+            render("{{ 'OrderItem' | kebab }} {{ 'order_item' | camel }}"),
+            "order-item orderItem"
+        );
+    }
+
+    #[test]
+    fn test_controller_template_pluralizes_list_functions() {
+        let env = create_micro_env();
+        let content = env
+            .get_template("controller.rs")
+            .unwrap()
+            .render(serde_json::json!({ "name": "category" }))
+            .unwrap();
+        assert!(content.contains("pub async fn list_categories("));
+        assert!(content.contains("category_service::list_categories(&state.db)"));
+    }
+
     #[test]
     fn test_template_context_json_values() {
         let mut ctx: TemplateContext = HashMap::new();
@@ -0,0 +1,389 @@
+//! Écriture des fichiers générés
+//!
+//! Tous les générateurs passent par `create` (fichier confié au développeur, soumis à
+//! la politique de conflit) ou `update` (fichier régénéré ou édité par Rustwork).
+//! En `--dry-run`, rien n'est écrit : chaque fichier est affiché avec son diff et
+//! gardé en mémoire pour que les étapes suivantes le relisent.
+
+use anyhow::{Context, Result};
+use std::collections::HashMap;
+use std::path::{Path, PathBuf};
+use std::sync::{Mutex, OnceLock};
+
+/// Que faire quand un fichier à créer existe déjà
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default, clap::ValueEnum)]
+pub enum ConflictPolicy {
+    /// Garder le fichier existant
+    #[default]
+    Skip,
+    /// Remplacer le fichier existant
+    Overwrite,
+    /// Garder les deux versions entre marqueurs de conflit (`<<<<<<<` / `>>>>>>>`)
+    Merge,
+}
+
+/// Options globales `--dry-run` / `--force` / `--on-conflict`
+#[derive(Debug, Clone, Copy, Default)]
+pub struct OutputOptions {
+    pub dry_run: bool,
+    pub on_conflict: ConflictPolicy,
+}
+
+/// Résultat de l'écriture d'un fichier
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum WriteOutcome {
+    Created,
+    Updated,
+    Unchanged,
+    Skipped,
+    Merged,
+}
+
+static OPTIONS: OnceLock<OutputOptions> = OnceLock::new();
+
+/// Fichiers « écrits » pendant un dry-run
+static PENDING: OnceLock<Mutex<HashMap<PathBuf, String>>> = OnceLock::new();
+
+/// Fixe les options pour toute la durée de la commande (premier appel uniquement)
+pub fn configure(options: OutputOptions) {
+    let _ = OPTIONS.set(options);
+}
+
+pub fn options() -> OutputOptions {
+    OPTIONS.get().copied().unwrap_or_default()
+}
+
+pub fn is_dry_run() -> bool {
+    options().dry_run
+}
+
+/// Lit un fichier en tenant compte des écritures simulées
+pub fn read_to_string(path: &Path) -> std::io::Result<String> {
+    if let Some(content) = pending().get(path) {
+        return Ok(content.clone());
+    }
+    std::fs::read_to_string(path)
+}
+
+pub fn exists(path: &Path) -> bool {
+    pending().contains_key(path) || path.exists()
+}
+
+/// `create_dir_all`, sans effet en dry-run
+pub fn create_dir_all(path: &Path) -> std::io::Result<()> {
+    if is_dry_run() {
+        return Ok(());
+    }
+    std::fs::create_dir_all(path)
+}
+
+/// Écrit un nouveau fichier ; s'il existe déjà, applique la politique de conflit
+pub fn create(path: &Path, content: &str) -> Result<WriteOutcome> {
+    let existing = read_existing(path)?;
+    let (outcome, final_content) = plan(existing.as_deref(), content, options().on_conflict);
+    apply(path, existing.as_deref(), outcome, final_content)
+}
+
+/// Écrit un fichier régénéré ou modifié par Rustwork (toujours remplacé)
+pub fn update(path: &Path, content: &str) -> Result<WriteOutcome> {
+    let existing = read_existing(path)?;
+    let (outcome, final_content) = plan(existing.as_deref(), content, ConflictPolicy::Overwrite);
+    let outcome = match outcome {
+        WriteOutcome::Created | WriteOutcome::Unchanged => outcome,
+        _ => WriteOutcome::Updated,
+    };
+    apply(path, existing.as_deref(), outcome, final_content)
+}
+
+/// Diff unifié : hunks `@@ -a,b +c,d @@` avec trois lignes de contexte
+pub fn unified_diff(before: &str, after: &str) -> String {
+    const CONTEXT: usize = 3;
+    let lines = diff_lines(before, after);
+
+    // Numéros de ligne (avant, après) atteints juste avant chaque entrée
+    let mut positions = Vec::with_capacity(lines.len() + 1);
+    let (mut old, mut new) = (0, 0);
+    for (tag, _) in &lines {
+        positions.push((old, new));
+        match tag {
+            '-' => old += 1,
+            '+' => new += 1,
+            _ => {
+                old += 1;
+                new += 1;
+            }
+        }
+    }
+    positions.push((old, new));
+
+    // Regroupe les changements séparés par au plus 2 × CONTEXT lignes communes
+    let mut hunks: Vec<(usize, usize)> = Vec::new();
+    for k in (0..lines.len()).filter(|&k| lines[k].0 != ' ') {
+        let start = k.saturating_sub(CONTEXT);
+        let end = (k + 1 + CONTEXT).min(lines.len());
+        match hunks.last_mut() {
+            Some(last) if start <= last.1 => last.1 = end,
+            _ => hunks.push((start, end)),
+        }
+    }
+
+    let mut diff = String::new();
+    for (start, end) in hunks {
+        let (old_from, new_from) = positions[start];
+        let (old_to, new_to) = positions[end];
+        diff.push_str(&format!(
+            "@@ -{} +{} @@\n",
+            hunk_range(old_from, old_to - old_from),
+            hunk_range(new_from, new_to - new_from)
+        ));
+        for (tag, line) in &lines[start..end] {
+            diff.push_str(&format!("{}{}\n", tag, line));
+        }
+    }
+    diff
+}
+
+/// `début,longueur` (1-based) ; un bloc vide pointe sur la ligne qui le précède
+fn hunk_range(from: usize, count: usize) -> String {
+    match count {
+        0 => format!("{},0", from),
+        1 => format!("{}", from + 1),
+        _ => format!("{},{}", from + 1, count),
+    }
+}
+
+fn pending() -> std::sync::MutexGuard<'static, HashMap<PathBuf, String>> {
+    PENDING
+        .get_or_init(Default::default)
+        .lock()
+        .unwrap_or_else(|poisoned| poisoned.into_inner())
+}
+
+fn read_existing(path: &Path) -> Result<Option<String>> {
+    if !exists(path) {
+        return Ok(None);
+    }
+    read_to_string(path)
+        .map(Some)
+        .with_context(|| format!("Failed to read {}", path.display()))
+}
+
+/// Décide de l'écriture sans toucher au disque
+fn plan(
+    existing: Option<&str>,
+    content: &str,
+    policy: ConflictPolicy,
+) -> (WriteOutcome, Option<String>) {
+    match existing {
+        None => (WriteOutcome::Created, Some(content.to_string())),
+        Some(existing) if existing == content => (WriteOutcome::Unchanged, None),
+        Some(existing) => match policy {
+            ConflictPolicy::Skip => (WriteOutcome::Skipped, None),
+            ConflictPolicy::Overwrite => (WriteOutcome::Updated, Some(content.to_string())),
+            ConflictPolicy::Merge => (WriteOutcome::Merged, Some(merge(existing, content))),
+        },
+    }
+}
+
+fn apply(
+    path: &Path,
+    existing: Option<&str>,
+    outcome: WriteOutcome,
+    content: Option<String>,
+) -> Result<WriteOutcome> {
+    let label = match outcome {
+        WriteOutcome::Created => "Created",
+        WriteOutcome::Updated => "Updated",
+        WriteOutcome::Merged => "Merged",
+        WriteOutcome::Skipped => "Skipped",
+        WriteOutcome::Unchanged => return Ok(outcome),
+    };
+
+    if is_dry_run() {
+        println!("  [dry-run] {}: {}", label, path.display());
+        if let Some(content) = &content {
+            println!("--- {}", path.display());
+            println!("+++ {}", path.display());
+            print!("{}", unified_diff(existing.unwrap_or_default(), content));
+            pending().insert(path.to_path_buf(), content.clone());
+        }
+        return Ok(outcome);
+    }
+
+    match outcome {
+        WriteOutcome::Skipped => println!(
+            "  Skipped: {} (already exists, use --force to overwrite)",
+            path.display()
+        ),
+        WriteOutcome::Merged => println!(
+            "  Merged: {} (resolve the conflict markers)",
+            path.display()
+        ),
+        _ => println!("  {}: {}", label, path.display()),
+    }
+
+    if let Some(content) = content {
+        if let Some(parent) = path.parent().filter(|p| !p.as_os_str().is_empty()) {
+            std::fs::create_dir_all(parent)?;
+        }
+        std::fs::write(path, content)
+            .with_context(|| format!("Failed to write file: {}", path.display()))?;
+    }
+
+    Ok(outcome)
+}
+
+/// Garde les lignes communes et encadre chaque bloc divergent de marqueurs de conflit
+fn merge(existing: &str, generated: &str) -> String {
+    let mut merged = String::new();
+    let mut ours = Vec::new();
+    let mut theirs = Vec::new();
+
+    let flush = |merged: &mut String, ours: &mut Vec<&str>, theirs: &mut Vec<&str>| {
+        if ours.is_empty() && theirs.is_empty() {
+            return;
+        }
+        merged.push_str("<<<<<<< existing\n");
+        for line in ours.drain(..) {
+            merged.push_str(line);
+            merged.push('\n');
+        }
+        merged.push_str("=======\n");
+        for line in theirs.drain(..) {
+            merged.push_str(line);
+            merged.push('\n');
+        }
+        merged.push_str(">>>>>>> generated\n");
+    };
+
+    for (tag, line) in diff_lines(existing, generated) {
+        match tag {
+            '-' => ours.push(line),
+            '+' => theirs.push(line),
+            _ => {
+                flush(&mut merged, &mut ours, &mut theirs);
+                merged.push_str(line);
+                merged.push('\n');
+            }
+        }
+    }
+    flush(&mut merged, &mut ours, &mut theirs);
+
+    merged
+}
+
+/// Plus longue sous-séquence commune : lignes `' '`, `'-'` (avant) et `'+'` (après)
+fn diff_lines<'a>(before: &'a str, after: &'a str) -> Vec<(char, &'a str)> {
+    let old: Vec<&str> = before.lines().collect();
+    let new: Vec<&str> = after.lines().collect();
+
+    let mut lcs = vec![vec![0usize; new.len() + 1]; old.len() + 1];
+    for i in (0..old.len()).rev() {
+        for j in (0..new.len()).rev() {
+            lcs[i][j] = if old[i] == new[j] {
+                lcs[i + 1][j + 1] + 1
+            } else {
+                lcs[i + 1][j].max(lcs[i][j + 1])
+            };
+        }
+    }
+
+    let mut lines = Vec::new();
+    let (mut i, mut j) = (0, 0);
+    while i < old.len() || j < new.len() {
+        if i < old.len() && j < new.len() && old[i] == new[j] {
+            lines.push((' ', old[i]));
+            i += 1;
+            j += 1;
+        } else if i < old.len() && (j == new.len() || lcs[i + 1][j] >= lcs[i][j + 1]) {
+            lines.push(('-', old[i]));
+            i += 1;
+        } else {
+            lines.push(('+', new[j]));
+            j += 1;
+        }
+    }
+
+    lines
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use tempfile::TempDir;
+
+    #[test]
+    fn test_plan_conflict_policies() {
+        assert_eq!(
+            plan(None, "new", ConflictPolicy::Skip),
+            (WriteOutcome::Created, Some("new".to_string()))
+        );
+        assert_eq!(
+            plan(Some("same"), "same", ConflictPolicy::Overwrite),
+            (WriteOutcome::Unchanged, None)
+        );
+        assert_eq!(
+            plan(Some("old"), "new", ConflictPolicy::Skip),
+            (WriteOutcome::Skipped, None)
+        );
+        assert_eq!(
+            plan(Some("old"), "new", ConflictPolicy::Overwrite),
+            (WriteOutcome::Updated, Some("new".to_string()))
+        );
+        assert_eq!(
+            plan(Some("old"), "new", ConflictPolicy::Merge).0,
+            WriteOutcome::Merged
+        );
+    }
+
+    #[test]
+    fn test_merge_marks_diverging_blocks() {
+        let merged = merge(
+            "use a;\n\nfn custom() {}\n\nfn end() {}\n",
+            "use a;\n\nfn generated() {}\n\nfn end() {}\n",
+        );
+
+        assert_eq!(
+            merged,
+            "use a;\n\n<<<<<<< existing\nfn custom() {}\n=======\nfn generated() {}\n>>>>>>> generated\n\nfn end() {}\n"
+        );
+    }
+
+    #[test]
+    fn test_unified_diff() {
+        let diff = unified_diff("a\nb\nc\nd\ne\nf\n", "a\nb\nc\nX\nd\ne\nf\n");
+        assert_eq!(diff, "@@ -1,6 +1,7 @@\n a\n b\n c\n+X\n d\n e\n f\n");
+
+        assert_eq!(unified_diff("", "a\nb\n"), "@@ -0,0 +1,2 @@\n+a\n+b\n");
+        assert_eq!(unified_diff("a\n", "a\n"), "");
+    }
+
+    #[test]
+    fn test_unified_diff_splits_distant_changes_into_hunks() {
+        let before: String = (1..=20).map(|n| format!("{}\n", n)).collect();
+        let after = before
+            .replace("\n2\n", "\ntwo\n")
+            .replace("\n18\n", "\n18\neighteen\n");
+
+        let diff = unified_diff(&before, &after);
+        assert_eq!(
+            diff,
+            "@@ -1,5 +1,5 @@\n 1\n-2\n+two\n 3\n 4\n 5\n\
+             @@ -16,5 +16,6 @@\n 16\n 17\n 18\n+eighteen\n 19\n 20\n"
+        );
+    }
+
+    #[test]
+    fn test_create_keeps_existing_file_by_default() {
+        let dir = TempDir::new().unwrap();
+        let path = dir.path().join("src/models/post.rs");
+
+        assert_eq!(create(&path, "v1").unwrap(), WriteOutcome::Created);
+        assert_eq!(create(&path, "v2").unwrap(), WriteOutcome::Skipped);
+        assert_eq!(std::fs::read_to_string(&path).unwrap(), "v1");
+
+        assert_eq!(update(&path, "v2").unwrap(), WriteOutcome::Updated);
+        assert_eq!(update(&path, "v2").unwrap(), WriteOutcome::Unchanged);
+        assert_eq!(std::fs::read_to_string(&path).unwrap(), "v2");
+    }
+}
@@ -0,0 +1,155 @@
+//! Surcharge des templates intégrés
+//!
+//! Un fichier `.rustwork/templates/<nom>` (ex. `.rustwork/templates/controller.rs`) remplace
+//! le template intégré de même nom. Ordre de priorité, du plus faible au plus fort :
+//! intégré, dossier utilisateur (`~/.config/rustwork/templates`), puis chaque
+//! `.rustwork/templates/` du workspace jusqu'au dossier courant.
+
+use minijinja::Environment;
+use std::path::{Path, PathBuf};
+
+/// Dossier des templates d'un workspace ou d'un service
+pub const TEMPLATES_DIR: &str = ".rustwork/templates";
+
+/// Dossier utilisateur : `$XDG_CONFIG_HOME/rustwork/templates` ou `~/.config/rustwork/templates`
+pub fn user_templates_dir() -> Option<PathBuf> {
+    let config = std::env::var_os("XDG_CONFIG_HOME")
+        .filter(|dir| !dir.is_empty())
+        .map(PathBuf::from)
+        .or_else(|| std::env::var_os("HOME").map(|home| PathBuf::from(home).join(".config")))?;
+    Some(config.join("rustwork/templates"))
+}
+
+/// Dossiers de surcharge existants, du moins prioritaire au plus prioritaire
+pub fn override_dirs(start: &Path) -> Vec<PathBuf> {
+    let mut dirs: Vec<PathBuf> = start
+        .ancestors()
+        .map(|dir| dir.join(TEMPLATES_DIR))
+        .filter(|dir| dir.is_dir())
+        .collect();
+
+    if let Some(user_dir) = user_templates_dir().filter(|dir| dir.is_dir()) {
+        if !dirs.contains(&user_dir) {
+            dirs.push(user_dir);
+        }
+    }
+
+    dirs.reverse();
+    dirs
+}
+
+/// Charge les templates d'un dossier (nom = chemin relatif, `/` comme séparateur)
+///
+/// Un template invalide est signalé et le template précédent est conservé.
+pub fn load_overrides(env: &mut Environment<'static>, dir: &Path) {
+    for path in template_files(dir) {
+        let Ok(relative) = path.strip_prefix(dir) else {
+            continue;
+        };
+        let name = relative
+            .components()
+            .map(|c| c.as_os_str().to_string_lossy())
+            .collect::<Vec<_>>()
+            .join("/");
+
+        // Compilé à part : en cas d'erreur, `add_template_owned` retirerait le template existant
+        let result = std::fs::read_to_string(&path)
+            .map_err(|e| e.to_string())
+            .and_then(|source| {
+                env.template_from_str(&source).map_err(|e| e.to_string())?;
+                env.add_template_owned(name, source)
+                    .map_err(|e| e.to_string())
+            });
+        if let Err(e) = result {
+            eprintln!("⚠️  Ignoring template override {}: {}", path.display(), e);
+        }
+    }
+}
+
+/// Fichiers d'un dossier de templates, récursivement et dans un ordre stable
+pub(crate) fn template_files(dir: &Path) -> Vec<PathBuf> {
+    let mut files = Vec::new();
+    let Ok(entries) = std::fs::read_dir(dir) else {
+        return files;
+    };
+
+    let mut paths: Vec<PathBuf> = entries.flatten().map(|e| e.path()).collect();
+    paths.sort();
+    for path in paths {
+        if path.is_dir() {
+            files.extend(template_files(&path));
+        } else if path.is_file() {
+            files.push(path);
+        }
+    }
+
+    files
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::templates::micro::TEMPLATES;
+    use tempfile::TempDir;
+
+    fn env_from(dirs: &[PathBuf]) -> Environment<'static> {
+        let mut env = Environment::new();
+        for (name, source) in TEMPLATES {
+            env.add_template(name, source).unwrap();
+        }
+        for dir in dirs {
+            load_overrides(&mut env, dir);
+        }
+        env
+    }
+
+    #[test]
+    fn test_nearest_override_wins() {
+        let root = TempDir::new().unwrap();
+        let service = root.path().join("Backend/services/users");
+        std::fs::create_dir_all(service.join(TEMPLATES_DIR)).unwrap();
+        std::fs::create_dir_all(root.path().join(TEMPLATES_DIR).join("partials")).unwrap();
+        std::fs::write(
+            root.path().join(TEMPLATES_DIR).join("model.rs"),
+            "// workspace model {{ struct_name }}",
+        )
+        .unwrap();
+        std::fs::write(
+            root.path().join(TEMPLATES_DIR).join("partials/header.rs"),
+            "// header",
+        )
+        .unwrap();
+        std::fs::write(
+            service.join(TEMPLATES_DIR).join("model.rs"),
+            "{% include \"partials/header.rs\" %}\n// service model {{ struct_name }}",
+        )
+        .unwrap();
+
+        let dirs: Vec<PathBuf> = override_dirs(&service)
+            .into_iter()
+            .filter(|dir| dir.starts_with(root.path()))
+            .collect();
+        assert_eq!(
+            dirs,
+            vec![root.path().join(TEMPLATES_DIR), service.join(TEMPLATES_DIR)]
+        );
+
+        let env = env_from(&dirs);
+        let ctx = serde_json::json!({ "struct_name": "Post" });
+        assert_eq!(
+            env.get_template("model.rs").unwrap().render(&ctx).unwrap(),
+            "// header\n// service model Post"
+        );
+        assert!(env.get_template("service.rs").is_ok());
+    }
+
+    #[test]
+    fn test_invalid_override_keeps_builtin() {
+        let dir = TempDir::new().unwrap();
+        std::fs::write(dir.path().join("model.rs"), "{% if %}").unwrap();
+
+        let env = env_from(&[dir.path().to_path_buf()]);
+        let source = env.get_template("model.rs").unwrap().source().to_string();
+        assert!(source.contains("pub struct Model"));
+    }
+}
@@ -0,0 +1,50 @@
+use anyhow::Result;
+use config::{Config, Environment, File};
+use std::collections::HashMap;
+use std::path::Path;
+
+/// Variables DB à la Laravel et clé de configuration qu'elles surchargent
+const DB_OVERRIDES: [(&str, &str); 8] = [
+    ("DB_CONNECTION", "database.connection"),
+    // DB_URL (priorité absolue)
+    ("DB_URL", "database.url"),
+    ("DB_SQLITE_PATH", "database.sqlite_path"),
+    ("DB_HOST", "database.host"),
+    ("DB_PORT", "database.port"),
+    ("DB_DATABASE", "database.database"),
+    ("DB_USERNAME", "database.username"),
+    ("DB_PASSWORD", "database.password"),
+];
+
+/// Sources de configuration d'un service : `config/default.toml`, `config/{profile}.toml`
+/// (relatifs à `base_dir`), puis les variables `DB_*` et `APP__*` de `vars`
+pub fn build_config(
+    base_dir: &Path,
+    profile: &str,
+    vars: &HashMap<String, String>,
+) -> Result<Config> {
+    let file = |name: &str| {
+        let path = base_dir.join("config").join(name);
+        File::with_name(&path.to_string_lossy()).required(false)
+    };
+    let mut builder = Config::builder()
+        // Commence par le fichier par défaut
+        .add_source(file("default"))
+        // Overlay avec le fichier profil
+        .add_source(file(profile));
+
+    for (var, key) in DB_OVERRIDES {
+        if let Some(value) = vars.get(var) {
+            builder = builder.set_override(key, value.as_str())?;
+        }
+    }
+
+    // Override avec variables d'environnement (préfixe APP__)
+    builder = builder.add_source(
+        Environment::with_prefix("APP")
+            .separator("__")
+            .source(Some(vars.clone().into_iter().collect())),
+    );
+
+    Ok(builder.build()?)
+}
@@ -1,9 +1,11 @@
+pub mod build_config;
 pub mod load_app_config;
 pub mod resolve_database_url;
 pub mod sanitize_database_url;
 pub mod validate_cors_config;
 
-pub use load_app_config::load_app_config;
+pub use build_config::build_config;
+pub use load_app_config::{load_app_config, load_app_config_for_profile};
 pub use resolve_database_url::resolve_database_url;
 pub use sanitize_database_url::sanitize_database_url;
 pub use validate_cors_config::validate_cors_config;
@@ -6,7 +6,9 @@ mod tests;
 
 // Re-exports publics pour compatibilité avec l'ancienne API
 pub use builders::{
-    load_app_config::load_app_config, resolve_database_url::resolve_database_url,
+    build_config::build_config,
+    load_app_config::{load_app_config, load_app_config_for_profile},
+    resolve_database_url::resolve_database_url,
     sanitize_database_url::sanitize_database_url,
 };
 pub use types::{AppConfig, CorsConfig, DatabaseConfig, DbConnection, PoolConfig, ServerConfig};
@@ -1,5 +1,10 @@
+use serde::{Deserialize, Serialize};
+
 /// Helper pour pagination
-#[derive(Debug, Clone)]
+///
+/// Utilisable comme extracteur `Query<Paginator>` (`?page=2&per_page=50`).
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(default)]
 pub struct Paginator {
     pub page: u64,
     pub per_page: u64,
@@ -0,0 +1,56 @@
+use crate::errors::AppResult;
+use crate::state::AppState;
+use crate::utils::BoxFuture;
+use serde::{de::DeserializeOwned, Serialize};
+use std::time::Duration;
+
+/// Travail exécuté en arrière-plan par un `Worker`
+///
+/// Le job est sérialisé en JSON dans la table `rustwork_jobs` ; son nom est
+/// celui de son type (`SendWelcomeEmail`), utilisé pour retrouver son handler.
+pub trait Job: Serialize + DeserializeOwned + Send + Sync + 'static {
+    /// File d'attente du job
+    fn queue(&self) -> &'static str {
+        "default"
+    }
+
+    /// Nombre d'exécutions avant de passer en dead-letter
+    fn max_attempts(&self) -> u32 {
+        3
+    }
+
+    /// Délai avant la tentative suivante, après l'échec de la tentative `attempt` (1, 2...)
+    fn backoff(&self, attempt: u32) -> Duration {
+        exponential_backoff(attempt)
+    }
+
+    fn handle<'a>(&'a self, state: &'a AppState) -> BoxFuture<'a, AppResult<()>>;
+}
+
+/// 10s, 20s, 40s... plafonné à une heure
+pub fn exponential_backoff(attempt: u32) -> Duration {
+    let secs = 10u64.saturating_mul(1 << attempt.saturating_sub(1).min(16));
+    Duration::from_secs(secs.min(3600))
+}
+
+/// Nom d'un job : dernier segment de son type (unicité vérifiée par `JobRegistry::register`)
+pub(crate) fn job_name<J: Job>() -> &'static str {
+    std::any::type_name::<J>()
+        .rsplit("::")
+        .next()
+        .unwrap_or_default()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_exponential_backoff() {
+        assert_eq!(exponential_backoff(1), Duration::from_secs(10));
+        assert_eq!(exponential_backoff(2), Duration::from_secs(20));
+        assert_eq!(exponential_backoff(4), Duration::from_secs(80));
+        assert_eq!(exponential_backoff(12), Duration::from_secs(3600));
+        assert_eq!(exponential_backoff(u32::MAX), Duration::from_secs(3600));
+    }
+}
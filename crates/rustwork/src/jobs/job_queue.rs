@@ -0,0 +1,672 @@
+use super::job::{job_name, Job};
+use super::schema::JOBS_TABLE;
+use crate::errors::{AppError, AppResult};
+use crate::state::DatabaseConnection;
+use chrono::{DateTime, Utc};
+use sqlx::{query::Query, ColumnIndex, Database, Decode, Encode, Row, Type};
+use std::time::Duration;
+
+const COLUMNS: &str = "id, queue, job_type, payload, attempts, max_attempts, last_error";
+
+/// Job enregistré dans `rustwork_jobs`
+#[derive(Debug, Clone, PartialEq)]
+pub struct QueuedJob {
+    pub id: i64,
+    pub queue: String,
+    pub job_type: String,
+    /// Job sérialisé en JSON
+    pub payload: String,
+    pub attempts: u32,
+    pub max_attempts: u32,
+    pub last_error: Option<String>,
+}
+
+/// File de jobs stockée dans la base du service
+///
+/// Réservation avec `FOR UPDATE SKIP LOCKED` sur PostgreSQL/MySQL ; sur SQLite,
+/// les écritures étant sérialisées, un `UPDATE ... RETURNING` suffit.
+#[derive(Clone)]
+pub struct JobQueue {
+    db: DatabaseConnection,
+}
+
+/// Valeur liée à une requête
+enum Arg {
+    Int(i64),
+    Text(String),
+    NullableText(Option<String>),
+}
+
+impl JobQueue {
+    pub fn new(db: DatabaseConnection) -> Self {
+        Self { db }
+    }
+
+    /// Ajoute un job à exécuter dès que possible ; retourne son id
+    pub async fn push<J: Job>(&self, job: &J) -> AppResult<i64> {
+        self.insert(job, now_millis()).await
+    }
+
+    /// Ajoute un job à exécuter après `delay`
+    pub async fn push_in<J: Job>(&self, job: &J, delay: Duration) -> AppResult<i64> {
+        self.insert(job, now_millis().saturating_add(millis(delay)))
+            .await
+    }
+
+    /// Ajoute un job à exécuter à partir de `run_at`
+    pub async fn push_at<J: Job>(&self, job: &J, run_at: DateTime<Utc>) -> AppResult<i64> {
+        self.insert(job, run_at.timestamp_millis()).await
+    }
+
+    /// Jobs en dead-letter (tentatives épuisées, payload invalide, type inconnu)
+    pub async fn dead_jobs(&self) -> AppResult<Vec<QueuedJob>> {
+        let sql = format!(
+            "SELECT {} FROM {} WHERE status = 'dead' ORDER BY id",
+            COLUMNS, JOBS_TABLE
+        );
+        self.fetch(&sql, Vec::new()).await
+    }
+
+    /// Remet un job en dead-letter dans sa file, avec ses tentatives remises à zéro
+    pub async fn retry(&self, id: i64) -> AppResult<()> {
+        let now = now_millis();
+        let sql = format!(
+            "UPDATE {} SET status = 'pending', attempts = 0, run_at = ?, last_error = NULL, updated_at = ? \
+             WHERE id = ? AND status = 'dead'",
+            JOBS_TABLE
+        );
+        let updated = self
+            .execute(&sql, vec![Arg::Int(now), Arg::Int(now), Arg::Int(id)])
+            .await?;
+
+        if updated == 0 {
+            return Err(AppError::NotFound(format!("Dead job #{} not found", id)));
+        }
+        Ok(())
+    }
+
+    /// Supprime les jobs en dead-letter ; retourne leur nombre
+    pub async fn purge_dead(&self) -> AppResult<u64> {
+        let sql = format!("DELETE FROM {} WHERE status = 'dead'", JOBS_TABLE);
+        self.execute(&sql, Vec::new()).await
+    }
+
+    /// Réserve le prochain job dû (ou dont la réservation a expiré) d'une des files (toutes si vide)
+    ///
+    /// Chaque réservation compte comme une tentative : un job dont le worker s'est
+    /// arrêté est repris tant qu'il lui reste des tentatives, puis passe en dead-letter.
+    pub(crate) async fn reserve(
+        &self,
+        queues: &[String],
+        reservation_timeout: Duration,
+    ) -> AppResult<Option<QueuedJob>> {
+        let now = now_millis();
+        let expired_before = now.saturating_sub(millis(reservation_timeout));
+        self.bury_expired(expired_before).await?;
+
+        let mut condition = String::from(
+            "((status = 'pending' AND run_at <= ?) OR (status = 'running' AND reserved_at <= ? AND attempts < max_attempts))",
+        );
+        if !queues.is_empty() {
+            condition.push_str(&format!(
+                " AND queue IN ({})",
+                vec!["?"; queues.len()].join(", ")
+            ));
+        }
+        let condition_args = || {
+            let mut args = vec![Arg::Int(now), Arg::Int(expired_before)];
+            args.extend(queues.iter().map(|q| Arg::Text(q.clone())));
+            args
+        };
+        let reserve = format!(
+            "UPDATE {} SET status = 'running', attempts = attempts + 1, reserved_at = ?, updated_at = ?",
+            JOBS_TABLE
+        );
+
+        match &self.db {
+            DatabaseConnection::Sqlite(_) | DatabaseConnection::Postgres(_) => {
+                let lock = match self.db {
+                    DatabaseConnection::Postgres(_) => " FOR UPDATE SKIP LOCKED",
+                    _ => "",
+                };
+                let sql = format!(
+                    "{} WHERE id = (SELECT id FROM {} WHERE {} ORDER BY run_at, id LIMIT 1{}) RETURNING {}",
+                    reserve, JOBS_TABLE, condition, lock, COLUMNS
+                );
+                let mut args = vec![Arg::Int(now), Arg::Int(now)];
+                args.extend(condition_args());
+
+                Ok(self.fetch(&sql, args).await?.pop())
+            }
+            DatabaseConnection::Mysql(pool) => {
+                // Pas de `RETURNING` : verrou, mise à jour et lecture dans une transaction
+                let mut tx = pool.begin().await?;
+
+                let select = format!(
+                    "SELECT id FROM {} WHERE {} ORDER BY run_at, id LIMIT 1 FOR UPDATE SKIP LOCKED",
+                    JOBS_TABLE, condition
+                );
+                let row = bind_args(sqlx::query(&select), condition_args())
+                    .fetch_optional(&mut *tx)
+                    .await?;
+                let Some(row) = row else {
+                    tx.commit().await?;
+                    return Ok(None);
+                };
+                let id: i64 = row.try_get(0)?;
+
+                let update = format!("{} WHERE id = ?", reserve);
+                bind_args(
+                    sqlx::query(&update),
+                    vec![Arg::Int(now), Arg::Int(now), Arg::Int(id)],
+                )
+                .execute(&mut *tx)
+                .await?;
+
+                let select = format!("SELECT {} FROM {} WHERE id = ?", COLUMNS, JOBS_TABLE);
+                let row = sqlx::query(&select).bind(id).fetch_one(&mut *tx).await?;
+                tx.commit().await?;
+
+                Ok(Some(job_from_row(&row)?))
+            }
+        }
+    }
+
+    /// Prolonge la réservation d'un job en cours d'exécution
+    pub(crate) async fn heartbeat(&self, id: i64) -> AppResult<()> {
+        let now = now_millis();
+        let sql = format!(
+            "UPDATE {} SET reserved_at = ?, updated_at = ? WHERE id = ? AND status = 'running'",
+            JOBS_TABLE
+        );
+        self.execute(&sql, vec![Arg::Int(now), Arg::Int(now), Arg::Int(id)])
+            .await?;
+        Ok(())
+    }
+
+    /// Passe en dead-letter les jobs expirés qui ont épuisé leurs tentatives
+    async fn bury_expired(&self, expired_before: i64) -> AppResult<()> {
+        let sql = format!(
+            "UPDATE {} SET status = 'dead', reserved_at = NULL, last_error = ?, updated_at = ? \
+             WHERE status = 'running' AND reserved_at <= ? AND attempts >= max_attempts",
+            JOBS_TABLE
+        );
+        self.execute(
+            &sql,
+            vec![
+                Arg::Text(
+                    "Reservation expired after the last attempt (worker stopped?)".to_string(),
+                ),
+                Arg::Int(now_millis()),
+                Arg::Int(expired_before),
+            ],
+        )
+        .await?;
+        Ok(())
+    }
+
+    /// Supprime un job terminé
+    pub(crate) async fn complete(&self, id: i64) -> AppResult<()> {
+        let sql = format!("DELETE FROM {} WHERE id = ?", JOBS_TABLE);
+        self.execute(&sql, vec![Arg::Int(id)]).await?;
+        Ok(())
+    }
+
+    /// Replanifie un job échoué après `delay`
+    pub(crate) async fn release(&self, id: i64, error: &str, delay: Duration) -> AppResult<()> {
+        let now = now_millis();
+        let sql = format!(
+            "UPDATE {} SET status = 'pending', run_at = ?, reserved_at = NULL, last_error = ?, updated_at = ? \
+             WHERE id = ?",
+            JOBS_TABLE
+        );
+        self.execute(
+            &sql,
+            vec![
+                Arg::Int(now.saturating_add(millis(delay))),
+                Arg::NullableText(Some(error.to_string())),
+                Arg::Int(now),
+                Arg::Int(id),
+            ],
+        )
+        .await?;
+        Ok(())
+    }
+
+    /// Passe un job en dead-letter
+    pub(crate) async fn bury(&self, id: i64, error: &str) -> AppResult<()> {
+        let sql = format!(
+            "UPDATE {} SET status = 'dead', reserved_at = NULL, last_error = ?, updated_at = ? WHERE id = ?",
+            JOBS_TABLE
+        );
+        self.execute(
+            &sql,
+            vec![
+                Arg::NullableText(Some(error.to_string())),
+                Arg::Int(now_millis()),
+                Arg::Int(id),
+            ],
+        )
+        .await?;
+        Ok(())
+    }
+
+    async fn insert<J: Job>(&self, job: &J, run_at: i64) -> AppResult<i64> {
+        let payload = serde_json::to_string(job).map_err(|e| {
+            AppError::InternalError(format!(
+                "Failed to serialize job {}: {}",
+                job_name::<J>(),
+                e
+            ))
+        })?;
+        let now = now_millis();
+        let sql = format!(
+            "INSERT INTO {} (queue, job_type, payload, status, attempts, max_attempts, run_at, created_at, updated_at) \
+             VALUES (?, ?, ?, 'pending', 0, ?, ?, ?, ?)",
+            JOBS_TABLE
+        );
+        let args = vec![
+            Arg::Text(job.queue().to_string()),
+            Arg::Text(job_name::<J>().to_string()),
+            Arg::Text(payload),
+            Arg::Int(i64::from(job.max_attempts().max(1))),
+            Arg::Int(run_at),
+            Arg::Int(now),
+            Arg::Int(now),
+        ];
+
+        match &self.db {
+            DatabaseConnection::Sqlite(pool) => {
+                let sql = format!("{} RETURNING id", sql);
+                let row = bind_args(sqlx::query(&sql), args).fetch_one(pool).await?;
+                Ok(row.try_get(0)?)
+            }
+            DatabaseConnection::Postgres(pool) => {
+                let sql = numbered(&format!("{} RETURNING id", sql));
+                let row = bind_args(sqlx::query(&sql), args).fetch_one(pool).await?;
+                Ok(row.try_get(0)?)
+            }
+            DatabaseConnection::Mysql(pool) => {
+                let result = bind_args(sqlx::query(&sql), args).execute(pool).await?;
+                Ok(result.last_insert_id() as i64)
+            }
+        }
+    }
+
+    async fn execute(&self, sql: &str, args: Vec<Arg>) -> AppResult<u64> {
+        let result = match &self.db {
+            DatabaseConnection::Sqlite(pool) => bind_args(sqlx::query(sql), args)
+                .execute(pool)
+                .await?
+                .rows_affected(),
+            DatabaseConnection::Postgres(pool) => {
+                let sql = numbered(sql);
+                bind_args(sqlx::query(&sql), args)
+                    .execute(pool)
+                    .await?
+                    .rows_affected()
+            }
+            DatabaseConnection::Mysql(pool) => bind_args(sqlx::query(sql), args)
+                .execute(pool)
+                .await?
+                .rows_affected(),
+        };
+
+        Ok(result)
+    }
+
+    async fn fetch(&self, sql: &str, args: Vec<Arg>) -> AppResult<Vec<QueuedJob>> {
+        let jobs = match &self.db {
+            DatabaseConnection::Sqlite(pool) => bind_args(sqlx::query(sql), args)
+                .fetch_all(pool)
+                .await?
+                .iter()
+                .map(job_from_row)
+                .collect::<Result<_, _>>()?,
+            DatabaseConnection::Postgres(pool) => {
+                let sql = numbered(sql);
+                bind_args(sqlx::query(&sql), args)
+                    .fetch_all(pool)
+                    .await?
+                    .iter()
+                    .map(job_from_row)
+                    .collect::<Result<_, _>>()?
+            }
+            DatabaseConnection::Mysql(pool) => bind_args(sqlx::query(sql), args)
+                .fetch_all(pool)
+                .await?
+                .iter()
+                .map(job_from_row)
+                .collect::<Result<_, _>>()?,
+        };
+
+        Ok(jobs)
+    }
+}
+
+pub(crate) fn now_millis() -> i64 {
+    Utc::now().timestamp_millis()
+}
+
+fn millis(duration: Duration) -> i64 {
+    i64::try_from(duration.as_millis()).unwrap_or(i64::MAX)
+}
+
+/// Placeholders `?` → `$1, $2...` (PostgreSQL), hors des chaînes `'...'`
+fn numbered(sql: &str) -> String {
+    let mut result = String::with_capacity(sql.len());
+    let mut index = 0;
+    let mut quoted = false;
+    for c in sql.chars() {
+        if c == '\'' {
+            // `''` (apostrophe échappée) ferme puis rouvre la chaîne
+            quoted = !quoted;
+            result.push(c);
+        } else if c == '?' && !quoted {
+            index += 1;
+            result.push_str(&format!("${}", index));
+        } else {
+            result.push(c);
+        }
+    }
+    result
+}
+
+fn bind_args<'q, DB>(
+    query: Query<'q, DB, <DB as Database>::Arguments<'q>>,
+    args: Vec<Arg>,
+) -> Query<'q, DB, <DB as Database>::Arguments<'q>>
+where
+    DB: Database,
+    i64: Encode<'q, DB> + Type<DB>,
+    String: Encode<'q, DB> + Type<DB>,
+    Option<String>: Encode<'q, DB> + Type<DB>,
+{
+    args.into_iter().fold(query, |query, arg| match arg {
+        Arg::Int(value) => query.bind(value),
+        Arg::Text(value) => query.bind(value),
+        Arg::NullableText(value) => query.bind(value),
+    })
+}
+
+fn job_from_row<'r, R>(row: &'r R) -> Result<QueuedJob, sqlx::Error>
+where
+    R: Row,
+    &'r str: ColumnIndex<R>,
+    i64: Decode<'r, R::Database> + Type<R::Database>,
+    String: Decode<'r, R::Database> + Type<R::Database>,
+{
+    let count = |column: &'r str| -> Result<u32, sqlx::Error> {
+        Ok(u32::try_from(row.try_get::<i64, _>(column)?).unwrap_or_default())
+    };
+
+    Ok(QueuedJob {
+        id: row.try_get("id")?,
+        queue: row.try_get("queue")?,
+        job_type: row.try_get("job_type")?,
+        payload: row.try_get("payload")?,
+        attempts: count("attempts")?,
+        max_attempts: count("max_attempts")?,
+        last_error: row.try_get("last_error")?,
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::config::DbConnection;
+    use crate::jobs::schema::{create_table_sql, drop_table_sql};
+    use crate::state::AppState;
+    use crate::utils::BoxFuture;
+    use serde::{Deserialize, Serialize};
+    use sqlx::sqlite::SqlitePoolOptions;
+
+    #[derive(Serialize, Deserialize)]
+    struct SendEmail {
+        to: String,
+    }
+
+    impl Job for SendEmail {
+        fn queue(&self) -> &'static str {
+            "mail"
+        }
+
+        fn handle<'a>(&'a self, _state: &'a AppState) -> BoxFuture<'a, AppResult<()>> {
+            Box::pin(async { Ok(()) })
+        }
+    }
+
+    async fn queue() -> JobQueue {
+        let pool = SqlitePoolOptions::new()
+            .max_connections(1)
+            .connect("sqlite::memory:")
+            .await
+            .unwrap();
+        sqlx::raw_sql(&create_table_sql(&DbConnection::Sqlite))
+            .execute(&pool)
+            .await
+            .unwrap();
+        JobQueue::new(DatabaseConnection::Sqlite(pool))
+    }
+
+    fn mail() -> Vec<String> {
+        vec!["mail".to_string()]
+    }
+
+    #[test]
+    fn test_numbered() {
+        assert_eq!(
+            numbered("UPDATE t SET a = ? WHERE id IN (?, ?)"),
+            "UPDATE t SET a = $1 WHERE id IN ($2, $3)"
+        );
+        assert_eq!(
+            numbered("UPDATE t SET a = 'why?', b = 'it''s ?' WHERE id = ?"),
+            "UPDATE t SET a = 'why?', b = 'it''s ?' WHERE id = $1"
+        );
+    }
+
+    #[tokio::test]
+    async fn test_push_and_reserve() {
+        let queue = queue().await;
+        let id = queue
+            .push(&SendEmail {
+                to: "ada@example.com".to_string(),
+            })
+            .await
+            .unwrap();
+
+        let timeout = Duration::from_secs(60);
+        assert!(queue
+            .reserve(&["default".to_string()], timeout)
+            .await
+            .unwrap()
+            .is_none());
+
+        let job = queue.reserve(&[], timeout).await.unwrap().unwrap();
+        assert_eq!(job.id, id);
+        assert_eq!(job.job_type, "SendEmail");
+        assert_eq!(job.payload, r#"{"to":"ada@example.com"}"#);
+        assert_eq!((job.attempts, job.max_attempts), (1, 3));
+
+        // Déjà réservé
+        assert!(queue.reserve(&mail(), timeout).await.unwrap().is_none());
+        // Réservation expirée : le job est repris
+        let job = queue
+            .reserve(&mail(), Duration::ZERO)
+            .await
+            .unwrap()
+            .unwrap();
+        assert_eq!(job.attempts, 2);
+
+        queue.complete(job.id).await.unwrap();
+        assert!(queue
+            .reserve(&mail(), Duration::ZERO)
+            .await
+            .unwrap()
+            .is_none());
+    }
+
+    #[tokio::test]
+    async fn test_expired_reservation_counts_as_attempt() {
+        let queue = queue().await;
+        let id = queue
+            .push(&SendEmail {
+                to: "ada@example.com".to_string(),
+            })
+            .await
+            .unwrap();
+
+        for attempt in 1..=3 {
+            let job = queue
+                .reserve(&mail(), Duration::ZERO)
+                .await
+                .unwrap()
+                .unwrap();
+            assert_eq!(job.attempts, attempt);
+        }
+
+        // Tentatives épuisées : le job n'est plus repris
+        assert!(queue
+            .reserve(&mail(), Duration::ZERO)
+            .await
+            .unwrap()
+            .is_none());
+        let dead = queue.dead_jobs().await.unwrap();
+        assert_eq!(dead[0].id, id);
+        assert_eq!(
+            dead[0].last_error.as_deref(),
+            Some("Reservation expired after the last attempt (worker stopped?)")
+        );
+    }
+
+    /// Serveur PostgreSQL de test : `RUSTWORK_TEST_POSTGRES_URL` (ignoré s'il n'est pas défini)
+    #[tokio::test]
+    async fn test_postgres_reserve_and_bury_expired() {
+        let Ok(url) = std::env::var("RUSTWORK_TEST_POSTGRES_URL") else {
+            return;
+        };
+        let pool = sqlx::PgPool::connect(&url).await.unwrap();
+        sqlx::raw_sql(&format!(
+            "{}{}",
+            drop_table_sql(),
+            create_table_sql(&DbConnection::Postgres)
+        ))
+        .execute(&pool)
+        .await
+        .unwrap();
+        let queue = JobQueue::new(DatabaseConnection::Postgres(pool.clone()));
+
+        let id = queue
+            .push(&SendEmail {
+                to: "ada@example.com".to_string(),
+            })
+            .await
+            .unwrap();
+        for attempt in 1..=3 {
+            let job = queue
+                .reserve(&mail(), Duration::ZERO)
+                .await
+                .unwrap()
+                .unwrap();
+            assert_eq!((job.id, job.attempts), (id, attempt));
+        }
+        assert!(queue
+            .reserve(&mail(), Duration::ZERO)
+            .await
+            .unwrap()
+            .is_none());
+        let dead = queue.dead_jobs().await.unwrap();
+
+        sqlx::raw_sql(&drop_table_sql())
+            .execute(&pool)
+            .await
+            .unwrap();
+        assert_eq!(
+            dead[0].last_error.as_deref(),
+            Some("Reservation expired after the last attempt (worker stopped?)")
+        );
+    }
+
+    #[tokio::test]
+    async fn test_heartbeat_extends_reservation() {
+        let queue = queue().await;
+        let id = queue
+            .push(&SendEmail {
+                to: "ada@example.com".to_string(),
+            })
+            .await
+            .unwrap();
+        let timeout = Duration::from_millis(200);
+
+        queue.reserve(&mail(), timeout).await.unwrap().unwrap();
+        tokio::time::sleep(Duration::from_millis(300)).await;
+        queue.heartbeat(id).await.unwrap();
+        assert!(queue.reserve(&mail(), timeout).await.unwrap().is_none());
+
+        tokio::time::sleep(Duration::from_millis(300)).await;
+        let job = queue.reserve(&mail(), timeout).await.unwrap().unwrap();
+        assert_eq!(job.attempts, 2);
+    }
+
+    #[tokio::test]
+    async fn test_scheduled_job() {
+        let queue = queue().await;
+        let job = SendEmail {
+            to: "ada@example.com".to_string(),
+        };
+        queue
+            .push_in(&job, Duration::from_secs(3600))
+            .await
+            .unwrap();
+        queue
+            .push_at(&job, Utc::now() - chrono::Duration::seconds(1))
+            .await
+            .unwrap();
+
+        let timeout = Duration::from_secs(60);
+        let due = queue.reserve(&mail(), timeout).await.unwrap().unwrap();
+        assert_eq!(due.id, 2);
+        assert!(queue.reserve(&mail(), timeout).await.unwrap().is_none());
+    }
+
+    #[tokio::test]
+    async fn test_release_bury_and_retry() {
+        let queue = queue().await;
+        let id = queue
+            .push(&SendEmail {
+                to: "ada@example.com".to_string(),
+            })
+            .await
+            .unwrap();
+        let timeout = Duration::from_secs(60);
+
+        queue.reserve(&mail(), timeout).await.unwrap().unwrap();
+        queue
+            .release(id, "SMTP down", Duration::ZERO)
+            .await
+            .unwrap();
+        let job = queue.reserve(&mail(), timeout).await.unwrap().unwrap();
+        assert_eq!(job.attempts, 2);
+        assert_eq!(job.last_error.as_deref(), Some("SMTP down"));
+
+        queue.bury(id, "SMTP still down").await.unwrap();
+        assert!(queue
+            .reserve(&mail(), Duration::ZERO)
+            .await
+            .unwrap()
+            .is_none());
+        let dead = queue.dead_jobs().await.unwrap();
+        assert_eq!(dead.len(), 1);
+        assert_eq!(dead[0].last_error.as_deref(), Some("SMTP still down"));
+
+        queue.retry(id).await.unwrap();
+        assert!(queue.dead_jobs().await.unwrap().is_empty());
+        let job = queue.reserve(&mail(), timeout).await.unwrap().unwrap();
+        assert_eq!(job.attempts, 1);
+        assert!(matches!(queue.retry(id).await, Err(AppError::NotFound(_))));
+
+        queue.bury(id, "gone").await.unwrap();
+        assert_eq!(queue.purge_dead().await.unwrap(), 1);
+        assert!(queue.dead_jobs().await.unwrap().is_empty());
+    }
+}
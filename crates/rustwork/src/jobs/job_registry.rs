@@ -0,0 +1,142 @@
+use super::job::{job_name, Job};
+use crate::state::AppState;
+use crate::utils::BoxFuture;
+use std::time::Duration;
+
+/// Résultat de l'exécution d'un job
+pub(crate) enum Outcome {
+    Done,
+    /// `retry_in: None` : échec définitif (payload invalide)
+    Failed {
+        error: String,
+        retry_in: Option<Duration>,
+    },
+}
+
+type Handler = Box<dyn Fn(AppState, String, u32) -> BoxFuture<'static, Outcome> + Send + Sync>;
+
+/// Types de jobs qu'un `Worker` sait exécuter
+#[derive(Default)]
+pub struct JobRegistry {
+    /// Nom du job, type Rust complet, handler
+    handlers: Vec<(&'static str, &'static str, Handler)>,
+}
+
+impl JobRegistry {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Enregistre un type de job
+    ///
+    /// Panique si un autre type porte déjà le même nom : leurs jobs seraient
+    /// indiscernables dans `rustwork_jobs`.
+    pub fn register<J: Job>(mut self) -> Self {
+        let name = job_name::<J>();
+        let type_name = std::any::type_name::<J>();
+        if let Some((_, registered, _)) = self
+            .handlers
+            .iter()
+            .find(|(other, registered, _)| *other == name && *registered != type_name)
+        {
+            panic!(
+                "Job name {} is used by both {} and {}; rename one of them",
+                name, registered, type_name
+            );
+        }
+
+        let handler: Handler = Box::new(|state, payload, attempt| {
+            Box::pin(async move {
+                let job: J = match serde_json::from_str(&payload) {
+                    Ok(job) => job,
+                    Err(e) => {
+                        return Outcome::Failed {
+                            error: format!("Invalid payload for job {}: {}", job_name::<J>(), e),
+                            retry_in: None,
+                        }
+                    }
+                };
+
+                match job.handle(&state).await {
+                    Ok(()) => Outcome::Done,
+                    Err(e) => Outcome::Failed {
+                        error: e.to_string(),
+                        retry_in: Some(job.backoff(attempt)),
+                    },
+                }
+            })
+        });
+
+        self.handlers.retain(|(other, _, _)| *other != name);
+        self.handlers.push((name, type_name, handler));
+        self
+    }
+
+    /// Noms des jobs, dans l'ordre d'enregistrement
+    pub fn names(&self) -> Vec<&str> {
+        self.handlers.iter().map(|(name, _, _)| *name).collect()
+    }
+
+    /// Lance le handler d'un job ; `None` si le type n'est pas enregistré
+    pub(crate) fn dispatch(
+        &self,
+        state: AppState,
+        job_type: &str,
+        payload: String,
+        attempt: u32,
+    ) -> Option<BoxFuture<'static, Outcome>> {
+        self.handlers
+            .iter()
+            .find(|(name, _, _)| *name == job_type)
+            .map(|(_, _, handler)| handler(state, payload, attempt))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::errors::AppResult;
+    use serde::{Deserialize, Serialize};
+
+    mod mail {
+        use super::*;
+
+        #[derive(Serialize, Deserialize)]
+        pub struct Notify;
+
+        impl Job for Notify {
+            fn handle<'a>(&'a self, _state: &'a AppState) -> BoxFuture<'a, AppResult<()>> {
+                Box::pin(async { Ok(()) })
+            }
+        }
+    }
+
+    mod sms {
+        use super::*;
+
+        #[derive(Serialize, Deserialize)]
+        pub struct Notify;
+
+        impl Job for Notify {
+            fn handle<'a>(&'a self, _state: &'a AppState) -> BoxFuture<'a, AppResult<()>> {
+                Box::pin(async { Ok(()) })
+            }
+        }
+    }
+
+    #[test]
+    fn test_register_same_type_twice() {
+        let registry = JobRegistry::new()
+            .register::<mail::Notify>()
+            .register::<mail::Notify>();
+        assert_eq!(registry.names(), vec!["Notify"]);
+    }
+
+    #[test]
+    #[should_panic(expected = "Job name Notify is used by both")]
+    fn test_register_homonymous_jobs_panics() {
+        let _ = JobRegistry::new()
+            .register::<mail::Notify>()
+            .register::<sms::Notify>();
+    }
+}
@@ -0,0 +1,15 @@
+//! Jobs en arrière-plan (`rustwork make job`)
+
+pub mod job;
+pub mod job_queue;
+pub mod job_registry;
+pub mod schema;
+pub mod worker;
+
+// Re-exports publics
+pub use crate::utils::BoxFuture;
+pub use job::{exponential_backoff, Job};
+pub use job_queue::{JobQueue, QueuedJob};
+pub use job_registry::JobRegistry;
+pub use schema::{create_table_sql, drop_table_sql, JOBS_TABLE};
+pub use worker::Worker;
@@ -0,0 +1,64 @@
+use crate::config::DbConnection;
+
+/// Table des jobs en attente, en cours et en dead-letter
+pub const JOBS_TABLE: &str = "rustwork_jobs";
+
+/// Migration `up` de la table des jobs pour un driver
+///
+/// Les dates sont des timestamps Unix en millisecondes (BIGINT) sur les trois drivers.
+pub fn create_table_sql(connection: &DbConnection) -> String {
+    let id = match connection {
+        DbConnection::Sqlite => "INTEGER PRIMARY KEY AUTOINCREMENT",
+        DbConnection::Postgres => "BIGSERIAL PRIMARY KEY",
+        DbConnection::Mysql => "BIGINT AUTO_INCREMENT PRIMARY KEY",
+    };
+    let index = "idx_rustwork_jobs_fetch ON rustwork_jobs (status, queue, run_at)";
+
+    let mut sql = format!(
+        "CREATE TABLE IF NOT EXISTS {JOBS_TABLE} (\n    \
+         id {id},\n    \
+         queue VARCHAR(255) NOT NULL,\n    \
+         job_type VARCHAR(255) NOT NULL,\n    \
+         payload TEXT NOT NULL,\n    \
+         status VARCHAR(16) NOT NULL DEFAULT 'pending',\n    \
+         attempts BIGINT NOT NULL DEFAULT 0,\n    \
+         max_attempts BIGINT NOT NULL,\n    \
+         run_at BIGINT NOT NULL,\n    \
+         reserved_at BIGINT,\n    \
+         last_error TEXT,\n    \
+         created_at BIGINT NOT NULL,\n    \
+         updated_at BIGINT NOT NULL"
+    );
+
+    match connection {
+        // MySQL n'accepte pas `CREATE INDEX IF NOT EXISTS`
+        DbConnection::Mysql => sql.push_str(&format!(",\n    INDEX {}\n);\n", index)),
+        _ => sql.push_str(&format!("\n);\n\nCREATE INDEX IF NOT EXISTS {};\n", index)),
+    }
+
+    sql
+}
+
+/// Migration `down` de la table des jobs
+pub fn drop_table_sql() -> String {
+    format!("DROP TABLE IF EXISTS {};\n", JOBS_TABLE)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_create_table_sql() {
+        let sqlite = create_table_sql(&DbConnection::Sqlite);
+        assert!(sqlite.contains("id INTEGER PRIMARY KEY AUTOINCREMENT,"));
+        assert!(sqlite.contains("CREATE INDEX IF NOT EXISTS idx_rustwork_jobs_fetch"));
+
+        assert!(create_table_sql(&DbConnection::Postgres).contains("id BIGSERIAL PRIMARY KEY,"));
+
+        let mysql = create_table_sql(&DbConnection::Mysql);
+        assert!(mysql.contains("id BIGINT AUTO_INCREMENT PRIMARY KEY,"));
+        assert!(mysql.contains("updated_at BIGINT NOT NULL,\n    INDEX idx_rustwork_jobs_fetch"));
+        assert!(!mysql.contains("CREATE INDEX"));
+    }
+}
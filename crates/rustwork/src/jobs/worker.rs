@@ -0,0 +1,458 @@
+use super::job::exponential_backoff;
+use super::job_queue::{JobQueue, QueuedJob};
+use super::job_registry::{JobRegistry, Outcome};
+use crate::errors::AppResult;
+use crate::state::AppState;
+use std::future::Future;
+use std::sync::Arc;
+use std::time::Duration;
+use tokio::sync::watch;
+use tracing::{error, info, warn};
+
+/// Exécute les jobs de la file du service
+///
+/// Lancé à côté du serveur HTTP (`tokio::spawn(worker.run())`) ou dans un
+/// process dédié ; plusieurs workers peuvent partager la même base.
+/// Pendant l'exécution d'un job, sa réservation est prolongée régulièrement :
+/// seul un job dont le worker s'est arrêté est repris par un autre.
+pub struct Worker {
+    state: AppState,
+    queue: JobQueue,
+    registry: Arc<JobRegistry>,
+    queues: Vec<String>,
+    concurrency: usize,
+    poll_interval: Duration,
+    reservation_timeout: Duration,
+}
+
+impl Worker {
+    pub fn new(state: AppState, registry: JobRegistry) -> Self {
+        Self {
+            queue: JobQueue::new(state.db.clone()),
+            state,
+            registry: Arc::new(registry),
+            queues: Vec::new(),
+            concurrency: 1,
+            poll_interval: Duration::from_secs(1),
+            reservation_timeout: Duration::from_secs(300),
+        }
+    }
+
+    /// Files traitées (défaut : toutes)
+    pub fn queues(mut self, queues: &[&str]) -> Self {
+        self.queues = queues.iter().map(|q| q.to_string()).collect();
+        self
+    }
+
+    /// Nombre de jobs exécutés en parallèle (défaut : 1)
+    pub fn concurrency(mut self, concurrency: usize) -> Self {
+        self.concurrency = concurrency.max(1);
+        self
+    }
+
+    /// Attente entre deux recherches quand la file est vide (défaut : 1s)
+    pub fn poll_interval(mut self, poll_interval: Duration) -> Self {
+        self.poll_interval = poll_interval;
+        self
+    }
+
+    /// Durée sans nouvelle du worker après laquelle un job réservé est repris (défaut : 5 min)
+    pub fn reservation_timeout(mut self, reservation_timeout: Duration) -> Self {
+        self.reservation_timeout = reservation_timeout;
+        self
+    }
+
+    /// Traite les jobs jusqu'à l'arrêt du process
+    pub async fn run(self) {
+        self.run_until(std::future::pending()).await
+    }
+
+    /// Traite les jobs jusqu'à ce que `shutdown` se termine, puis attend la fin des jobs en cours
+    ///
+    /// ```ignore
+    /// worker.run_until(async { tokio::signal::ctrl_c().await.ok(); }).await;
+    /// ```
+    pub async fn run_until<F>(self, shutdown: F)
+    where
+        F: Future<Output = ()> + Send,
+    {
+        let queues = if self.queues.is_empty() {
+            "all".to_string()
+        } else {
+            self.queues.join(", ")
+        };
+        info!(
+            "Job worker started (queues: {}, concurrency: {})",
+            queues, self.concurrency
+        );
+
+        let (stop, stopped) = watch::channel(false);
+        let worker = Arc::new(self);
+        let loops: Vec<_> = (0..worker.concurrency)
+            .map(|_| {
+                let worker = worker.clone();
+                let mut stopped = stopped.clone();
+                tokio::spawn(async move {
+                    let mut unavailable = false;
+                    while !*stopped.borrow() {
+                        let idle = match worker.work_once().await {
+                            Ok(found) => {
+                                unavailable = false;
+                                !found
+                            }
+                            Err(e) => {
+                                // Une seule erreur tant que la file reste indisponible
+                                if !unavailable {
+                                    error!(
+                                        "Job queue unavailable: {} (is the rustwork_jobs migration applied?)",
+                                        e
+                                    );
+                                }
+                                unavailable = true;
+                                true
+                            }
+                        };
+
+                        if idle {
+                            tokio::select! {
+                                _ = tokio::time::sleep(worker.poll_interval) => {}
+                                _ = stopped.changed() => {}
+                            }
+                        }
+                    }
+                })
+            })
+            .collect();
+
+        shutdown.await;
+        info!("Job worker stopping, waiting for running jobs");
+        let _ = stop.send(true);
+
+        for handle in loops {
+            let _ = handle.await;
+        }
+        info!("Job worker stopped");
+    }
+
+    /// Exécute le prochain job dû ; `false` si aucun job n'est prêt
+    pub async fn work_once(&self) -> AppResult<bool> {
+        let Some(job) = self
+            .queue
+            .reserve(&self.queues, self.reservation_timeout)
+            .await?
+        else {
+            return Ok(false);
+        };
+
+        match self.execute_with_heartbeat(&job).await {
+            Outcome::Done => {
+                self.queue.complete(job.id).await?;
+                info!("Job {} #{} done", job.job_type, job.id);
+            }
+            Outcome::Failed { error, retry_in } => match retry_in {
+                Some(delay) if job.attempts < job.max_attempts => {
+                    warn!(
+                        "Job {} #{} failed (attempt {}/{}), retrying in {:?}: {}",
+                        job.job_type, job.id, job.attempts, job.max_attempts, delay, error
+                    );
+                    self.queue.release(job.id, &error, delay).await?;
+                }
+                _ => {
+                    error!(
+                        "Job {} #{} moved to dead-letter after {} attempt(s): {}",
+                        job.job_type, job.id, job.attempts, error
+                    );
+                    self.queue.bury(job.id, &error).await?;
+                }
+            },
+        }
+
+        Ok(true)
+    }
+
+    /// Exécute le job en prolongeant sa réservation tant qu'il tourne
+    async fn execute_with_heartbeat(&self, job: &QueuedJob) -> Outcome {
+        let execute = self.execute(job);
+        tokio::pin!(execute);
+
+        let period = (self.reservation_timeout / 3).max(Duration::from_millis(10));
+        let mut heartbeat = tokio::time::interval_at(tokio::time::Instant::now() + period, period);
+        loop {
+            tokio::select! {
+                outcome = &mut execute => return outcome,
+                _ = heartbeat.tick() => {
+                    if let Err(e) = self.queue.heartbeat(job.id).await {
+                        warn!("Failed to extend reservation of job {} #{}: {}", job.job_type, job.id, e);
+                    }
+                }
+            }
+        }
+    }
+
+    async fn execute(&self, job: &QueuedJob) -> Outcome {
+        let Some(future) = self.registry.dispatch(
+            self.state.clone(),
+            &job.job_type,
+            job.payload.clone(),
+            job.attempts,
+        ) else {
+            return Outcome::Failed {
+                error: format!("No handler registered for job {}", job.job_type),
+                retry_in: None,
+            };
+        };
+
+        // Tâche séparée : un panic du job est traité comme un échec
+        match tokio::spawn(future).await {
+            Ok(outcome) => outcome,
+            Err(e) => Outcome::Failed {
+                error: format!("Job panicked: {}", e),
+                retry_in: Some(exponential_backoff(job.attempts)),
+            },
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::config::types::{CorsConfig, DatabaseConfig, ServerConfig};
+    use crate::config::{AppConfig, DbConnection};
+    use crate::errors::AppError;
+    use crate::jobs::{create_table_sql, BoxFuture, Job};
+    use crate::state::DatabaseConnection;
+    use serde::{Deserialize, Serialize};
+    use sqlx::sqlite::SqlitePoolOptions;
+    use sqlx::SqlitePool;
+
+    #[derive(Serialize, Deserialize)]
+    struct RecordVisit {
+        page: String,
+    }
+
+    impl Job for RecordVisit {
+        fn handle<'a>(&'a self, state: &'a AppState) -> BoxFuture<'a, AppResult<()>> {
+            Box::pin(async move {
+                let DatabaseConnection::Sqlite(pool) = &state.db else {
+                    unreachable!()
+                };
+                sqlx::query("INSERT INTO visits (page) VALUES (?)")
+                    .bind(&self.page)
+                    .execute(pool)
+                    .await?;
+                Ok(())
+            })
+        }
+    }
+
+    #[derive(Serialize, Deserialize)]
+    struct AlwaysFails;
+
+    impl Job for AlwaysFails {
+        fn max_attempts(&self) -> u32 {
+            2
+        }
+
+        fn backoff(&self, _attempt: u32) -> Duration {
+            Duration::ZERO
+        }
+
+        fn handle<'a>(&'a self, _state: &'a AppState) -> BoxFuture<'a, AppResult<()>> {
+            Box::pin(async { Err(AppError::InternalError("boom".to_string())) })
+        }
+    }
+
+    #[derive(Serialize, Deserialize)]
+    struct Panics;
+
+    impl Job for Panics {
+        fn handle<'a>(&'a self, _state: &'a AppState) -> BoxFuture<'a, AppResult<()>> {
+            Box::pin(async { panic!("job panicked") })
+        }
+    }
+
+    /// Job plus long que la réservation des tests
+    #[derive(Serialize, Deserialize)]
+    struct Slow;
+
+    impl Job for Slow {
+        fn handle<'a>(&'a self, state: &'a AppState) -> BoxFuture<'a, AppResult<()>> {
+            Box::pin(async move {
+                tokio::time::sleep(Duration::from_millis(500)).await;
+                RecordVisit {
+                    page: "/slow".to_string(),
+                }
+                .handle(state)
+                .await
+            })
+        }
+    }
+
+    fn registry() -> JobRegistry {
+        JobRegistry::new()
+            .register::<RecordVisit>()
+            .register::<AlwaysFails>()
+            .register::<Panics>()
+            .register::<Slow>()
+    }
+
+    async fn setup() -> (SqlitePool, Worker) {
+        let pool = SqlitePoolOptions::new()
+            .max_connections(1)
+            .connect("sqlite::memory:")
+            .await
+            .unwrap();
+        sqlx::raw_sql(&create_table_sql(&DbConnection::Sqlite))
+            .execute(&pool)
+            .await
+            .unwrap();
+        sqlx::query("CREATE TABLE visits (id INTEGER PRIMARY KEY, page TEXT)")
+            .execute(&pool)
+            .await
+            .unwrap();
+
+        let config = AppConfig {
+            server: ServerConfig {
+                host: "127.0.0.1".to_string(),
+                port: 3000,
+            },
+            database: DatabaseConfig::default(),
+            cors: CorsConfig::default(),
+        };
+        let state = AppState::new(DatabaseConnection::Sqlite(pool.clone()), config);
+
+        (pool, Worker::new(state, registry()))
+    }
+
+    async fn pages(pool: &SqlitePool) -> Vec<String> {
+        sqlx::query_scalar("SELECT page FROM visits")
+            .fetch_all(pool)
+            .await
+            .unwrap()
+    }
+
+    async fn status(pool: &SqlitePool) -> Vec<(String, i64)> {
+        sqlx::query_as("SELECT status, attempts FROM rustwork_jobs ORDER BY id")
+            .fetch_all(pool)
+            .await
+            .unwrap()
+    }
+
+    #[tokio::test]
+    async fn test_work_once_runs_and_deletes_job() {
+        let (pool, worker) = setup().await;
+        worker
+            .queue
+            .push(&RecordVisit {
+                page: "/home".to_string(),
+            })
+            .await
+            .unwrap();
+
+        assert!(worker.work_once().await.unwrap());
+        assert!(!worker.work_once().await.unwrap());
+
+        assert_eq!(pages(&pool).await, vec!["/home"]);
+        assert!(status(&pool).await.is_empty());
+    }
+
+    #[tokio::test]
+    async fn test_failed_job_is_retried_then_dead() {
+        let (pool, worker) = setup().await;
+        worker.queue.push(&AlwaysFails).await.unwrap();
+
+        assert!(worker.work_once().await.unwrap());
+        assert_eq!(status(&pool).await, vec![("pending".to_string(), 1)]);
+
+        assert!(worker.work_once().await.unwrap());
+        assert_eq!(status(&pool).await, vec![("dead".to_string(), 2)]);
+        assert!(!worker.work_once().await.unwrap());
+
+        let dead = worker.queue.dead_jobs().await.unwrap();
+        assert_eq!(dead[0].job_type, "AlwaysFails");
+        assert_eq!(
+            dead[0].last_error.as_deref(),
+            Some("Internal server error: boom")
+        );
+    }
+
+    #[tokio::test]
+    async fn test_long_running_job_keeps_its_reservation() {
+        let (pool, worker) = setup().await;
+        let timeout = Duration::from_millis(150);
+        let other = Worker::new(worker.state.clone(), registry()).reservation_timeout(timeout);
+        let worker = worker.reservation_timeout(timeout);
+        worker.queue.push(&Slow).await.unwrap();
+
+        let running = tokio::spawn(async move { worker.work_once().await.unwrap() });
+        tokio::time::sleep(Duration::from_millis(350)).await;
+
+        // Réservation prolongée : l'autre worker ne reprend pas le job
+        assert!(!other.work_once().await.unwrap());
+        assert!(running.await.unwrap());
+        assert_eq!(pages(&pool).await, vec!["/slow"]);
+        assert!(status(&pool).await.is_empty());
+    }
+
+    #[tokio::test]
+    async fn test_run_until_finishes_running_job() {
+        let (pool, worker) = setup().await;
+        worker.queue.push(&Slow).await.unwrap();
+        worker.queue.push(&Slow).await.unwrap();
+
+        worker
+            .run_until(tokio::time::sleep(Duration::from_millis(100)))
+            .await;
+
+        // Le job en cours est terminé, le suivant reste en attente
+        assert_eq!(pages(&pool).await, vec!["/slow"]);
+        assert_eq!(status(&pool).await, vec![("pending".to_string(), 0)]);
+    }
+
+    #[tokio::test]
+    async fn test_panicking_job_is_retried_later() {
+        let (pool, worker) = setup().await;
+        worker.queue.push(&Panics).await.unwrap();
+
+        assert!(worker.work_once().await.unwrap());
+        assert_eq!(status(&pool).await, vec![("pending".to_string(), 1)]);
+        // Backoff de 10s : pas encore dû
+        assert!(!worker.work_once().await.unwrap());
+    }
+
+    #[tokio::test]
+    async fn test_unknown_or_invalid_job_is_dead() {
+        let (pool, worker) = setup().await;
+        sqlx::query(
+            "INSERT INTO rustwork_jobs (queue, job_type, payload, max_attempts, run_at, created_at, updated_at) \
+             VALUES ('default', 'Removed', '{}', 3, 0, 0, 0), ('default', 'RecordVisit', '{}', 3, 0, 0, 0)",
+        )
+        .execute(&pool)
+        .await
+        .unwrap();
+
+        assert!(worker.work_once().await.unwrap());
+        assert!(worker.work_once().await.unwrap());
+
+        let dead = worker.queue.dead_jobs().await.unwrap();
+        assert_eq!(dead.len(), 2);
+        assert_eq!(
+            dead[0].last_error.as_deref(),
+            Some("No handler registered for job Removed")
+        );
+        assert!(dead[1]
+            .last_error
+            .as_deref()
+            .unwrap()
+            .starts_with("Invalid payload for job RecordVisit"));
+    }
+
+    #[tokio::test]
+    async fn test_registry_names() {
+        let (_, worker) = setup().await;
+        assert_eq!(
+            worker.registry.names(),
+            vec!["RecordVisit", "AlwaysFails", "Panics", "Slow"]
+        );
+    }
+}
@@ -1,14 +1,24 @@
 pub mod config;
 pub mod database;
 pub mod errors;
+pub mod jobs;
+pub mod logging;
 pub mod middleware;
 pub mod response;
 pub mod routing;
+pub mod seeding;
 pub mod state;
+pub mod utils;
+
+#[cfg(any(test, feature = "testing"))]
+pub mod testing;
 
 pub use config::{AppConfig, CorsConfig, DatabaseConfig, DbConnection, PoolConfig, ServerConfig};
 pub use database::{connect_database, connect_db, init_database, Paginator};
 pub use errors::{AppError, AppResult};
+pub use jobs::{Job, JobQueue, JobRegistry, Worker};
+pub use logging::init_tracing;
 pub use response::{created, error, ok, ApiResponse};
-pub use routing::build_router;
-pub use state::AppState;
+pub use routing::{build_router, openapi_router};
+pub use seeding::{SeedCommand, Seeder, SeederRegistry};
+pub use state::{AppState, DatabaseConnection};
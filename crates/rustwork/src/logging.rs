@@ -0,0 +1,43 @@
+//! Initialisation des logs des services
+
+use tracing_subscriber::{layer::SubscriberExt, util::SubscriberInitExt, EnvFilter};
+
+/// Variable qui choisit le format des logs (`json`, sinon texte)
+///
+/// `rustwork dev` la positionne à `json` pour agréger les logs des services.
+pub const LOG_FORMAT_ENV: &str = "RUSTWORK_LOG_FORMAT";
+
+/// Installe le subscriber `tracing` global
+///
+/// Le filtre vient de `RUST_LOG`, sinon de `default_filter`. Les logs sont en JSON
+/// (champs des spans inclus, dont `request_id`) si `RUSTWORK_LOG_FORMAT=json`.
+pub fn init_tracing(default_filter: &str) {
+    let filter =
+        EnvFilter::try_from_default_env().unwrap_or_else(|_| EnvFilter::new(default_filter));
+    let registry = tracing_subscriber::registry().with(filter);
+
+    if json_requested(std::env::var(LOG_FORMAT_ENV).ok().as_deref()) {
+        registry
+            .with(tracing_subscriber::fmt::layer().json().flatten_event(true))
+            .init();
+    } else {
+        registry.with(tracing_subscriber::fmt::layer()).init();
+    }
+}
+
+fn json_requested(format: Option<&str>) -> bool {
+    format.is_some_and(|f| f.eq_ignore_ascii_case("json"))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_json_requested() {
+        assert!(json_requested(Some("json")));
+        assert!(json_requested(Some("JSON")));
+        assert!(!json_requested(Some("text")));
+        assert!(!json_requested(None));
+    }
+}
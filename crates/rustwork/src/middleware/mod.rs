@@ -3,4 +3,4 @@ pub mod request_id;
 
 // Re-exports publics
 pub use cors::build_cors_layer;
-pub use request_id::{request_id_middleware, REQUEST_ID_HEADER};
+pub use request_id::{incoming_request_id, request_id_middleware, REQUEST_ID_HEADER};
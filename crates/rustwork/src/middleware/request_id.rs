@@ -1,12 +1,34 @@
-use axum::{extract::Request, http::HeaderValue, middleware::Next, response::Response};
-use tracing::info;
+use axum::{
+    extract::Request,
+    http::{HeaderMap, HeaderValue},
+    middleware::Next,
+    response::Response,
+};
+use tracing::{info, info_span, Instrument};
 use uuid::Uuid;
 
 pub const REQUEST_ID_HEADER: &str = "x-request-id";
 
+/// Longueur maximale d'un request_id reçu
+const MAX_REQUEST_ID_LEN: usize = 128;
+
+/// request_id reçu dans `x-request-id` (gateway, proxy), s'il est exploitable
+pub fn incoming_request_id(headers: &HeaderMap) -> Option<&str> {
+    headers
+        .get(REQUEST_ID_HEADER)?
+        .to_str()
+        .ok()
+        .filter(|id| !id.is_empty() && id.len() <= MAX_REQUEST_ID_LEN)
+}
+
 /// Middleware pour ajouter un request_id à chaque requête
+///
+/// Un `x-request-id` reçu est conservé pour corréler les logs entre services. Les logs
+/// émis pendant la requête sont dans un span `request` qui porte le `request_id`.
 pub async fn request_id_middleware(mut req: Request, next: Next) -> Response {
-    let request_id = Uuid::new_v4().to_string();
+    let request_id = incoming_request_id(req.headers())
+        .map(str::to_string)
+        .unwrap_or_else(|| Uuid::new_v4().to_string());
 
     // Ajoute le request_id dans les headers de la requête
     req.headers_mut().insert(
@@ -14,9 +36,10 @@ pub async fn request_id_middleware(mut req: Request, next: Next) -> Response {
         HeaderValue::from_str(&request_id).unwrap(),
     );
 
-    info!(request_id = %request_id, method = %req.method(), uri = %req.uri(), "Incoming request");
+    let span = info_span!("request", request_id = %request_id);
+    span.in_scope(|| info!(method = %req.method(), uri = %req.uri(), "Incoming request"));
 
-    let mut response = next.run(req).await;
+    let mut response = next.run(req).instrument(span).await;
 
     // Ajoute le request_id dans les headers de la réponse
     response.headers_mut().insert(
@@ -51,6 +74,44 @@ mod tests {
         assert!(header_val.is_ok());
     }
 
+    #[test]
+    fn test_incoming_request_id() {
+        let mut headers = HeaderMap::new();
+        assert_eq!(incoming_request_id(&headers), None);
+
+        headers.insert(REQUEST_ID_HEADER, HeaderValue::from_static("abc-123"));
+        assert_eq!(incoming_request_id(&headers), Some("abc-123"));
+
+        headers.insert(REQUEST_ID_HEADER, HeaderValue::from_static(""));
+        assert_eq!(incoming_request_id(&headers), None);
+
+        let too_long = "a".repeat(MAX_REQUEST_ID_LEN + 1);
+        headers.insert(REQUEST_ID_HEADER, HeaderValue::from_str(&too_long).unwrap());
+        assert_eq!(incoming_request_id(&headers), None);
+    }
+
+    #[tokio::test]
+    async fn test_middleware_keeps_incoming_request_id() {
+        use axum::{body::Body, middleware, routing::get, Router};
+        use tower::ServiceExt;
+
+        let app = Router::new()
+            .route("/", get(|| async { "ok" }))
+            .layer(middleware::from_fn(request_id_middleware));
+
+        let request = Request::builder()
+            .uri("/")
+            .header(REQUEST_ID_HEADER, "from-gateway")
+            .body(Body::empty())
+            .unwrap();
+        let response = app.clone().oneshot(request).await.unwrap();
+        assert_eq!(response.headers()[REQUEST_ID_HEADER], "from-gateway");
+
+        let request = Request::builder().uri("/").body(Body::empty()).unwrap();
+        let response = app.oneshot(request).await.unwrap();
+        assert_eq!(response.headers()[REQUEST_ID_HEADER].len(), 36);
+    }
+
     #[test]
     fn test_request_id_header_name_lowercase() {
         // Les noms de headers HTTP sont case-insensitive mais conventionnellement lowercase
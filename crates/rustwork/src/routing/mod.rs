@@ -1,5 +1,7 @@
 pub mod build_router;
 pub mod handlers;
+pub mod openapi;
 
 // Re-export public
 pub use build_router::build_router;
+pub use openapi::openapi_router;
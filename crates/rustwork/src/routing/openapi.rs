@@ -0,0 +1,101 @@
+use crate::errors::AppError;
+use axum::{extract::State, response::Html, routing::get, Json, Router};
+use std::path::PathBuf;
+use std::sync::Arc;
+
+/// Page Swagger UI (assets servis par le CDN unpkg)
+const SWAGGER_UI_HTML: &str = r##"<!DOCTYPE html>
+<html lang="en">
+<head>
+    <meta charset="utf-8" />
+    <meta name="viewport" content="width=device-width, initial-scale=1" />
+    <title>API documentation</title>
+    <link rel="stylesheet" href="https://unpkg.com/swagger-ui-dist@5/swagger-ui.css" />
+</head>
+<body>
+    <div id="swagger-ui"></div>
+    <script src="https://unpkg.com/swagger-ui-dist@5/swagger-ui-bundle.js" crossorigin></script>
+    <script>
+        window.onload = () => {
+            // Relatif à /docs : fonctionne sous un préfixe (nest, gateway /<service>/docs)
+            const docs = location.origin + location.pathname.replace(/\/+$/, "");
+            const url = new URL("openapi.json", docs).href;
+            window.ui = SwaggerUIBundle({ url, dom_id: "#swagger-ui" });
+        };
+    </script>
+</body>
+</html>
+"##;
+
+/// Router exposant `/openapi.json` et `/docs` (Swagger UI)
+///
+/// Le document est relu à chaque requête : un `rustwork openapi` suffit à le
+/// mettre à jour sans redémarrer le service.
+pub fn openapi_router<S>(spec_path: impl Into<PathBuf>) -> Router<S>
+where
+    S: Clone + Send + Sync + 'static,
+{
+    let spec_path = Arc::new(spec_path.into());
+
+    Router::new()
+        .route("/openapi.json", get(openapi_json))
+        .route("/docs", get(swagger_ui))
+        .with_state(spec_path)
+}
+
+/// Handler pour /openapi.json
+async fn openapi_json(
+    State(spec_path): State<Arc<PathBuf>>,
+) -> Result<Json<serde_json::Value>, AppError> {
+    let content = tokio::fs::read_to_string(spec_path.as_ref())
+        .await
+        .map_err(|_| {
+            AppError::NotFound(format!(
+                "OpenAPI document not found at {} (run `rustwork openapi`)",
+                spec_path.display()
+            ))
+        })?;
+
+    let spec = serde_json::from_str(&content)
+        .map_err(|e| AppError::InternalError(format!("Invalid OpenAPI document: {}", e)))?;
+
+    Ok(Json(spec))
+}
+
+/// Handler pour /docs
+async fn swagger_ui() -> Html<&'static str> {
+    Html(SWAGGER_UI_HTML)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use axum::response::IntoResponse;
+
+    #[tokio::test]
+    async fn test_openapi_json_reads_spec_file() {
+        let path =
+            std::env::temp_dir().join(format!("rustwork_openapi_{}.json", uuid::Uuid::new_v4()));
+        std::fs::write(&path, r#"{"openapi":"3.1.0"}"#).unwrap();
+
+        let Json(spec) = openapi_json(State(Arc::new(path.clone()))).await.unwrap();
+        assert_eq!(spec["openapi"], "3.1.0");
+
+        let _ = std::fs::remove_file(&path);
+    }
+
+    #[tokio::test]
+    async fn test_openapi_json_missing_file() {
+        let path = PathBuf::from("/nonexistent/openapi.json");
+        let result = openapi_json(State(Arc::new(path))).await;
+        assert!(matches!(result, Err(AppError::NotFound(_))));
+    }
+
+    #[tokio::test]
+    async fn test_swagger_ui_points_to_spec() {
+        let response = swagger_ui().await.into_response();
+        assert_eq!(response.status(), axum::http::StatusCode::OK);
+        assert!(SWAGGER_UI_HTML.contains(r#"new URL("openapi.json", docs)"#));
+        assert!(!SWAGGER_UI_HTML.contains("\"/openapi.json\""));
+    }
+}
@@ -0,0 +1,11 @@
+//! Seeders de données de référence (`rustwork db seed`)
+
+pub mod seed_command;
+pub mod seeder;
+pub mod seeder_registry;
+
+// Re-exports publics
+pub use crate::utils::BoxFuture;
+pub use seed_command::SeedCommand;
+pub use seeder::Seeder;
+pub use seeder_registry::SeederRegistry;
@@ -0,0 +1,71 @@
+/// Mode `seed` d'un service : `<service> seed [--class <Seeder>]`
+///
+/// `rustwork db seed` lance le binaire du service dans ce mode ; le profil
+/// vient de `APP_ENV` (défaut : `dev`).
+#[derive(Debug, Clone, PartialEq)]
+pub struct SeedCommand {
+    pub class: Option<String>,
+    pub profile: String,
+}
+
+impl SeedCommand {
+    /// Lit `std::env::args()` ; `None` si le service n'est pas lancé en mode seed
+    pub fn from_args() -> Option<Self> {
+        let profile = std::env::var("APP_ENV").unwrap_or_else(|_| "dev".to_string());
+        Self::parse(std::env::args().skip(1), profile)
+    }
+
+    pub fn parse(args: impl IntoIterator<Item = String>, profile: String) -> Option<Self> {
+        let mut args = args.into_iter();
+        if args.next().as_deref() != Some("seed") {
+            return None;
+        }
+
+        let mut class = None;
+        while let Some(arg) = args.next() {
+            if let Some(value) = arg.strip_prefix("--class=") {
+                class = Some(value.to_string());
+            } else if arg == "--class" {
+                class = args.next();
+            }
+        }
+
+        Some(Self { class, profile })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn args(list: &[&str]) -> Vec<String> {
+        list.iter().map(|s| s.to_string()).collect()
+    }
+
+    #[test]
+    fn test_parse_seed_mode() {
+        let command = SeedCommand::parse(args(&["seed"]), "dev".to_string()).unwrap();
+        assert_eq!(command.class, None);
+        assert_eq!(command.profile, "dev");
+    }
+
+    #[test]
+    fn test_parse_class() {
+        let command = SeedCommand::parse(
+            args(&["seed", "--class", "RolesSeeder"]),
+            "prod".to_string(),
+        )
+        .unwrap();
+        assert_eq!(command.class.as_deref(), Some("RolesSeeder"));
+
+        let command =
+            SeedCommand::parse(args(&["seed", "--class=UsersSeeder"]), "dev".to_string()).unwrap();
+        assert_eq!(command.class.as_deref(), Some("UsersSeeder"));
+    }
+
+    #[test]
+    fn test_parse_other_mode() {
+        assert!(SeedCommand::parse(args(&[]), "dev".to_string()).is_none());
+        assert!(SeedCommand::parse(args(&["serve"]), "dev".to_string()).is_none());
+    }
+}
@@ -0,0 +1,21 @@
+use crate::errors::AppResult;
+use crate::state::DatabaseConnection;
+use crate::utils::BoxFuture;
+
+/// Remplit la base avec des données de référence
+///
+/// Le nom d'un seeder est celui de son type (`RolesSeeder`), utilisé par
+/// `dependencies()` et `rustwork db seed --class`.
+pub trait Seeder: Send + Sync {
+    /// Seeders à exécuter avant celui-ci
+    fn dependencies(&self) -> Vec<&'static str> {
+        Vec::new()
+    }
+
+    /// Profils (`APP_ENV`) où le seeder s'exécute ; vide = tous
+    fn profiles(&self) -> Vec<&'static str> {
+        Vec::new()
+    }
+
+    fn run<'a>(&'a self, db: &'a DatabaseConnection) -> BoxFuture<'a, AppResult<()>>;
+}
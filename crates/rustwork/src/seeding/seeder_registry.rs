@@ -0,0 +1,292 @@
+use super::seed_command::SeedCommand;
+use super::seeder::Seeder;
+use crate::errors::{AppError, AppResult};
+use crate::state::DatabaseConnection;
+use tracing::info;
+
+/// Seeders d'un service, exécutés dans l'ordre des dépendances
+#[derive(Default)]
+pub struct SeederRegistry {
+    seeders: Vec<(String, Box<dyn Seeder>)>,
+}
+
+impl SeederRegistry {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    pub fn register<S: Seeder + 'static>(mut self, seeder: S) -> Self {
+        let name = std::any::type_name::<S>()
+            .rsplit("::")
+            .next()
+            .unwrap_or_default()
+            .to_string();
+        self.seeders.push((name, Box::new(seeder)));
+        self
+    }
+
+    /// Noms des seeders, dans l'ordre d'enregistrement
+    pub fn names(&self) -> Vec<&str> {
+        self.seeders.iter().map(|(name, _)| name.as_str()).collect()
+    }
+
+    /// Ordre d'exécution : `class` et ses dépendances, ou tous les seeders du profil
+    pub fn plan(&self, class: Option<&str>, profile: &str) -> AppResult<Vec<&str>> {
+        let roots: Vec<&str> = match class {
+            Some(class) => {
+                let (name, seeder) = self.find(class).ok_or_else(|| {
+                    AppError::NotFound(format!(
+                        "Seeder '{}' not found (available: {})",
+                        class,
+                        self.names().join(", ")
+                    ))
+                })?;
+                if !enabled(seeder, profile) {
+                    return Err(AppError::BadRequest(format!(
+                        "Seeder '{}' is not enabled for profile '{}'",
+                        class, profile
+                    )));
+                }
+                vec![name]
+            }
+            None => self
+                .seeders
+                .iter()
+                .filter(|(_, seeder)| enabled(seeder.as_ref(), profile))
+                .map(|(name, _)| name.as_str())
+                .collect(),
+        };
+
+        let mut order = Vec::new();
+        let mut visiting = Vec::new();
+        for root in roots {
+            self.visit(root, profile, &mut visiting, &mut order)?;
+        }
+
+        Ok(order)
+    }
+
+    /// Exécute les seeders planifiés et retourne leurs noms
+    pub async fn run(
+        &self,
+        db: &DatabaseConnection,
+        command: &SeedCommand,
+    ) -> AppResult<Vec<String>> {
+        let plan = self.plan(command.class.as_deref(), &command.profile)?;
+
+        for name in &plan {
+            info!("Seeding: {}", name);
+            if let Some((_, seeder)) = self.find(name) {
+                seeder.run(db).await?;
+            }
+        }
+
+        info!("{} seeder(s) executed", plan.len());
+        Ok(plan.into_iter().map(str::to_string).collect())
+    }
+
+    fn find(&self, name: &str) -> Option<(&str, &dyn Seeder)> {
+        self.seeders
+            .iter()
+            .find(|(n, _)| n == name)
+            .map(|(n, seeder)| (n.as_str(), seeder.as_ref()))
+    }
+
+    fn visit<'a>(
+        &'a self,
+        name: &'a str,
+        profile: &str,
+        visiting: &mut Vec<&'a str>,
+        order: &mut Vec<&'a str>,
+    ) -> AppResult<()> {
+        if order.contains(&name) {
+            return Ok(());
+        }
+        if visiting.contains(&name) {
+            visiting.push(name);
+            return Err(AppError::BadRequest(format!(
+                "Circular seeder dependency: {}",
+                visiting.join(" -> ")
+            )));
+        }
+
+        let (name, seeder) = self
+            .find(name)
+            .ok_or_else(|| AppError::NotFound(format!("Seeder '{}' not found", name)))?;
+
+        visiting.push(name);
+        for dependency in seeder.dependencies() {
+            let Some((dep_name, dep)) = self.find(dependency) else {
+                return Err(AppError::NotFound(format!(
+                    "Seeder '{}' depends on unknown seeder '{}'",
+                    name, dependency
+                )));
+            };
+            if !enabled(dep, profile) {
+                return Err(AppError::BadRequest(format!(
+                    "Seeder '{}' depends on '{}', which is not enabled for profile '{}'",
+                    name, dep_name, profile
+                )));
+            }
+            self.visit(dep_name, profile, visiting, order)?;
+        }
+        visiting.pop();
+
+        order.push(name);
+        Ok(())
+    }
+}
+
+fn enabled(seeder: &dyn Seeder, profile: &str) -> bool {
+    let profiles = seeder.profiles();
+    profiles.is_empty() || profiles.contains(&profile)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::utils::BoxFuture;
+    use sqlx::SqlitePool;
+
+    struct RolesSeeder;
+    struct UsersSeeder;
+    struct FakePostsSeeder;
+    struct LoopSeeder;
+
+    impl Seeder for RolesSeeder {
+        fn run<'a>(&'a self, db: &'a DatabaseConnection) -> BoxFuture<'a, AppResult<()>> {
+            Box::pin(async move {
+                let DatabaseConnection::Sqlite(pool) = db else {
+                    unreachable!()
+                };
+                sqlx::query("INSERT INTO log (name) VALUES ('roles')")
+                    .execute(pool)
+                    .await?;
+                Ok(())
+            })
+        }
+    }
+
+    impl Seeder for UsersSeeder {
+        fn dependencies(&self) -> Vec<&'static str> {
+            vec!["RolesSeeder"]
+        }
+
+        fn run<'a>(&'a self, db: &'a DatabaseConnection) -> BoxFuture<'a, AppResult<()>> {
+            Box::pin(async move {
+                let DatabaseConnection::Sqlite(pool) = db else {
+                    unreachable!()
+                };
+                sqlx::query("INSERT INTO log (name) VALUES ('users')")
+                    .execute(pool)
+                    .await?;
+                Ok(())
+            })
+        }
+    }
+
+    impl Seeder for FakePostsSeeder {
+        fn dependencies(&self) -> Vec<&'static str> {
+            vec!["UsersSeeder"]
+        }
+
+        fn profiles(&self) -> Vec<&'static str> {
+            vec!["dev"]
+        }
+
+        fn run<'a>(&'a self, _db: &'a DatabaseConnection) -> BoxFuture<'a, AppResult<()>> {
+            Box::pin(async { Ok(()) })
+        }
+    }
+
+    impl Seeder for LoopSeeder {
+        fn dependencies(&self) -> Vec<&'static str> {
+            vec!["LoopSeeder"]
+        }
+
+        fn run<'a>(&'a self, _db: &'a DatabaseConnection) -> BoxFuture<'a, AppResult<()>> {
+            Box::pin(async { Ok(()) })
+        }
+    }
+
+    fn registry() -> SeederRegistry {
+        SeederRegistry::new()
+            .register(FakePostsSeeder)
+            .register(UsersSeeder)
+            .register(RolesSeeder)
+    }
+
+    #[test]
+    fn test_register_uses_type_name() {
+        assert_eq!(
+            registry().names(),
+            vec!["FakePostsSeeder", "UsersSeeder", "RolesSeeder"]
+        );
+    }
+
+    #[test]
+    fn test_plan_resolves_dependencies() {
+        assert_eq!(
+            registry().plan(None, "dev").unwrap(),
+            vec!["RolesSeeder", "UsersSeeder", "FakePostsSeeder"]
+        );
+        assert_eq!(
+            registry().plan(Some("UsersSeeder"), "prod").unwrap(),
+            vec!["RolesSeeder", "UsersSeeder"]
+        );
+    }
+
+    #[test]
+    fn test_plan_filters_profiles() {
+        assert_eq!(
+            registry().plan(None, "prod").unwrap(),
+            vec!["RolesSeeder", "UsersSeeder"]
+        );
+        assert!(matches!(
+            registry().plan(Some("FakePostsSeeder"), "prod"),
+            Err(AppError::BadRequest(_))
+        ));
+    }
+
+    #[test]
+    fn test_plan_errors() {
+        assert!(matches!(
+            registry().plan(Some("MissingSeeder"), "dev"),
+            Err(AppError::NotFound(_))
+        ));
+        assert!(matches!(
+            SeederRegistry::new()
+                .register(UsersSeeder)
+                .plan(None, "dev"),
+            Err(AppError::NotFound(_))
+        ));
+        let err = SeederRegistry::new()
+            .register(LoopSeeder)
+            .plan(None, "dev")
+            .unwrap_err();
+        assert!(err.to_string().contains("LoopSeeder -> LoopSeeder"));
+    }
+
+    #[tokio::test]
+    async fn test_run_executes_in_order() {
+        let pool = SqlitePool::connect("sqlite::memory:").await.unwrap();
+        sqlx::query("CREATE TABLE log (id INTEGER PRIMARY KEY, name TEXT)")
+            .execute(&pool)
+            .await
+            .unwrap();
+        let db = DatabaseConnection::Sqlite(pool.clone());
+
+        let command = SeedCommand {
+            class: None,
+            profile: "prod".to_string(),
+        };
+        let ran = registry().run(&db, &command).await.unwrap();
+        assert_eq!(ran, vec!["RolesSeeder", "UsersSeeder"]);
+
+        let names: Vec<String> = sqlx::query_scalar("SELECT name FROM log ORDER BY id")
+            .fetch_all(&pool)
+            .await
+            .unwrap();
+        assert_eq!(names, vec!["roles", "users"]);
+    }
+}
@@ -0,0 +1,399 @@
+use super::insert_row::{insert_row, RowConnection};
+use crate::errors::{AppError, AppResult};
+use crate::state::DatabaseConnection;
+use crate::utils::BoxFuture;
+use serde::{de::DeserializeOwned, Serialize};
+use serde_json::{Map, Value};
+use std::any::TypeId;
+use std::collections::HashMap;
+use std::marker::PhantomData;
+use std::sync::{Mutex, OnceLock};
+
+/// Attributs de test d'un modèle (`make model` génère un `<Model>Factory`)
+///
+/// ```ignore
+/// impl Factory for PostFactory {
+///     type Model = Post;
+///
+///     fn table() -> &'static str {
+///         "posts"
+///     }
+///
+///     fn definition(seq: u64) -> Value {
+///         json!({ "title": format!("Post {}", seq) })
+///     }
+///
+///     fn relations() -> Vec<Relation> {
+///         vec![Relation::belongs_to::<UserFactory>("user_id")]
+///     }
+/// }
+///
+/// let post = PostFactory::builder().set("title", "Hello").create(app.db()).await?;
+/// ```
+pub trait Factory: Sized + 'static {
+    type Model: DeserializeOwned;
+
+    fn table() -> &'static str;
+
+    /// Clé primaire renseignée après insertion si absente des attributs
+    fn primary_key() -> &'static str {
+        "id"
+    }
+
+    /// Attributs par défaut ; `seq` est unique et croissant pour cette factory
+    fn definition(seq: u64) -> Value;
+
+    /// Parents créés automatiquement si la clé étrangère n'est pas fournie
+    fn relations() -> Vec<Relation> {
+        Vec::new()
+    }
+
+    fn builder() -> FactoryBuilder<Self> {
+        FactoryBuilder::new()
+    }
+}
+
+/// Relation `belongs_to` : crée un parent et renseigne la clé étrangère
+pub struct Relation {
+    foreign_key: &'static str,
+    create_parent: for<'a> fn(&'a dyn FactoryTarget) -> BoxFuture<'a, AppResult<Value>>,
+}
+
+impl Relation {
+    pub fn belongs_to<P: Factory>(foreign_key: &'static str) -> Self {
+        Self {
+            foreign_key,
+            create_parent: create_parent::<P>,
+        }
+    }
+}
+
+fn create_parent<P: Factory>(target: &dyn FactoryTarget) -> BoxFuture<'_, AppResult<Value>> {
+    Box::pin(async move {
+        let row = FactoryBuilder::<P>::new().insert(target).await?;
+        Ok(row.get(P::primary_key()).cloned().unwrap_or(Value::Null))
+    })
+}
+
+/// Destination des insertions : `DatabaseConnection` ou `TestTransaction`
+pub trait FactoryTarget: Sync {
+    /// Insère une ligne et retourne la colonne `returning` générée
+    fn insert_row<'a>(
+        &'a self,
+        table: &'a str,
+        row: &'a Map<String, Value>,
+        returning: Option<&'a str>,
+    ) -> BoxFuture<'a, AppResult<Option<Value>>>;
+}
+
+impl FactoryTarget for DatabaseConnection {
+    fn insert_row<'a>(
+        &'a self,
+        table: &'a str,
+        row: &'a Map<String, Value>,
+        returning: Option<&'a str>,
+    ) -> BoxFuture<'a, AppResult<Option<Value>>> {
+        Box::pin(async move {
+            match self {
+                DatabaseConnection::Sqlite(pool) => {
+                    let mut conn = pool.acquire().await?;
+                    insert_row(RowConnection::Sqlite(&mut conn), table, row, returning).await
+                }
+                DatabaseConnection::Postgres(pool) => {
+                    let mut conn = pool.acquire().await?;
+                    insert_row(RowConnection::Postgres(&mut conn), table, row, returning).await
+                }
+                DatabaseConnection::Mysql(pool) => {
+                    let mut conn = pool.acquire().await?;
+                    insert_row(RowConnection::Mysql(&mut conn), table, row, returning).await
+                }
+            }
+        })
+    }
+}
+
+/// Construction d'un ou plusieurs modèles à partir d'une factory
+pub struct FactoryBuilder<F: Factory> {
+    overrides: Map<String, Value>,
+    /// Premier attribut non sérialisable, signalé par `attributes`/`make`/`create`
+    invalid: Option<(String, serde_json::Error)>,
+    _factory: PhantomData<fn() -> F>,
+}
+
+impl<F: Factory> Default for FactoryBuilder<F> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<F: Factory> FactoryBuilder<F> {
+    pub fn new() -> Self {
+        Self {
+            overrides: Map::new(),
+            invalid: None,
+            _factory: PhantomData,
+        }
+    }
+
+    /// Remplace un attribut de la définition
+    pub fn set(mut self, attribute: &str, value: impl Serialize) -> Self {
+        match serde_json::to_value(value) {
+            Ok(value) => {
+                self.overrides.insert(attribute.to_string(), value);
+            }
+            Err(e) if self.invalid.is_none() => self.invalid = Some((attribute.to_string(), e)),
+            Err(_) => {}
+        }
+        self
+    }
+
+    /// Attributs finaux (définition + surcharges), sans insertion ni relations
+    pub fn attributes(&self) -> AppResult<Map<String, Value>> {
+        if let Some((attribute, e)) = &self.invalid {
+            return Err(AppError::InternalError(format!(
+                "Factory for '{}': attribute '{}' is not serializable: {}",
+                F::table(),
+                attribute,
+                e
+            )));
+        }
+
+        let mut attributes = match F::definition(next_sequence::<F>()) {
+            Value::Object(map) => map,
+            other => {
+                return Err(AppError::InternalError(format!(
+                    "Factory for '{}' must return a JSON object, got {}",
+                    F::table(),
+                    other
+                )))
+            }
+        };
+        attributes.extend(self.overrides.clone());
+        Ok(attributes)
+    }
+
+    /// Construit le modèle sans l'insérer
+    pub fn make(&self) -> AppResult<F::Model> {
+        to_model::<F>(self.attributes()?)
+    }
+
+    /// Insère le modèle (et ses parents) puis le retourne
+    pub async fn create(&self, target: &impl FactoryTarget) -> AppResult<F::Model> {
+        to_model::<F>(self.insert(target).await?)
+    }
+
+    pub async fn create_many(
+        &self,
+        count: usize,
+        target: &impl FactoryTarget,
+    ) -> AppResult<Vec<F::Model>> {
+        let mut models = Vec::with_capacity(count);
+        for _ in 0..count {
+            models.push(self.create(target).await?);
+        }
+        Ok(models)
+    }
+
+    async fn insert(&self, target: &dyn FactoryTarget) -> AppResult<Map<String, Value>> {
+        let mut row = self.attributes()?;
+
+        for relation in F::relations() {
+            if !row.contains_key(relation.foreign_key) {
+                let parent_key = (relation.create_parent)(target).await?;
+                row.insert(relation.foreign_key.to_string(), parent_key);
+            }
+        }
+
+        let primary_key = F::primary_key();
+        let returning = (!row.contains_key(primary_key)).then_some(primary_key);
+        if let Some(key) = target.insert_row(F::table(), &row, returning).await? {
+            row.insert(primary_key.to_string(), key);
+        }
+
+        Ok(row)
+    }
+}
+
+fn to_model<F: Factory>(row: Map<String, Value>) -> AppResult<F::Model> {
+    serde_json::from_value(Value::Object(row)).map_err(|e| {
+        AppError::InternalError(format!(
+            "Factory for '{}' produced invalid attributes: {}",
+            F::table(),
+            e
+        ))
+    })
+}
+
+/// Compteur par factory (1, 2, 3...) partagé par tout le processus de test
+fn next_sequence<F: Factory>() -> u64 {
+    static SEQUENCES: OnceLock<Mutex<HashMap<TypeId, u64>>> = OnceLock::new();
+
+    let mut sequences = SEQUENCES
+        .get_or_init(Default::default)
+        .lock()
+        .unwrap_or_else(|poisoned| poisoned.into_inner());
+    let seq = sequences.entry(TypeId::of::<F>()).or_insert(0);
+    *seq += 1;
+    *seq
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::testing::TestTransaction;
+    use serde::Deserialize;
+    use serde_json::json;
+    use sqlx::sqlite::SqlitePoolOptions;
+
+    #[derive(Debug, Deserialize)]
+    struct User {
+        id: i64,
+        email: String,
+    }
+
+    #[derive(Debug, Deserialize)]
+    struct Post {
+        id: i64,
+        title: String,
+        user_id: i64,
+    }
+
+    struct UserFactory;
+
+    impl Factory for UserFactory {
+        type Model = User;
+
+        fn table() -> &'static str {
+            "users"
+        }
+
+        fn definition(seq: u64) -> Value {
+            json!({ "email": format!("user{}@example.com", seq) })
+        }
+    }
+
+    struct PostFactory;
+
+    impl Factory for PostFactory {
+        type Model = Post;
+
+        fn table() -> &'static str {
+            "posts"
+        }
+
+        fn definition(seq: u64) -> Value {
+            json!({ "title": format!("Post {}", seq) })
+        }
+
+        fn relations() -> Vec<Relation> {
+            vec![Relation::belongs_to::<UserFactory>("user_id")]
+        }
+    }
+
+    async fn database() -> DatabaseConnection {
+        let pool = SqlitePoolOptions::new()
+            .max_connections(1)
+            .connect("sqlite::memory:")
+            .await
+            .unwrap();
+        sqlx::query(
+            "CREATE TABLE users (id INTEGER PRIMARY KEY AUTOINCREMENT, email TEXT NOT NULL UNIQUE);
+             CREATE TABLE posts (id INTEGER PRIMARY KEY AUTOINCREMENT, title TEXT NOT NULL, user_id INTEGER NOT NULL REFERENCES users(id));",
+        )
+        .execute(&pool)
+        .await
+        .unwrap();
+        DatabaseConnection::Sqlite(pool)
+    }
+
+    async fn count(db: &DatabaseConnection, table: &str) -> i64 {
+        let DatabaseConnection::Sqlite(pool) = db else {
+            unreachable!()
+        };
+        sqlx::query_scalar(&format!("SELECT COUNT(*) FROM {}", table))
+            .fetch_one(pool)
+            .await
+            .unwrap()
+    }
+
+    #[tokio::test]
+    async fn test_create_uses_sequence_and_returns_primary_key() {
+        let db = database().await;
+
+        let users = UserFactory::builder().create_many(3, &db).await.unwrap();
+
+        assert_eq!(users.len(), 3);
+        assert!(users.iter().all(|u| u.id > 0));
+        assert_ne!(users[0].email, users[1].email);
+        assert_eq!(count(&db, "users").await, 3);
+    }
+
+    #[tokio::test]
+    async fn test_set_overrides_definition() {
+        let db = database().await;
+
+        let user = UserFactory::builder()
+            .set("email", "ada@example.com")
+            .create(&db)
+            .await
+            .unwrap();
+
+        assert_eq!(user.email, "ada@example.com");
+    }
+
+    #[tokio::test]
+    async fn test_set_with_unserializable_value_returns_error() {
+        let db = database().await;
+
+        // Clés de map non textuelles : refusées par serde_json
+        let builder = UserFactory::builder().set("email", HashMap::from([((1, 2), 3)]));
+
+        let error = builder.create(&db).await.unwrap_err();
+        assert!(error.to_string().contains("attribute 'email'"), "{}", error);
+        assert!(builder.make().is_err());
+        assert_eq!(count(&db, "users").await, 0);
+    }
+
+    #[tokio::test]
+    async fn test_belongs_to_creates_parent() {
+        let db = database().await;
+
+        let post = PostFactory::builder().create(&db).await.unwrap();
+        assert!(post.title.starts_with("Post "));
+        assert_eq!(count(&db, "users").await, 1);
+
+        // Clé étrangère fournie : pas de nouveau parent
+        let post = PostFactory::builder()
+            .set("user_id", post.user_id)
+            .create(&db)
+            .await
+            .unwrap();
+        assert!(post.id > 0);
+        assert_eq!(count(&db, "users").await, 1);
+    }
+
+    #[tokio::test]
+    async fn test_make_does_not_insert() {
+        let db = database().await;
+
+        let attributes = UserFactory::builder().attributes().unwrap();
+        assert!(attributes["email"]
+            .as_str()
+            .unwrap()
+            .ends_with("@example.com"));
+        assert!(UserFactory::builder().make().is_err()); // pas d'id sans insertion
+        assert_eq!(count(&db, "users").await, 0);
+    }
+
+    #[tokio::test]
+    async fn test_create_inside_transaction_is_rolled_back() {
+        let db = database().await;
+
+        let tx = TestTransaction::begin(&db).await.unwrap();
+        PostFactory::builder().create_many(2, &tx).await.unwrap();
+        tx.rollback().await.unwrap();
+
+        assert_eq!(count(&db, "posts").await, 0);
+        assert_eq!(count(&db, "users").await, 0);
+    }
+}
@@ -0,0 +1,276 @@
+use super::factory::FactoryTarget;
+use super::test_transaction::TestTransaction;
+use crate::errors::{AppError, AppResult};
+use crate::state::DatabaseConnection;
+use serde::de::{Deserializer, MapAccess, Visitor};
+use serde::Deserialize;
+use serde_json::{Map, Value};
+use std::fmt;
+use std::path::Path;
+use yaml_rust2::{Yaml, YamlLoader};
+
+type Row = Map<String, Value>;
+
+/// Lignes à insérer, par table, dans l'ordre du fichier (parents avant enfants)
+///
+/// ```yaml
+/// users:
+///   - id: 1
+///     email: ada@example.com
+/// posts:
+///   - title: Hello
+///     user_id: 1
+/// ```
+#[derive(Debug, Clone, Default)]
+pub struct Fixtures {
+    tables: Vec<(String, Vec<Row>)>,
+}
+
+impl Fixtures {
+    /// Charge un fichier `.json`, `.yaml` ou `.yml`
+    pub fn from_file(path: impl AsRef<Path>) -> AppResult<Self> {
+        let path = path.as_ref();
+        let content = std::fs::read_to_string(path)
+            .map_err(|e| AppError::NotFound(format!("Fixture file {}: {}", path.display(), e)))?;
+
+        match path.extension().and_then(|e| e.to_str()) {
+            Some("json") => Self::from_json(&content),
+            Some("yaml" | "yml") => Self::from_yaml(&content),
+            _ => Err(AppError::BadRequest(format!(
+                "Unsupported fixture format: {} (expected .json, .yaml or .yml)",
+                path.display()
+            ))),
+        }
+    }
+
+    pub fn from_json(content: &str) -> AppResult<Self> {
+        serde_json::from_str(content)
+            .map_err(|e| AppError::BadRequest(format!("Invalid JSON fixtures: {}", e)))
+    }
+
+    pub fn from_yaml(content: &str) -> AppResult<Self> {
+        let invalid = |msg: String| AppError::BadRequest(format!("Invalid YAML fixtures: {}", msg));
+
+        let documents = YamlLoader::load_from_str(content).map_err(|e| invalid(e.to_string()))?;
+        let Some(document) = documents.into_iter().next() else {
+            return Ok(Self::default());
+        };
+        let Yaml::Hash(tables) = document else {
+            return Err(invalid("expected a mapping of table names".to_string()));
+        };
+
+        let mut fixtures = Self::default();
+        for (table, rows) in tables {
+            let table = table
+                .into_string()
+                .ok_or_else(|| invalid("table names must be strings".to_string()))?;
+            let rows = match yaml_to_json(rows) {
+                Value::Array(rows) => rows,
+                _ => return Err(invalid(format!("'{}' must be a list of rows", table))),
+            };
+            let rows = rows
+                .into_iter()
+                .map(|row| match row {
+                    Value::Object(row) => Ok(row),
+                    _ => Err(invalid(format!("rows of '{}' must be mappings", table))),
+                })
+                .collect::<AppResult<Vec<_>>>()?;
+            fixtures.tables.push((table, rows));
+        }
+
+        Ok(fixtures)
+    }
+
+    /// Tables et nombre de lignes, dans l'ordre d'insertion
+    pub fn tables(&self) -> impl Iterator<Item = (&str, usize)> {
+        self.tables
+            .iter()
+            .map(|(table, rows)| (table.as_str(), rows.len()))
+    }
+
+    /// Insère toutes les lignes (sans transaction)
+    pub async fn insert(&self, target: &impl FactoryTarget) -> AppResult<()> {
+        for (table, rows) in &self.tables {
+            for row in rows {
+                target.insert_row(table, row, None).await?;
+            }
+        }
+
+        Ok(())
+    }
+
+    /// Ouvre une transaction, y insère les fixtures et la retourne (annulée au drop)
+    pub async fn load(&self, db: &DatabaseConnection) -> AppResult<TestTransaction> {
+        let tx = TestTransaction::begin(db).await?;
+        self.insert(&tx).await?;
+        Ok(tx)
+    }
+}
+
+impl<'de> Deserialize<'de> for Fixtures {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        struct FixturesVisitor;
+
+        impl<'de> Visitor<'de> for FixturesVisitor {
+            type Value = Fixtures;
+
+            fn expecting(&self, f: &mut fmt::Formatter) -> fmt::Result {
+                f.write_str("a map of table names to lists of rows")
+            }
+
+            // Visite manuelle pour conserver l'ordre des tables du fichier
+            fn visit_map<A: MapAccess<'de>>(self, mut map: A) -> Result<Fixtures, A::Error> {
+                let mut tables = Vec::new();
+                while let Some((table, rows)) = map.next_entry::<String, Vec<Row>>()? {
+                    tables.push((table, rows));
+                }
+                Ok(Fixtures { tables })
+            }
+        }
+
+        deserializer.deserialize_map(FixturesVisitor)
+    }
+}
+
+fn yaml_to_json(yaml: Yaml) -> Value {
+    match yaml {
+        Yaml::Integer(i) => Value::from(i),
+        Yaml::Real(s) => s
+            .parse::<f64>()
+            .ok()
+            .and_then(serde_json::Number::from_f64)
+            .map(Value::Number)
+            .unwrap_or(Value::String(s)),
+        Yaml::String(s) => Value::String(s),
+        Yaml::Boolean(b) => Value::Bool(b),
+        Yaml::Array(items) => Value::Array(items.into_iter().map(yaml_to_json).collect()),
+        Yaml::Hash(hash) => Value::Object(
+            hash.into_iter()
+                .filter_map(|(k, v)| {
+                    let key = match k {
+                        Yaml::String(s) | Yaml::Real(s) => s,
+                        Yaml::Integer(i) => i.to_string(),
+                        Yaml::Boolean(b) => b.to_string(),
+                        _ => return None,
+                    };
+                    Some((key, yaml_to_json(v)))
+                })
+                .collect(),
+        ),
+        Yaml::Alias(_) | Yaml::Null | Yaml::BadValue => Value::Null,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::testing::TransactionConnection;
+    use sqlx::sqlite::SqlitePoolOptions;
+
+    const YAML: &str = "
+users:
+  - id: 1
+    email: ada@example.com
+    active: true
+posts:
+  - title: Hello
+    user_id: 1
+    score: 4.5
+";
+
+    async fn database() -> DatabaseConnection {
+        let pool = SqlitePoolOptions::new()
+            .max_connections(1)
+            .connect("sqlite::memory:")
+            .await
+            .unwrap();
+        sqlx::query(
+            "CREATE TABLE users (id INTEGER PRIMARY KEY, email TEXT NOT NULL, active BOOLEAN NOT NULL);
+             CREATE TABLE posts (id INTEGER PRIMARY KEY, title TEXT NOT NULL, user_id INTEGER NOT NULL, score REAL);",
+        )
+        .execute(&pool)
+        .await
+        .unwrap();
+        DatabaseConnection::Sqlite(pool)
+    }
+
+    #[test]
+    fn test_from_yaml_keeps_table_order() {
+        let fixtures = Fixtures::from_yaml(YAML).unwrap();
+        let tables: Vec<_> = fixtures.tables().collect();
+        assert_eq!(tables, vec![("users", 1), ("posts", 1)]);
+        assert_eq!(fixtures.tables[1].1[0]["score"], 4.5);
+        assert_eq!(fixtures.tables[0].1[0]["active"], true);
+    }
+
+    #[test]
+    fn test_from_json_keeps_table_order() {
+        let fixtures =
+            Fixtures::from_json(r#"{"users": [{"id": 1}], "comments": [], "posts": [{"id": 2}]}"#)
+                .unwrap();
+        let tables: Vec<_> = fixtures.tables().map(|(t, _)| t).collect();
+        assert_eq!(tables, vec!["users", "comments", "posts"]);
+    }
+
+    #[test]
+    fn test_invalid_fixtures() {
+        assert!(Fixtures::from_yaml("- just\n- a list\n").is_err());
+        assert!(Fixtures::from_yaml("users: 42\n").is_err());
+        assert!(Fixtures::from_json(r#"{"users": [1]}"#).is_err());
+        assert!(Fixtures::from_file("fixtures.toml").is_err());
+    }
+
+    #[tokio::test]
+    async fn test_load_is_rolled_back() {
+        let db = database().await;
+        let fixtures = Fixtures::from_yaml(YAML).unwrap();
+
+        let tx = fixtures.load(&db).await.unwrap();
+        {
+            let mut conn = tx.connection().await;
+            let TransactionConnection::Sqlite(conn) = &mut *conn else {
+                unreachable!()
+            };
+            let title: String = sqlx::query_scalar("SELECT title FROM posts WHERE user_id = 1")
+                .fetch_one(&mut **conn)
+                .await
+                .unwrap();
+            assert_eq!(title, "Hello");
+        }
+        drop(tx);
+
+        let DatabaseConnection::Sqlite(pool) = &db else {
+            unreachable!()
+        };
+        let users: i64 = sqlx::query_scalar("SELECT COUNT(*) FROM users")
+            .fetch_one(pool)
+            .await
+            .unwrap();
+        assert_eq!(users, 0);
+    }
+
+    #[tokio::test]
+    async fn test_insert_from_file() {
+        let db = database().await;
+        let path =
+            std::env::temp_dir().join(format!("rustwork_fixtures_{}.yml", uuid::Uuid::new_v4()));
+        std::fs::write(&path, YAML).unwrap();
+
+        Fixtures::from_file(&path)
+            .unwrap()
+            .insert(&db)
+            .await
+            .unwrap();
+
+        let DatabaseConnection::Sqlite(pool) = &db else {
+            unreachable!()
+        };
+        let active: bool = sqlx::query_scalar("SELECT active FROM users WHERE id = 1")
+            .fetch_one(pool)
+            .await
+            .unwrap();
+        assert!(active);
+
+        let _ = std::fs::remove_file(&path);
+    }
+}
@@ -0,0 +1,381 @@
+use crate::errors::{AppError, AppResult};
+use serde_json::{Map, Value};
+use sqlx::{
+    query::Query, Database, Encode, MySqlConnection, PgConnection, Row, SqliteConnection, Type,
+};
+use std::collections::HashMap;
+use uuid::Uuid;
+
+/// Connexion (pool ou transaction) sur laquelle insérer une ligne
+pub(crate) enum RowConnection<'a> {
+    Sqlite(&'a mut SqliteConnection),
+    Postgres(&'a mut PgConnection),
+    Mysql(&'a mut MySqlConnection),
+}
+
+/// Insère une ligne JSON dans `table` et retourne la colonne `returning` générée
+pub(crate) async fn insert_row(
+    conn: RowConnection<'_>,
+    table: &str,
+    row: &Map<String, Value>,
+    returning: Option<&str>,
+) -> AppResult<Option<Value>> {
+    check_identifier(table)?;
+    for column in row.keys() {
+        check_identifier(column)?;
+    }
+    if let Some(column) = returning {
+        check_identifier(column)?;
+    }
+
+    let columns: Vec<&str> = row.keys().map(String::as_str).collect();
+
+    match conn {
+        RowConnection::Sqlite(conn) => {
+            let types = sqlite_column_types(&mut *conn, table).await?;
+            let placeholders = vec!["?".to_string(); columns.len()];
+            let sql = insert_sql(table, &columns, &placeholders, returning);
+            let query = bind_row(sqlx::query(&sql), row, &types);
+
+            match returning {
+                Some(_) => {
+                    let row = query.fetch_one(&mut *conn).await?;
+                    Ok(Some(
+                        row.try_get::<i64, _>(0)
+                            .map(Value::from)
+                            .or_else(|_| row.try_get::<String, _>(0).map(Value::from))
+                            .or_else(|_| row.try_get::<Uuid, _>(0).map(uuid_value))?,
+                    ))
+                }
+                None => {
+                    query.execute(&mut *conn).await?;
+                    Ok(None)
+                }
+            }
+        }
+        RowConnection::Postgres(conn) => {
+            let types = postgres_column_types(&mut *conn, table).await?;
+            // Les chaînes et NULL sont envoyés en TEXT : cast explicite vers le type de la colonne
+            let placeholders: Vec<String> = row
+                .iter()
+                .enumerate()
+                .map(|(i, (column, value))| match (value, types.get(column)) {
+                    (Value::Bool(_) | Value::Number(_), _) | (_, None) => format!("${}", i + 1),
+                    (_, Some(ty)) => format!("${}::{}", i + 1, ty),
+                })
+                .collect();
+            let sql = insert_sql(table, &columns, &placeholders, returning);
+            let query = row.values().fold(sqlx::query(&sql), bind_json);
+
+            match returning {
+                Some(_) => {
+                    let row = query.fetch_one(&mut *conn).await?;
+                    Ok(Some(
+                        row.try_get::<i64, _>(0)
+                            .map(Value::from)
+                            .or_else(|_| row.try_get::<i32, _>(0).map(Value::from))
+                            .or_else(|_| row.try_get::<String, _>(0).map(Value::from))
+                            .or_else(|_| row.try_get::<Uuid, _>(0).map(uuid_value))?,
+                    ))
+                }
+                None => {
+                    query.execute(&mut *conn).await?;
+                    Ok(None)
+                }
+            }
+        }
+        RowConnection::Mysql(conn) => {
+            let types = mysql_column_types(&mut *conn, table).await?;
+            let placeholders = vec!["?".to_string(); columns.len()];
+            let sql = insert_sql(table, &columns, &placeholders, None);
+            let result = bind_row(sqlx::query(&sql), row, &types)
+                .execute(&mut *conn)
+                .await?;
+
+            Ok(returning.map(|_| Value::from(result.last_insert_id())))
+        }
+    }
+}
+
+fn insert_sql(
+    table: &str,
+    columns: &[&str],
+    placeholders: &[String],
+    returning: Option<&str>,
+) -> String {
+    let mut sql = if columns.is_empty() {
+        format!("INSERT INTO {} DEFAULT VALUES", table)
+    } else {
+        format!(
+            "INSERT INTO {} ({}) VALUES ({})",
+            table,
+            columns.join(", "),
+            placeholders.join(", ")
+        )
+    };
+
+    if let Some(column) = returning {
+        sql.push_str(&format!(" RETURNING {}", column));
+    }
+
+    sql
+}
+
+/// Types des colonnes (`udt_name`) d'une table PostgreSQL
+async fn postgres_column_types(
+    conn: &mut PgConnection,
+    table: &str,
+) -> AppResult<HashMap<String, String>> {
+    let (schema, name) = match table.split_once('.') {
+        Some((schema, name)) => (Some(schema), name),
+        None => (None, table),
+    };
+
+    let rows: Vec<(String, String)> = sqlx::query_as(
+        "SELECT column_name::text, udt_name::text FROM information_schema.columns \
+         WHERE table_name = $1 AND table_schema = COALESCE($2, current_schema())",
+    )
+    .bind(name)
+    .bind(schema)
+    .fetch_all(conn)
+    .await?;
+
+    Ok(rows.into_iter().collect())
+}
+
+/// Types déclarés des colonnes d'une table SQLite
+async fn sqlite_column_types(
+    conn: &mut SqliteConnection,
+    table: &str,
+) -> AppResult<HashMap<String, String>> {
+    let (schema, name) = match table.split_once('.') {
+        Some((schema, name)) => (schema, name),
+        None => ("main", table),
+    };
+
+    let rows: Vec<(String, String)> =
+        sqlx::query_as("SELECT name, type FROM pragma_table_info(?1, ?2)")
+            .bind(name)
+            .bind(schema)
+            .fetch_all(conn)
+            .await?;
+
+    Ok(rows.into_iter().collect())
+}
+
+/// Types des colonnes (`column_type`) d'une table MySQL
+async fn mysql_column_types(
+    conn: &mut MySqlConnection,
+    table: &str,
+) -> AppResult<HashMap<String, String>> {
+    let (schema, name) = match table.split_once('.') {
+        Some((schema, name)) => (Some(schema), name),
+        None => (None, table),
+    };
+
+    let rows: Vec<(String, String)> = sqlx::query_as(
+        "SELECT CAST(column_name AS CHAR), CAST(column_type AS CHAR) FROM information_schema.columns \
+         WHERE table_name = ? AND table_schema = COALESCE(?, DATABASE())",
+    )
+    .bind(name)
+    .bind(schema)
+    .fetch_all(conn)
+    .await?;
+
+    Ok(rows.into_iter().collect())
+}
+
+/// Colonne UUID stockée en binaire (`--uuid-pk` : BLOB en SQLite, BINARY(16) en MySQL)
+fn is_binary_uuid_column(ty: &str) -> bool {
+    matches!(ty.to_ascii_lowercase().as_str(), "blob" | "binary(16)")
+}
+
+/// Lie les valeurs d'une ligne ; les UUID des colonnes binaires sont envoyés en `Uuid`
+fn bind_row<'q, DB>(
+    query: Query<'q, DB, <DB as Database>::Arguments<'q>>,
+    row: &Map<String, Value>,
+    types: &HashMap<String, String>,
+) -> Query<'q, DB, <DB as Database>::Arguments<'q>>
+where
+    DB: Database,
+    bool: Encode<'q, DB> + Type<DB>,
+    i64: Encode<'q, DB> + Type<DB>,
+    f64: Encode<'q, DB> + Type<DB>,
+    String: Encode<'q, DB> + Type<DB>,
+    Option<String>: Encode<'q, DB> + Type<DB>,
+    Uuid: Encode<'q, DB> + Type<DB>,
+{
+    row.iter().fold(query, |query, (column, value)| {
+        let binary_uuid = types
+            .get(column)
+            .is_some_and(|ty| is_binary_uuid_column(ty));
+        match value {
+            Value::String(s) if binary_uuid => match Uuid::parse_str(s) {
+                Ok(id) => query.bind(id),
+                Err(_) => bind_json(query, value),
+            },
+            _ => bind_json(query, value),
+        }
+    })
+}
+
+fn uuid_value(id: Uuid) -> Value {
+    Value::from(id.to_string())
+}
+
+/// Lie une valeur JSON avec le type Rust le plus proche
+fn bind_json<'q, DB>(
+    query: Query<'q, DB, <DB as Database>::Arguments<'q>>,
+    value: &Value,
+) -> Query<'q, DB, <DB as Database>::Arguments<'q>>
+where
+    DB: Database,
+    bool: Encode<'q, DB> + Type<DB>,
+    i64: Encode<'q, DB> + Type<DB>,
+    f64: Encode<'q, DB> + Type<DB>,
+    String: Encode<'q, DB> + Type<DB>,
+    Option<String>: Encode<'q, DB> + Type<DB>,
+{
+    match value {
+        Value::Null => query.bind(None::<String>),
+        Value::Bool(b) => query.bind(*b),
+        Value::Number(n) => match n.as_i64() {
+            Some(i) => query.bind(i),
+            None => query.bind(n.as_f64().unwrap_or_default()),
+        },
+        Value::String(s) => query.bind(s.clone()),
+        other => query.bind(other.to_string()),
+    }
+}
+
+/// Noms de tables/colonnes interpolés dans le SQL : `[A-Za-z0-9_.]` uniquement
+fn check_identifier(name: &str) -> AppResult<()> {
+    if !name.is_empty()
+        && name
+            .chars()
+            .all(|c| c.is_ascii_alphanumeric() || c == '_' || c == '.')
+    {
+        Ok(())
+    } else {
+        Err(AppError::BadRequest(format!(
+            "Invalid SQL identifier: {:?}",
+            name
+        )))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use sqlx::Connection;
+
+    const ID: &str = "67e55044-10b1-426f-9247-bb680e5fe0c8";
+
+    fn row() -> Map<String, Value> {
+        let mut row = Map::new();
+        row.insert("id".to_string(), Value::from(ID));
+        row.insert("title".to_string(), Value::from("Hello"));
+        row
+    }
+
+    #[test]
+    fn test_is_binary_uuid_column() {
+        assert!(is_binary_uuid_column("BLOB"));
+        assert!(is_binary_uuid_column("binary(16)"));
+        assert!(!is_binary_uuid_column("TEXT"));
+        assert!(!is_binary_uuid_column("char(36)"));
+        assert!(!is_binary_uuid_column("uuid"));
+    }
+
+    #[tokio::test]
+    async fn test_sqlite_binds_uuid_as_blob() {
+        let mut conn = SqliteConnection::connect("sqlite::memory:").await.unwrap();
+        sqlx::query("CREATE TABLE posts (id BLOB PRIMARY KEY, title TEXT NOT NULL)")
+            .execute(&mut conn)
+            .await
+            .unwrap();
+
+        insert_row(RowConnection::Sqlite(&mut conn), "posts", &row(), None)
+            .await
+            .unwrap();
+
+        let (kind, id, title): (String, Uuid, String) =
+            sqlx::query_as("SELECT typeof(id), id, title FROM posts")
+                .fetch_one(&mut conn)
+                .await
+                .unwrap();
+        assert_eq!(kind, "blob");
+        assert_eq!(id, Uuid::parse_str(ID).unwrap());
+        assert_eq!(title, "Hello");
+    }
+
+    /// Serveur PostgreSQL de test : `RUSTWORK_TEST_POSTGRES_URL` (ignoré s'il n'est pas défini)
+    #[tokio::test]
+    async fn test_postgres_binds_uuid_column() {
+        let Ok(url) = std::env::var("RUSTWORK_TEST_POSTGRES_URL") else {
+            return;
+        };
+        let mut conn = PgConnection::connect(&url).await.unwrap();
+        sqlx::query("DROP TABLE IF EXISTS uuid_posts")
+            .execute(&mut conn)
+            .await
+            .unwrap();
+        sqlx::query("CREATE TABLE uuid_posts (id UUID PRIMARY KEY, title TEXT NOT NULL)")
+            .execute(&mut conn)
+            .await
+            .unwrap();
+
+        insert_row(
+            RowConnection::Postgres(&mut conn),
+            "uuid_posts",
+            &row(),
+            None,
+        )
+        .await
+        .unwrap();
+
+        let id: Uuid = sqlx::query_scalar("SELECT id FROM uuid_posts")
+            .fetch_one(&mut conn)
+            .await
+            .unwrap();
+        sqlx::query("DROP TABLE uuid_posts")
+            .execute(&mut conn)
+            .await
+            .unwrap();
+        assert_eq!(id, Uuid::parse_str(ID).unwrap());
+    }
+
+    /// Serveur MySQL de test : `RUSTWORK_TEST_MYSQL_URL` (ignoré s'il n'est pas défini)
+    #[tokio::test]
+    async fn test_mysql_binds_uuid_as_binary() {
+        let Ok(url) = std::env::var("RUSTWORK_TEST_MYSQL_URL") else {
+            return;
+        };
+        let mut conn = MySqlConnection::connect(&url).await.unwrap();
+        sqlx::query("DROP TABLE IF EXISTS uuid_posts")
+            .execute(&mut conn)
+            .await
+            .unwrap();
+        sqlx::query(
+            "CREATE TABLE uuid_posts (id BINARY(16) PRIMARY KEY, title VARCHAR(255) NOT NULL)",
+        )
+        .execute(&mut conn)
+        .await
+        .unwrap();
+
+        insert_row(RowConnection::Mysql(&mut conn), "uuid_posts", &row(), None)
+            .await
+            .unwrap();
+
+        let (length, id): (i64, Uuid) = sqlx::query_as("SELECT LENGTH(id), id FROM uuid_posts")
+            .fetch_one(&mut conn)
+            .await
+            .unwrap();
+        sqlx::query("DROP TABLE uuid_posts")
+            .execute(&mut conn)
+            .await
+            .unwrap();
+        assert_eq!(length, 16);
+        assert_eq!(id, Uuid::parse_str(ID).unwrap());
+    }
+}
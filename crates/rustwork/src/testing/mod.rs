@@ -0,0 +1,19 @@
+//! Outils de tests d'intégration pour les services rustwork (feature `testing`)
+
+pub mod factory;
+pub mod fixtures;
+pub mod test_app;
+pub mod test_request;
+pub mod test_response;
+pub mod test_transaction;
+
+mod insert_row;
+mod test_database;
+
+// Re-exports publics
+pub use factory::{Factory, FactoryBuilder, FactoryTarget, Relation};
+pub use fixtures::Fixtures;
+pub use test_app::{TestApp, TestAppBuilder};
+pub use test_request::TestRequest;
+pub use test_response::TestResponse;
+pub use test_transaction::{TestTransaction, TransactionConnection};
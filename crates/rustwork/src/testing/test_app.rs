@@ -0,0 +1,340 @@
+use super::fixtures::Fixtures;
+use super::test_database::{create_test_database, run_migrations, TestDatabase};
+use super::test_request::TestRequest;
+use crate::config::{load_app_config_for_profile, AppConfig};
+use crate::errors::{AppError, AppResult};
+use crate::state::{AppState, DatabaseConnection};
+use axum::http::Method;
+use axum::Router;
+use std::path::PathBuf;
+
+/// Application de test : config du profil `test`, base isolée migrée, router prêt à l'emploi
+pub struct TestApp {
+    router: Router,
+    state: AppState,
+    database: TestDatabase,
+}
+
+/// Options de construction d'un `TestApp`
+pub struct TestAppBuilder {
+    profile: String,
+    config: Option<AppConfig>,
+    migrations: Option<PathBuf>,
+    fixtures: Vec<PathBuf>,
+    in_memory: bool,
+}
+
+impl Default for TestAppBuilder {
+    fn default() -> Self {
+        Self {
+            profile: "test".to_string(),
+            config: None,
+            migrations: Some(PathBuf::from("migrations")),
+            fixtures: Vec::new(),
+            in_memory: false,
+        }
+    }
+}
+
+impl TestAppBuilder {
+    /// Profil de configuration chargé (config/{profile}.toml, défaut : `test`)
+    pub fn profile(mut self, profile: impl Into<String>) -> Self {
+        self.profile = profile.into();
+        self
+    }
+
+    /// Utilise cette config au lieu de charger le profil
+    pub fn config(mut self, config: AppConfig) -> Self {
+        self.config = Some(config);
+        self
+    }
+
+    /// Dossier des migrations SQL (défaut : `migrations`, ignoré s'il n'existe pas)
+    pub fn migrations(mut self, dir: impl Into<PathBuf>) -> Self {
+        self.migrations = Some(dir.into());
+        self
+    }
+
+    pub fn without_migrations(mut self) -> Self {
+        self.migrations = None;
+        self
+    }
+
+    /// Fichier de fixtures (JSON/YAML) inséré après les migrations
+    pub fn fixtures(mut self, path: impl Into<PathBuf>) -> Self {
+        self.fixtures.push(path.into());
+        self
+    }
+
+    /// Force une base SQLite en mémoire quel que soit le driver configuré
+    pub fn in_memory(mut self) -> Self {
+        self.in_memory = true;
+        self
+    }
+
+    /// Crée la base, applique les migrations et construit le router
+    pub async fn build<F>(self, build_router: F) -> AppResult<TestApp>
+    where
+        F: FnOnce(AppState) -> Router,
+    {
+        let config = match self.config {
+            Some(config) => config,
+            None => load_app_config_for_profile(&self.profile).map_err(|e| {
+                AppError::InternalError(format!(
+                    "Failed to load '{}' config profile: {}",
+                    self.profile, e
+                ))
+            })?,
+        };
+
+        let (db, database) = create_test_database(&config.database, self.in_memory).await?;
+
+        if let Some(dir) = self.migrations.filter(|dir| dir.is_dir()) {
+            run_migrations(&db, &dir).await?;
+        }
+
+        for path in &self.fixtures {
+            Fixtures::from_file(path)?.insert(&db).await?;
+        }
+
+        let state = AppState::new(db, config);
+        let router = build_router(state.clone());
+
+        Ok(TestApp {
+            router,
+            state,
+            database,
+        })
+    }
+}
+
+impl TestApp {
+    /// Raccourci pour `TestApp::builder().build(build_router)`
+    pub async fn new<F>(build_router: F) -> AppResult<Self>
+    where
+        F: FnOnce(AppState) -> Router,
+    {
+        Self::builder().build(build_router).await
+    }
+
+    pub fn builder() -> TestAppBuilder {
+        TestAppBuilder::default()
+    }
+
+    pub fn state(&self) -> &AppState {
+        &self.state
+    }
+
+    pub fn config(&self) -> &AppConfig {
+        &self.state.config
+    }
+
+    pub fn db(&self) -> &DatabaseConnection {
+        &self.state.db
+    }
+
+    pub fn request(&self, method: Method, uri: &str) -> TestRequest<'_> {
+        TestRequest::new(&self.router, method, uri)
+    }
+
+    pub fn get(&self, uri: &str) -> TestRequest<'_> {
+        self.request(Method::GET, uri)
+    }
+
+    pub fn post(&self, uri: &str) -> TestRequest<'_> {
+        self.request(Method::POST, uri)
+    }
+
+    pub fn put(&self, uri: &str) -> TestRequest<'_> {
+        self.request(Method::PUT, uri)
+    }
+
+    pub fn patch(&self, uri: &str) -> TestRequest<'_> {
+        self.request(Method::PATCH, uri)
+    }
+
+    pub fn delete(&self, uri: &str) -> TestRequest<'_> {
+        self.request(Method::DELETE, uri)
+    }
+
+    /// Ferme le pool et supprime la base temporaire (PostgreSQL)
+    pub async fn cleanup(mut self) -> AppResult<()> {
+        match &self.state.db {
+            DatabaseConnection::Sqlite(pool) => pool.close().await,
+            DatabaseConnection::Postgres(pool) => pool.close().await,
+            DatabaseConnection::Mysql(pool) => pool.close().await,
+        }
+
+        self.database.drop_database().await
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::config::types::{CorsConfig, DatabaseConfig, ServerConfig};
+    use crate::response::{created, ok};
+    use axum::extract::{Path, State};
+    use axum::http::StatusCode;
+    use axum::routing::get;
+    use axum::Json;
+    use serde_json::{json, Value};
+
+    fn test_config() -> AppConfig {
+        AppConfig {
+            server: ServerConfig {
+                host: "127.0.0.1".to_string(),
+                port: 0,
+            },
+            database: DatabaseConfig {
+                url: Some("sqlite::memory:".to_string()),
+                ..DatabaseConfig::default()
+            },
+            cors: CorsConfig::default(),
+        }
+    }
+
+    async fn count_items(
+        State(state): State<AppState>,
+    ) -> AppResult<impl axum::response::IntoResponse> {
+        let DatabaseConnection::Sqlite(pool) = &state.db else {
+            return Err(AppError::InternalError("expected sqlite".to_string()));
+        };
+        let (count,): (i64,) = sqlx::query_as("SELECT COUNT(*) FROM items")
+            .fetch_one(pool)
+            .await?;
+        Ok(ok(count))
+    }
+
+    async fn create_item(Json(body): Json<Value>) -> impl axum::response::IntoResponse {
+        created(body)
+    }
+
+    async fn show_item(Path(id): Path<i64>) -> AppResult<impl axum::response::IntoResponse> {
+        if id == 1 {
+            Ok(ok(json!({ "id": 1 })))
+        } else {
+            Err(AppError::NotFound(format!("Item {} not found", id)))
+        }
+    }
+
+    fn router(state: AppState) -> Router {
+        Router::new()
+            .route("/items", get(count_items).post(create_item))
+            .route("/items/{id}", get(show_item))
+            .with_state(state)
+    }
+
+    fn migrations_dir() -> PathBuf {
+        let dir =
+            std::env::temp_dir().join(format!("rustwork_migrations_{}", uuid::Uuid::new_v4()));
+        std::fs::create_dir_all(&dir).unwrap();
+        std::fs::write(
+            dir.join("20240101000001_create_items.up.sql"),
+            "CREATE TABLE items (id INTEGER PRIMARY KEY);\nINSERT INTO items (id) VALUES (1), (2);",
+        )
+        .unwrap();
+        std::fs::write(
+            dir.join("20240101000001_create_items.down.sql"),
+            "DROP TABLE items;",
+        )
+        .unwrap();
+        dir
+    }
+
+    #[tokio::test]
+    async fn test_app_applies_migrations() {
+        let dir = migrations_dir();
+        let app = TestApp::builder()
+            .config(test_config())
+            .migrations(&dir)
+            .build(router)
+            .await
+            .unwrap();
+
+        app.get("/items")
+            .send()
+            .await
+            .assert_status(StatusCode::OK)
+            .assert_data(2);
+
+        app.cleanup().await.unwrap();
+        let _ = std::fs::remove_dir_all(&dir);
+    }
+
+    #[tokio::test]
+    async fn test_app_databases_are_isolated() {
+        let dir = migrations_dir();
+        let first = TestApp::builder()
+            .config(test_config())
+            .migrations(&dir)
+            .build(router)
+            .await
+            .unwrap();
+        let second = TestApp::builder()
+            .config(test_config())
+            .migrations(&dir)
+            .build(router)
+            .await
+            .unwrap();
+
+        let DatabaseConnection::Sqlite(pool) = first.db() else {
+            panic!("expected sqlite");
+        };
+        sqlx::query("DELETE FROM items")
+            .execute(pool)
+            .await
+            .unwrap();
+
+        first.get("/items").send().await.assert_data(0);
+        second.get("/items").send().await.assert_data(2);
+
+        let _ = std::fs::remove_dir_all(&dir);
+    }
+
+    #[tokio::test]
+    async fn test_request_json_body_and_created() {
+        let app = TestApp::builder()
+            .config(test_config())
+            .without_migrations()
+            .build(router)
+            .await
+            .unwrap();
+
+        let response = app
+            .post("/items")
+            .json(&json!({ "name": "book" }))
+            .send()
+            .await;
+        response
+            .assert_status(StatusCode::CREATED)
+            .assert_success()
+            .assert_data(json!({ "name": "book" }));
+        assert_eq!(response.header("content-type"), Some("application/json"));
+
+        let data: Value = response.data();
+        assert_eq!(data["name"], "book");
+    }
+
+    #[tokio::test]
+    async fn test_response_error_assertions() {
+        let app = TestApp::builder()
+            .config(test_config())
+            .without_migrations()
+            .build(router)
+            .await
+            .unwrap();
+
+        app.get("/items/42")
+            .send()
+            .await
+            .assert_status(StatusCode::NOT_FOUND)
+            .assert_error("Item 42 not found");
+
+        app.get("/items/1")
+            .send()
+            .await
+            .assert_ok()
+            .assert_json(json!({ "success": true, "data": { "id": 1 }, "message": null }));
+    }
+}
@@ -0,0 +1,154 @@
+use crate::config::types::DatabaseConfig;
+use crate::errors::{AppError, AppResult};
+use crate::state::DatabaseConnection;
+use sqlx::{
+    migrate::Migrator,
+    postgres::{PgConnectOptions, PgPoolOptions},
+    sqlite::SqlitePoolOptions,
+    Connection, PgConnection, PgPool,
+};
+use std::path::Path;
+use std::str::FromStr;
+
+/// Base de données isolée créée pour un `TestApp`
+pub(crate) enum TestDatabase {
+    /// SQLite en mémoire : disparaît avec le pool
+    Sqlite,
+    /// Base PostgreSQL temporaire, supprimée par `TestApp::cleanup` ou, à défaut, au drop
+    Postgres {
+        admin: PgPool,
+        options: Box<PgConnectOptions>,
+        name: String,
+        dropped: bool,
+    },
+}
+
+/// Crée une base isolée à partir de la config du profil de test
+pub(crate) async fn create_test_database(
+    config: &DatabaseConfig,
+    in_memory: bool,
+) -> AppResult<(DatabaseConnection, TestDatabase)> {
+    if in_memory {
+        return create_sqlite_database().await;
+    }
+
+    let url = config.resolved_url()?;
+
+    if url.starts_with("sqlite:") {
+        create_sqlite_database().await
+    } else if url.starts_with("postgres:") || url.starts_with("postgresql:") {
+        let options = PgConnectOptions::from_str(&url)?;
+        let admin = PgPoolOptions::new()
+            .max_connections(1)
+            .connect_with(options.clone())
+            .await?;
+
+        let name = format!("rustwork_test_{}", uuid::Uuid::new_v4().simple());
+        sqlx::query(&format!("CREATE DATABASE \"{}\"", name))
+            .execute(&admin)
+            .await?;
+
+        let pool = PgPoolOptions::new()
+            .max_connections(config.pool.max_connections)
+            .connect_with(options.clone().database(&name))
+            .await?;
+
+        Ok((
+            DatabaseConnection::Postgres(pool),
+            TestDatabase::Postgres {
+                admin,
+                options: Box::new(options),
+                name,
+                dropped: false,
+            },
+        ))
+    } else {
+        Err(AppError::Database(
+            "TestApp supports SQLite and PostgreSQL databases only".to_string(),
+        ))
+    }
+}
+
+/// SQLite en mémoire, une seule connexion : chaque connexion `:memory:` ouvre une base distincte
+async fn create_sqlite_database() -> AppResult<(DatabaseConnection, TestDatabase)> {
+    let pool = SqlitePoolOptions::new()
+        .max_connections(1)
+        .idle_timeout(None)
+        .max_lifetime(None)
+        .connect("sqlite::memory:")
+        .await?;
+
+    Ok((DatabaseConnection::Sqlite(pool), TestDatabase::Sqlite))
+}
+
+/// Applique les migrations SQL (`<version>_<name>.up.sql`) d'un dossier
+pub(crate) async fn run_migrations(db: &DatabaseConnection, dir: &Path) -> AppResult<()> {
+    let migrator = Migrator::new(dir)
+        .await
+        .map_err(|e| AppError::Database(format!("Failed to load migrations: {}", e)))?;
+
+    let result = match db {
+        DatabaseConnection::Sqlite(pool) => migrator.run(pool).await,
+        DatabaseConnection::Postgres(pool) => migrator.run(pool).await,
+        DatabaseConnection::Mysql(pool) => migrator.run(pool).await,
+    };
+
+    result.map_err(|e| AppError::Database(format!("Failed to run migrations: {}", e)))
+}
+
+fn drop_statement(name: &str) -> String {
+    format!("DROP DATABASE IF EXISTS \"{}\" WITH (FORCE)", name)
+}
+
+impl TestDatabase {
+    /// Supprime la base temporaire (PostgreSQL)
+    pub(crate) async fn drop_database(&mut self) -> AppResult<()> {
+        if let TestDatabase::Postgres {
+            admin,
+            name,
+            dropped,
+            ..
+        } = self
+        {
+            sqlx::query(&drop_statement(name)).execute(&*admin).await?;
+            admin.close().await;
+            *dropped = true;
+        }
+
+        Ok(())
+    }
+}
+
+impl Drop for TestDatabase {
+    /// Test en échec (panic) ou sans `cleanup()` : la base est supprimée sur un runtime
+    /// dédié, le runtime du test pouvant déjà être arrêté
+    fn drop(&mut self) {
+        let TestDatabase::Postgres {
+            options,
+            name,
+            dropped: false,
+            ..
+        } = self
+        else {
+            return;
+        };
+
+        let options = (**options).clone();
+        let statement = drop_statement(name);
+        let handle = std::thread::spawn(move || {
+            let Ok(runtime) = tokio::runtime::Builder::new_current_thread()
+                .enable_all()
+                .build()
+            else {
+                return;
+            };
+            runtime.block_on(async {
+                if let Ok(mut conn) = PgConnection::connect_with(&options).await {
+                    let _ = sqlx::query(&statement).execute(&mut conn).await;
+                    let _ = conn.close().await;
+                }
+            });
+        });
+        let _ = handle.join();
+    }
+}
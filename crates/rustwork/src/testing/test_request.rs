@@ -0,0 +1,67 @@
+use super::test_response::TestResponse;
+use axum::body::Body;
+use axum::http::{header, request, HeaderValue, Method, Request};
+use axum::Router;
+use serde::Serialize;
+use tower::ServiceExt;
+
+/// Requête HTTP construite sur un `TestApp` et exécutée sans ouvrir de socket
+pub struct TestRequest<'a> {
+    router: &'a Router,
+    builder: request::Builder,
+    body: Body,
+}
+
+impl<'a> TestRequest<'a> {
+    pub(crate) fn new(router: &'a Router, method: Method, uri: &str) -> Self {
+        Self {
+            router,
+            builder: Request::builder().method(method).uri(uri),
+            body: Body::empty(),
+        }
+    }
+
+    pub fn header(mut self, name: &str, value: &str) -> Self {
+        self.builder = self.builder.header(name, value);
+        self
+    }
+
+    /// Header `Authorization: Bearer <token>`
+    pub fn bearer_auth(self, token: &str) -> Self {
+        self.header(header::AUTHORIZATION.as_str(), &format!("Bearer {}", token))
+    }
+
+    /// Corps JSON (`Content-Type: application/json`)
+    pub fn json<T: Serialize>(mut self, body: &T) -> Self {
+        let bytes = serde_json::to_vec(body).expect("request body is not serializable");
+        self.builder = self.builder.header(
+            header::CONTENT_TYPE,
+            HeaderValue::from_static("application/json"),
+        );
+        self.body = Body::from(bytes);
+        self
+    }
+
+    /// Corps brut
+    pub fn body(mut self, body: impl Into<Body>) -> Self {
+        self.body = body.into();
+        self
+    }
+
+    /// Exécute la requête contre le router
+    pub async fn send(self) -> TestResponse {
+        let request = self.builder.body(self.body).expect("invalid test request");
+
+        let response = match self.router.clone().oneshot(request).await {
+            Ok(response) => response,
+            Err(never) => match never {},
+        };
+
+        let (parts, body) = response.into_parts();
+        let body = axum::body::to_bytes(body, usize::MAX)
+            .await
+            .expect("failed to read response body");
+
+        TestResponse::new(parts.status, parts.headers, body)
+    }
+}
@@ -0,0 +1,145 @@
+use crate::response::ApiResponse;
+use axum::body::Bytes;
+use axum::http::{HeaderMap, StatusCode};
+use serde::{de::DeserializeOwned, Serialize};
+use serde_json::Value;
+
+/// Réponse capturée par `TestRequest::send`, avec assertions sur l'enveloppe `ApiResponse`
+#[derive(Debug)]
+pub struct TestResponse {
+    status: StatusCode,
+    headers: HeaderMap,
+    body: Bytes,
+}
+
+impl TestResponse {
+    pub(crate) fn new(status: StatusCode, headers: HeaderMap, body: Bytes) -> Self {
+        Self {
+            status,
+            headers,
+            body,
+        }
+    }
+
+    pub fn status(&self) -> StatusCode {
+        self.status
+    }
+
+    pub fn headers(&self) -> &HeaderMap {
+        &self.headers
+    }
+
+    /// Valeur d'un header (si présente et ASCII)
+    pub fn header(&self, name: &str) -> Option<&str> {
+        self.headers.get(name).and_then(|v| v.to_str().ok())
+    }
+
+    pub fn bytes(&self) -> &Bytes {
+        &self.body
+    }
+
+    pub fn text(&self) -> String {
+        String::from_utf8_lossy(&self.body).into_owned()
+    }
+
+    /// Désérialise le corps JSON (panique si invalide)
+    #[track_caller]
+    pub fn json<T: DeserializeOwned>(&self) -> T {
+        serde_json::from_slice(&self.body).unwrap_or_else(|e| {
+            panic!(
+                "response body is not valid JSON for the expected type: {}\nbody: {}",
+                e,
+                self.text()
+            )
+        })
+    }
+
+    /// Désérialise l'enveloppe `ApiResponse<T>`
+    #[track_caller]
+    pub fn api<T: DeserializeOwned>(&self) -> ApiResponse<T> {
+        self.json()
+    }
+
+    /// Vérifie `success: true` et retourne `data`
+    #[track_caller]
+    pub fn data<T: DeserializeOwned>(&self) -> T {
+        let response: ApiResponse<T> = self.assert_success().api();
+        response
+            .data
+            .unwrap_or_else(|| panic!("ApiResponse has no data\nbody: {}", self.text()))
+    }
+
+    #[track_caller]
+    pub fn assert_status(&self, expected: StatusCode) -> &Self {
+        assert_eq!(
+            self.status,
+            expected,
+            "unexpected status code\nbody: {}",
+            self.text()
+        );
+        self
+    }
+
+    /// Vérifie un statut 2xx
+    #[track_caller]
+    pub fn assert_ok(&self) -> &Self {
+        assert!(
+            self.status.is_success(),
+            "expected a 2xx status, got {}\nbody: {}",
+            self.status,
+            self.text()
+        );
+        self
+    }
+
+    /// Vérifie `success: true` dans l'enveloppe `ApiResponse`
+    #[track_caller]
+    pub fn assert_success(&self) -> &Self {
+        let response: ApiResponse<Value> = self.api();
+        assert!(
+            response.success,
+            "expected a successful ApiResponse (status {})\nbody: {}",
+            self.status,
+            self.text()
+        );
+        self
+    }
+
+    /// Vérifie `success: false` et un message d'erreur contenant `message`
+    #[track_caller]
+    pub fn assert_error(&self, message: &str) -> &Self {
+        let response: ApiResponse<Value> = self.api();
+        assert!(
+            !response.success,
+            "expected a failed ApiResponse (status {})\nbody: {}",
+            self.status,
+            self.text()
+        );
+        let error = response.error.unwrap_or_default();
+        assert!(
+            error.contains(message),
+            "expected error containing {:?}, got {:?}",
+            message,
+            error
+        );
+        self
+    }
+
+    /// Compare `data` à la valeur attendue (via sa représentation JSON)
+    #[track_caller]
+    pub fn assert_data<T: Serialize>(&self, expected: T) -> &Self {
+        let actual: Value = self.data();
+        let expected = serde_json::to_value(expected).expect("expected value is not serializable");
+        assert_eq!(actual, expected, "unexpected ApiResponse data");
+        self
+    }
+
+    /// Compare le corps JSON complet à la valeur attendue
+    #[track_caller]
+    pub fn assert_json<T: Serialize>(&self, expected: T) -> &Self {
+        let actual: Value = self.json();
+        let expected = serde_json::to_value(expected).expect("expected value is not serializable");
+        assert_eq!(actual, expected, "unexpected response body");
+        self
+    }
+}
@@ -0,0 +1,73 @@
+use super::factory::FactoryTarget;
+use super::insert_row::{insert_row, RowConnection};
+use crate::errors::AppResult;
+use crate::state::DatabaseConnection;
+use crate::utils::BoxFuture;
+use serde_json::{Map, Value};
+use sqlx::{MySql, Postgres, Sqlite, Transaction};
+use tokio::sync::{Mutex, MutexGuard};
+
+/// Transaction ouverte sur la base de test
+pub enum TransactionConnection {
+    Sqlite(Transaction<'static, Sqlite>),
+    Postgres(Transaction<'static, Postgres>),
+    Mysql(Transaction<'static, MySql>),
+}
+
+/// Transaction annulée à la fin du test (`rollback()` ou drop)
+///
+/// Les factories et les fixtures peuvent y insérer leurs lignes ; les requêtes
+/// sqlx passent par `connection()`.
+pub struct TestTransaction {
+    inner: Mutex<TransactionConnection>,
+}
+
+impl TestTransaction {
+    pub async fn begin(db: &DatabaseConnection) -> AppResult<Self> {
+        let inner = match db {
+            DatabaseConnection::Sqlite(pool) => TransactionConnection::Sqlite(pool.begin().await?),
+            DatabaseConnection::Postgres(pool) => {
+                TransactionConnection::Postgres(pool.begin().await?)
+            }
+            DatabaseConnection::Mysql(pool) => TransactionConnection::Mysql(pool.begin().await?),
+        };
+
+        Ok(Self {
+            inner: Mutex::new(inner),
+        })
+    }
+
+    /// Accès exclusif à la transaction (`&mut **tx` pour exécuter une requête)
+    pub async fn connection(&self) -> MutexGuard<'_, TransactionConnection> {
+        self.inner.lock().await
+    }
+
+    pub async fn rollback(self) -> AppResult<()> {
+        match self.inner.into_inner() {
+            TransactionConnection::Sqlite(tx) => tx.rollback().await?,
+            TransactionConnection::Postgres(tx) => tx.rollback().await?,
+            TransactionConnection::Mysql(tx) => tx.rollback().await?,
+        }
+
+        Ok(())
+    }
+}
+
+impl FactoryTarget for TestTransaction {
+    fn insert_row<'a>(
+        &'a self,
+        table: &'a str,
+        row: &'a Map<String, Value>,
+        returning: Option<&'a str>,
+    ) -> BoxFuture<'a, AppResult<Option<Value>>> {
+        Box::pin(async move {
+            let mut guard = self.inner.lock().await;
+            let conn = match &mut *guard {
+                TransactionConnection::Sqlite(tx) => RowConnection::Sqlite(tx),
+                TransactionConnection::Postgres(tx) => RowConnection::Postgres(tx),
+                TransactionConnection::Mysql(tx) => RowConnection::Mysql(tx),
+            };
+            insert_row(conn, table, row, returning).await
+        })
+    }
+}
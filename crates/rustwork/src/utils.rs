@@ -0,0 +1,7 @@
+//! Types partagés par les traits asynchrones du framework
+
+use std::future::Future;
+use std::pin::Pin;
+
+/// Future retournée par les traits implémentés par l'application (`Job`, `Seeder`, `Factory`)
+pub type BoxFuture<'a, T> = Pin<Box<dyn Future<Output = T> + Send + 'a>>;